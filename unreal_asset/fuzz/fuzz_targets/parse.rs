@@ -0,0 +1,26 @@
+//! Parses arbitrary bytes as an asset, malformed input must return an error instead of panicking
+
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use unreal_asset::{engine_version::EngineVersion, Asset};
+
+const ENGINE_VERSIONS: [EngineVersion; 4] = [
+    EngineVersion::VER_UE4_18,
+    EngineVersion::VER_UE4_23,
+    EngineVersion::VER_UE4_27,
+    EngineVersion::VER_UE5_1,
+];
+
+fuzz_target!(|data: &[u8]| {
+    let Some((engine_version, data)) = data.split_first() else {
+        return;
+    };
+    let engine_version = ENGINE_VERSIONS[*engine_version as usize % ENGINE_VERSIONS.len()];
+
+    if let Ok(asset) = Asset::new(Cursor::new(data), None, engine_version, None) {
+        let _ = asset.write_data(&mut Cursor::new(Vec::new()), None);
+    }
+});
@@ -0,0 +1,84 @@
+//! Parses and writes mutated test assets
+//!
+//! Unmodified test assets must be written back byte for byte,
+//! mutated assets that parse must be written the same way after being parsed again
+
+#![no_main]
+
+use std::io::Cursor;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+macro_rules! test_asset {
+    ($path:literal, $engine_version:expr) => {
+        (
+            include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../tests/assets/general/",
+                $path
+            ))
+            .as_slice(),
+            $engine_version,
+        )
+    };
+}
+
+const TEST_ASSETS: [(&[u8], EngineVersion); 4] = [
+    test_asset!(
+        "Astroneer_prebulk/Augment_BroadBrush.uasset",
+        EngineVersion::VER_UE4_23
+    ),
+    test_asset!(
+        "BloodStained/PB_DT_RandomizerRoomCheck.uasset",
+        EngineVersion::VER_UE4_18
+    ),
+    test_asset!(
+        "BloodStained/m01SIP_000_Gimmick.umap",
+        EngineVersion::VER_UE4_18
+    ),
+    test_asset!(
+        "Astroneer_prebulk/Augment_BroadBrush.uasset",
+        EngineVersion::VER_UE4_22
+    ),
+];
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    /// Index of the test asset
+    asset: u8,
+    /// Byte offsets and values to overwrite
+    mutations: Vec<(u32, u8)>,
+}
+
+fn write(asset: &Asset<Cursor<Vec<u8>>>) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    Ok(cursor.into_inner())
+}
+
+fuzz_target!(|input: Input| {
+    let (data, engine_version) = TEST_ASSETS[input.asset as usize % TEST_ASSETS.len()];
+
+    let mut data = data.to_vec();
+    for (offset, value) in &input.mutations {
+        let len = data.len();
+        data[*offset as usize % len] = *value;
+    }
+
+    let Ok(asset) = Asset::new(Cursor::new(data.clone()), None, engine_version, None) else {
+        return;
+    };
+    let Ok(written) = write(&asset) else {
+        return;
+    };
+    if input.mutations.is_empty() {
+        assert_eq!(written, data, "test asset didn't round trip");
+    }
+
+    let reparsed = Asset::new(Cursor::new(written.clone()), None, engine_version, None)
+        .expect("written asset failed to parse");
+    let rewritten = write(&reparsed).expect("reparsed asset failed to write");
+    assert_eq!(rewritten, written, "written asset didn't round trip");
+});
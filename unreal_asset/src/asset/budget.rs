@@ -0,0 +1,83 @@
+//! Limits on file-derived counts while parsing
+
+use std::io::{Read, Seek};
+use std::mem::size_of;
+
+use unreal_asset_base::{
+    error::Error, reader::ArchiveTrait, types::gatherable_text_data::GatherableTextData, Import,
+};
+use unreal_asset_exports::{Export, ExportBaseTrait};
+use unreal_asset_properties::object_property::SoftObjectPath;
+
+use super::Asset;
+
+impl<C: Read + Seek> Asset<C> {
+    /// Check the counts and export sizes read so far against the file size and the parse budget
+    ///
+    /// Every table entry takes at least 4 bytes, so counts the file can't hold are rejected
+    /// before anything is allocated for them
+    pub(super) fn check_parse_budget(&mut self) -> Result<(), Error> {
+        let data_length = self.data_length()?;
+        for (name, count) in [
+            ("name", self.name_count),
+            ("soft object path", self.soft_object_paths_count),
+            ("gatherable text data", self.gatherable_text_data_count),
+            ("import", self.import_count),
+            ("export", self.export_count),
+            ("soft package reference", self.soft_package_reference_count),
+        ] {
+            if count as i64 * 4 > data_length as i64 {
+                return Err(Error::invalid_file(format!(
+                    "{name} count {count} doesn't fit in {data_length} bytes"
+                )));
+            }
+        }
+        if self.name_count < 0 || self.import_count < 0 || self.export_count < 0 {
+            return Err(Error::invalid_file(format!(
+                "Negative counts, names: {}, imports: {}, exports: {}",
+                self.name_count, self.import_count, self.export_count
+            )));
+        }
+
+        if let Some(max_exports) = self.options.max_exports {
+            if self.export_count as usize > max_exports {
+                return Err(Error::invalid_file(format!(
+                    "Asset has {} exports, more than the maximum of {max_exports}",
+                    self.export_count
+                )));
+            }
+        }
+        if let Some(max_names) = self.options.max_names {
+            if self.name_count as usize > max_names {
+                return Err(Error::invalid_file(format!(
+                    "Asset has {} names, more than the maximum of {max_names}",
+                    self.name_count
+                )));
+            }
+        }
+
+        if let Some(max_allocation) = self.options.max_allocation {
+            let tables = self.name_count as u64 * size_of::<String>() as u64
+                + self.soft_object_paths_count.max(0) as u64 * size_of::<SoftObjectPath>() as u64
+                + self.gatherable_text_data_count.max(0) as u64
+                    * size_of::<GatherableTextData>() as u64
+                + self.import_count as u64 * size_of::<Import>() as u64
+                + self.export_count as u64 * size_of::<Export>() as u64
+                + self.soft_package_reference_count.max(0) as u64 * size_of::<String>() as u64;
+            let export_data = self
+                .asset_data
+                .exports
+                .iter()
+                .map(|e| e.get_base_export().serial_size.max(0) as u64)
+                .fold(0u64, u64::saturating_add);
+            let estimate = tables.saturating_add(export_data);
+            if estimate > max_allocation {
+                return Err(Error::invalid_file(format!(
+                    "Parsing would allocate about {estimate} bytes, more than the maximum of {max_allocation}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+//! Stripping editor only data and removing exports
+
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    engine_version::EngineVersion, error::Error, flags::EPackageFlags, types::PackageIndex,
+};
+use unreal_asset_exports::{Export, ExportBaseTrait};
+
+use super::remap::remap_export_indices;
+use super::Asset;
+
+impl<C: Read + Seek> Asset<C> {
+    /// Strip data that is only used by the editor
+    ///
+    /// This clears the thumbnail table, gatherable text data, the localization id, searchable names
+    /// and asset registry dependency data and marks the package as editor-only filtered
+    pub fn strip_editor_only_data(&mut self) {
+        if !self
+            .asset_data
+            .package_flags
+            .contains(EPackageFlags::PKG_FILTER_EDITOR_ONLY)
+        {
+            // editor packages store registry dependency data after the registry objects
+            self.registry_tail.clear();
+        }
+        self.thumbnail_table_offset = 0;
+        self.asset_data.thumbnail_table = None;
+        self.gatherable_text_data_count = 0;
+        self.gatherable_text_data_offset = 0;
+        self.asset_data.gatherable_text_data = None;
+        self.asset_data.localization_id = None;
+        self.searchable_names_offset = 0;
+        self.asset_data.package_flags |= EPackageFlags::PKG_FILTER_EDITOR_ONLY;
+    }
+
+    /// Convert this asset into a minimal cooked asset
+    ///
+    /// Strips editor-only data, retargets the asset to `target` and
+    /// switches to the event driven loader layout, so it must be written with a uexp cursor afterwards
+    ///
+    /// Editor-only exports and their subobjects are removed, see [`Asset::remove_exports`]
+    /// for how references to them are handled. If they can't be removed an error is returned
+    /// and the asset is left unchanged
+    pub fn cook(&mut self, target: EngineVersion) -> Result<(), Error> {
+        let editor_only = self
+            .asset_data
+            .exports
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                let base_export = e.get_base_export();
+                base_export.not_for_client && base_export.not_for_server
+            })
+            .map(|(i, _)| PackageIndex::from_export(i as i32))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.remove_exports(&editor_only)?;
+
+        self.set_engine_version(target);
+        self.strip_editor_only_data();
+        self.asset_data.use_event_driven_loader = true;
+
+        Ok(())
+    }
+
+    /// Remove exports along with every export they are the outer of
+    ///
+    /// The remaining exports are moved down and all references to them are remapped.
+    /// References to removed exports become null, null entries are dropped
+    /// from preload dependencies, struct children, function maps and the depends map.
+    /// Raw exports and raw kismet bytecode can't be remapped, an error is returned
+    /// if any are present and the asset is left unchanged
+    pub fn remove_exports(&mut self, indices: &[PackageIndex]) -> Result<(), Error> {
+        let export_count = self.asset_data.exports.len();
+        let mut removed = vec![false; export_count];
+        for index in indices {
+            match index.is_export() && index.index as usize <= export_count {
+                true => removed[index.index as usize - 1] = true,
+                false => {
+                    return Err(Error::invalid_package_index(format!(
+                        "Export {} does not exist",
+                        index.index
+                    )))
+                }
+            }
+        }
+
+        // subobjects are removed with their outer, outers can come after their subobjects
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, export) in self.asset_data.exports.iter().enumerate() {
+                let outer_index = export.get_base_export().outer_index;
+                if !removed[i]
+                    && outer_index.is_export()
+                    && removed
+                        .get(outer_index.index as usize - 1)
+                        .copied()
+                        .unwrap_or(false)
+                {
+                    removed[i] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        if !removed.contains(&true) {
+            return Ok(());
+        }
+
+        let mut new_indices = Vec::with_capacity(export_count);
+        let mut next_index = 1;
+        for removed in &removed {
+            match removed {
+                true => new_indices.push(0),
+                false => {
+                    new_indices.push(next_index);
+                    next_index += 1;
+                }
+            }
+        }
+        let mut remap = |index: PackageIndex| {
+            if !index.is_export() {
+                return Ok(index);
+            }
+            new_indices
+                .get(index.index as usize - 1)
+                .map(|e| PackageIndex::new(*e))
+                .ok_or_else(|| {
+                    Error::invalid_package_index(format!("Export {} does not exist", index.index))
+                })
+        };
+
+        // remap copies so the asset is left unchanged on failure
+        let mut exports = Vec::with_capacity(export_count);
+        for (export, _) in self
+            .asset_data
+            .exports
+            .iter()
+            .zip(&removed)
+            .filter(|(_, removed)| !**removed)
+        {
+            let mut export = export.clone();
+            remap_export_indices(&mut export, &mut remap)?;
+            remove_null_references(&mut export);
+            exports.push(export);
+        }
+
+        let mut imports = self.imports.clone();
+        for import in &mut imports {
+            import.outer_index = remap(import.outer_index)?;
+        }
+
+        let mut data_resources = self.asset_data.data_resources.clone();
+        for data_resource in data_resources.iter_mut().flatten() {
+            data_resource.outer_index = remap(data_resource.outer_index)?;
+        }
+
+        let depends_map = match &self.depends_map {
+            Some(depends_map) => {
+                let mut new_depends_map = Vec::with_capacity(exports.len());
+                for (dependencies, _) in depends_map
+                    .iter()
+                    .zip(&removed)
+                    .filter(|(_, removed)| !**removed)
+                {
+                    let mut new_dependencies = Vec::with_capacity(dependencies.len());
+                    for dependency in dependencies {
+                        let dependency = remap(PackageIndex::new(*dependency))?;
+                        if dependency.index != 0 {
+                            new_dependencies.push(dependency.index);
+                        }
+                    }
+                    new_depends_map.push(new_dependencies);
+                }
+                Some(new_depends_map)
+            }
+            None => None,
+        };
+
+        self.asset_data.exports = exports;
+        self.imports = imports;
+        self.asset_data.data_resources = data_resources;
+        self.depends_map = depends_map;
+        Ok(())
+    }
+}
+
+/// Drop null references left by removed exports from lists of an export
+fn remove_null_references(export: &mut Export) {
+    let base_export = export.get_base_export_mut();
+    for dependencies in [
+        &mut base_export.serialization_before_serialization_dependencies,
+        &mut base_export.create_before_serialization_dependencies,
+        &mut base_export.serialization_before_create_dependencies,
+        &mut base_export.create_before_create_dependencies,
+    ] {
+        dependencies.retain(|e| e.index != 0);
+    }
+
+    let struct_export = match export {
+        Export::ClassExport(class_export) => {
+            class_export.func_map.retain(|_, e| e.index != 0);
+            &mut class_export.struct_export
+        }
+        Export::StructExport(struct_export) => struct_export,
+        Export::FunctionExport(function_export) => &mut function_export.struct_export,
+        Export::UserDefinedStructExport(user_defined_struct_export) => {
+            &mut user_defined_struct_export.struct_export
+        }
+        _ => return,
+    };
+    struct_export.children.retain(|e| e.index != 0);
+}
@@ -0,0 +1,202 @@
+//! Copying exports and imports between assets
+
+use std::collections::{HashMap, HashSet};
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    error::Error,
+    reader::ArchiveTrait,
+    types::{fname::FNameContainer, FName, PackageIndex},
+    Import,
+};
+
+use super::remap::remap_export_indices;
+use super::Asset;
+
+impl<C: Read + Seek> Asset<C> {
+    /// Copy exports of another asset into this asset
+    ///
+    /// Imports and names used by the exports are copied as well. `PackageIndex` references of the
+    /// exports are remapped to this asset, including the children, function maps and kismet bytecode
+    /// of struct, class and function exports. Raw exports and raw kismet bytecode can't be remapped
+    /// and return an error, as does referencing an export of `donor` that isn't being merged.
+    /// The exports, imports and names of this asset are left unchanged if merging fails.
+    /// A warning is logged if merging makes the name map [unexpectedly large](unreal_asset_base::containers::NameMap::is_unexpectedly_large).
+    ///
+    /// Returns the new indices of the exports in the order of `indices`
+    pub fn merge_exports_from<D: Read + Seek>(
+        &mut self,
+        donor: &Asset<D>,
+        indices: &[PackageIndex],
+    ) -> Result<Vec<PackageIndex>, Error> {
+        let import_count = self.imports.len();
+        let export_count = self.asset_data.exports.len();
+        let name_map = self.name_map.get_ref().clone();
+        let merged = self
+            .merge_exports_from_inner(donor, indices)
+            .and_then(|merged| self.add_written_names().map(|_| merged));
+        if merged.is_err() {
+            self.imports.truncate(import_count);
+            self.asset_data.exports.truncate(export_count);
+            if let Some(depends_map) = self.depends_map.as_mut() {
+                depends_map.truncate(export_count);
+            }
+            *self.name_map.get_mut() = name_map;
+        } else if !name_map.is_unexpectedly_large()
+            && self.name_map.get_ref().is_unexpectedly_large()
+        {
+            log::warn!(
+                "Name map grew from {} to {} names after merging exports",
+                name_map.len(),
+                self.name_map.get_ref().len()
+            );
+        }
+        merged
+    }
+
+    /// Deep copy an export of another asset into this asset
+    ///
+    /// Exports of `source` referenced by the export are copied along with it, as are exports
+    /// referenced by those, cyclic references are copied once.
+    /// See [`Asset::merge_exports_from`] for how imports, names and references are copied.
+    ///
+    /// Returns the new index of the export
+    pub fn import_export<D: Read + Seek>(
+        &mut self,
+        source: &Asset<D>,
+        index: PackageIndex,
+    ) -> Result<PackageIndex, Error> {
+        let mut indices = vec![index];
+        let mut visited = HashSet::from([index.index]);
+        let mut i = 0;
+        while i < indices.len() {
+            let mut export = source
+                .get_export(indices[i])
+                .ok_or_else(|| {
+                    Error::invalid_package_index(format!(
+                        "Export {} does not exist in the source asset",
+                        indices[i].index
+                    ))
+                })?
+                .clone();
+
+            remap_export_indices(&mut export, &mut |reference| {
+                if reference.is_export() && visited.insert(reference.index) {
+                    indices.push(reference);
+                }
+                Ok(reference)
+            })?;
+            i += 1;
+        }
+
+        let merged = self.merge_exports_from(source, &indices)?;
+        Ok(merged[0])
+    }
+
+    /// Copy exports of another asset into this asset, see [`Asset::merge_exports_from`]
+    fn merge_exports_from_inner<D: Read + Seek>(
+        &mut self,
+        donor: &Asset<D>,
+        indices: &[PackageIndex],
+    ) -> Result<Vec<PackageIndex>, Error> {
+        let mut export_map = HashMap::with_capacity(indices.len());
+        let mut exports = Vec::with_capacity(indices.len());
+        for (i, index) in indices.iter().enumerate() {
+            let export = donor.get_export(*index).ok_or_else(|| {
+                Error::invalid_package_index(format!(
+                    "Export {} does not exist in the donor asset",
+                    index.index
+                ))
+            })?;
+
+            let new_index = PackageIndex::new((self.asset_data.exports.len() + i) as i32 + 1);
+            if export_map.insert(index.index, new_index).is_some() {
+                return Err(Error::invalid_package_index(format!(
+                    "Export {} is merged more than once",
+                    index.index
+                )));
+            }
+            exports.push(export.clone());
+        }
+
+        let mut name_map = self.name_map.clone();
+        for export in &mut exports {
+            remap_export_indices(export, &mut |index| {
+                self.merge_index_from(donor, index, &export_map)
+            })?;
+
+            export.traverse_fnames(&mut |name| {
+                let content = name.get_owned_content();
+                let FName::Backed {
+                    index,
+                    name_map: fname_name_map,
+                    ..
+                } = name
+                else {
+                    return;
+                };
+
+                *index = name_map.get_mut().add_name_reference(content, false);
+                *fname_name_map = name_map.clone();
+            });
+        }
+
+        self.asset_data.exports.extend(exports);
+        if self.depends_map.is_some() {
+            self.ensure_depends_map();
+        }
+
+        Ok(indices.iter().map(|e| export_map[&e.index]).collect())
+    }
+
+    /// Map a `PackageIndex` of `donor` to this asset, copying imports as needed
+    fn merge_index_from<D: Read + Seek>(
+        &mut self,
+        donor: &Asset<D>,
+        index: PackageIndex,
+        export_map: &HashMap<i32, PackageIndex>,
+    ) -> Result<PackageIndex, Error> {
+        if index.is_export() {
+            return export_map.get(&index.index).copied().ok_or_else(|| {
+                Error::invalid_package_index(format!(
+                    "Export {} of the donor asset is referenced but not merged",
+                    index.index
+                ))
+            });
+        }
+
+        if !index.is_import() {
+            return Ok(index);
+        }
+
+        let import = donor.get_import(index).ok_or_else(|| {
+            Error::invalid_package_index(format!(
+                "Import {} does not exist in the donor asset",
+                index.index
+            ))
+        })?;
+        let outer_index = self.merge_index_from(donor, import.outer_index, export_map)?;
+
+        let mut copy_fname = |name: &FName| {
+            name.get_content(|content| {
+                self.name_map
+                    .get_mut()
+                    .add_fname_with_number(content, name.get_number())
+            })
+        };
+        let class_package = copy_fname(&import.class_package);
+        let class_name = copy_fname(&import.class_name);
+        let object_name = copy_fname(&import.object_name);
+
+        match self.find_import(&class_package, &class_name, outer_index, &object_name) {
+            Some(index) => Ok(PackageIndex::new(index)),
+            None => Ok(self.add_import(Import::new(
+                class_package,
+                class_name,
+                outer_index,
+                object_name,
+                import.optional,
+            ))),
+        }
+    }
+}
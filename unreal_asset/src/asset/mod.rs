@@ -0,0 +1,3266 @@
+//! Main [`Asset`] type
+
+#[cfg(feature = "serde")]
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::mem::size_of;
+use std::ops::Range;
+
+use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
+
+use unreal_asset_base::{
+    cast,
+    containers::{name_map::NameMapIter, Chain, IndexedMap, NameMap, SharedResource},
+    crc,
+    custom_version::{CustomVersion, CustomVersionTrait},
+    engine_version::{guess_engine_version, EngineVersion},
+    enums::ECustomVersionSerializationFormat,
+    error::Error,
+    flags::EPackageFlags,
+    import::ImportHandle,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::{
+        archive_reader::DEFAULT_MAX_PROPERTY_DEPTH, ArchiveReader, ArchiveTrait, ArchiveType,
+        ArchiveWriter, PassthroughArchiveReader, RawReader, RawWriter,
+    },
+    types::{
+        bulk_data::FByteBulkData, fname::FNameContainer, gatherable_text_data::GatherableTextData,
+        native_struct_layout::NativeStructLayout, object_data_resource::FObjectDataResource,
+        thumbnail_table::ThumbnailTable, FName, GenerationInfo, PackageIndex,
+    },
+    unversioned::{Ancestry, Usmap},
+    FNameContainer, Guid, Import,
+};
+use unreal_asset_exports::{BaseExport, Export, ExportBaseTrait, ExportNormalTrait, ExportTrait};
+use unreal_asset_properties::object_property::SoftObjectPath;
+use unreal_asset_properties::property_tag::PropertyTag;
+use unreal_asset_properties::world_tile_property::FWorldTileInfo;
+use unreal_asset_properties::{Property, PropertyDataTrait};
+
+use crate::asset_archive_writer::AssetArchiveWriter;
+use crate::asset_data::{
+    serial_size_overrun, AssetData, AssetTrait, AssetWarning, AssetWarningKind, ExportReaderTrait,
+    StructTypeOverrides,
+};
+use crate::asset_registry_data::AssetRegistryData;
+use crate::fengineversion::FEngineVersion;
+use crate::section_layout::{HeaderSection, LayoutIssue, SectionLayout};
+use crate::stream_writer::{CountingWriter, StreamWriter};
+use crate::{PACKAGE_FILE_TAG_SIZE, UE4_ASSET_MAGIC};
+
+mod budget;
+mod cook;
+mod merge;
+mod paths;
+mod remap;
+
+/// Parent Class Info
+#[derive(FNameContainer, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParentClassInfo {
+    /// Parent classpath
+    pub parent_class_path: FName,
+    /// Parent class export name
+    pub parent_class_export_name: FName,
+}
+
+/// Object referenced by a `PackageIndex`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolvedReference<'a> {
+    /// Null reference
+    Null,
+    /// Import
+    Import(&'a Import),
+    /// Export
+    Export(&'a Export),
+}
+
+/// Options used when parsing an asset
+#[derive(Debug, Clone)]
+pub struct AssetOptions {
+    /// Record the byte range of every versioned property into [`Asset::property_offsets`]
+    pub track_property_offsets: bool,
+    /// Check that the package tag is present at the bulk data start offset after parsing,
+    /// used to detect truncated files
+    pub verify_tail_magic: bool,
+    /// Fail with [`Error::ExportParse`] instead of reading exports that failed to parse as a `RawExport`
+    pub strict_exports: bool,
+    /// Keep the raw script bytecode of struct exports even when it was parsed successfully,
+    /// see [`StructExport::raw_bytecode`](crate::exports::StructExport::raw_bytecode)
+    pub retain_raw_bytecode: bool,
+    /// Struct type overrides to parse with instead of the built-in ones,
+    /// e.g. overrides saved from [`AssetData::get_struct_type_overrides`] of a previous parse
+    pub struct_type_overrides: Option<StructTypeOverrides>,
+    /// Layouts of natively serialized structs to parse with,
+    /// see [`AssetData::native_struct_layouts`]
+    pub native_struct_layouts: IndexedMap<String, NativeStructLayout>,
+    /// Maximum nesting depth of properties, deeper properties fail to parse
+    ///
+    /// This protects against stack overflows when parsing untrusted assets,
+    /// note that debug builds need a lot more stack per nesting level
+    pub max_property_depth: usize,
+    /// Maximum amount of exports an asset may declare, assets declaring more fail to parse
+    pub max_exports: Option<usize>,
+    /// Maximum amount of names an asset may declare, assets declaring more fail to parse
+    pub max_names: Option<usize>,
+    /// Maximum amount of bytes parsing may allocate for the header tables and export data
+    ///
+    /// This is estimated from the counts in the package summary and the export sizes in the export map,
+    /// so it is checked before anything is allocated for them
+    pub max_allocation: Option<u64>,
+}
+
+impl Default for AssetOptions {
+    fn default() -> Self {
+        AssetOptions {
+            track_property_offsets: false,
+            verify_tail_magic: false,
+            strict_exports: false,
+            retain_raw_bytecode: false,
+            struct_type_overrides: None,
+            native_struct_layouts: IndexedMap::new(),
+            max_property_depth: DEFAULT_MAX_PROPERTY_DEPTH,
+            max_exports: None,
+            max_names: None,
+            max_allocation: None,
+        }
+    }
+}
+
+/// Asset header
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AssetHeader {
+    /// Name map offset
+    name_offset: i32,
+    /// Soft object path list count
+    soft_object_paths_count: i32,
+    /// Soft object path list offset
+    soft_object_paths_offset: i32,
+    /// Gatherable text data count
+    gatherable_text_data_count: i32,
+    /// Gatherable text data offset
+    gatherable_text_data_offset: i32,
+    /// Imports offset
+    import_offset: i32,
+    /// Exports offset
+    export_offset: i32,
+    /// Dependencies offset
+    depends_offset: i32,
+    /// Soft package references offset
+    soft_package_reference_offset: i32,
+    /// Thumbnail table offset
+    thumbnail_table_offset: i32,
+    /// Asset registry data offset
+    asset_registry_data_offset: i32,
+    /// World tile info offset
+    world_tile_info_offset: i32,
+    /// Preload dependency count
+    preload_dependency_count: i32,
+    /// Preload dependency offset
+    preload_dependency_offset: i32,
+    /// Data resource offset
+    data_resource_offset: i32,
+    /// Header offset
+    header_offset: i32,
+    /// Bulk data start offset
+    bulk_data_start_offset: i64,
+}
+
+/// Offsets computed while writing an asset
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WrittenLayout {
+    /// Asset header
+    header: AssetHeader,
+    /// Start offset of every export
+    export_starts: Vec<u64>,
+}
+
+impl WrittenLayout {
+    /// Write the export map with the serial offsets and sizes of the written exports
+    fn write_export_map<Writer: ArchiveWriter>(
+        &self,
+        writer: &mut Writer,
+        asset_data: &AssetData,
+    ) -> Result<(), Error> {
+        let mut first_export_dependency_offset = 0;
+        for (i, export) in asset_data.exports.iter().enumerate() {
+            let unk = export.get_base_export();
+            let next_loc = match self.export_starts.get(i + 1) {
+                Some(next) => *next as i64,
+                None => self.header.bulk_data_start_offset,
+            };
+            unk.write_export_map_entry(
+                writer,
+                next_loc - self.export_starts[i] as i64,
+                self.export_starts[i] as i64,
+                match asset_data.use_event_driven_loader {
+                    true => first_export_dependency_offset,
+                    false => -1,
+                },
+            )?;
+            first_export_dependency_offset +=
+                (unk.serialization_before_serialization_dependencies.len()
+                    + unk.create_before_serialization_dependencies.len()
+                    + unk.serialization_before_create_dependencies.len()
+                    + unk.create_before_create_dependencies.len()) as i32;
+        }
+        Ok(())
+    }
+}
+
+//#[derive(Debug)]
+/// Unreal Engine uasset
+#[derive(FNameContainer)]
+pub struct Asset<C: Read + Seek> {
+    /// Raw reader
+    #[container_ignore]
+    pub raw_reader: RawReader<C>,
+    // parsed data
+    /// Asset info
+    pub info: String,
+    /// Asset data
+    pub asset_data: AssetData,
+    /// Legacy file version
+    pub legacy_file_version: i32,
+
+    // imports
+    // exports
+    // depends map
+    // soft package reference list
+    // asset registry data
+    // world tile info
+    // preload dependencies
+    /// Generations
+    #[container_ignore]
+    pub generations: Vec<GenerationInfo>,
+    /// Asset guid
+    pub package_guid: Guid,
+    /// Recorded engine version
+    #[container_ignore]
+    pub engine_version_recorded: FEngineVersion,
+    /// Compatible engine version
+    #[container_ignore]
+    pub engine_version_compatible: FEngineVersion,
+    /// Chunk ids
+    chunk_ids: Vec<i32>,
+    /// Asset source
+    pub package_source: u32,
+    /// Folder name
+    pub folder_name: String,
+
+    // map struct type override
+    // override name map hashes
+    // todo: isn't this just AssetHeader?
+    /// Header offset
+    header_offset: i32,
+    /// Name count
+    name_count: i32,
+    /// Name offset
+    name_offset: i32,
+    /// Soft object path list count
+    soft_object_paths_count: i32,
+    /// Soft object path list offset
+    soft_object_paths_offset: i32,
+    /// Gatherable text data count
+    gatherable_text_data_count: i32,
+    /// Gatherable text data offset
+    gatherable_text_data_offset: i32,
+    /// Export count
+    export_count: i32,
+    /// Exports offset
+    export_offset: i32,
+    /// Import count
+    import_count: i32,
+    /// Imports offset
+    import_offset: i32,
+    /// Depends offset
+    depends_offset: i32,
+    /// Soft package reference count
+    soft_package_reference_count: i32,
+    /// Soft package reference offset
+    soft_package_reference_offset: i32,
+    /// Searchable names offset
+    searchable_names_offset: i32,
+    /// Thumbnail table offset
+    thumbnail_table_offset: i32,
+    /// Compression flags
+    compression_flags: u32,
+    /// Asset registry data offset
+    asset_registry_data_offset: i32,
+    /// Bulk data start offset
+    pub bulk_data_start_offset: i64,
+    /// World tile info offset
+    world_tile_info_offset: i32,
+    /// Preload dependency count
+    preload_dependency_count: i32,
+    /// Preload dependency offset
+    preload_dependency_offset: i32,
+    /// Amount of names referenced from exports
+    names_referenced_from_export_data_count: i32,
+    /// TOC payload offset
+    payload_toc_offset: i64,
+    /// Data resource offset
+    data_resource_offset: i32,
+
+    /// Overriden name map hashes
+    #[container_ignore]
+    pub override_name_map_hashes: IndexedMap<String, u32>,
+    /// Name map
+    #[container_ignore]
+    name_map: SharedResource<NameMap>,
+    /// Imports
+    pub imports: Vec<Import>,
+    /// Depends map
+    #[container_ignore]
+    depends_map: Option<Vec<Vec<i32>>>,
+    /// Soft package reference list
+    #[container_ignore]
+    soft_package_reference_list: Option<Vec<String>>,
+    /// Opaque bytes following the asset registry objects up to the next section
+    ///
+    /// These include any trailing dependency data,
+    /// they are written back as-is to keep the round-trip byte-exact
+    #[container_ignore]
+    pub registry_tail: Vec<u8>,
+
+    /// Parent class
+    parent_class: Option<ParentClassInfo>,
+
+    /// Options this asset was parsed with
+    #[container_ignore]
+    options: AssetOptions,
+    /// Byte ranges of properties keyed by export and property path
+    ///
+    /// Only filled when parsed with [`AssetOptions::track_property_offsets`],
+    /// each range covers the property tag and value.
+    ///
+    /// Paths are dot separated property names, e.g. `RelativeLocation` or `Struct.Inner[1]`,
+    /// array elements are addressed by their index, e.g. `Array.0` or `Array.0.Inner`
+    #[container_ignore]
+    pub property_offsets: HashMap<(PackageIndex, String), Range<u64>>,
+    /// Warnings emitted while reading exports
+    ///
+    /// Exports that failed to parse were read as a `RawExport` instead
+    #[container_ignore]
+    pub warnings: Vec<AssetWarning>,
+    /// Sorted serial offsets of the exports in the export map, used to find where an export ends
+    #[container_ignore]
+    export_serial_offsets: Vec<i64>,
+    /// Whether the name map is shared with other assets, see [`Asset::new_with_name_map`]
+    #[container_ignore]
+    shares_name_map: bool,
+}
+
+impl<'a, C: Read + Seek> Asset<C> {
+    /// Create an asset from a binary file
+    pub fn new(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Result<Self, Error> {
+        Self::new_with_options(
+            asset_data,
+            bulk_data,
+            engine_version,
+            mappings,
+            AssetOptions::default(),
+        )
+    }
+
+    /// Create an asset from a binary file which uses a shared name map
+    ///
+    /// All names of this asset get added to `name_map`, which can be shared between many assets
+    /// to deduplicate name strings.
+    /// Writing such an asset only writes the names it uses, they are copied to a new name map
+    /// on every write, use [`Asset::detach_name_map`] to move the asset to its own name map once.
+    ///
+    /// Returns an error if an export couldn't be parsed or has raw kismet bytecode,
+    /// the name indices in their raw data can't be renumbered
+    pub fn new_with_name_map(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        mut name_map: SharedResource<NameMap>,
+    ) -> Result<Self, Error> {
+        let mut asset = Self::new(asset_data, bulk_data, engine_version, mappings)?;
+
+        // keep names which aren't referenced by any FName, they are still needed for writing
+        for name in asset.name_map.get_ref().get_name_map_index_list() {
+            name_map.get_mut().add_name_reference(name.clone(), false);
+        }
+
+        asset.move_to_name_map(name_map)?;
+        asset.shares_name_map = true;
+        Ok(asset)
+    }
+
+    /// Create an asset from a binary file with parsing options
+    pub fn new_with_options(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        options: AssetOptions,
+    ) -> Result<Self, Error> {
+        let mut asset = Self::new_unparsed(asset_data, bulk_data, mappings, options);
+        asset.set_engine_version(engine_version);
+        asset.parse_data()?;
+        Ok(asset)
+    }
+
+    /// Create an asset from a binary file without parsing export data
+    ///
+    /// Only the package summary, the import map and the export map are parsed,
+    /// exports stay `BaseExport`s until they are parsed with [`Asset::parse_export`].
+    /// This is a lot faster for large assets when only a few exports are needed.
+    ///
+    /// Exports that weren't parsed are written without their data
+    pub fn new_lazy(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Result<Self, Error> {
+        Self::new_lazy_with_options(
+            asset_data,
+            bulk_data,
+            engine_version,
+            mappings,
+            AssetOptions::default(),
+        )
+    }
+
+    /// Create an asset from a binary file without parsing export data with parsing options
+    ///
+    /// See [`Asset::new_lazy`], the options are also used by [`Asset::parse_export`]
+    pub fn new_lazy_with_options(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        options: AssetOptions,
+    ) -> Result<Self, Error> {
+        let mut asset = Self::new_unparsed(asset_data, bulk_data, mappings, options);
+        asset.set_engine_version(engine_version);
+        asset.parse_header_data()?;
+        Ok(asset)
+    }
+
+    /// Create an asset from a binary file with exact object and custom versions
+    ///
+    /// Unlike [`Asset::new`] this doesn't map an [`EngineVersion`] to versions,
+    /// which is useful for unversioned assets of games with a serialization combination
+    /// no engine version describes, e.g. versions taken from a sibling versioned asset
+    pub fn new_with_versions(
+        asset_data: C,
+        bulk_data: Option<C>,
+        object_version: ObjectVersion,
+        object_version_ue5: ObjectVersionUE5,
+        custom_versions: Vec<CustomVersion>,
+        mappings: Option<Usmap>,
+    ) -> Result<Self, Error> {
+        Self::new_with_versions_and_options(
+            asset_data,
+            bulk_data,
+            object_version,
+            object_version_ue5,
+            custom_versions,
+            mappings,
+            AssetOptions::default(),
+        )
+    }
+
+    /// Create an asset from a binary file with exact object and custom versions and parsing options
+    ///
+    /// See [`Asset::new_with_versions`]
+    pub fn new_with_versions_and_options(
+        asset_data: C,
+        bulk_data: Option<C>,
+        object_version: ObjectVersion,
+        object_version_ue5: ObjectVersionUE5,
+        custom_versions: Vec<CustomVersion>,
+        mappings: Option<Usmap>,
+        options: AssetOptions,
+    ) -> Result<Self, Error> {
+        let mut asset = Self::new_unparsed(asset_data, bulk_data, mappings, options);
+        asset.asset_data.engine_version =
+            guess_engine_version(object_version, object_version_ue5, &custom_versions);
+        asset.asset_data.object_version = object_version;
+        asset.asset_data.object_version_ue5 = object_version_ue5;
+        asset.asset_data.custom_versions = custom_versions;
+        asset.raw_reader.object_version = object_version;
+        asset.raw_reader.object_version_ue5 = object_version_ue5;
+        asset.parse_data()?;
+        Ok(asset)
+    }
+
+    /// Create an asset without parsing any data
+    fn new_unparsed(
+        asset_data: C,
+        bulk_data: Option<C>,
+        mappings: Option<Usmap>,
+        options: AssetOptions,
+    ) -> Self {
+        let use_event_driven_loader = bulk_data.is_some();
+
+        let chain = Chain::new(asset_data, bulk_data);
+        let name_map = NameMap::new();
+        let mut raw_reader = RawReader::new(
+            chain,
+            ObjectVersion::UNKNOWN,
+            ObjectVersionUE5::UNKNOWN,
+            use_event_driven_loader,
+            name_map.clone(),
+        );
+        raw_reader.max_property_depth = options.max_property_depth;
+
+        let mut asset = Asset {
+            raw_reader,
+            info: String::from("Serialized with unrealmodding/uasset"),
+            asset_data: AssetData {
+                use_event_driven_loader,
+                ..Default::default()
+            },
+            legacy_file_version: 0,
+            generations: Vec::new(),
+            package_guid: Guid::default(),
+            engine_version_recorded: FEngineVersion::unknown(),
+            engine_version_compatible: FEngineVersion::unknown(),
+            chunk_ids: Vec::new(),
+            package_source: 0,
+            folder_name: String::from(""),
+            header_offset: 0,
+            name_count: 0,
+            name_offset: 0,
+            soft_object_paths_count: 0,
+            soft_object_paths_offset: 0,
+            gatherable_text_data_count: 0,
+            gatherable_text_data_offset: 0,
+            export_count: 0,
+            export_offset: 0,
+            import_count: 0,
+            import_offset: 0,
+            depends_offset: 0,
+            soft_package_reference_count: 0,
+            soft_package_reference_offset: 0,
+            searchable_names_offset: 0,
+            thumbnail_table_offset: 0,
+            compression_flags: 0,
+            asset_registry_data_offset: 0,
+            bulk_data_start_offset: 0,
+            world_tile_info_offset: 0,
+            preload_dependency_count: 0,
+            preload_dependency_offset: 0,
+            names_referenced_from_export_data_count: 0,
+            payload_toc_offset: 0,
+            data_resource_offset: 0,
+
+            override_name_map_hashes: IndexedMap::new(),
+            name_map,
+            imports: Vec::new(),
+            depends_map: None,
+            soft_package_reference_list: None,
+            registry_tail: Vec::new(),
+            parent_class: None,
+
+            options,
+            property_offsets: HashMap::new(),
+            warnings: Vec::new(),
+            export_serial_offsets: Vec::new(),
+            shares_name_map: false,
+        };
+        asset.asset_data.mappings = mappings;
+        if let Some(overrides) = asset.options.struct_type_overrides.clone() {
+            asset.asset_data.set_struct_type_overrides(overrides);
+        }
+        asset.asset_data.native_struct_layouts = asset.options.native_struct_layouts.clone();
+        asset
+    }
+
+    /// Create an empty versioned asset that uses `name_map`, used by [`AssetBuilder`](crate::asset_builder::AssetBuilder)
+    ///
+    /// Header offsets are left at zero, they are computed when the asset is written
+    pub(crate) fn new_empty(
+        asset_data: C,
+        engine_version: EngineVersion,
+        name_map: SharedResource<NameMap>,
+    ) -> Self {
+        let mut asset = Self::new_unparsed(asset_data, None, None, AssetOptions::default());
+        asset.set_engine_version(engine_version);
+        asset.asset_data.unversioned = false;
+        asset.legacy_file_version =
+            match asset.asset_data.object_version_ue5 > ObjectVersionUE5::UNKNOWN {
+                true => -8,
+                false => -7,
+            };
+        asset.raw_reader.name_map = name_map.clone();
+        asset.name_map = name_map;
+        asset
+    }
+
+    /// Set asset engine version
+    fn set_engine_version(&mut self, engine_version: EngineVersion) {
+        self.asset_data.set_engine_version(engine_version);
+        self.raw_reader.object_version = self.asset_data.object_version;
+        self.raw_reader.object_version_ue5 = self.asset_data.object_version_ue5;
+    }
+
+    /// Parse asset header
+    fn parse_header(&mut self) -> Result<(), Error> {
+        // reuseable buffers for reading
+
+        // seek to start
+        self.seek(SeekFrom::Start(0))?;
+
+        // read and check magic
+        if self.read_u32::<BE>()? != UE4_ASSET_MAGIC {
+            return Err(Error::invalid_file(
+                "File is not a valid uasset file".to_string(),
+            ));
+        }
+
+        // read legacy version
+        self.legacy_file_version = self.read_i32::<LE>()?;
+        if self.legacy_file_version != -4 {
+            // LegacyUE3Version for backwards-compatibility with UE3 games: usually 864 in versioned assets, 0 in unversioned assets
+            self.asset_data.legacy_ue3_version = Some(self.read_i32::<LE>()?);
+        }
+
+        // read unreal version
+        let file_version = self.read_i32::<LE>()?.try_into()?;
+
+        self.asset_data.unversioned = file_version == ObjectVersion::UNKNOWN;
+
+        if self.asset_data.unversioned {
+            if self.asset_data.object_version == ObjectVersion::UNKNOWN {
+                return Err(Error::NoEngineVersion);
+            }
+        } else {
+            self.asset_data.object_version = file_version;
+        }
+
+        if self.legacy_file_version <= -8 {
+            let object_version_ue5: ObjectVersionUE5 = self.read_i32::<LE>()?.try_into()?;
+            if object_version_ue5 > ObjectVersionUE5::UNKNOWN {
+                self.asset_data.object_version_ue5 = object_version_ue5;
+            }
+        }
+
+        if self.asset_data.object_version_ue5 == ObjectVersionUE5::UNKNOWN {
+            let mappings_version = self
+                .get_mappings()
+                .map(|e| e.object_version_ue5)
+                .unwrap_or(ObjectVersionUE5::UNKNOWN);
+            if mappings_version > ObjectVersionUE5::UNKNOWN {
+                self.asset_data.object_version_ue5 = mappings_version;
+            }
+        }
+
+        // read file license version
+        self.asset_data.file_license_version = self.read_i32::<LE>()?;
+
+        // read custom versions container
+        if self.legacy_file_version <= -2 {
+            // TODO: support for enum-based custom versions
+            // versioned assets list every custom version they were serialized with,
+            // anything missing from the container was never used and must not be
+            // filled in from the engine version defaults
+            let old_container = match self.asset_data.unversioned {
+                true => Some(self.asset_data.custom_versions.clone()),
+                false => None,
+            };
+            self.asset_data.custom_versions = self.read_custom_version_container(
+                self.get_custom_version_serialization_format(),
+                old_container.as_deref(),
+            )?;
+        }
+
+        // read header offset
+        self.header_offset = self.read_i32::<LE>()?;
+
+        // read folder name
+        self.folder_name = self
+            .read_fstring()?
+            .ok_or_else(|| Error::no_data("folder_name is None".to_string()))?;
+
+        // read package flags
+        self.asset_data.package_flags = EPackageFlags::from_bits(self.read_u32::<LE>()?)
+            .ok_or_else(|| Error::invalid_file("Invalid package flags".to_string()))?;
+
+        // read name count and offset
+        self.name_count = self.read_i32::<LE>()?;
+        self.name_offset = self.read_i32::<LE>()?;
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST {
+            self.soft_object_paths_count = self.read_i32::<LE>()?;
+            self.soft_object_paths_offset = self.read_i32::<LE>()?;
+        }
+
+        // read localization id
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
+            && !self
+                .asset_data
+                .package_flags
+                .contains(EPackageFlags::PKG_FILTER_EDITOR_ONLY)
+        {
+            self.asset_data.localization_id = self.read_fstring()?;
+        }
+
+        // read text gatherable data
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_SERIALIZE_TEXT_IN_PACKAGES {
+            self.gatherable_text_data_count = self.read_i32::<LE>()?;
+            self.gatherable_text_data_offset = self.read_i32::<LE>()?;
+        }
+
+        // read count and offset for exports, imports, depends, soft package references, searchable names, thumbnail table
+        self.export_count = self.read_i32::<LE>()?;
+        self.export_offset = self.read_i32::<LE>()?;
+        self.import_count = self.read_i32::<LE>()?;
+        self.import_offset = self.read_i32::<LE>()?;
+        self.depends_offset = self.read_i32::<LE>()?;
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP
+        {
+            self.soft_package_reference_count = self.read_i32::<LE>()?;
+            self.soft_package_reference_offset = self.read_i32::<LE>()?;
+        }
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADDED_SEARCHABLE_NAMES {
+            self.searchable_names_offset = self.read_i32::<LE>()?;
+        }
+        self.thumbnail_table_offset = self.read_i32::<LE>()?;
+
+        // read guid
+        self.package_guid = self.raw_reader.read_guid()?;
+
+        // read generations
+        let generations_count = self.read_i32::<LE>()?;
+        for _ in 0..generations_count {
+            let export_count = self.read_i32::<LE>()?;
+            let name_count = self.read_i32::<LE>()?;
+            self.generations.push(GenerationInfo {
+                export_count,
+                name_count,
+            });
+        }
+
+        // read advanced engine version
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_ENGINE_VERSION_OBJECT {
+            self.engine_version_recorded = FEngineVersion::read(self)?;
+        } else {
+            self.engine_version_recorded =
+                FEngineVersion::new(4, 0, 0, self.read_u32::<LE>()?, None);
+        }
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_PACKAGE_SUMMARY_HAS_COMPATIBLE_ENGINE_VERSION
+        {
+            self.engine_version_compatible = FEngineVersion::read(self)?;
+        } else {
+            self.engine_version_compatible = self.engine_version_recorded.clone();
+        }
+
+        // read compression data
+        self.compression_flags = self.read_u32::<LE>()?;
+        let compression_block_count = self.read_u32::<LE>()?;
+        if compression_block_count > 0 {
+            return Err(Error::invalid_file(
+                "Compression block count is not zero".to_string(),
+            ));
+        }
+
+        self.package_source = self.read_u32::<LE>()?;
+
+        // some other old unsupported stuff
+        let additional_to_cook = self.read_i32::<LE>()?;
+        if additional_to_cook != 0 {
+            return Err(Error::invalid_file(
+                "Additional to cook is not zero".to_string(),
+            ));
+        }
+        if self.legacy_file_version > -7 {
+            let texture_allocations_count = self.read_i32::<LE>()?;
+            if texture_allocations_count != 0 {
+                return Err(Error::invalid_file(
+                    "Texture allocations count is not zero".to_string(),
+                ));
+            }
+        }
+
+        self.asset_registry_data_offset = self.read_i32::<LE>()?;
+        self.bulk_data_start_offset = self.read_i64::<LE>()?;
+
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_WORLD_LEVEL_INFO {
+            self.world_tile_info_offset = self.read_i32::<LE>()?;
+        }
+
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_CHANGED_CHUNKID_TO_BE_AN_ARRAY_OF_CHUNKIDS
+        {
+            let chunk_id_count = self.read_i32::<LE>()?;
+
+            for _ in 0..chunk_id_count {
+                let chunk_id = self.read_i32::<LE>()?;
+                self.chunk_ids.push(chunk_id);
+            }
+        } else if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE
+        {
+            self.chunk_ids = vec![self.read_i32::<LE>()?];
+        }
+
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS
+        {
+            self.preload_dependency_count = self.read_i32::<LE>()?;
+            self.preload_dependency_offset = self.read_i32::<LE>()?;
+        }
+
+        self.names_referenced_from_export_data_count = match self.get_object_version_ue5()
+            >= ObjectVersionUE5::NAMES_REFERENCED_FROM_EXPORT_DATA
+        {
+            true => self.read_i32::<LE>()?,
+            false => self.name_count,
+        };
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::PAYLOAD_TOC {
+            self.payload_toc_offset = self.read_i64::<LE>()?;
+        }
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::DATA_RESOURCES {
+            self.data_resource_offset = self.read_i32::<LE>()?;
+        }
+
+        Ok(())
+    }
+
+    /// Get name map
+    /// This method should be used if you want to mutate the namemap
+    ///
+    /// # Panics
+    ///
+    /// If the name map is borrowed mutably and you try to write the asset, the lib will panic
+    pub fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    /// Search an FName reference
+    pub fn search_name_reference(&self, name: &str) -> Option<i32> {
+        self.name_map.get_ref().search_name_reference(name)
+    }
+
+    /// Add an FName reference
+    pub fn add_name_reference(&mut self, name: String, force_add_duplicates: bool) -> i32 {
+        self.name_map
+            .get_mut()
+            .add_name_reference(name, force_add_duplicates)
+    }
+
+    /// Get a name reference by an FName map index
+    pub fn get_name_reference<T>(&self, index: i32, func: impl FnOnce(&str) -> T) -> T {
+        func(self.name_map.get_ref().get_name_reference(index))
+    }
+
+    /// Get a name reference by an FName map index, or `None` if the index is out of range
+    pub fn try_get_name_reference(&self, index: i32) -> Option<String> {
+        self.name_map
+            .get_ref()
+            .try_get_name_reference(index)
+            .map(|name| name.to_string())
+    }
+
+    /// Iterate over all names with their FName map indices and do something with them
+    ///
+    /// The name map stays borrowed while `func` runs
+    pub fn name_map_iter<T>(&self, func: impl FnOnce(NameMapIter<'_>) -> T) -> T {
+        func(self.name_map.get_ref().iter())
+    }
+
+    /// Add an `FName`
+    pub fn add_fname(&mut self, slice: &str) -> FName {
+        self.name_map.get_mut().add_fname(slice)
+    }
+
+    /// Add an `Import`
+    ///
+    /// The returned index is the import's position in the import table,
+    /// it becomes invalid if imports before it are removed or reordered.
+    /// Use [`Asset::add_import_handle`] to detect this
+    pub fn add_import(&mut self, import: Import) -> PackageIndex {
+        let index = -(self.imports.len() as i32) - 1;
+        let import = import;
+        self.imports.push(import);
+        PackageIndex::new(index)
+    }
+
+    /// Add an `Import` and get a handle to it
+    ///
+    /// Resolving the handle with [`Asset::resolve_import_handle`] fails
+    /// if the import table was modified in a way that moved the import
+    pub fn add_import_handle(&mut self, import: Import) -> ImportHandle {
+        let index = self.add_import(import.clone());
+        ImportHandle::new(index, import)
+    }
+
+    /// Get the current index of an import handle
+    pub fn resolve_import_handle(&self, handle: &ImportHandle) -> Result<PackageIndex, Error> {
+        handle.resolve(&self.imports)
+    }
+
+    /// Find an import, FName comparison is content-based
+    pub fn find_import(
+        &self,
+        class_package: &FName,
+        class_name: &FName,
+        outer_index: PackageIndex,
+        object_name: &FName,
+    ) -> Option<i32> {
+        for i in 0..self.imports.len() {
+            let import = &self.imports[i];
+            if import.class_package.eq_content(class_package)
+                && import.class_name.eq_content(class_name)
+                && import.outer_index == outer_index
+                && import.object_name.eq_content(object_name)
+            {
+                return Some(-(i as i32) - 1);
+            }
+        }
+        None
+    }
+
+    /// Find an import without specifying outer index, FName comparison is content-based
+    pub fn find_import_no_index(
+        &self,
+        class_package: &FName,
+        class_name: &FName,
+        object_name: &FName,
+    ) -> Option<i32> {
+        for i in 0..self.imports.len() {
+            let import = &self.imports[i];
+            if import.class_package.eq_content(class_package)
+                && import.class_name.eq_content(class_name)
+                && import.object_name.eq_content(object_name)
+            {
+                return Some(-(i as i32) - 1);
+            }
+        }
+        None
+    }
+
+    /// Find an export by its object name, FName comparison is content-based and includes the number
+    ///
+    /// Multiple exports can share a name under different outers, in which case the first one is returned.
+    /// Use [`Asset::find_export_by_name_in_outer`] to disambiguate them
+    pub fn find_export_by_name(&self, name: &FName) -> Option<PackageIndex> {
+        self.find_export_by_name_in_outer(name, None)
+    }
+
+    /// Find an export by its object name and optionally its outer,
+    /// FName comparison is content-based and includes the number
+    pub fn find_export_by_name_in_outer(
+        &self,
+        name: &FName,
+        outer_index: Option<PackageIndex>,
+    ) -> Option<PackageIndex> {
+        self.asset_data
+            .exports
+            .iter()
+            .position(|export| {
+                let base_export = export.get_base_export();
+                base_export.object_name.eq_content(name)
+                    && outer_index.is_none_or(|outer| base_export.outer_index == outer)
+            })
+            .map(|index| PackageIndex::new(index as i32 + 1))
+    }
+
+    /// Get an export
+    pub fn get_export(&'a self, index: PackageIndex) -> Option<&'a Export> {
+        self.asset_data.get_export(index)
+    }
+
+    /// Get a mutable export reference
+    pub fn get_export_mut(&'a mut self, index: PackageIndex) -> Option<&'a mut Export> {
+        self.asset_data.get_export_mut(index)
+    }
+
+    /// Parse the data of an export of an asset created with [`Asset::new_lazy`]
+    ///
+    /// Exports which were already parsed are returned as is
+    pub fn parse_export(&mut self, index: PackageIndex) -> Result<&Export, Error> {
+        if !index.is_export() || index.index as usize > self.asset_data.exports.len() {
+            return Err(Error::invalid_package_index(format!(
+                "{} is not an export index, the asset has {} exports",
+                index.index,
+                self.asset_data.exports.len()
+            )));
+        }
+
+        let i = index.index as usize - 1;
+        if cast!(Export, BaseExport, &self.asset_data.exports[i]).is_some() {
+            self.asset_data.exports[i] = self.read_export_with_options(i)?;
+        }
+
+        Ok(&self.asset_data.exports[i])
+    }
+
+    /// Change the class of an export
+    ///
+    /// The class must be an import of a class or a `ClassExport` in this asset.
+    /// The export keeps its parsed type, write and re-read the asset to parse it as the new class
+    pub fn set_export_class(
+        &mut self,
+        index: PackageIndex,
+        class: PackageIndex,
+    ) -> Result<(), Error> {
+        let is_class = match class.is_import() {
+            true => self
+                .get_import(class)
+                .is_some_and(|e| e.class_name.get_content(|name| name.ends_with("Class"))),
+            false => self
+                .get_export(class)
+                .is_some_and(|e| cast!(Export, ClassExport, e).is_some()),
+        };
+        if !is_class {
+            return Err(Error::invalid_package_index(format!(
+                "{} is not a class",
+                class.index
+            )));
+        }
+
+        let export = self.asset_data.get_export_mut(index).ok_or_else(|| {
+            Error::invalid_package_index(format!("Export {} does not exist", index.index))
+        })?;
+        export.get_base_export_mut().class_index = class;
+
+        Ok(())
+    }
+
+    /// Set a property on every export of a class
+    ///
+    /// `value` is renamed to `property_name` and replaces the property with that name
+    /// and duplication index on each export whose class is named `class_filter`, or is appended if there is none.
+    /// Exports which don't hold properties are skipped.
+    ///
+    /// Returns the number of modified exports
+    pub fn set_property_on_exports(
+        &mut self,
+        class_filter: &str,
+        property_name: &str,
+        mut value: Property,
+    ) -> usize {
+        let indices = self
+            .asset_data
+            .exports
+            .iter()
+            .enumerate()
+            .filter(|(_, export)| {
+                self.get_class_name(export.get_base_export().class_index)
+                    .is_some_and(|e| e == class_filter)
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        *value.get_name_mut() = self.add_fname(property_name);
+        let duplication_index = value.get_duplication_index();
+
+        let mut modified = 0;
+        for i in indices {
+            let ancestry = Ancestry::new(
+                self.asset_data.exports[i]
+                    .get_base_export()
+                    .get_class_type_for_ancestry(self),
+            );
+            let Some(normal_export) = self.asset_data.exports[i].get_normal_export_mut() else {
+                continue;
+            };
+
+            let mut property = value.clone();
+            *property.get_ancestry_mut() = ancestry;
+            match normal_export.properties.iter_mut().find(|e| {
+                e.get_name().eq_content(&property.get_name())
+                    && e.get_duplication_index() == duplication_index
+            }) {
+                Some(existing) => *existing = property,
+                None => normal_export.properties.push(property),
+            }
+            modified += 1;
+        }
+
+        modified
+    }
+
+    /// Make sure the depends map exists and has an entry for every export
+    ///
+    /// Missing entries are initialized empty, so newly created assets write the depends section
+    pub fn ensure_depends_map(&mut self) {
+        let export_count = self.asset_data.exports.len();
+        let depends_map = self.depends_map.get_or_insert_with(Vec::new);
+        if depends_map.len() < export_count {
+            depends_map.resize_with(export_count, Vec::new);
+        }
+    }
+
+    /// Get all exports that failed to parse and were read as a `RawExport` instead
+    ///
+    /// Exports whose class type can't be resolved are not included,
+    /// returns the export index and the class name of every export
+    pub fn raw_fallback_exports(&self) -> Vec<(PackageIndex, String)> {
+        let mut raw_exports = Vec::new();
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            if !matches!(export, Export::RawExport(_)) {
+                continue;
+            }
+
+            let class_index = export.get_base_export().class_index;
+            if let Some(class_name) = self.get_class_name(class_index) {
+                raw_exports.push((
+                    PackageIndex::new(i as i32 + 1),
+                    class_name.get_owned_content(),
+                ));
+            }
+        }
+        raw_exports
+    }
+
+    /// Check that the serial ranges of all exports fit together
+    ///
+    /// Every export's `[serial_offset, serial_offset + serial_size)` range must lie
+    /// between the end of the header and the bulk data start offset
+    /// without overlapping other exports or leaving unused bytes in between
+    pub fn validate_layout(&self) -> Vec<LayoutIssue> {
+        let mut issues = Vec::new();
+        if self.asset_data.exports.is_empty() {
+            return issues;
+        }
+
+        let data_start = self.header_offset as i64;
+        let data_end = self.bulk_data_start_offset;
+
+        let mut ranges = Vec::with_capacity(self.asset_data.exports.len());
+        for (export_index, export) in self.asset_data.exports.iter().enumerate() {
+            let base_export = export.get_base_export();
+            let start = base_export.serial_offset;
+            let end = match start.checked_add(base_export.serial_size) {
+                Some(end) if start >= 0 && base_export.serial_size >= 0 => end,
+                _ => {
+                    issues.push(LayoutIssue::InvalidRange { export_index });
+                    continue;
+                }
+            };
+
+            if start < data_start || end > data_end {
+                issues.push(LayoutIssue::OutOfBounds { export_index });
+            }
+            ranges.push((start, end, export_index));
+        }
+
+        // export data isn't necessarily stored in export map order
+        ranges.sort_unstable();
+
+        let mut position = data_start;
+        let mut last_export = None;
+        for (start, end, export_index) in ranges {
+            if start > position {
+                issues.push(LayoutIssue::Gap {
+                    offset: position,
+                    size: start - position,
+                });
+            } else if start < position && start < end {
+                if let Some(other_export_index) = last_export {
+                    issues.push(LayoutIssue::Overlap {
+                        export_index,
+                        other_export_index,
+                    });
+                }
+            }
+
+            if end > position {
+                position = end;
+                last_export = Some(export_index);
+            }
+        }
+
+        if position < data_end {
+            issues.push(LayoutIssue::Gap {
+                offset: position,
+                size: data_end - position,
+            });
+        }
+
+        issues
+    }
+
+    /// Get the values of all enum exports in this asset
+    ///
+    /// Returns a map of enum names to a map of enum values to their names
+    pub fn enum_tables(&self) -> HashMap<String, HashMap<i64, String>> {
+        self.asset_data
+            .exports
+            .iter()
+            .filter_map(|e| cast!(Export, EnumExport, e))
+            .map(|e| {
+                let values = e
+                    .value
+                    .names
+                    .iter()
+                    .map(|(name, value)| (*value, name.get_owned_content()))
+                    .collect();
+                (e.get_base_export().object_name.get_owned_content(), values)
+            })
+            .collect()
+    }
+
+    /// Get the editor-only meta data of objects in this asset
+    ///
+    /// Returns a map of objects to their meta data key/value pairs,
+    /// or `None` if this asset has no meta data export
+    pub fn meta_data(&self) -> Option<HashMap<PackageIndex, HashMap<String, String>>> {
+        let meta_data_export = self
+            .asset_data
+            .exports
+            .iter()
+            .find_map(|e| cast!(Export, MetaDataExport, e))?;
+
+        Some(
+            meta_data_export
+                .object_meta_data
+                .iter()
+                .map(|e| {
+                    let values = e
+                        .values
+                        .iter()
+                        .map(|(key, value)| {
+                            (key.get_owned_content(), value.clone().unwrap_or_default())
+                        })
+                        .collect();
+                    (e.object, values)
+                })
+                .collect(),
+        )
+    }
+
+    /// Check if this asset is a level
+    ///
+    /// Checks the `PKG_CONTAINS_MAP` package flag, falling back to looking for a level export
+    pub fn is_level(&self) -> bool {
+        self.asset_data
+            .package_flags
+            .contains(EPackageFlags::PKG_CONTAINS_MAP)
+            || self
+                .asset_data
+                .exports
+                .iter()
+                .any(|e| cast!(Export, LevelExport, e).is_some())
+    }
+
+    /// Read a bulk data payload of this asset
+    ///
+    /// Payloads stored in a separate file, e.g. `.ubulk`, are read from `separate_file`
+    pub fn read_bulk_data_payload<R: Read + Seek>(
+        &mut self,
+        bulk_data: &FByteBulkData,
+        separate_file: Option<&mut R>,
+    ) -> Result<Vec<u8>, Error> {
+        let bulk_data_start_offset = self.bulk_data_start_offset;
+        match bulk_data.header.is_in_separate_file() {
+            true => bulk_data.read_payload(separate_file, bulk_data_start_offset),
+            false => bulk_data.read_payload(Some(self), bulk_data_start_offset),
+        }
+    }
+
+    /// Find the world settings actor of a level asset
+    ///
+    /// The engine always stores world settings as the first actor of the level,
+    /// if there is no level export, exports are searched for an instance of `WorldSettings`
+    /// or of a class in this asset that derives from it
+    pub fn world_settings(&self) -> Option<PackageIndex> {
+        let level_actor = self
+            .asset_data
+            .exports
+            .iter()
+            .find_map(|e| cast!(Export, LevelExport, e))
+            .and_then(|e| e.actors.first().copied());
+        if let Some(actor) = level_actor.filter(|e| e.is_export()) {
+            return Some(actor);
+        }
+
+        self.asset_data
+            .exports
+            .iter()
+            .position(|e| self.is_world_settings_class(e.get_base_export().class_index))
+            .map(|i| PackageIndex::new(i as i32 + 1))
+    }
+
+    /// Check if a class is `WorldSettings` or derives from it through classes in this asset
+    fn is_world_settings_class(&self, mut class_index: PackageIndex) -> bool {
+        for _ in 0..=self.asset_data.exports.len() {
+            if self
+                .get_class_name(class_index)
+                .is_some_and(|e| e == "WorldSettings")
+            {
+                return true;
+            }
+
+            match self
+                .get_export(class_index)
+                .and_then(|e| cast!(Export, ClassExport, e))
+            {
+                Some(class_export) => class_index = class_export.struct_export.super_struct,
+                None => return false,
+            }
+        }
+
+        false
+    }
+
+    /// Get the name of a class referenced by an import or an export
+    fn get_class_name(&self, class_index: PackageIndex) -> Option<FName> {
+        match class_index.is_import() {
+            true => self.get_import(class_index).map(|e| e.object_name),
+            false => self
+                .get_export(class_index)
+                .map(|e| e.get_base_export().object_name.clone()),
+        }
+    }
+
+    /// Compute a hash of the asset contents
+    ///
+    /// Only imports and exports are hashed, names are hashed by their content
+    /// and serial offsets and sizes are ignored, so equivalent assets saved differently have the same hash.
+    ///
+    /// The hash is a cityhash64 over the data fed by the [`Hash`] implementations of imports and exports,
+    /// with integers encoded as little endian, so it does not depend on the platform or on the process.
+    pub fn content_hash(&self) -> u64 {
+        let mut imports = self.imports.clone();
+        let mut exports = self.asset_data.exports.clone();
+
+        for export in &mut exports {
+            let base_export = export.get_base_export_mut();
+            base_export.serial_size = 0;
+            base_export.serial_offset = 0;
+            base_export.first_export_dependency_offset = 0;
+        }
+
+        let mut to_content = |name: &mut FName| {
+            *name = FName::new_dummy(name.get_owned_content(), name.get_number());
+        };
+        imports.traverse_fnames(&mut to_content);
+        exports.traverse_fnames(&mut to_content);
+
+        let mut hasher = crc::CityHasher::default();
+        imports.hash(&mut hasher);
+        exports.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Export the structure of this asset as a Graphviz DOT graph
+    ///
+    /// Exports and the imports they reference are nodes labeled with their name and class,
+    /// edges go from an export to its outer, its class and its preload dependencies.
+    /// Invalid references are skipped.
+    pub fn to_dot(&self) -> String {
+        // (from, to, kind, style)
+        let mut edges = Vec::new();
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let index = PackageIndex::new(i as i32 + 1);
+            let base_export = export.get_base_export();
+            edges.push((index, base_export.outer_index, "outer", "solid"));
+            edges.push((index, base_export.class_index, "class", "dashed"));
+
+            let dependencies = [
+                &base_export.serialization_before_serialization_dependencies,
+                &base_export.create_before_serialization_dependencies,
+                &base_export.serialization_before_create_dependencies,
+                &base_export.create_before_create_dependencies,
+            ];
+            for dependency in dependencies.into_iter().flatten() {
+                edges.push((index, *dependency, "dependency", "dotted"));
+            }
+        }
+        edges.retain(|(_, to, _, _)| {
+            !matches!(self.resolve(*to), None | Some(ResolvedReference::Null))
+        });
+
+        let mut imports = edges
+            .iter()
+            .map(|(_, to, _, _)| *to)
+            .filter(PackageIndex::is_import)
+            .collect::<Vec<_>>();
+        imports.sort_by_key(|e| -e.index);
+        imports.dedup();
+
+        let mut dot = String::from("digraph asset {\n");
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let index = PackageIndex::new(i as i32 + 1);
+            let base_export = export.get_base_export();
+            let class = match self.resolve(base_export.class_index) {
+                Some(ResolvedReference::Import(import)) => dot_name(&import.object_name),
+                Some(ResolvedReference::Export(export)) => {
+                    dot_name(&export.get_base_export().object_name)
+                }
+                _ => String::from("Class"),
+            };
+            dot.push_str(&format!(
+                "    {} [label=\"{}\\n{}\"];\n",
+                dot_node(index),
+                dot_name(&base_export.object_name),
+                class
+            ));
+        }
+        for index in imports {
+            let Some(import) = self.get_import(index) else {
+                continue;
+            };
+            dot.push_str(&format!(
+                "    {} [label=\"{}\\n{}\", shape=box];\n",
+                dot_node(index),
+                dot_name(&import.object_name),
+                dot_name(&import.class_name)
+            ));
+        }
+        for (from, to, kind, style) in edges {
+            dot.push_str(&format!(
+                "    {} -> {} [label=\"{}\", style={}];\n",
+                dot_node(from),
+                dot_node(to),
+                kind,
+                style
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Read the property tags of an export without parsing property values
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - export index
+    /// * `reader` - reader over the same data this asset was parsed from,
+    ///   use [`Chain`] if the asset was split into .uasset and .uexp
+    pub fn dump_export_property_tags<R: Read + Seek>(
+        &self,
+        index: usize,
+        reader: R,
+    ) -> Result<Vec<PropertyTag>, Error> {
+        if self.asset_data.has_unversioned_properties() {
+            return Err(Error::invalid_file(
+                "Unversioned properties don't have property tags".to_string(),
+            ));
+        }
+
+        let export = self.asset_data.exports.get(index).ok_or_else(|| {
+            Error::invalid_package_index(format!("Export index {index} out of range"))
+        })?;
+
+        let mut reader = RawReader::new(
+            Chain::new(reader, None),
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        reader.seek(SeekFrom::Start(
+            export.get_base_export().serial_offset as u64,
+        ))?;
+
+        let mut tags = Vec::new();
+        while let Some(tag) = PropertyTag::new(&mut reader)? {
+            reader.seek(SeekFrom::Current(tag.length as i64))?;
+            tags.push(tag);
+        }
+
+        Ok(tags)
+    }
+
+    /// Get the latest generation of this package
+    ///
+    /// When writing, the latest generation is updated to the current export and name counts
+    pub fn latest_generation(&self) -> Option<&GenerationInfo> {
+        self.generations.last()
+    }
+
+    /// Get how many exports and names each generation added to the package
+    ///
+    /// The first generation is compared against an empty package,
+    /// counts are negative if a generation removed exports or names
+    pub fn generation_growth(&self) -> Vec<GenerationInfo> {
+        let mut previous = GenerationInfo::new(0, 0);
+        self.generations
+            .iter()
+            .map(|generation| {
+                let growth = GenerationInfo::new(
+                    generation.export_count - previous.export_count,
+                    generation.name_count - previous.name_count,
+                );
+                previous = generation.clone();
+                growth
+            })
+            .collect()
+    }
+
+    /// Get file licensee version
+    pub fn get_file_license_version(&self) -> i32 {
+        self.asset_data.file_license_version
+    }
+
+    /// Set file licensee version
+    ///
+    /// Some licensee engine builds gate serialization on this value,
+    /// it's written to the header as is
+    pub fn set_file_license_version(&mut self, file_license_version: i32) {
+        self.asset_data.file_license_version = file_license_version;
+    }
+
+    /// Get the chunk ids this package is assigned to
+    pub fn get_chunk_ids(&self) -> &[i32] {
+        &self.chunk_ids
+    }
+
+    /// Set the chunk ids this package is assigned to
+    ///
+    /// Packages older than `VER_UE4_CHANGED_CHUNKID_TO_BE_AN_ARRAY_OF_CHUNKIDS`
+    /// only store the first chunk id
+    pub fn set_chunk_ids(&mut self, chunk_ids: Vec<i32>) {
+        self.chunk_ids = chunk_ids;
+    }
+
+    /// Get custom version serialization format
+    pub fn get_custom_version_serialization_format(&self) -> ECustomVersionSerializationFormat {
+        if self.legacy_file_version > 3 {
+            return ECustomVersionSerializationFormat::Enums;
+        }
+        if self.legacy_file_version > -6 {
+            return ECustomVersionSerializationFormat::Guids;
+        }
+        ECustomVersionSerializationFormat::Optimized
+    }
+
+    /// Parse asset data
+    fn parse_data(&mut self) -> Result<(), Error> {
+        self.parse_header_data()?;
+
+        if self.header_offset > 0 && !self.asset_data.exports.is_empty() {
+            let mut new_exports = Vec::with_capacity(self.asset_data.exports.len());
+            for i in 0..self.asset_data.exports.len() {
+                new_exports.push(self.read_export_with_options(i)?);
+            }
+
+            self.asset_data.exports = new_exports;
+
+            if self.options.track_property_offsets && !self.asset_data.has_unversioned_properties()
+            {
+                for i in 0..self.asset_data.exports.len() {
+                    if self.asset_data.exports[i].get_normal_export().is_none() {
+                        continue;
+                    }
+
+                    let base_export = self.asset_data.exports[i].get_base_export();
+                    let class_index = base_export.class_index;
+                    self.seek(SeekFrom::Start(base_export.serial_offset as u64))?;
+                    let export_index = PackageIndex::from_export(i as i32)?;
+
+                    // offsets are informational, the export itself was already read
+                    if let Err(error) = self.read_property_offsets(export_index, "") {
+                        let class_type = self
+                            .get_export_class_type(class_index)
+                            .map(|e| e.get_owned_content());
+                        self.add_warning(AssetWarning {
+                            export_index: i,
+                            class_type,
+                            kind: AssetWarningKind::PropertyOffsets,
+                            error,
+                        });
+                    }
+                }
+            }
+        }
+
+        if self.options.verify_tail_magic {
+            self.verify_tail_magic()?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse everything but export data, exports are left as `BaseExport`s
+    fn parse_header_data(&mut self) -> Result<(), Error> {
+        self.parse_header()?;
+        self.check_parse_budget()?;
+
+        self.seek(SeekFrom::Start(self.name_offset as u64))?;
+
+        // names are unique by position, so they are added without searching for duplicates
+        let mut names = Vec::new();
+        for i in 0..self.name_count {
+            let (name, hash) = self.read_name_map_string(None)?;
+            log::trace!("Read name {i}: {name}");
+            if hash == 0 {
+                // todo: good FString type
+                self.override_name_map_hashes.insert(name.clone(), 0);
+            }
+            names.push(name);
+        }
+        self.name_map.get_mut().add_name_references(names);
+
+        if self.soft_object_paths_count > 0 && self.soft_object_paths_offset > 0 {
+            self.seek(SeekFrom::Start(self.soft_object_paths_offset as u64))?;
+            let mut soft_object_path_list =
+                Vec::with_capacity(self.soft_object_paths_count as usize);
+            for _ in 0..self.soft_object_paths_count {
+                soft_object_path_list.push(SoftObjectPath::read_path(self)?);
+            }
+            self.asset_data.soft_object_path_list = soft_object_path_list;
+        }
+
+        if self.gatherable_text_data_offset > 0 {
+            self.seek(SeekFrom::Start(self.gatherable_text_data_offset as u64))?;
+            let mut gatherable_text_data =
+                Vec::with_capacity(self.gatherable_text_data_count.max(0) as usize);
+            for _ in 0..self.gatherable_text_data_count {
+                gatherable_text_data.push(GatherableTextData::read(self)?);
+            }
+            self.asset_data.gatherable_text_data = Some(gatherable_text_data);
+        }
+
+        if self.import_offset > 0 {
+            self.seek(SeekFrom::Start(self.import_offset as u64))?;
+            for _i in 0..self.import_count {
+                let class_package = self.read_fname()?;
+                let class_name = self.read_fname()?;
+                let outer_index = PackageIndex::new(self.read_i32::<LE>()?);
+                let object_name = self.read_fname()?;
+                let optional =
+                    match self.get_object_version_ue5() >= ObjectVersionUE5::OPTIONAL_RESOURCES {
+                        true => self.read_i32::<LE>()? == 1,
+                        false => false,
+                    };
+
+                let import = Import::new(
+                    class_package,
+                    class_name,
+                    outer_index,
+                    object_name,
+                    optional,
+                );
+                self.imports.push(import);
+            }
+        }
+
+        if self.export_offset > 0 {
+            self.seek(SeekFrom::Start(self.export_offset as u64))?;
+            for _i in 0..self.export_count {
+                let export = BaseExport::read_export_map_entry(self)?;
+                self.asset_data.exports.push(export.into());
+            }
+            self.check_parse_budget()?;
+
+            self.export_serial_offsets = self
+                .asset_data
+                .exports
+                .iter()
+                .map(|e| e.get_base_export().serial_offset)
+                .collect();
+            self.export_serial_offsets.sort_unstable();
+        }
+
+        let depends_offset_zero_version_range =
+            ObjectVersion::VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS
+                ..ObjectVersion::VER_UE4_64BIT_EXPORTMAP_SERIALSIZES;
+        if self.depends_offset > 0
+            || depends_offset_zero_version_range.contains(&self.get_object_version())
+        {
+            let mut depends_map = Vec::with_capacity(self.export_count as usize);
+
+            // 4.14-4.15 the depends offset wasnt updated so always serialized as 0
+            if self.depends_offset > 0 {
+                self.seek(SeekFrom::Start(self.depends_offset as u64))?;
+            }
+
+            for _i in 0..self.export_count as usize {
+                let size = self.read_i32::<LE>()?;
+                let mut data: Vec<i32> = Vec::new();
+                for _j in 0..size {
+                    data.push(self.read_i32::<LE>()?);
+                }
+                depends_map.push(data);
+            }
+            self.depends_map = Some(depends_map);
+        }
+
+        if self.soft_package_reference_offset > 0 {
+            let mut soft_package_reference_list =
+                Vec::with_capacity(self.soft_package_reference_count.max(0) as usize);
+
+            self.seek(SeekFrom::Start(self.soft_package_reference_offset as u64))?;
+
+            for _i in 0..self.soft_package_reference_count as usize {
+                if let Some(reference) = self.read_fstring()? {
+                    soft_package_reference_list.push(reference);
+                }
+            }
+            self.soft_package_reference_list = Some(soft_package_reference_list);
+        }
+
+        let mut thumbnail_data_offset = 0;
+        if self.thumbnail_table_offset > 0 {
+            self.seek(SeekFrom::Start(self.thumbnail_table_offset as u64))?;
+            let (thumbnail_table, data_offset) = ThumbnailTable::read(self)?;
+            self.asset_data.thumbnail_table = Some(thumbnail_table);
+            thumbnail_data_offset = data_offset as i64;
+        }
+
+        if self.asset_registry_data_offset > 0 {
+            self.seek(SeekFrom::Start(self.asset_registry_data_offset as u64))?;
+            let package_flags = self.asset_data.package_flags;
+            self.asset_data.asset_registry_data =
+                Some(AssetRegistryData::read(self, package_flags)?);
+
+            // registry data is followed by the next header section or export data
+            let start = self.position();
+            let end = [
+                self.name_offset as i64,
+                self.gatherable_text_data_offset as i64,
+                self.import_offset as i64,
+                self.export_offset as i64,
+                self.depends_offset as i64,
+                self.soft_package_reference_offset as i64,
+                thumbnail_data_offset,
+                self.world_tile_info_offset as i64,
+                self.preload_dependency_offset as i64,
+                self.data_resource_offset as i64,
+                self.header_offset as i64,
+            ]
+            .into_iter()
+            .filter(|&e| e >= start as i64)
+            .min()
+            .unwrap_or(start as i64) as u64;
+
+            self.registry_tail = self.read_bytes(end - start)?;
+        }
+
+        if self.world_tile_info_offset > 0 {
+            self.seek(SeekFrom::Start(self.world_tile_info_offset as u64))?;
+            self.asset_data.world_tile_info = Some(FWorldTileInfo::new(self)?);
+        }
+
+        if self.data_resource_offset > 0 {
+            self.seek(SeekFrom::Start(self.data_resource_offset as u64))?;
+            self.asset_data.data_resources = Some(FObjectDataResource::read_table(self)?);
+        }
+
+        if self.asset_data.use_event_driven_loader {
+            for (i, export) in self.asset_data.exports.iter_mut().enumerate() {
+                let unk_export = export.get_base_export_mut();
+
+                let sizes = [
+                    unk_export.serialization_before_serialization_dependencies_size,
+                    unk_export.create_before_serialization_dependencies_size,
+                    unk_export.serialization_before_create_dependencies_size,
+                    unk_export.create_before_create_dependencies_size,
+                ];
+                if sizes.iter().any(|&e| e < 0) {
+                    return Err(Error::invalid_file(format!(
+                        "Export {} has negative preload dependency sizes {:?}",
+                        i, sizes
+                    )));
+                }
+
+                // exports without dependencies may have an offset of -1
+                let dependency_count = sizes.iter().map(|&e| e as i64).sum::<i64>();
+                let dependencies_end =
+                    unk_export.first_export_dependency_offset as i64 + dependency_count;
+                if dependency_count > 0
+                    && (unk_export.first_export_dependency_offset < 0
+                        || dependencies_end > self.preload_dependency_count as i64)
+                {
+                    return Err(Error::invalid_file(format!(
+                        "Preload dependencies {}..{} of export {} are out of range, the asset has {} preload dependencies",
+                        unk_export.first_export_dependency_offset,
+                        dependencies_end,
+                        i,
+                        self.preload_dependency_count
+                    )));
+                }
+
+                self.raw_reader
+                    .seek(SeekFrom::Start(self.preload_dependency_offset as u64))?;
+                self.raw_reader.seek(SeekFrom::Current(
+                    unk_export.first_export_dependency_offset as i64 * size_of::<i32>() as i64,
+                ))?;
+
+                unk_export.serialization_before_serialization_dependencies =
+                    self.raw_reader.read_array_with_length(
+                        unk_export.serialization_before_serialization_dependencies_size,
+                        |reader| Ok(PackageIndex::new(reader.read_i32::<LE>()?)),
+                    )?;
+
+                unk_export.create_before_serialization_dependencies =
+                    self.raw_reader.read_array_with_length(
+                        unk_export.create_before_serialization_dependencies_size,
+                        |reader| Ok(PackageIndex::new(reader.read_i32::<LE>()?)),
+                    )?;
+
+                unk_export.serialization_before_create_dependencies =
+                    self.raw_reader.read_array_with_length(
+                        unk_export.serialization_before_create_dependencies_size,
+                        |reader| Ok(PackageIndex::new(reader.read_i32::<LE>()?)),
+                    )?;
+
+                unk_export.create_before_create_dependencies =
+                    self.raw_reader.read_array_with_length(
+                        unk_export.create_before_create_dependencies_size,
+                        |reader| Ok(PackageIndex::new(reader.read_i32::<LE>()?)),
+                    )?;
+            }
+            self.seek(SeekFrom::Start(self.preload_dependency_offset as u64))?;
+        }
+
+        Ok(())
+    }
+
+    /// Read an export, failing instead of falling back to a `RawExport` with [`AssetOptions::strict_exports`]
+    fn read_export_with_options(&mut self, i: usize) -> Result<Export, Error> {
+        match self.options.strict_exports {
+            true => self.read_export_strict(i),
+            false => self.read_export(i),
+        }
+    }
+
+    /// Read an export without falling back to a `RawExport`
+    fn read_export_strict(&mut self, i: usize) -> Result<Export, Error> {
+        let base_export = cast!(Export, BaseExport, self.asset_data.exports[i].clone())
+            .ok_or_else(|| {
+                Error::invalid_file("Couldn't cast to BaseExport when reading exports".to_string())
+            })?;
+        let class_index = base_export.class_index;
+
+        let export = self.read_export_no_raw(base_export, i).and_then(|e| {
+            match e.reduce(&mut self.asset_data) {
+                Export::RawExport(_) => Err(serial_size_overrun()),
+                export => Ok(export),
+            }
+        });
+
+        export.map_err(|e| {
+            let class_name = self
+                .get_class_name(class_index)
+                .map(|e| e.get_owned_content())
+                .unwrap_or_else(|| String::from("Unknown"));
+            Error::export_parse(i as i32 + 1, class_name, e)
+        })
+    }
+
+    /// Check that the package tag is present at the bulk data start offset
+    fn verify_tail_magic(&mut self) -> Result<(), Error> {
+        let data_length = self.data_length()?;
+        if self.bulk_data_start_offset < 0
+            || self.bulk_data_start_offset as u64 + PACKAGE_FILE_TAG_SIZE > data_length
+        {
+            return Err(Error::invalid_file(format!(
+                "Bulk data start offset {} is out of bounds, file is {} bytes long",
+                self.bulk_data_start_offset, data_length
+            )));
+        }
+
+        self.seek(SeekFrom::Start(self.bulk_data_start_offset as u64))?;
+        if self.read_u32::<BE>()? != UE4_ASSET_MAGIC {
+            return Err(Error::invalid_file(format!(
+                "Package tag is missing at bulk data start offset {}",
+                self.bulk_data_start_offset
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Record byte ranges of a tagged property list at the current position
+    ///
+    /// Tagged structs and array elements are recorded as well,
+    /// nesting is limited by [`AssetOptions::max_property_depth`]
+    fn read_property_offsets(
+        &mut self,
+        export_index: PackageIndex,
+        path: &str,
+    ) -> Result<(), Error> {
+        loop {
+            let start = self.position();
+            let Some(tag) = PropertyTag::new(self)? else {
+                return Ok(());
+            };
+            let value_start = self.position();
+            let end = u64::try_from(tag.length)
+                .ok()
+                .and_then(|e| value_start.checked_add(e))
+                .ok_or_else(|| {
+                    Error::invalid_file(format!(
+                        "Property {} has invalid length {}",
+                        tag.name, tag.length
+                    ))
+                })?;
+
+            let mut property_path = tag.name.to_string();
+            if tag.duplication_index != 0 {
+                property_path = format!("{}[{}]", property_path, tag.duplication_index);
+            }
+            if !path.is_empty() {
+                property_path = format!("{path}.{property_path}");
+            }
+
+            // structs without custom serialization are tagged property lists as well
+            let is_tagged_struct = tag.length > 0
+                && tag
+                    .struct_type
+                    .as_ref()
+                    .is_some_and(|e| !e.get_content(Property::has_custom_serialization));
+            let is_array = tag.length > 0 && tag.property_type == "ArrayProperty";
+            if is_tagged_struct || is_array {
+                self.enter_property()?;
+                let result = match (is_tagged_struct, &tag.inner_type) {
+                    (true, _) => self.read_property_offsets(export_index, &property_path),
+                    (false, Some(inner_type)) => self.read_array_element_offsets(
+                        export_index,
+                        &property_path,
+                        inner_type,
+                        end,
+                    ),
+                    (false, None) => Ok(()),
+                };
+                self.leave_property();
+                result?;
+
+                if self.position() > end {
+                    return Err(Error::invalid_file(format!(
+                        "Property {property_path} is longer than its length {}",
+                        tag.length
+                    )));
+                }
+            }
+
+            self.property_offsets
+                .insert((export_index, property_path), start..end);
+            self.seek(SeekFrom::Start(end))?;
+        }
+    }
+
+    /// Record byte ranges of array elements at the current position
+    ///
+    /// Elements are recorded as `Array.0`, `Array.1`, ...
+    /// Only elements that are tagged structs or have a fixed size are recorded
+    fn read_array_element_offsets(
+        &mut self,
+        export_index: PackageIndex,
+        path: &str,
+        inner_type: &FName,
+        end: u64,
+    ) -> Result<(), Error> {
+        let count = self.read_i32::<LE>()?;
+        if count < 0 {
+            return Err(Error::invalid_file(format!(
+                "Array {path} has invalid element count {count}"
+            )));
+        }
+        if count == 0 {
+            return Ok(());
+        }
+
+        let mut element_size = inner_type.get_content(|ty| match ty {
+            "BoolProperty" | "Int8Property" => Some(1),
+            "Int16Property" | "UInt16Property" => Some(2),
+            "IntProperty" | "UInt32Property" | "FloatProperty" | "ObjectProperty" => Some(4),
+            "Int64Property" | "UInt64Property" | "DoubleProperty" | "NameProperty" => Some(8),
+            _ => None,
+        });
+
+        // struct arrays store a tag for their elements
+        if *inner_type == "StructProperty" {
+            if self.get_object_version() < ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
+                return Ok(());
+            }
+            let Some(inner_tag) = PropertyTag::new(self)? else {
+                return Ok(());
+            };
+
+            let is_tagged_struct = inner_tag
+                .struct_type
+                .as_ref()
+                .is_some_and(|e| !e.get_content(Property::has_custom_serialization));
+            if is_tagged_struct {
+                for i in 0..count {
+                    let start = self.position();
+                    let element_path = format!("{path}.{i}");
+                    self.read_property_offsets(export_index, &element_path)?;
+                    let end = self.position();
+                    self.property_offsets
+                        .insert((export_index, element_path), start..end);
+                }
+                return Ok(());
+            }
+
+            element_size = u64::try_from(inner_tag.length)
+                .ok()
+                .filter(|e| e % count as u64 == 0)
+                .map(|e| e / count as u64);
+        }
+
+        let start = self.position();
+        let Some(element_size) =
+            element_size.filter(|e| start.checked_add(e * count as u64) == Some(end))
+        else {
+            return Ok(());
+        };
+        for i in 0..count as u64 {
+            let element_start = start + i * element_size;
+            self.property_offsets.insert(
+                (export_index, format!("{path}.{i}")),
+                element_start..element_start + element_size,
+            );
+        }
+        Ok(())
+    }
+
+    /// Write asset header
+    fn write_header<Writer: ArchiveWriter>(
+        &self,
+        cursor: &mut Writer,
+        asset_header: &AssetHeader,
+    ) -> Result<(), Error> {
+        cursor.write_u32::<BE>(UE4_ASSET_MAGIC)?;
+        cursor.write_i32::<LE>(self.legacy_file_version)?;
+
+        if self.legacy_file_version != -4 {
+            let legacy_ue3_version = match self.asset_data.unversioned {
+                true => self.asset_data.legacy_ue3_version.unwrap_or(0),
+                false => self.asset_data.legacy_ue3_version.unwrap_or(864),
+            };
+            cursor.write_i32::<LE>(legacy_ue3_version)?;
+        }
+
+        match self.asset_data.unversioned {
+            true => cursor.write_i32::<LE>(0)?,
+            false => cursor.write_i32::<LE>(self.asset_data.object_version as i32)?,
+        };
+
+        if self.legacy_file_version <= -8 {
+            match self.asset_data.unversioned {
+                true => cursor.write_i32::<LE>(0)?,
+                false => cursor.write_i32::<LE>(self.get_object_version_ue5() as i32)?,
+            };
+        }
+
+        cursor.write_i32::<LE>(self.asset_data.file_license_version)?;
+        if self.legacy_file_version <= -2 {
+            match self.asset_data.unversioned {
+                true => cursor.write_i32::<LE>(0)?,
+                false => {
+                    cursor.write_i32::<LE>(self.asset_data.custom_versions.len() as i32)?;
+                    let format = self.get_custom_version_serialization_format();
+                    for custom_version in &self.asset_data.custom_versions {
+                        cursor.write_guid(&custom_version.guid)?;
+                        cursor.write_i32::<LE>(custom_version.version)?;
+                        if format == ECustomVersionSerializationFormat::Guids {
+                            cursor.write_fstring(custom_version.friendly_name.as_deref())?;
+                        }
+                    }
+                }
+            };
+        }
+
+        cursor.write_i32::<LE>(asset_header.header_offset)?;
+        cursor.write_fstring(Some(&self.folder_name))?;
+        cursor.write_u32::<LE>(self.asset_data.package_flags.bits())?;
+        cursor.write_i32::<LE>(self.name_map.get_ref().serialized_len()?)?;
+        cursor.write_i32::<LE>(asset_header.name_offset)?;
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST {
+            cursor.write_i32::<LE>(asset_header.soft_object_paths_count)?;
+            cursor.write_i32::<LE>(asset_header.soft_object_paths_offset)?;
+        }
+
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
+            && !self
+                .asset_data
+                .package_flags
+                .contains(EPackageFlags::PKG_FILTER_EDITOR_ONLY)
+        {
+            cursor.write_fstring(self.asset_data.localization_id.as_deref())?;
+        }
+
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_SERIALIZE_TEXT_IN_PACKAGES {
+            cursor.write_i32::<LE>(asset_header.gatherable_text_data_count)?;
+            cursor.write_i32::<LE>(asset_header.gatherable_text_data_offset)?;
+        }
+
+        cursor.write_i32::<LE>(self.asset_data.exports.len() as i32)?;
+        cursor.write_i32::<LE>(asset_header.export_offset)?;
+        cursor.write_i32::<LE>(self.imports.len() as i32)?;
+        cursor.write_i32::<LE>(asset_header.import_offset)?;
+        cursor.write_i32::<LE>(asset_header.depends_offset)?;
+
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP
+        {
+            cursor.write_i32::<LE>(self.soft_package_reference_count)?;
+            cursor.write_i32::<LE>(asset_header.soft_package_reference_offset)?;
+        }
+
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADDED_SEARCHABLE_NAMES {
+            cursor.write_i32::<LE>(self.searchable_names_offset)?;
+        }
+
+        cursor.write_i32::<LE>(asset_header.thumbnail_table_offset)?;
+        cursor.write_guid(&self.package_guid)?;
+        cursor.write_i32::<LE>(self.generations.len() as i32)?;
+
+        // older generations are package history, the latest one describes the package being written
+        if let Some((_, history)) = self.generations.split_last() {
+            for generation in history {
+                cursor.write_i32::<LE>(generation.export_count)?;
+                cursor.write_i32::<LE>(generation.name_count)?;
+            }
+            cursor.write_i32::<LE>(self.asset_data.exports.len() as i32)?;
+            cursor.write_i32::<LE>(self.name_map.get_ref().serialized_len()?)?;
+        }
+
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_ENGINE_VERSION_OBJECT {
+            self.engine_version_recorded.write(cursor)?;
+        } else {
+            cursor.write_u32::<LE>(self.engine_version_recorded.build)?;
+        }
+
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_PACKAGE_SUMMARY_HAS_COMPATIBLE_ENGINE_VERSION
+        {
+            self.engine_version_recorded.write(cursor)?;
+        }
+
+        cursor.write_u32::<LE>(self.compression_flags)?;
+        cursor.write_i32::<LE>(0)?; // numCompressedChunks
+        cursor.write_u32::<LE>(self.package_source)?;
+        cursor.write_i32::<LE>(0)?; // numAdditionalPackagesToCook
+
+        if self.legacy_file_version > -7 {
+            cursor.write_i32::<LE>(0)?; // numTextureallocations
+        }
+
+        cursor.write_i32::<LE>(asset_header.asset_registry_data_offset)?;
+        cursor.write_i64::<LE>(asset_header.bulk_data_start_offset)?;
+
+        if self.asset_data.object_version >= ObjectVersion::VER_UE4_WORLD_LEVEL_INFO {
+            cursor.write_i32::<LE>(asset_header.world_tile_info_offset)?;
+        }
+
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_CHANGED_CHUNKID_TO_BE_AN_ARRAY_OF_CHUNKIDS
+        {
+            cursor.write_i32::<LE>(self.chunk_ids.len() as i32)?;
+            for chunk_id in &self.chunk_ids {
+                cursor.write_i32::<LE>(*chunk_id)?;
+            }
+        } else if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE
+        {
+            // a package without chunks was stored with INDEX_NONE
+            cursor.write_i32::<LE>(self.chunk_ids.first().copied().unwrap_or(-1))?;
+        }
+
+        if self.asset_data.object_version
+            >= ObjectVersion::VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS
+        {
+            cursor.write_i32::<LE>(asset_header.preload_dependency_count)?;
+            cursor.write_i32::<LE>(asset_header.preload_dependency_offset)?;
+        }
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::NAMES_REFERENCED_FROM_EXPORT_DATA {
+            cursor.write_i32::<LE>(self.names_referenced_from_export_data_count)?;
+        }
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::PAYLOAD_TOC {
+            cursor.write_i64::<LE>(self.payload_toc_offset)?;
+        }
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::DATA_RESOURCES {
+            cursor.write_i32::<LE>(asset_header.data_resource_offset)?;
+        }
+
+        Ok(())
+    }
+
+    /// Rebuild the FName map
+    /// This can be used if it's too complicated to keep track of all FNames that were added into the asset
+    /// This is useful when copying export from one asset into another
+    /// This will automatically figure out every new FName and add them to the name map
+    pub fn rebuild_name_map(&mut self) {
+        let mut current_name_map = self.name_map.clone();
+        self.traverse_fnames(&mut |mut name| {
+            let content = name.get_owned_content();
+            let FName::Backed { index, name_map, .. } = &mut name else {
+                return;
+            };
+
+            if *name_map != current_name_map {
+                let new_index = current_name_map
+                    .get_mut()
+                    .add_name_reference(content, false);
+
+                *index = new_index;
+                *name_map = current_name_map.clone();
+            }
+        });
+    }
+
+    /// Move this asset to its own name map containing only the names it uses
+    ///
+    /// This is useful for assets created with [`Asset::new_with_name_map`] which are written many times.
+    /// Returns an error if raw exports or raw kismet bytecode reference names, see [`Asset::prune_name_map`]
+    pub fn detach_name_map(&mut self) -> Result<(), Error> {
+        self.move_to_name_map(NameMap::new())?;
+        self.shares_name_map = false;
+        self.add_written_names()
+    }
+
+    /// Add names which are only added when writing, like property types, to the name map
+    ///
+    /// They need to be in the name map before it's written, so do a write pass to collect them
+    fn add_written_names(&self) -> Result<(), Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut uexp_cursor = Cursor::new(Vec::new());
+        let uexp_cursor = match self.asset_data.use_event_driven_loader {
+            true => Some(&mut uexp_cursor),
+            false => None,
+        };
+        self.write_data(&mut cursor, uexp_cursor)
+    }
+
+    /// Remove names this asset doesn't use from its name map
+    ///
+    /// The remaining names keep their relative order and all FNames are remapped to their new indices.
+    /// Like [`Asset::detach_name_map`] this moves the asset to its own name map
+    /// and needs a write pass to find the names that are only referenced when writing.
+    ///
+    /// Raw exports and raw kismet bytecode reference names by index, an error is returned
+    /// if the asset has any because their names can't be renumbered.
+    /// Extra data following the properties of an export isn't parsed and is assumed not to reference names
+    pub fn prune_name_map(&mut self) -> Result<(), Error> {
+        let old_name_map = self.name_map.clone();
+        self.detach_name_map()?;
+
+        // restore the original order, names that weren't in the old name map go last
+        let mut names = self.name_map.get_ref().get_name_map_index_list().to_vec();
+        let old_index = |name: &str| {
+            old_name_map
+                .get_ref()
+                .search_name_reference(name)
+                .unwrap_or(i32::MAX)
+        };
+        names.sort_by_cached_key(|name| old_index(name));
+
+        // names referenced from export data are sorted first
+        self.names_referenced_from_export_data_count = names
+            .iter()
+            .filter(|name| old_index(name) < self.names_referenced_from_export_data_count)
+            .count() as i32;
+
+        let mut name_map = NameMap::new();
+        name_map.get_mut().add_name_references(names);
+        self.move_to_name_map(name_map)?;
+
+        let name_map = self.name_map.get_ref();
+        self.override_name_map_hashes
+            .retain(|name, _| name_map.search_name_reference(name).is_some());
+        Ok(())
+    }
+
+    /// Move all FNames of this asset to another name map
+    ///
+    /// Fails without changing the asset if raw data references names
+    fn move_to_name_map(&mut self, mut new_name_map: SharedResource<NameMap>) -> Result<(), Error> {
+        self.check_raw_name_references()?;
+        self.traverse_fnames(&mut |name| {
+            let content = name.get_owned_content();
+            let FName::Backed {
+                index, name_map, ..
+            } = name
+            else {
+                return;
+            };
+
+            *index = new_name_map.get_mut().add_name_reference(content, false);
+            *name_map = new_name_map.clone();
+        });
+
+        self.raw_reader.name_map = new_name_map.clone();
+        self.name_map = new_name_map;
+        Ok(())
+    }
+
+    /// Check that no export references names from data that isn't parsed
+    ///
+    /// Raw exports and raw kismet bytecode hold name map indices which `traverse_fnames` can't reach
+    fn check_raw_name_references(&self) -> Result<(), Error> {
+        for export in &self.asset_data.exports {
+            let struct_export = match export {
+                Export::RawExport(raw_export) => {
+                    return Err(Error::unimplemented(format!(
+                        "Cannot renumber names referenced by raw export {}",
+                        raw_export.base_export.object_name.get_owned_content()
+                    )));
+                }
+                Export::ClassExport(class_export) => &class_export.struct_export,
+                Export::StructExport(struct_export) => struct_export,
+                Export::FunctionExport(function_export) => &function_export.struct_export,
+                Export::UserDefinedStructExport(user_defined_struct_export) => {
+                    &user_defined_struct_export.struct_export
+                }
+                _ => continue,
+            };
+
+            if struct_export.script_bytecode.is_none()
+                && struct_export
+                    .script_bytecode_raw
+                    .as_ref()
+                    .is_some_and(|e| !e.is_empty())
+            {
+                return Err(Error::unimplemented(format!(
+                    "Cannot renumber names referenced by raw kismet bytecode in export {}",
+                    struct_export
+                        .normal_export
+                        .base_export
+                        .object_name
+                        .get_owned_content()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy this asset for writing it with its own name map
+    ///
+    /// The copy has no data to read from, and warnings and property offsets aren't copied
+    fn copy_for_writing(&self) -> Asset<Cursor<Vec<u8>>> {
+        let mut raw_reader = RawReader::new(
+            Chain::new(Cursor::new(Vec::new()), None),
+            self.raw_reader.object_version,
+            self.raw_reader.object_version_ue5,
+            self.raw_reader.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        raw_reader.max_property_depth = self.raw_reader.max_property_depth;
+
+        Asset {
+            raw_reader,
+            info: self.info.clone(),
+            asset_data: self.asset_data.clone(),
+            legacy_file_version: self.legacy_file_version,
+            generations: self.generations.clone(),
+            package_guid: self.package_guid,
+            engine_version_recorded: self.engine_version_recorded.clone(),
+            engine_version_compatible: self.engine_version_compatible.clone(),
+            chunk_ids: self.chunk_ids.clone(),
+            package_source: self.package_source,
+            folder_name: self.folder_name.clone(),
+            header_offset: self.header_offset,
+            name_count: self.name_count,
+            name_offset: self.name_offset,
+            soft_object_paths_count: self.soft_object_paths_count,
+            soft_object_paths_offset: self.soft_object_paths_offset,
+            gatherable_text_data_count: self.gatherable_text_data_count,
+            gatherable_text_data_offset: self.gatherable_text_data_offset,
+            export_count: self.export_count,
+            export_offset: self.export_offset,
+            import_count: self.import_count,
+            import_offset: self.import_offset,
+            depends_offset: self.depends_offset,
+            soft_package_reference_count: self.soft_package_reference_count,
+            soft_package_reference_offset: self.soft_package_reference_offset,
+            searchable_names_offset: self.searchable_names_offset,
+            thumbnail_table_offset: self.thumbnail_table_offset,
+            compression_flags: self.compression_flags,
+            asset_registry_data_offset: self.asset_registry_data_offset,
+            bulk_data_start_offset: self.bulk_data_start_offset,
+            world_tile_info_offset: self.world_tile_info_offset,
+            preload_dependency_count: self.preload_dependency_count,
+            preload_dependency_offset: self.preload_dependency_offset,
+            names_referenced_from_export_data_count: self.names_referenced_from_export_data_count,
+            payload_toc_offset: self.payload_toc_offset,
+            data_resource_offset: self.data_resource_offset,
+
+            override_name_map_hashes: self.override_name_map_hashes.clone(),
+            name_map: self.name_map.clone(),
+            imports: self.imports.clone(),
+            depends_map: self.depends_map.clone(),
+            soft_package_reference_list: self.soft_package_reference_list.clone(),
+            registry_tail: self.registry_tail.clone(),
+            parent_class: self.parent_class.clone(),
+
+            options: self.options.clone(),
+            property_offsets: HashMap::new(),
+            warnings: Vec::new(),
+            export_serial_offsets: self.export_serial_offsets.clone(),
+            shares_name_map: self.shares_name_map,
+        }
+    }
+
+    /// Write asset data
+    pub fn write_data<W: Read + Seek + Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+    ) -> Result<(), Error> {
+        self.write_data_with_layout(cursor, uexp_cursor, &SectionLayout::default())
+    }
+
+    /// Write asset data, placing header sections in the order given by `layout`
+    ///
+    /// Section offsets in the package summary are updated to match the layout
+    pub fn write_data_with_layout<W: Read + Seek + Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+        layout: &SectionLayout,
+    ) -> Result<(), Error> {
+        self.write_data_inner(cursor, uexp_cursor, layout, None)?;
+        Ok(())
+    }
+
+    /// Write asset data to sinks that can't seek, e.g. compression streams or sockets
+    ///
+    /// The asset is written twice, the first pass only computes the offsets
+    /// and the second pass writes the final bytes in order.
+    /// Each export is buffered in memory until it's fully written.
+    pub fn write_data_streaming<W: Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+    ) -> Result<(), Error> {
+        self.write_data_streaming_with_layout(cursor, uexp_cursor, &SectionLayout::default())
+    }
+
+    /// Write asset data to sinks that can't seek, placing header sections in the order given by `layout`
+    ///
+    /// See [`Asset::write_data_streaming`]
+    pub fn write_data_streaming_with_layout<W: Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+        layout: &SectionLayout,
+    ) -> Result<(), Error> {
+        let mut counting_cursor = CountingWriter::default();
+        let mut counting_uexp_cursor = uexp_cursor.as_ref().map(|_| CountingWriter::default());
+        let written_layout = self.write_data_inner(
+            &mut counting_cursor,
+            counting_uexp_cursor.as_mut(),
+            layout,
+            None,
+        )?;
+
+        let mut stream_cursor = StreamWriter::new(cursor);
+        let mut stream_uexp_cursor = uexp_cursor.map(StreamWriter::new);
+        self.write_data_inner(
+            &mut stream_cursor,
+            stream_uexp_cursor.as_mut(),
+            layout,
+            Some(&written_layout),
+        )?;
+        Ok(())
+    }
+
+    /// Write asset data
+    ///
+    /// When the layout of a previous write is given the header is written with it
+    /// and the writer never seeks to data before the last flush,
+    /// otherwise the header is rewritten after the exports were written.
+    fn write_data_inner<W: Seek + Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+        layout: &SectionLayout,
+        precomputed: Option<&WrittenLayout>,
+    ) -> Result<WrittenLayout, Error> {
+        if self.shares_name_map {
+            // only write the names this asset uses instead of the whole shared name map
+            let mut asset = self.copy_for_writing();
+            asset.detach_name_map()?;
+            return asset.write_data_inner(cursor, uexp_cursor, layout, precomputed);
+        }
+
+        if !layout.is_valid() {
+            return Err(Error::no_data(
+                "section layout must contain every header section exactly once".to_string(),
+            ));
+        }
+
+        if self.asset_data.use_event_driven_loader != uexp_cursor.is_some() {
+            return Err(Error::no_data(format!(
+                "use_separate_bulk_data_files is {} but uexp_cursor is {}",
+                self.asset_data.use_event_driven_loader,
+                match uexp_cursor.is_some() {
+                    true => "Some(...)",
+                    false => "None",
+                }
+            )));
+        }
+
+        let placeholder_header = AssetHeader {
+            name_offset: self.name_offset,
+            soft_object_paths_count: self.soft_object_paths_count,
+            soft_object_paths_offset: self.soft_object_paths_offset,
+            gatherable_text_data_count: self.gatherable_text_data_count,
+            gatherable_text_data_offset: self.gatherable_text_data_offset,
+            import_offset: self.import_offset,
+            export_offset: self.export_offset,
+            depends_offset: self.depends_offset,
+            soft_package_reference_offset: self.soft_package_reference_offset,
+            thumbnail_table_offset: self.thumbnail_table_offset,
+            asset_registry_data_offset: self.asset_registry_data_offset,
+            world_tile_info_offset: self.world_tile_info_offset,
+            preload_dependency_count: 0,
+            preload_dependency_offset: self.preload_dependency_offset,
+            data_resource_offset: self.data_resource_offset,
+            header_offset: self.header_offset,
+            bulk_data_start_offset: self.bulk_data_start_offset,
+        };
+
+        let mut raw_serializer = RawWriter::new(
+            cursor,
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        let mut serializer = AssetArchiveWriter::new(
+            &mut raw_serializer,
+            &self.asset_data,
+            &self.imports,
+            self.name_map.clone(),
+        );
+
+        self.write_header(
+            &mut serializer,
+            precomputed
+                .map(|e| &e.header)
+                .unwrap_or(&placeholder_header),
+        )?;
+
+        let mut name_offset = 0;
+        let mut soft_object_paths_count = 0;
+        let mut soft_object_paths_offset = 0;
+        let mut gatherable_text_data_count = 0;
+        let mut gatherable_text_data_offset = 0;
+        let mut import_offset = 0;
+        let mut export_offset = 0;
+        let mut depends_offset = 0;
+        let mut soft_package_reference_offset = 0;
+        let mut thumbnail_table_offset = 0;
+        let mut asset_registry_data_offset = 0;
+        let mut world_tile_info_offset = 0;
+        let mut preload_dependency_count = -1;
+        let mut preload_dependency_offset = 0;
+        let mut data_resource_offset = self.data_resource_offset;
+
+        for section in &layout.sections {
+            match section {
+                HeaderSection::NameMap => {
+                    name_offset = match !self.name_map.get_ref().is_empty() {
+                        true => serializer.position() as i32,
+                        false => 0,
+                    };
+
+                    for name in self.name_map.get_ref().get_name_map_index_list() {
+                        // todo: case preserving FString
+                        serializer.write_fstring(Some(name))?;
+
+                        if self.asset_data.object_version
+                            >= ObjectVersion::VER_UE4_NAME_HASHES_SERIALIZED
+                        {
+                            match self.override_name_map_hashes.get_by_key(name) {
+                                Some(e) => serializer.write_u32::<LE>(*e)?,
+                                None => serializer.write_u32::<LE>(crc::generate_hash(name))?,
+                            };
+                        }
+                    }
+                }
+                HeaderSection::SoftObjectPaths => {
+                    if self.asset_data.object_version_ue5
+                        >= ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST
+                    {
+                        soft_object_paths_count =
+                            self.asset_data.soft_object_path_list.len() as i32;
+                        soft_object_paths_offset = serializer.position() as i32;
+                        for path in &self.asset_data.soft_object_path_list {
+                            path.write_path(&mut serializer)?;
+                        }
+                    }
+                }
+                HeaderSection::GatherableTextData => {
+                    if let Some(ref gatherable_text_data) = self.asset_data.gatherable_text_data {
+                        if self.asset_data.object_version
+                            >= ObjectVersion::VER_UE4_SERIALIZE_TEXT_IN_PACKAGES
+                        {
+                            gatherable_text_data_count = gatherable_text_data.len() as i32;
+                            gatherable_text_data_offset = serializer.position() as i32;
+                            for text_data in gatherable_text_data {
+                                text_data.write(&mut serializer)?;
+                            }
+                        }
+                    }
+                }
+                HeaderSection::Imports => {
+                    import_offset = match !self.imports.is_empty() {
+                        true => serializer.position() as i32,
+                        false => 0,
+                    };
+
+                    for import in &self.imports {
+                        serializer.write_fname(&import.class_package)?;
+                        serializer.write_fname(&import.class_name)?;
+                        serializer.write_i32::<LE>(import.outer_index.index)?;
+                        serializer.write_fname(&import.object_name)?;
+                        if serializer.get_object_version_ue5()
+                            >= ObjectVersionUE5::OPTIONAL_RESOURCES
+                        {
+                            serializer.write_i32::<LE>(match import.optional {
+                                true => 1,
+                                false => 0,
+                            })?;
+                        }
+                    }
+                }
+                HeaderSection::Exports => {
+                    export_offset = match !self.asset_data.exports.is_empty() {
+                        true => serializer.position() as i32,
+                        false => 0,
+                    };
+
+                    match precomputed {
+                        Some(precomputed) => {
+                            precomputed.write_export_map(&mut serializer, &self.asset_data)?
+                        }
+                        None => {
+                            for export in &self.asset_data.exports {
+                                let unk: &BaseExport = export.get_base_export();
+                                unk.write_export_map_entry(
+                                    &mut serializer,
+                                    unk.serial_size,
+                                    unk.serial_offset,
+                                    unk.first_export_dependency_offset,
+                                )?;
+                            }
+                        }
+                    }
+                }
+                HeaderSection::DependsMap => {
+                    depends_offset = match self.depends_map {
+                        Some(_) => serializer.position() as i32,
+                        None => 0,
+                    };
+
+                    if let Some(ref map) = self.depends_map {
+                        for i in 0..self.asset_data.exports.len() {
+                            let dummy = Vec::new();
+                            let current_data = match map.get(i) {
+                                Some(e) => e,
+                                None => &dummy,
+                            };
+                            serializer.write_i32::<LE>(current_data.len() as i32)?;
+                            for i in current_data {
+                                serializer.write_i32::<LE>(*i)?;
+                            }
+                        }
+                    }
+                }
+                HeaderSection::SoftPackageReferences => {
+                    soft_package_reference_offset = match self.soft_package_reference_list {
+                        Some(_) => serializer.position() as i32,
+                        None => 0,
+                    };
+
+                    if let Some(ref package_references) = self.soft_package_reference_list {
+                        for reference in package_references {
+                            serializer.write_fstring(Some(reference))?;
+                        }
+                    }
+                }
+                HeaderSection::Thumbnails => {
+                    if let Some(ref thumbnail_table) = self.asset_data.thumbnail_table {
+                        thumbnail_table_offset = thumbnail_table.write(&mut serializer)? as i32;
+                    }
+                }
+                HeaderSection::AssetRegistryData => {
+                    if let Some(ref asset_registry_data) = self.asset_data.asset_registry_data {
+                        asset_registry_data_offset = serializer.position() as i32;
+                        asset_registry_data
+                            .write(&mut serializer, self.asset_data.package_flags)?;
+                        serializer.write_all(&self.registry_tail)?;
+                    }
+                }
+                HeaderSection::WorldTileInfo => {
+                    if let Some(ref world_tile_info) = self.asset_data.world_tile_info {
+                        world_tile_info_offset = serializer.position() as i32;
+                        world_tile_info.write(&mut serializer)?;
+                    }
+                }
+                HeaderSection::PreloadDependencies => {
+                    preload_dependency_offset = serializer.position() as i32;
+
+                    if self.asset_data.use_event_driven_loader {
+                        preload_dependency_count = 0;
+                        for export in &self.asset_data.exports {
+                            let unk_export = export.get_base_export();
+
+                            for element in
+                                &unk_export.serialization_before_serialization_dependencies
+                            {
+                                serializer.write_i32::<LE>(element.index)?;
+                            }
+
+                            for element in &unk_export.create_before_serialization_dependencies {
+                                serializer.write_i32::<LE>(element.index)?;
+                            }
+
+                            for element in &unk_export.serialization_before_create_dependencies {
+                                serializer.write_i32::<LE>(element.index)?;
+                            }
+
+                            for element in &unk_export.create_before_create_dependencies {
+                                serializer.write_i32::<LE>(element.index)?;
+                            }
+
+                            preload_dependency_count += unk_export
+                                .serialization_before_serialization_dependencies
+                                .len()
+                                as i32
+                                + unk_export.create_before_serialization_dependencies.len() as i32
+                                + unk_export.serialization_before_create_dependencies.len() as i32
+                                + unk_export.create_before_create_dependencies.len() as i32;
+                        }
+                    }
+                }
+                HeaderSection::DataResources => {
+                    if let Some(ref data_resources) = self.asset_data.data_resources {
+                        data_resource_offset = serializer.position() as i32;
+                        FObjectDataResource::write_table(&mut serializer, data_resources)?;
+                    }
+                }
+            }
+        }
+
+        let header_offset = match !self.asset_data.exports.is_empty() {
+            true => serializer.position() as i32,
+            false => 0,
+        };
+        serializer.flush()?;
+
+        let mut category_starts = Vec::with_capacity(self.asset_data.exports.len());
+
+        let final_cursor_pos = serializer.position();
+
+        let mut raw_bulk_serializer = match self.asset_data.use_event_driven_loader {
+            true => Some(RawWriter::new(
+                uexp_cursor.unwrap(),
+                self.asset_data.object_version,
+                self.asset_data.object_version_ue5,
+                self.asset_data.use_event_driven_loader,
+                self.name_map.clone(),
+            )),
+            false => None,
+        };
+
+        let mut bulk_serializer = match self.asset_data.use_event_driven_loader {
+            true => Some(
+                AssetArchiveWriter::new(
+                    raw_bulk_serializer.as_mut().unwrap(),
+                    &self.asset_data,
+                    &self.imports,
+                    self.name_map.clone(),
+                )
+                .with_package_offset(final_cursor_pos),
+            ),
+            false => None,
+        };
+
+        let bulk_serializer = match self.asset_data.use_event_driven_loader {
+            true => bulk_serializer.as_mut().unwrap(),
+            false => &mut serializer,
+        };
+
+        for export in &self.asset_data.exports {
+            category_starts.push(match self.asset_data.use_event_driven_loader {
+                true => bulk_serializer.position() + final_cursor_pos,
+                false => bulk_serializer.position(),
+            });
+            export.write(bulk_serializer)?;
+            if let Some(normal_export) = export.get_normal_export() {
+                bulk_serializer.write_all(&normal_export.extras)?;
+            }
+            bulk_serializer.flush()?;
+        }
+        // the package tag terminates the export data, bulk data starts at the tag itself
+        let bulk_data_start_offset = bulk_serializer.position() as i64
+            + match self.asset_data.use_event_driven_loader {
+                true => final_cursor_pos as i64,
+                false => 0,
+            };
+        bulk_serializer.write_u32::<BE>(UE4_ASSET_MAGIC)?;
+        bulk_serializer.flush()?;
+
+        let header = AssetHeader {
+            name_offset,
+            soft_object_paths_count,
+            soft_object_paths_offset,
+            gatherable_text_data_count,
+            gatherable_text_data_offset,
+            import_offset,
+            export_offset,
+            depends_offset,
+            soft_package_reference_offset,
+            thumbnail_table_offset,
+            asset_registry_data_offset,
+            world_tile_info_offset,
+            preload_dependency_count,
+            preload_dependency_offset,
+            data_resource_offset,
+            header_offset,
+            bulk_data_start_offset,
+        };
+        let written_layout = WrittenLayout {
+            header,
+            export_starts: category_starts,
+        };
+
+        if let Some(precomputed) = precomputed {
+            // the header was already written, it can't be fixed up anymore
+            if *precomputed != written_layout {
+                return Err(Error::no_data(
+                    "asset layout changed between the passes of a streaming write".to_string(),
+                ));
+            }
+            return Ok(written_layout);
+        }
+
+        if !self.asset_data.exports.is_empty() {
+            serializer.seek(SeekFrom::Start(export_offset as u64))?;
+            written_layout.write_export_map(&mut serializer, &self.asset_data)?;
+        }
+
+        serializer.seek(SeekFrom::Start(0))?;
+        self.write_header(&mut serializer, &written_layout.header)?;
+
+        serializer.seek(SeekFrom::Start(0))?;
+        Ok(written_layout)
+    }
+}
+
+/// Get the DOT node id of an import or an export
+fn dot_node(index: PackageIndex) -> String {
+    match index.is_import() {
+        true => format!("import_{}", -index.index),
+        false => format!("export_{}", index.index),
+    }
+}
+
+/// Get the name of an object escaped for a DOT label
+fn dot_name(name: &FName) -> String {
+    name.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl<'a> Asset<Cursor<&'a [u8]>> {
+    /// Create an asset from in-memory .uasset and .uexp data
+    ///
+    /// Useful for packages extracted from a .pak archive into memory
+    pub fn new_from_bytes(
+        uasset: &'a [u8],
+        uexp: Option<&'a [u8]>,
+        engine_version: EngineVersion,
+    ) -> Result<Self, Error> {
+        Self::new(
+            Cursor::new(uasset),
+            uexp.map(Cursor::new),
+            engine_version,
+            None,
+        )
+    }
+}
+
+impl<C: Read + Seek> AssetTrait for Asset<C> {
+    fn get_asset_data(&self) -> &AssetData {
+        &self.asset_data
+    }
+
+    fn get_asset_data_mut(&mut self) -> &mut AssetData {
+        &mut self.asset_data
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    fn search_name_reference(&self, name: &str) -> Option<i32> {
+        self.name_map.get_ref().search_name_reference(name)
+    }
+
+    fn add_name_reference(&mut self, name: String, force_add_duplicates: bool) -> i32 {
+        self.name_map
+            .get_mut()
+            .add_name_reference(name, force_add_duplicates)
+    }
+
+    fn get_name_reference<T>(&self, index: i32, func: impl FnOnce(&str) -> T) -> T {
+        func(self.name_map.get_ref().get_name_reference(index))
+    }
+
+    fn add_fname(&mut self, slice: &str) -> FName {
+        self.name_map.get_mut().add_fname(slice)
+    }
+
+    fn add_warning(&mut self, warning: AssetWarning) {
+        self.warnings.push(warning);
+    }
+
+    fn get_next_export_offset(&self, serial_offset: i64) -> Option<i64> {
+        let index = self
+            .export_serial_offsets
+            .partition_point(|e| *e <= serial_offset);
+        self.export_serial_offsets.get(index).copied()
+    }
+}
+
+impl<C: Read + Seek> ArchiveTrait for Asset<C> {
+    fn get_archive_type(&self) -> ArchiveType {
+        ArchiveType::UAsset
+    }
+
+    fn get_custom_version<T>(&self) -> CustomVersion
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        self.asset_data.get_custom_version::<T>()
+    }
+
+    fn has_unversioned_properties(&self) -> bool {
+        self.asset_data.has_unversioned_properties()
+    }
+
+    fn use_event_driven_loader(&self) -> bool {
+        self.asset_data.use_event_driven_loader
+    }
+
+    fn retain_raw_bytecode(&self) -> bool {
+        self.options.retain_raw_bytecode
+    }
+
+    fn position(&mut self) -> u64 {
+        self.raw_reader.position()
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    fn get_array_struct_type_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.array_struct_type_override
+    }
+
+    fn get_map_key_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.map_key_override
+    }
+
+    fn get_map_value_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.map_value_override
+    }
+
+    fn get_native_struct_layout(&self, struct_type: &str) -> Option<&NativeStructLayout> {
+        self.asset_data
+            .native_struct_layouts
+            .get_by_key(struct_type)
+    }
+
+    fn get_soft_object_path_list(&self) -> &[SoftObjectPath] {
+        &self.asset_data.soft_object_path_list
+    }
+
+    fn get_data_resources(&self) -> &[FObjectDataResource] {
+        self.asset_data
+            .data_resources
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.asset_data.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.asset_data.object_version
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.asset_data.object_version_ue5
+    }
+
+    fn get_mappings(&self) -> Option<&Usmap> {
+        self.asset_data.mappings.as_ref()
+    }
+
+    fn get_parent_class_export_name(&self) -> Option<FName> {
+        self.asset_data
+            .exports
+            .iter()
+            .find_map(|e| cast!(Export, ClassExport, e))
+            .and_then(|e| self.get_import(e.struct_export.super_struct))
+            .and_then(|e| self.get_import(e.outer_index))
+            .map(|e| e.object_name)
+    }
+
+    fn get_import(&self, index: PackageIndex) -> Option<Import> {
+        if !index.is_import() {
+            return None;
+        }
+
+        let index = -(index.index + 1);
+        if index < 0 || index >= self.imports.len() as i32 {
+            return None;
+        }
+
+        Some(self.imports[index as usize].clone())
+    }
+}
+
+impl<C: Read + Seek> PassthroughArchiveReader for Asset<C> {
+    type Passthrough = RawReader<C>;
+
+    fn get_passthrough(&mut self) -> &mut Self::Passthrough {
+        &mut self.raw_reader
+    }
+}
+
+impl<C: Read + Seek> Read for Asset<C> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.raw_reader.read(buf)
+    }
+}
+
+impl<C: Read + Seek> Seek for Asset<C> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.raw_reader.seek(pos)
+    }
+}
+
+// custom debug implementation to not print the whole data buffer
+impl<C: Read + Seek> Debug for Asset<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("Asset")
+            .field("info", &self.info)
+            .field("asset_data", &self.asset_data)
+            .field("legacy_file_version", &self.legacy_file_version)
+            // imports
+            // exports
+            // depends map
+            // soft package reference list
+            // asset registry data
+            // world tile info
+            // preload dependencies
+            .field("generations", &self.generations)
+            .field("package_guid", &self.package_guid)
+            .field("engine_version", &self.asset_data.get_engine_version())
+            .field("engine_version_recorded", &self.engine_version_recorded)
+            .field("engine_version_compatible", &self.engine_version_compatible)
+            .field("chunk_ids", &self.chunk_ids)
+            .field("package_flags", &self.asset_data.package_flags)
+            .field("package_source", &self.package_source)
+            .field("folder_name", &self.folder_name)
+            // map struct type override
+            // override name map hashes
+            .field("header_offset", &self.header_offset)
+            .field("name_count", &self.name_count)
+            .field("name_offset", &self.name_offset)
+            .field(
+                "gatherable_text_data_count",
+                &self.gatherable_text_data_count,
+            )
+            .field(
+                "gatherable_text_data_offset",
+                &self.gatherable_text_data_offset,
+            )
+            .field("export_count", &self.export_count)
+            .field("export_offset", &self.export_offset)
+            .field("import_count", &self.import_count)
+            .field("import_offset", &self.import_offset)
+            .field("depends_offset", &self.depends_offset)
+            .field(
+                "soft_package_reference_count",
+                &self.soft_package_reference_count,
+            )
+            .field(
+                "soft_package_reference_offset",
+                &self.soft_package_reference_offset,
+            )
+            .field("searchable_names_offset", &self.searchable_names_offset)
+            .field("thumbnail_table_offset", &self.thumbnail_table_offset)
+            .field("compression_flags", &self.compression_flags)
+            .field(
+                "asset_registry_data_offset",
+                &self.asset_registry_data_offset,
+            )
+            .field("bulk_data_start_offset", &self.bulk_data_start_offset)
+            .field("world_tile_info_data_offset", &self.world_tile_info_offset)
+            .field("preload_dependency_count", &self.preload_dependency_count)
+            .field("preload_dependency_offset", &self.preload_dependency_offset)
+            .finish()
+    }
+}
+
+/// Serialized form of an [`Asset`], contains everything needed to write the asset back
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedAsset<'a> {
+    info: Cow<'a, str>,
+    legacy_file_version: i32,
+    package_guid: Guid,
+    package_source: u32,
+    folder_name: Cow<'a, str>,
+    engine_version_recorded: Cow<'a, FEngineVersion>,
+    engine_version_compatible: Cow<'a, FEngineVersion>,
+    generations: Cow<'a, [GenerationInfo]>,
+    chunk_ids: Cow<'a, [i32]>,
+    header_offset: i32,
+    soft_object_paths_count: i32,
+    soft_object_paths_offset: i32,
+    soft_package_reference_count: i32,
+    searchable_names_offset: i32,
+    compression_flags: u32,
+    bulk_data_start_offset: i64,
+    names_referenced_from_export_data_count: i32,
+    payload_toc_offset: i64,
+    data_resource_offset: i32,
+    override_name_map_hashes: Cow<'a, IndexedMap<String, u32>>,
+    name_map: Cow<'a, [String]>,
+    imports: Cow<'a, [Import]>,
+    depends_map: Cow<'a, Option<Vec<Vec<i32>>>>,
+    soft_package_reference_list: Cow<'a, Option<Vec<String>>>,
+    #[serde(with = "unreal_asset_base::serde_base64")]
+    registry_tail: Vec<u8>,
+    parent_class: Cow<'a, Option<ParentClassInfo>>,
+    asset_data: Cow<'a, AssetData>,
+}
+
+/// Serialize an asset into a human-readable tree
+///
+/// `FName`s are serialized as strings, the name map is serialized separately to keep its order.
+/// Mappings aren't serialized
+#[cfg(feature = "serde")]
+impl<C: Read + Seek> serde::Serialize for Asset<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let name_map = self.name_map.get_ref();
+        SerializedAsset {
+            info: Cow::Borrowed(&self.info),
+            legacy_file_version: self.legacy_file_version,
+            package_guid: self.package_guid,
+            package_source: self.package_source,
+            folder_name: Cow::Borrowed(&self.folder_name),
+            engine_version_recorded: Cow::Borrowed(&self.engine_version_recorded),
+            engine_version_compatible: Cow::Borrowed(&self.engine_version_compatible),
+            generations: Cow::Borrowed(&self.generations),
+            chunk_ids: Cow::Borrowed(&self.chunk_ids),
+            header_offset: self.header_offset,
+            soft_object_paths_count: self.soft_object_paths_count,
+            soft_object_paths_offset: self.soft_object_paths_offset,
+            soft_package_reference_count: self.soft_package_reference_count,
+            searchable_names_offset: self.searchable_names_offset,
+            compression_flags: self.compression_flags,
+            bulk_data_start_offset: self.bulk_data_start_offset,
+            names_referenced_from_export_data_count: self.names_referenced_from_export_data_count,
+            payload_toc_offset: self.payload_toc_offset,
+            data_resource_offset: self.data_resource_offset,
+            override_name_map_hashes: Cow::Borrowed(&self.override_name_map_hashes),
+            name_map: Cow::Borrowed(name_map.get_name_map_index_list()),
+            imports: Cow::Borrowed(&self.imports),
+            depends_map: Cow::Borrowed(&self.depends_map),
+            soft_package_reference_list: Cow::Borrowed(&self.soft_package_reference_list),
+            registry_tail: self.registry_tail.clone(),
+            parent_class: Cow::Borrowed(&self.parent_class),
+            asset_data: Cow::Borrowed(&self.asset_data),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserialize an asset serialized with [`Asset`]'s `Serialize` implementation
+///
+/// Names are resolved against the serialized name map, so names added while editing
+/// must also be added to it.
+/// Unversioned assets need their mappings to be set in `asset_data.mappings` before writing
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Asset<Cursor<Vec<u8>>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut name_map = NameMap::new();
+        let serialized = FName::with_deserialize_name_map(&name_map, || {
+            SerializedAsset::deserialize(deserializer)
+        })?;
+
+        for name in serialized.name_map.into_owned() {
+            // keep duplicate names so that name indices stay the same
+            name_map.get_mut().add_name_reference(name, true);
+        }
+
+        let asset_data = serialized.asset_data.into_owned();
+        let mut asset = Asset::new_empty(
+            Cursor::new(Vec::new()),
+            asset_data.get_engine_version(),
+            name_map.clone(),
+        );
+        asset.raw_reader.object_version = asset_data.object_version;
+        asset.raw_reader.object_version_ue5 = asset_data.object_version_ue5;
+        asset.raw_reader.use_event_driven_loader = asset_data.use_event_driven_loader;
+        asset.asset_data = asset_data;
+
+        asset.info = serialized.info.into_owned();
+        asset.legacy_file_version = serialized.legacy_file_version;
+        asset.package_guid = serialized.package_guid;
+        asset.package_source = serialized.package_source;
+        asset.folder_name = serialized.folder_name.into_owned();
+        asset.engine_version_recorded = serialized.engine_version_recorded.into_owned();
+        asset.engine_version_compatible = serialized.engine_version_compatible.into_owned();
+        asset.generations = serialized.generations.into_owned();
+        asset.chunk_ids = serialized.chunk_ids.into_owned();
+        asset.header_offset = serialized.header_offset;
+        asset.soft_object_paths_count = serialized.soft_object_paths_count;
+        asset.soft_object_paths_offset = serialized.soft_object_paths_offset;
+        asset.soft_package_reference_count = serialized.soft_package_reference_count;
+        asset.searchable_names_offset = serialized.searchable_names_offset;
+        asset.compression_flags = serialized.compression_flags;
+        asset.bulk_data_start_offset = serialized.bulk_data_start_offset;
+        asset.names_referenced_from_export_data_count =
+            serialized.names_referenced_from_export_data_count;
+        asset.payload_toc_offset = serialized.payload_toc_offset;
+        asset.data_resource_offset = serialized.data_resource_offset;
+        asset.override_name_map_hashes = serialized.override_name_map_hashes.into_owned();
+        asset.imports = serialized.imports.into_owned();
+        asset.depends_map = serialized.depends_map.into_owned();
+        asset.soft_package_reference_list = serialized.soft_package_reference_list.into_owned();
+        asset.registry_tail = serialized.registry_tail;
+        asset.parent_class = serialized.parent_class.into_owned();
+
+        // deserialized names are dummies, back the ones present in the name map by it
+        // names missing from it were dummies in the original asset and are never written directly
+        asset.traverse_fnames(&mut |name| {
+            if let FName::Dummy { value, number } = name {
+                let Some(index) = name_map.get_ref().search_name_reference(value) else {
+                    return;
+                };
+                *name = name_map.get_ref().create_fname(index, *number);
+            }
+        });
+
+        Ok(asset)
+    }
+}
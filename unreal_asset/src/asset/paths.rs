@@ -0,0 +1,365 @@
+//! Object path lookup and soft object path rewriting
+
+use std::collections::{HashMap, HashSet};
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    cast,
+    containers::{IndexedMap, NameMap, SharedResource},
+    reader::ArchiveTrait,
+    types::PackageIndex,
+};
+use unreal_asset_exports::{Export, ExportBaseTrait, ExportNormalTrait};
+use unreal_asset_properties::material_input_property::MaterialExpression;
+use unreal_asset_properties::object_property::SoftObjectPath;
+use unreal_asset_properties::soft_path_property::{
+    SoftAssetPathProperty, SoftClassPathProperty, SoftObjectPathProperty,
+    SoftObjectPathPropertyValue, StringAssetReferenceProperty,
+};
+use unreal_asset_properties::Property;
+
+use super::{Asset, ResolvedReference};
+
+impl<C: Read + Seek> Asset<C> {
+    /// Rewrite every soft object path in this asset
+    ///
+    /// Visits soft object, soft object path, soft class path, soft asset path
+    /// and string asset reference properties, including ones nested in structs, arrays, sets, maps and data table rows,
+    /// as well as the soft package reference list.
+    /// The closure receives the asset path without the sub path,
+    /// returning `Some` replaces it and adds any new names to the name map
+    pub fn rewrite_soft_paths(&mut self, mut f: impl FnMut(&str) -> Option<String>) {
+        if let Some(soft_package_reference_list) = self.soft_package_reference_list.as_mut() {
+            for reference in soft_package_reference_list.iter_mut() {
+                if let Some(new_reference) = f(reference) {
+                    *reference = new_reference;
+                }
+            }
+        }
+
+        // export data references these by index, so they are rewritten together
+        for path in &mut self.asset_data.soft_object_path_list {
+            rewrite_soft_object_path(path, &mut self.name_map, &mut f);
+        }
+
+        for export in &mut self.asset_data.exports {
+            if let Some(data_table_export) = cast!(Export, DataTableExport, export) {
+                for row in &mut data_table_export.table.data {
+                    for property in &mut row.value {
+                        rewrite_property_soft_paths(property, &mut self.name_map, &mut f);
+                    }
+                }
+            }
+
+            if let Some(normal_export) = export.get_normal_export_mut() {
+                for property in &mut normal_export.properties {
+                    rewrite_property_soft_paths(property, &mut self.name_map, &mut f);
+                }
+            }
+        }
+    }
+
+    /// Get the object path of an import or an export
+    ///
+    /// Paths are built from the outer chain like the engine builds path names, objects directly below
+    /// a package are separated by `.` and their subobjects by `:`, deeper subobjects use `.` again,
+    /// e.g. `/Script/Engine.Actor` for an import or `Default__Foo_C:Component` for an export.
+    /// Exports without an outer are relative to this asset.
+    ///
+    /// Returns `None` for a null index or if the outer chain is invalid
+    pub fn index_to_path(&self, index: PackageIndex) -> Option<String> {
+        self.cached_object_path(index, &mut HashMap::new())
+            .map(|(path, _)| path)
+    }
+
+    /// Get the object paths of all imports and exports, see [`Asset::index_to_path`]
+    ///
+    /// Paths are built once for the whole asset, which is cheaper than
+    /// calling [`Asset::index_to_path`] for every object.
+    /// If objects share a path the first import with it is kept, then the first export.
+    pub fn object_paths(&self) -> HashMap<String, PackageIndex> {
+        let imports = (0..self.imports.len()).map(|i| PackageIndex::new(-(i as i32) - 1));
+        let exports = (0..self.asset_data.exports.len()).map(|i| PackageIndex::new(i as i32 + 1));
+
+        let mut cache = HashMap::new();
+        let mut paths = HashMap::new();
+        for index in imports.chain(exports) {
+            if let Some((path, _)) = self.cached_object_path(index, &mut cache) {
+                paths.entry(path).or_insert(index);
+            }
+        }
+        paths
+    }
+
+    /// Build the object path of an index on top of the cached paths of its outers
+    ///
+    /// Returns the path along with how deep the object is below its package
+    fn cached_object_path(
+        &self,
+        index: PackageIndex,
+        cache: &mut HashMap<i32, Option<(String, usize)>>,
+    ) -> Option<(String, usize)> {
+        // walk up until a cached outer or the root is found, (index, name, is package)
+        let mut chain = Vec::new();
+        let mut current = index;
+        let mut outer_path = None;
+        while current.index != 0 {
+            let object = match cache.get(&current.index) {
+                Some(Some(cached)) => {
+                    outer_path = Some(cached.clone());
+                    break;
+                }
+                // an outer is already known to be invalid
+                Some(None) => None,
+                // circular outer chain
+                None if chain.len() > self.imports.len() + self.asset_data.exports.len() => None,
+                None => match current.is_import() {
+                    true => self
+                        .get_import(current)
+                        .map(|e| (e.object_name.clone(), e.outer_index)),
+                    false => self.get_export(current).map(|e| {
+                        let base_export = e.get_base_export();
+                        (base_export.object_name.clone(), base_export.outer_index)
+                    }),
+                },
+            };
+            let Some((name, outer_index)) = object else {
+                for (index, _, _) in chain {
+                    cache.insert(index, None);
+                }
+                return None;
+            };
+
+            chain.push((
+                current.index,
+                name,
+                current.is_import() && outer_index.index == 0,
+            ));
+            current = outer_index;
+        }
+
+        for (index, name, is_package) in chain.into_iter().rev() {
+            let path = match outer_path {
+                Some((outer_path, outer_depth)) => {
+                    let separator = match outer_depth {
+                        1 => ':',
+                        _ => '.',
+                    };
+                    (format!("{outer_path}{separator}{name}"), outer_depth + 1)
+                }
+                None => (name.to_string(), if is_package { 0 } else { 1 }),
+            };
+            cache.insert(index, Some(path.clone()));
+            outer_path = Some(path);
+        }
+        outer_path
+    }
+
+    /// Resolve a `PackageIndex` to the import or export it references
+    ///
+    /// Returns `None` if the index is out of range
+    pub fn resolve(&self, index: PackageIndex) -> Option<ResolvedReference<'_>> {
+        match index.index {
+            0 => Some(ResolvedReference::Null),
+            i if i < 0 => self
+                .imports
+                .get((-i - 1) as usize)
+                .map(ResolvedReference::Import),
+            _ => self.get_export(index).map(ResolvedReference::Export),
+        }
+    }
+
+    /// Get the name of this package, e.g. `/Game/Foo`
+    ///
+    /// Packages don't store their own name, it's looked up in the name map as a package name
+    /// that isn't imported and ends with the name of a top level export,
+    /// e.g. `/Game/Foo` for `Foo_C` and `Default__Foo_C`
+    pub fn get_package_name(&self) -> Option<String> {
+        let asset_names = self
+            .asset_data
+            .exports
+            .iter()
+            .map(|e| e.get_base_export())
+            .filter(|e| e.outer_index.index == 0)
+            .map(|e| {
+                e.object_name.get_content(|name| {
+                    let name = name.strip_prefix("Default__").unwrap_or(name);
+                    name.strip_suffix("_C").unwrap_or(name).to_string()
+                })
+            })
+            .collect::<HashSet<_>>();
+
+        let name_map = self.name_map.get_ref();
+        name_map
+            .get_name_map_index_list()
+            .iter()
+            .filter(|name| name.starts_with('/'))
+            .filter(|name| {
+                name.rsplit_once('/')
+                    .is_some_and(|(_, asset_name)| asset_names.contains(asset_name))
+            })
+            .find(|name| {
+                !self
+                    .imports
+                    .iter()
+                    .any(|e| e.object_name.get_content(|e| e == name.as_str()))
+            })
+            .cloned()
+    }
+
+    /// Get the full object path of an import or an export
+    ///
+    /// Same as [`Asset::index_to_path`], but paths of exports are prefixed with the package name
+    /// of this asset if it's known, e.g. `/Game/Foo.Default__Foo_C:Component`,
+    /// see [`Asset::get_package_name`]
+    pub fn resolve_name(&self, index: PackageIndex) -> Option<String> {
+        let path = self.index_to_path(index)?;
+
+        // index_to_path already rejected invalid outer chains
+        let mut root = index;
+        loop {
+            let outer_index = match root.is_import() {
+                true => self.get_import(root)?.outer_index,
+                false => self.get_export(root)?.get_base_export().outer_index,
+            };
+            if outer_index.index == 0 {
+                break;
+            }
+            root = outer_index;
+        }
+
+        match root.is_export() {
+            true => match self.get_package_name() {
+                Some(package_name) => Some(format!("{}.{}", package_name, path)),
+                None => Some(path),
+            },
+            false => Some(path),
+        }
+    }
+
+    /// Get the material expression export a material input is connected to
+    ///
+    /// Cooked material inputs reference their expression by its object name,
+    /// the connected output of the expression is [`MaterialExpression::output_index`].
+    /// Returns `None` if the input is unconnected or the expression isn't an export of this asset
+    pub fn resolve_material_input(&self, input: &MaterialExpression) -> Option<&Export> {
+        if input.expression_name == "None" {
+            return None;
+        }
+
+        self.asset_data.exports.iter().find(|e| {
+            let base_export = e.get_base_export();
+            base_export.object_name == input.expression_name
+                && self
+                    .get_import(base_export.class_index)
+                    .is_some_and(|class| {
+                        class
+                            .object_name
+                            .get_content(|e| e.starts_with("MaterialExpression"))
+                    })
+        })
+    }
+
+    /// Find an import or an export by its object path
+    ///
+    /// Inverse of [`Asset::index_to_path`], imports are searched first.
+    /// Use [`Asset::object_paths`] to look up many paths
+    pub fn path_to_index(&self, path: &str) -> Option<PackageIndex> {
+        self.object_paths().get(path).copied()
+    }
+}
+
+/// Rewrite soft object paths in a property and all properties nested in it
+fn rewrite_property_soft_paths(
+    property: &mut Property,
+    name_map: &mut SharedResource<NameMap>,
+    f: &mut dyn FnMut(&str) -> Option<String>,
+) {
+    match property {
+        Property::SoftObjectProperty(property) => {
+            rewrite_soft_object_path(&mut property.value, name_map, f)
+        }
+        Property::SoftAssetPathProperty(SoftAssetPathProperty { value, .. })
+        | Property::SoftObjectPathProperty(SoftObjectPathProperty { value, .. })
+        | Property::SoftClassPathProperty(SoftClassPathProperty { value, .. })
+        | Property::StringAssetReferenceProperty(StringAssetReferenceProperty { value, .. }) => {
+            match value {
+                SoftObjectPathPropertyValue::Old(Some(path)) => {
+                    if let Some(new_path) = f(path) {
+                        *path = new_path;
+                    }
+                }
+                SoftObjectPathPropertyValue::Old(None) => {}
+                SoftObjectPathPropertyValue::New(path) => {
+                    rewrite_soft_object_path(path, name_map, f)
+                }
+            }
+        }
+        Property::StructProperty(property) => {
+            for property in &mut property.value {
+                rewrite_property_soft_paths(property, name_map, f);
+            }
+        }
+        Property::ArrayProperty(property) => {
+            for property in &mut property.value {
+                rewrite_property_soft_paths(property, name_map, f);
+            }
+        }
+        Property::SetProperty(property) => {
+            for property in property
+                .value
+                .value
+                .iter_mut()
+                .chain(property.removed_items.value.iter_mut())
+            {
+                rewrite_property_soft_paths(property, name_map, f);
+            }
+        }
+        Property::MapProperty(property) => {
+            // keys are immutable in the map, so it has to be rebuilt
+            let entries = std::mem::replace(&mut property.value, IndexedMap::new());
+            let mut value = IndexedMap::with_capacity(entries.len());
+            for (_, mut key, mut entry) in entries {
+                rewrite_property_soft_paths(&mut key, name_map, f);
+                rewrite_property_soft_paths(&mut entry, name_map, f);
+                value.insert(key, entry);
+            }
+            property.value = value;
+        }
+        _ => {}
+    }
+}
+
+/// Rewrite the asset path of a `SoftObjectPath`
+///
+/// Since UE5.1 the path is split into a package name and an asset name,
+/// it's joined with a `.` before being passed to the closure
+fn rewrite_soft_object_path(
+    path: &mut SoftObjectPath,
+    name_map: &mut SharedResource<NameMap>,
+    f: &mut dyn FnMut(&str) -> Option<String>,
+) {
+    let asset_path = &mut path.asset_path;
+    let Some(package_name) = asset_path.package_name.as_mut() else {
+        let Some(new_path) = f(&asset_path.asset_name.to_string()) else {
+            return;
+        };
+        asset_path.asset_name = name_map.get_mut().add_fname_from_display(&new_path);
+        return;
+    };
+
+    // names are written with their number suffix, e.g. `Foo_0`, so it survives the rewrite
+    let full_path = match asset_path.asset_name == "None" {
+        true => package_name.to_string(),
+        false => format!("{}.{}", package_name, asset_path.asset_name),
+    };
+    let Some(new_path) = f(&full_path) else {
+        return;
+    };
+
+    let (new_package_name, new_asset_name) = new_path
+        .rsplit_once('.')
+        .unwrap_or((new_path.as_str(), "None"));
+    *package_name = name_map.get_mut().add_fname_from_display(new_package_name);
+    asset_path.asset_name = name_map.get_mut().add_fname_from_display(new_asset_name);
+}
@@ -0,0 +1,169 @@
+//! Remapping `PackageIndex` references held by exports
+
+use unreal_asset_base::{
+    error::Error,
+    types::{PackageIndex, PackageIndexContainer},
+};
+use unreal_asset_exports::properties::{fproperty::FPropertyTrait, uproperty::UPropertyTrait};
+use unreal_asset_exports::{Export, ExportBaseTrait, ExportNormalTrait, StructExport};
+
+/// Remap package indices of the base export, of properties and of the references specific to each export type
+///
+/// Struct, class and function exports have their children, super struct, function map, loaded
+/// properties and kismet bytecode remapped. Raw exports and raw kismet bytecode can't be remapped
+/// and return an error.
+pub(super) fn remap_export_indices(
+    export: &mut Export,
+    remap: &mut dyn FnMut(PackageIndex) -> Result<PackageIndex, Error>,
+) -> Result<(), Error> {
+    let base_export = export.get_base_export_mut();
+    base_export.class_index = remap(base_export.class_index)?;
+    base_export.super_index = remap(base_export.super_index)?;
+    base_export.template_index = remap(base_export.template_index)?;
+    base_export.outer_index = remap(base_export.outer_index)?;
+    for dependency in base_export
+        .serialization_before_serialization_dependencies
+        .iter_mut()
+        .chain(
+            base_export
+                .create_before_serialization_dependencies
+                .iter_mut(),
+        )
+        .chain(
+            base_export
+                .serialization_before_create_dependencies
+                .iter_mut(),
+        )
+        .chain(base_export.create_before_create_dependencies.iter_mut())
+    {
+        *dependency = remap(*dependency)?;
+    }
+
+    match export {
+        Export::DataTableExport(data_table_export) => {
+            for row in &mut data_table_export.table.data {
+                remap_traversed_indices(remap, |mut f| row.value.traverse_package_indices(&mut f))?;
+            }
+        }
+        Export::ClassExport(class_export) => {
+            for function in class_export.func_map.values_mut() {
+                *function = remap(*function)?;
+            }
+            class_export.class_within = remap(class_export.class_within)?;
+            for interface in &mut class_export.interfaces {
+                interface.class = remap(interface.class)?;
+            }
+            class_export.class_generated_by = remap(class_export.class_generated_by)?;
+            class_export.class_default_object = remap(class_export.class_default_object)?;
+            remap_struct_indices(&mut class_export.struct_export, remap)?;
+        }
+        Export::StructExport(struct_export) => remap_struct_indices(struct_export, remap)?,
+        Export::FunctionExport(function_export) => {
+            remap_struct_indices(&mut function_export.struct_export, remap)?
+        }
+        Export::UserDefinedStructExport(user_defined_struct_export) => {
+            remap_struct_indices(&mut user_defined_struct_export.struct_export, remap)?;
+            remap_traversed_indices(remap, |mut f| {
+                user_defined_struct_export
+                    .default_struct_instance
+                    .traverse_package_indices(&mut f)
+            })?;
+        }
+        Export::PropertyExport(property_export) => remap_traversed_indices(remap, |mut f| {
+            property_export.property.traverse_package_indices(&mut f)
+        })?,
+        Export::LevelExport(level_export) => {
+            for index in level_export
+                .actors
+                .iter_mut()
+                .chain(level_export.model_components.iter_mut())
+            {
+                *index = remap(*index)?;
+            }
+            level_export.model = remap(level_export.model)?;
+            level_export.level_script = remap(level_export.level_script)?;
+            level_export.nav_list_start = remap(level_export.nav_list_start)?;
+            level_export.nav_list_end = remap(level_export.nav_list_end)?;
+        }
+        Export::WorldExport(world_export) => {
+            world_export.persistent_level = remap(world_export.persistent_level)?;
+            for index in world_export
+                .extra_objects
+                .iter_mut()
+                .chain(world_export.streaming_levels.iter_mut())
+            {
+                *index = remap(*index)?;
+            }
+        }
+        Export::MetaDataExport(meta_data_export) => {
+            for object_meta_data in &mut meta_data_export.object_meta_data {
+                object_meta_data.object = remap(object_meta_data.object)?;
+            }
+        }
+        Export::RawExport(raw_export) => {
+            return Err(Error::unimplemented(format!(
+                "Cannot remap references of raw export {}",
+                raw_export.base_export.object_name.get_owned_content()
+            )));
+        }
+        _ => {}
+    }
+
+    if let Some(normal_export) = export.get_normal_export_mut() {
+        remap_traversed_indices(remap, |mut f| {
+            normal_export.properties.traverse_package_indices(&mut f)
+        })?;
+    }
+    Ok(())
+}
+
+/// Remap the package indices of a struct export that aren't part of its normal export
+fn remap_struct_indices(
+    struct_export: &mut StructExport,
+    remap: &mut dyn FnMut(PackageIndex) -> Result<PackageIndex, Error>,
+) -> Result<(), Error> {
+    if struct_export.script_bytecode.is_none()
+        && struct_export
+            .script_bytecode_raw
+            .as_ref()
+            .is_some_and(|e| !e.is_empty())
+    {
+        return Err(Error::unimplemented(format!(
+            "Cannot remap references of raw kismet bytecode in export {}",
+            struct_export
+                .normal_export
+                .base_export
+                .object_name
+                .get_owned_content()
+        )));
+    }
+
+    remap_traversed_indices(remap, |mut f| {
+        struct_export.field.traverse_package_indices(&mut f);
+        struct_export.super_struct.traverse_package_indices(&mut f);
+        struct_export.children.traverse_package_indices(&mut f);
+        for property in &mut struct_export.loaded_properties {
+            property.traverse_package_indices(&mut f);
+        }
+        struct_export
+            .script_bytecode
+            .traverse_package_indices(&mut f);
+    })
+}
+
+/// Remap the package indices visited by `traverse`, stopping at the first error
+fn remap_traversed_indices(
+    remap: &mut dyn FnMut(PackageIndex) -> Result<PackageIndex, Error>,
+    traverse: impl FnOnce(&mut dyn FnMut(&mut PackageIndex)),
+) -> Result<(), Error> {
+    let mut result = Ok(());
+    traverse(&mut |index| {
+        if result.is_ok() {
+            match remap(*index) {
+                Ok(new_index) => *index = new_index,
+                Err(e) => result = Err(e),
+            }
+        }
+    });
+    result
+}
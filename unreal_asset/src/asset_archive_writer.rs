@@ -1,5 +1,6 @@
 //! Archive that can be used to write an asset
 
+use std::collections::HashMap;
 use std::io::{Seek, Write};
 
 use unreal_asset_base::{
@@ -10,7 +11,10 @@ use unreal_asset_base::{
     flags::EPackageFlags,
     object_version::{ObjectVersion, ObjectVersionUE5},
     reader::{ArchiveTrait, ArchiveType, ArchiveWriter, PassthroughArchiveWriter},
-    types::{FName, PackageIndex},
+    types::{
+        native_struct_layout::NativeStructLayout, object_data_resource::FObjectDataResource,
+        soft_object_path::SoftObjectPath, FName, PackageIndex,
+    },
     unversioned::Usmap,
     Import,
 };
@@ -28,6 +32,10 @@ pub struct AssetArchiveWriter<'parent_writer, 'asset, ParentWriter: ArchiveWrite
     imports: &'asset [Import],
     /// Asset name map
     name_map: SharedResource<NameMap>,
+    /// Indices of the asset's soft object paths in its soft object path list
+    soft_object_path_indices: HashMap<&'asset SoftObjectPath, usize>,
+    /// Offset of the parent writer's start in the package
+    package_offset: u64,
 }
 
 impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter>
@@ -40,13 +48,28 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter>
         imports: &'asset [Import],
         name_map: SharedResource<NameMap>,
     ) -> Self {
+        // `FName` hashes don't depend on the shared name map
+        #[allow(clippy::mutable_key_type)]
+        let mut soft_object_path_indices = HashMap::new();
+        for (index, path) in asset_data.soft_object_path_list.iter().enumerate() {
+            soft_object_path_indices.entry(path).or_insert(index);
+        }
+
         AssetArchiveWriter {
             writer: parent_writer,
             asset_data,
             imports,
             name_map,
+            soft_object_path_indices,
+            package_offset: 0,
         }
     }
+
+    /// Set the offset of the parent writer's start in the package
+    pub fn with_package_offset(mut self, package_offset: u64) -> Self {
+        self.package_offset = package_offset;
+        self
+    }
 }
 
 impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter> ArchiveTrait
@@ -74,6 +97,10 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter> ArchiveTrait
         self.asset_data.use_event_driven_loader
     }
 
+    fn get_package_offset(&self) -> u64 {
+        self.package_offset
+    }
+
     fn position(&mut self) -> u64 {
         self.writer.position()
     }
@@ -98,6 +125,27 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter> ArchiveTrait
         &self.asset_data.map_value_override
     }
 
+    fn get_native_struct_layout(&self, struct_type: &str) -> Option<&NativeStructLayout> {
+        self.asset_data
+            .native_struct_layouts
+            .get_by_key(struct_type)
+    }
+
+    fn get_soft_object_path_list(&self) -> &[SoftObjectPath] {
+        &self.asset_data.soft_object_path_list
+    }
+
+    fn get_soft_object_path_index(&self, path: &SoftObjectPath) -> Option<usize> {
+        self.soft_object_path_indices.get(path).copied()
+    }
+
+    fn get_data_resources(&self) -> &[FObjectDataResource] {
+        self.asset_data
+            .data_resources
+            .as_deref()
+            .unwrap_or_default()
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.asset_data.get_engine_version()
     }
@@ -129,8 +177,8 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter> ArchiveTrait
             return None;
         }
 
-        let index = -index.index - 1;
-        if index < 0 || index > self.imports.len() as i32 {
+        let index = -(index.index + 1);
+        if index < 0 || index >= self.imports.len() as i32 {
             return None;
         }
 
@@ -0,0 +1,161 @@
+//! Asset builder
+
+use std::io::Cursor;
+
+use unreal_asset_base::{
+    containers::{NameMap, SharedResource},
+    crc,
+    engine_version::EngineVersion,
+    error::Error,
+    flags::EPackageFlags,
+    types::{FName, GenerationInfo, PackageIndex},
+    Guid, Import,
+};
+use unreal_asset_exports::Export;
+
+use crate::asset::Asset;
+
+/// Builder for creating an [`Asset`] from scratch
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::Cursor;
+///
+/// use unreal_asset::{
+///     asset_builder::AssetBuilder, engine_version::EngineVersion, types::PackageIndex, Import,
+/// };
+///
+/// let mut builder = AssetBuilder::new()
+///     .engine_version(EngineVersion::VER_UE4_27)
+///     .folder_name("/Game/MyAsset");
+///
+/// let class_package = builder.add_fname("/Script/CoreUObject");
+/// let class_name = builder.add_fname("Package");
+/// let object_name = builder.add_fname("/Script/Engine");
+/// builder.add_import(Import::new(
+///     class_package,
+///     class_name,
+///     PackageIndex::new(0),
+///     object_name,
+///     false,
+/// ));
+///
+/// let asset = builder.build().unwrap();
+/// let mut cursor = Cursor::new(Vec::new());
+/// asset.write_data(&mut cursor, None).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AssetBuilder {
+    engine_version: Option<EngineVersion>,
+    package_flags: EPackageFlags,
+    folder_name: String,
+    package_guid: Option<Guid>,
+    name_map: SharedResource<NameMap>,
+    imports: Vec<Import>,
+    exports: Vec<Export>,
+}
+
+impl AssetBuilder {
+    /// Create a new `AssetBuilder` instance
+    pub fn new() -> Self {
+        AssetBuilder {
+            engine_version: None,
+            package_flags: EPackageFlags::PKG_NONE,
+            folder_name: String::from("None"),
+            package_guid: None,
+            name_map: NameMap::new(),
+            imports: Vec::new(),
+            exports: Vec::new(),
+        }
+    }
+
+    /// Set the engine version the asset is serialized with
+    pub fn engine_version(mut self, engine_version: EngineVersion) -> Self {
+        self.engine_version = Some(engine_version);
+        self
+    }
+
+    /// Set package flags
+    pub fn package_flags(mut self, package_flags: EPackageFlags) -> Self {
+        self.package_flags = package_flags;
+        self
+    }
+
+    /// Set the folder name
+    pub fn folder_name(mut self, folder_name: &str) -> Self {
+        self.folder_name = folder_name.to_string();
+        self
+    }
+
+    /// Set the package guid
+    ///
+    /// If not set, the guid is derived from the folder name and the name map
+    pub fn package_guid(mut self, package_guid: Guid) -> Self {
+        self.package_guid = Some(package_guid);
+        self
+    }
+
+    /// Add an `FName` to the name map of the built asset
+    pub fn add_fname(&mut self, slice: &str) -> FName {
+        self.name_map.get_mut().add_fname(slice)
+    }
+
+    /// Add an `Import`, returns its index
+    pub fn add_import(&mut self, import: Import) -> PackageIndex {
+        self.imports.push(import);
+        PackageIndex::new(-(self.imports.len() as i32))
+    }
+
+    /// Add an `Export`, returns its index
+    ///
+    /// Serial offsets and sizes of the export are computed when the asset is written
+    pub fn add_export(&mut self, export: impl Into<Export>) -> PackageIndex {
+        self.exports.push(export.into());
+        PackageIndex::new(self.exports.len() as i32)
+    }
+
+    /// Build the asset
+    ///
+    /// Fails with [`Error::NoEngineVersion`] if no engine version was set
+    pub fn build(self) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+        let engine_version = match self.engine_version {
+            Some(e) if e != EngineVersion::UNKNOWN => e,
+            _ => return Err(Error::NoEngineVersion),
+        };
+
+        let package_guid = self
+            .package_guid
+            .unwrap_or_else(|| Self::default_package_guid(&self.folder_name, &self.name_map));
+        let name_count = self.name_map.get_ref().get_name_map_index_list().len() as i32;
+
+        let mut asset = Asset::new_empty(Cursor::new(Vec::new()), engine_version, self.name_map);
+        asset.asset_data.package_flags = self.package_flags;
+        asset.folder_name = self.folder_name;
+        asset.package_guid = package_guid;
+        asset.generations = vec![GenerationInfo::new(self.exports.len() as i32, name_count)];
+        asset.imports = self.imports;
+        asset.asset_data.exports = self.exports;
+
+        Ok(asset)
+    }
+
+    /// Derive a stable package guid, so that building the same asset twice produces the same bytes
+    fn default_package_guid(folder_name: &str, name_map: &SharedResource<NameMap>) -> Guid {
+        let folder_hash = crc::cityhash64_to_lower(folder_name);
+        let names_hash =
+            crc::cityhash64_to_lower(&name_map.get_ref().get_name_map_index_list().join("/"));
+        Guid::from_ints(
+            (folder_hash >> 32) as u32,
+            folder_hash as u32,
+            (names_hash >> 32) as u32,
+            names_hash as u32,
+        )
+    }
+}
+
+impl Default for AssetBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -12,22 +12,40 @@ use unreal_asset_base::{
     flags::EPackageFlags,
     object_version::{ObjectVersion, ObjectVersionUE5},
     reader::ArchiveReader,
-    types::{FName, PackageIndex},
+    types::{
+        gatherable_text_data::GatherableTextData, native_struct_layout::NativeStructLayout,
+        object_data_resource::FObjectDataResource, soft_object_path::SoftObjectPath,
+        thumbnail_table::ThumbnailTable, FName, PackageIndex,
+    },
     unversioned::Usmap,
-    FNameContainer,
+    FNameContainer, Guid,
 };
 use unreal_asset_exports::{
     base_export::BaseExport, class_export::ClassExport, data_table_export::DataTableExport,
     enum_export::EnumExport, function_export::FunctionExport, level_export::LevelExport,
-    normal_export::NormalExport, properties::fproperty::FProperty, property_export::PropertyExport,
-    raw_export::RawExport, string_table_export::StringTableExport,
-    user_defined_struct_export::UserDefinedStructExport, world_export::WorldExport, Export,
-    ExportNormalTrait,
+    meta_data_export::MetaDataExport, normal_export::NormalExport,
+    properties::fproperty::FProperty, property_export::PropertyExport, raw_export::RawExport,
+    string_table_export::StringTableExport, struct_export::StructExport,
+    texture_2d_export::Texture2DExport, user_defined_struct_export::UserDefinedStructExport,
+    world_export::WorldExport, Export, ExportBaseTrait, ExportNormalTrait,
 };
 use unreal_asset_properties::world_tile_property::FWorldTileInfo;
 
+use crate::asset_registry_data::AssetRegistryData;
+use crate::PACKAGE_FILE_TAG_SIZE;
+
+/// Property definition of a struct
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyDefinition<'a> {
+    /// Property stored inside of the struct, used since `FCoreObjectVersion::FProperties`
+    FProperty(&'a FProperty),
+    /// Property stored as a separate export, used before `FCoreObjectVersion::FProperties`
+    UProperty(&'a PropertyExport),
+}
+
 /// Unreal asset data, this is relevant for all assets
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssetData {
     /// Does asset use the event driven loader
     pub use_event_driven_loader: bool,
@@ -39,6 +57,10 @@ pub struct AssetData {
 
     /// File licensee version, used by some games for their own engine versioning.
     pub file_license_version: i32,
+    /// Legacy UE3 version, stored for all legacy file versions except -4
+    ///
+    /// `None` writes the default, 864 for versioned assets and 0 for unversioned assets
+    pub legacy_ue3_version: Option<i32>,
 
     /// Object version
     #[container_ignore]
@@ -56,15 +78,42 @@ pub struct AssetData {
 
     /// .usmap mappings
     #[container_ignore]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mappings: Option<Usmap>,
 
     /// Object exports
     pub exports: Vec<Export>,
 
+    /// Asset registry data
+    #[container_ignore]
+    pub asset_registry_data: Option<AssetRegistryData>,
+
     /// World tile information used by WorldComposition
     /// Degines propertiesn ecessary for tile positioning in the world
     pub world_tile_info: Option<FWorldTileInfo>,
 
+    /// Data resource table, locates bulk data payloads since `ObjectVersionUE5::DATA_RESOURCES`
+    #[container_ignore]
+    pub data_resources: Option<Vec<FObjectDataResource>>,
+
+    /// Soft object path list since `ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST`
+    ///
+    /// When it isn't empty soft object paths in export data are stored as indices into it
+    pub soft_object_path_list: Vec<SoftObjectPath>,
+
+    /// Thumbnail table
+    #[container_ignore]
+    pub thumbnail_table: Option<ThumbnailTable>,
+
+    /// Gatherable text data, localizable text of uncooked packages
+    #[container_ignore]
+    pub gatherable_text_data: Option<Vec<GatherableTextData>>,
+
+    /// Localization id of uncooked packages since `ObjectVersion::VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID`,
+    /// the package's text is keyed by it, see [`AssetData::get_localization_id`]
+    #[container_ignore]
+    pub localization_id: Option<String>,
+
     /// Map properties with StructProperties inside, have no way of determining the underlying type of the struct
     /// This is used for specifying those types for keys
     #[container_ignore]
@@ -78,6 +127,50 @@ pub struct AssetData {
     /// This is used for specifying those types
     #[container_ignore]
     pub array_struct_type_override: IndexedMap<String, String>,
+
+    /// Layouts of natively serialized structs by struct type
+    ///
+    /// Structs of these types are read as their fields in order instead of as tagged properties,
+    /// the struct type of map keys and values still has to be known through the map overrides
+    #[container_ignore]
+    pub native_struct_layouts: IndexedMap<String, NativeStructLayout>,
+}
+
+/// Struct types of containers whose inner struct type isn't stored in the asset
+///
+/// Collected while parsing, save these to re-parse an asset with the same context
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StructTypeOverrides {
+    /// Struct types of map keys by property name
+    pub map_key: IndexedMap<String, String>,
+    /// Struct types of map values by property name
+    pub map_value: IndexedMap<String, String>,
+    /// Struct types of array elements by property name
+    pub array_struct_type: IndexedMap<String, String>,
+}
+
+/// Warning emitted while reading an asset
+#[derive(Debug)]
+pub struct AssetWarning {
+    /// Index of the export in [`AssetData::exports`]
+    pub export_index: usize,
+    /// Class type the export was read as, `None` if it couldn't be resolved
+    pub class_type: Option<String>,
+    /// What failed
+    pub kind: AssetWarningKind,
+    /// Error that caused the warning
+    pub error: Error,
+}
+
+/// Kind of an [`AssetWarning`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetWarningKind {
+    /// The export failed to parse and was read as a [`RawExport`] instead
+    ExportParse,
+    /// Reading the export's property offsets with
+    /// [`AssetOptions::track_property_offsets`](crate::asset::AssetOptions::track_property_offsets) failed,
+    /// the export itself was read and only its property offsets are incomplete
+    PropertyOffsets,
 }
 
 /// Export read from [`AssetData`]
@@ -128,6 +221,11 @@ impl ReadExport {
     }
 }
 
+/// Error for an export that was read as a [`RawExport`] because its data is longer than its serial size
+pub(crate) fn serial_size_overrun() -> Error {
+    Error::invalid_file("Export data is longer than its serial size".to_string())
+}
+
 impl AssetData {
     /// Creates a new `AssetData` instance
     pub fn new() -> AssetData {
@@ -145,7 +243,7 @@ impl AssetData {
         self.engine_version = engine_version;
         self.object_version = object_version;
         self.object_version_ue5 = object_version_ue5;
-        self.custom_versions = CustomVersion::get_default_custom_version_container(engine_version);
+        self.custom_versions = engine_version.default_custom_versions();
     }
 
     /// Get a custom version from this AssetData
@@ -176,6 +274,38 @@ impl AssetData {
             .unwrap_or_else(|| CustomVersion::new(T::GUID, 0))
     }
 
+    /// Set a custom version of this AssetData
+    ///
+    /// This only changes a single custom version, unlike [`AssetData::set_engine_version`],
+    /// reading and writing use the new version in all branches gated by it.
+    /// The custom version enum variants document which serialization changes they introduced.
+    ///
+    /// Unversioned assets don't store custom versions, they are only used while writing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run,ignore
+    /// use unreal_asset::{
+    ///     asset::AssetData,
+    ///     custom_version::FCoreObjectVersion,
+    /// };
+    /// let mut data: AssetData = ...;
+    /// // write struct exports with UProperty children, the last version before FProperties
+    /// data.set_custom_version(FCoreObjectVersion::SkeletalMaterialEditorDataStripping);
+    /// ```
+    pub fn set_custom_version<T>(&mut self, version: T)
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        let version: i32 = version.into();
+        match self.custom_versions.iter_mut().find(|e| e.guid == T::GUID) {
+            Some(custom_version) => custom_version.version = version,
+            None => self
+                .custom_versions
+                .push(CustomVersion::new(T::GUID, version)),
+        }
+    }
+
     /// Get engine version
     pub fn get_engine_version(&self) -> EngineVersion {
         self.engine_version
@@ -218,11 +348,88 @@ impl AssetData {
             .find_map(|e| cast!(Export, ClassExport, e))
     }
 
+    /// Get a `StructExport`, including the ones of class, function and user defined struct exports
+    pub fn get_struct_export(&self, index: PackageIndex) -> Option<&StructExport> {
+        match self.get_export(index)? {
+            Export::StructExport(e) => Some(e),
+            Export::ClassExport(e) => Some(&e.struct_export),
+            Export::FunctionExport(e) => Some(&e.struct_export),
+            Export::UserDefinedStructExport(e) => Some(&e.struct_export),
+            _ => None,
+        }
+    }
+
+    /// Get property definitions of a struct export
+    ///
+    /// Before `FCoreObjectVersion::FProperties` properties are separate `PropertyExport`s
+    /// referenced from the struct's children, after that they are stored in `loaded_properties`
+    pub fn get_struct_properties(
+        &self,
+        index: PackageIndex,
+    ) -> Option<Vec<PropertyDefinition<'_>>> {
+        let struct_export = self.get_struct_export(index)?;
+
+        let mut properties = struct_export
+            .loaded_properties
+            .iter()
+            .map(PropertyDefinition::FProperty)
+            .collect::<Vec<_>>();
+
+        properties.extend(
+            struct_export
+                .children
+                .iter()
+                .filter_map(|e| self.get_export(*e))
+                .filter_map(|e| cast!(Export, PropertyExport, e))
+                .map(PropertyDefinition::UProperty),
+        );
+
+        Some(properties)
+    }
+
     /// Get if the asset has unversioned properties
     pub fn has_unversioned_properties(&self) -> bool {
         self.package_flags
             .contains(EPackageFlags::PKG_UNVERSIONED_PROPERTIES)
     }
+
+    /// Get all struct type overrides, including the ones collected while parsing
+    pub fn get_struct_type_overrides(&self) -> StructTypeOverrides {
+        StructTypeOverrides {
+            map_key: self.map_key_override.clone(),
+            map_value: self.map_value_override.clone(),
+            array_struct_type: self.array_struct_type_override.clone(),
+        }
+    }
+
+    /// Replace all struct type overrides
+    pub fn set_struct_type_overrides(&mut self, overrides: StructTypeOverrides) {
+        self.map_key_override = overrides.map_key;
+        self.map_value_override = overrides.map_value;
+        self.array_struct_type_override = overrides.array_struct_type;
+    }
+
+    /// Get the localization id as a guid
+    ///
+    /// Returns `None` if the package has no localization id or it isn't a guid
+    pub fn get_localization_id(&self) -> Option<Guid> {
+        let digits = self.localization_id.as_deref()?.replace('-', "");
+        if digits.len() != 32 || !digits.bytes().all(|e| e.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let mut ints = [0u32; 4];
+        for (i, int) in ints.iter_mut().enumerate() {
+            *int = u32::from_str_radix(&digits[i * 8..i * 8 + 8], 16).ok()?;
+        }
+        Some(Guid::from_ints(ints[0], ints[1], ints[2], ints[3]))
+    }
+
+    /// Set the localization id from a guid, formatted the way the editor formats it
+    pub fn set_localization_id(&mut self, localization_id: Guid) {
+        let (a, b, c, d) = localization_id.into();
+        self.localization_id = Some(format!("{a:08X}{b:08X}{c:08X}{d:08X}"));
+    }
 }
 
 impl Default for AssetData {
@@ -232,13 +439,20 @@ impl Default for AssetData {
             unversioned: true,
             package_flags: EPackageFlags::PKG_NONE,
             file_license_version: 0,
+            legacy_ue3_version: None,
             engine_version: EngineVersion::UNKNOWN,
             object_version: ObjectVersion::UNKNOWN,
             object_version_ue5: ObjectVersionUE5::UNKNOWN,
             custom_versions: Vec::new(),
             mappings: None,
             exports: Vec::new(),
+            asset_registry_data: None,
             world_tile_info: None,
+            data_resources: None,
+            soft_object_path_list: Vec::new(),
+            thumbnail_table: None,
+            gatherable_text_data: None,
+            localization_id: None,
             map_key_override: IndexedMap::from([
                 ("PlayerCharacterIDs".to_string(), "Guid".to_string()),
                 (
@@ -285,6 +499,7 @@ impl Default for AssetData {
                 "Keys".to_string(),
                 "RichCurveKey".to_string(),
             )]),
+            native_struct_layouts: IndexedMap::new(),
         }
     }
 }
@@ -311,6 +526,23 @@ pub trait AssetTrait {
 
     /// Add an `FName`
     fn add_fname(&mut self, slice: &str) -> FName;
+
+    /// Record a warning emitted while reading the asset
+    ///
+    /// Warnings are dropped by default
+    fn add_warning(&mut self, _warning: AssetWarning) {}
+
+    /// Get the serial offset of the closest export stored after `serial_offset`
+    ///
+    /// Export data isn't necessarily stored in export map order, by default this scans the export map
+    fn get_next_export_offset(&self, serial_offset: i64) -> Option<i64> {
+        self.get_asset_data()
+            .exports
+            .iter()
+            .map(|e| e.get_base_export().serial_offset)
+            .filter(|e| *e > serial_offset)
+            .min()
+    }
 }
 
 /// Export reader trait, used to read exports from an asset, implemented for all assets that implemented [`ArchiveReader`]+[`AssetTrait`]
@@ -324,19 +556,16 @@ pub trait ExportReaderTrait: ArchiveReader + AssetTrait + Sized {
     /// # Arguments
     ///
     /// * `base_export` - base export used for reading this export
-    /// * `i` - export index
+    /// * `_i` - export index
     fn read_export_no_raw(
         &mut self,
         base_export: BaseExport,
         i: usize,
     ) -> Result<ReadExport, Error> {
-        let asset_data = self.get_asset_data();
-        let next_starting = match i < (asset_data.exports.len() - 1) {
-            true => match &asset_data.exports[i + 1] {
-                Export::BaseExport(next_export) => next_export.serial_offset as u64,
-                _ => self.data_length()? - 4,
-            },
-            false => self.data_length()? - 4,
+        // this export ends where the closest export after it starts
+        let next_starting = match self.get_next_export_offset(base_export.serial_offset) {
+            Some(offset) => offset as u64,
+            None => self.data_length()? - PACKAGE_FILE_TAG_SIZE,
         };
 
         self.seek(SeekFrom::Start(base_export.serial_offset as u64))?;
@@ -345,6 +574,7 @@ pub trait ExportReaderTrait: ArchiveReader + AssetTrait + Sized {
         let export_class_type = self
             .get_export_class_type(base_export.class_index)
             .ok_or_else(|| Error::invalid_package_index("Unknown class type".to_string()))?;
+        export_class_type.get_content(|class| log::debug!("Export {i} class type: {class}"));
 
         let mut new_map_key_overrides = IndexedMap::new();
         let mut new_map_value_overrides = IndexedMap::new();
@@ -360,6 +590,8 @@ pub trait ExportReaderTrait: ArchiveReader + AssetTrait + Sized {
                 "StringTable" => StringTableExport::from_base(&base_export, self)?.into(),
                 "Enum" | "UserDefinedEnum" => EnumExport::from_base(&base_export, self)?.into(),
                 "Function" => FunctionExport::from_base(&base_export, self)?.into(),
+                "MetaData" => MetaDataExport::from_base(&base_export, self)?.into(),
+                "Texture2D" => Texture2DExport::from_base(&base_export, self)?.into(),
                 _ => {
                     if export_class_type.ends_with("DataTable") {
                         DataTableExport::from_base(&base_export, self)?.into()
@@ -417,9 +649,8 @@ pub trait ExportReaderTrait: ArchiveReader + AssetTrait + Sized {
         })?;
 
         let extras_len = next_starting as i64 - self.position() as i64;
-        if extras_len < 0 {
-            // todo: warning?
-
+        if extras_len < 0 || extras_len as u64 > self.data_length()?.saturating_sub(self.position())
+        {
             self.seek(SeekFrom::Start(base_export.serial_offset as u64))?;
             let export: Export = RawExport::from_base(base_export, self)?.into();
             return Ok(ReadExport::new(
@@ -444,7 +675,8 @@ pub trait ExportReaderTrait: ArchiveReader + AssetTrait + Sized {
 
     /// Read an export from this asset
     ///
-    /// If an error occurs during export reading, it reads a RawExport and returns that
+    /// If an error occurs during export reading, it reads a RawExport and returns that,
+    /// the error is recorded as an [`AssetWarning`]
     ///
     /// This function also automatically reduces the [`ReadExport`] to an [`Export`]
     ///
@@ -459,16 +691,33 @@ pub trait ExportReaderTrait: ArchiveReader + AssetTrait + Sized {
             })?;
 
         let serial_offset = base_export.serial_offset as u64;
+        let class_type = self
+            .get_export_class_type(base_export.class_index)
+            .map(|e| e.get_owned_content());
 
         match self.read_export_no_raw(base_export.clone(), i) {
             Ok(e) => {
                 let asset_data_mut = self.get_asset_data_mut();
                 let reduced = e.reduce(asset_data_mut);
 
+                if let Export::RawExport(_) = reduced {
+                    self.add_warning(AssetWarning {
+                        export_index: i,
+                        class_type,
+                        kind: AssetWarningKind::ExportParse,
+                        error: serial_size_overrun(),
+                    });
+                }
+
                 Ok(reduced)
             }
-            Err(_e) => {
-                // todo: warning?
+            Err(e) => {
+                self.add_warning(AssetWarning {
+                    export_index: i,
+                    class_type,
+                    kind: AssetWarningKind::ExportParse,
+                    error: e,
+                });
                 self.seek(SeekFrom::Start(serial_offset))?;
                 Ok(RawExport::from_base(base_export, self)?.into())
             }
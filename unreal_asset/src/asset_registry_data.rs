@@ -0,0 +1,135 @@
+//! Package asset registry data
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    flags::EPackageFlags,
+    object_version::ObjectVersion,
+    reader::{ArchiveReader, ArchiveWriter},
+    Error,
+};
+use unreal_asset_registry::objects::asset_bundle_data::{
+    parse_asset_bundle_tag, ASSET_BUNDLE_DATA_TAG,
+};
+
+/// Asset registry tags of a single object in a package
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetRegistryObject {
+    /// Object path relative to the package
+    pub object_path: Option<String>,
+    /// Object class name
+    pub object_class_name: Option<String>,
+    /// Tag key/value pairs
+    pub tags: Vec<(Option<String>, Option<String>)>,
+}
+
+impl AssetRegistryObject {
+    /// Get the asset paths of every asset bundle keyed by bundle name
+    ///
+    /// Parsed from the [`ASSET_BUNDLE_DATA_TAG`] tag, returns `None` if the tag isn't present
+    pub fn get_asset_bundles(&self) -> Result<Option<HashMap<String, Vec<String>>>, Error> {
+        self.tags
+            .iter()
+            .find(|(key, _)| key.as_deref() == Some(ASSET_BUNDLE_DATA_TAG))
+            .and_then(|(_, value)| value.as_deref())
+            .map(parse_asset_bundle_tag)
+            .transpose()
+    }
+}
+
+/// Asset registry data stored in a package summary
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetRegistryData {
+    /// Objects with asset registry tags
+    pub objects: Vec<AssetRegistryObject>,
+}
+
+impl AssetRegistryData {
+    /// Check if the registry data starts with a dependency data offset
+    ///
+    /// Cooked packages are frozen at the format before `VER_UE4_ASSETREGISTRY_DEPENDENCYFLAGS`
+    fn has_dependency_data_offset(
+        object_version: ObjectVersion,
+        package_flags: EPackageFlags,
+    ) -> bool {
+        object_version >= ObjectVersion::VER_UE4_ASSETREGISTRY_DEPENDENCYFLAGS
+            && !package_flags.contains(EPackageFlags::PKG_FILTER_EDITOR_ONLY)
+    }
+
+    /// Read `AssetRegistryData` from an asset
+    ///
+    /// The dependency data following the objects is not read
+    pub(crate) fn read<Reader: ArchiveReader>(
+        asset: &mut Reader,
+        package_flags: EPackageFlags,
+    ) -> Result<Self, Error> {
+        if Self::has_dependency_data_offset(asset.get_object_version(), package_flags) {
+            // dependency data always directly follows the objects
+            asset.read_i64::<LE>()?;
+        }
+
+        let object_count = asset.read_i32::<LE>()?;
+        let objects = asset.read_array_with_length(object_count, |asset| {
+            let object_path = asset.read_fstring()?;
+            let object_class_name = asset.read_fstring()?;
+
+            let tag_count = asset.read_i32::<LE>()?;
+            let tags = asset.read_array_with_length(tag_count, |asset| {
+                Ok((asset.read_fstring()?, asset.read_fstring()?))
+            })?;
+
+            Ok(AssetRegistryObject {
+                object_path,
+                object_class_name,
+                tags,
+            })
+        })?;
+
+        Ok(AssetRegistryData { objects })
+    }
+
+    /// Write `AssetRegistryData` to an asset
+    ///
+    /// The dependency data offset, if present, points right after the objects
+    pub(crate) fn write<Writer: ArchiveWriter>(
+        &self,
+        asset: &mut Writer,
+        package_flags: EPackageFlags,
+    ) -> Result<(), Error> {
+        let dependency_data_offset_position =
+            match Self::has_dependency_data_offset(asset.get_object_version(), package_flags) {
+                true => {
+                    let position = asset.position();
+                    asset.write_i64::<LE>(0)?;
+                    Some(position)
+                }
+                false => None,
+            };
+
+        asset.write_i32::<LE>(self.objects.len() as i32)?;
+        for object in &self.objects {
+            asset.write_fstring(object.object_path.as_deref())?;
+            asset.write_fstring(object.object_class_name.as_deref())?;
+
+            asset.write_i32::<LE>(object.tags.len() as i32)?;
+            for (key, value) in &object.tags {
+                asset.write_fstring(key.as_deref())?;
+                asset.write_fstring(value.as_deref())?;
+            }
+        }
+
+        if let Some(position) = dependency_data_offset_position {
+            let dependency_data_offset = asset.position();
+            asset.seek(SeekFrom::Start(position))?;
+            asset.write_i64::<LE>(dependency_data_offset as i64)?;
+            asset.seek(SeekFrom::Start(dependency_data_offset))?;
+        }
+
+        Ok(())
+    }
+}
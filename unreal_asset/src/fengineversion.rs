@@ -11,6 +11,7 @@ use unreal_asset_base::{
 
 /// EngineVersion for an Asset
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FEngineVersion {
     pub(crate) major: u16,
     pub(crate) minor: u16,
@@ -84,9 +84,18 @@ pub use unreal_asset_registry as registry;
 pub mod ac7;
 pub mod asset;
 pub mod asset_archive_writer;
+pub mod asset_builder;
 pub mod asset_data;
+pub mod asset_registry_data;
 pub mod fengineversion;
+pub mod section_layout;
+mod stream_writer;
+pub mod zen_asset;
 
 pub use asset::Asset;
+pub use zen_asset::ZenAsset;
 
-const UE4_ASSET_MAGIC: u32 = u32::from_be_bytes([0xc1, 0x83, 0x2a, 0x9e]);
+use base::UE4_ASSET_MAGIC;
+/// Size of the package tag ([`UE4_ASSET_MAGIC`]) that terminates the export data,
+/// `bulk_data_start_offset` points at the start of this tag
+const PACKAGE_FILE_TAG_SIZE: u64 = std::mem::size_of::<u32>() as u64;
@@ -0,0 +1,105 @@
+//! Package summary section and export data layout
+
+/// A section of the package summary that is located by an offset in the header
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HeaderSection {
+    /// Name map
+    NameMap,
+    /// Soft object path list
+    SoftObjectPaths,
+    /// Gatherable text data
+    GatherableTextData,
+    /// Import map
+    Imports,
+    /// Export map
+    Exports,
+    /// Depends map
+    DependsMap,
+    /// Soft package reference list
+    SoftPackageReferences,
+    /// Thumbnail data and table
+    Thumbnails,
+    /// Asset registry data
+    AssetRegistryData,
+    /// World tile info
+    WorldTileInfo,
+    /// Preload dependencies
+    PreloadDependencies,
+    /// Data resource table
+    DataResources,
+}
+
+/// Order in which header sections are written
+///
+/// Export data is always written after all header sections
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SectionLayout {
+    /// Sections in the order they are written, must contain every section exactly once
+    pub sections: Vec<HeaderSection>,
+}
+
+impl SectionLayout {
+    /// All header sections in the order the engine writes them
+    pub const DEFAULT_ORDER: [HeaderSection; 12] = [
+        HeaderSection::NameMap,
+        HeaderSection::SoftObjectPaths,
+        HeaderSection::GatherableTextData,
+        HeaderSection::Imports,
+        HeaderSection::Exports,
+        HeaderSection::DependsMap,
+        HeaderSection::SoftPackageReferences,
+        HeaderSection::Thumbnails,
+        HeaderSection::AssetRegistryData,
+        HeaderSection::WorldTileInfo,
+        HeaderSection::PreloadDependencies,
+        HeaderSection::DataResources,
+    ];
+
+    /// Create a new `SectionLayout` instance
+    pub fn new(sections: Vec<HeaderSection>) -> Self {
+        SectionLayout { sections }
+    }
+
+    /// Check that every section is present exactly once
+    pub fn is_valid(&self) -> bool {
+        self.sections.len() == Self::DEFAULT_ORDER.len()
+            && Self::DEFAULT_ORDER
+                .iter()
+                .all(|section| self.sections.contains(section))
+    }
+}
+
+impl Default for SectionLayout {
+    fn default() -> Self {
+        SectionLayout::new(Self::DEFAULT_ORDER.to_vec())
+    }
+}
+
+/// Problem found in the serial layout of export data
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LayoutIssue {
+    /// Export has a negative serial offset or size
+    InvalidRange {
+        /// Index of the export in the export map
+        export_index: usize,
+    },
+    /// Export data starts before the end of the header or ends after the bulk data start offset
+    OutOfBounds {
+        /// Index of the export in the export map
+        export_index: usize,
+    },
+    /// Export data overlaps the data of another export
+    Overlap {
+        /// Index of the export in the export map
+        export_index: usize,
+        /// Index of the export it overlaps
+        other_export_index: usize,
+    },
+    /// Bytes between the header, exports and bulk data that belong to no export
+    Gap {
+        /// Offset of the first unused byte
+        offset: i64,
+        /// Amount of unused bytes
+        size: i64,
+    },
+}
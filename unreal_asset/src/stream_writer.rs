@@ -0,0 +1,115 @@
+//! Writers used for writing assets to sinks that can't seek
+
+use std::io::{self, Seek, SeekFrom, Write};
+
+/// Resolve a seek relative to a position and the end of the written data
+fn resolve_seek(pos: SeekFrom, position: u64, end: u64) -> io::Result<u64> {
+    let target = match pos {
+        SeekFrom::Start(offset) => Some(offset),
+        SeekFrom::Current(offset) => position.checked_add_signed(offset),
+        SeekFrom::End(offset) => end.checked_add_signed(offset),
+    };
+    target.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )
+    })
+}
+
+/// A writer that discards all data and only keeps track of the position and length,
+/// used for computing offsets before a streaming write
+#[derive(Debug, Default)]
+pub(crate) struct CountingWriter {
+    /// Current position
+    position: u64,
+    /// Length of the written data
+    len: u64,
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.position += buf.len() as u64;
+        self.len = self.len.max(self.position);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for CountingWriter {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.position = resolve_seek(pos, self.position, self.len)?;
+        Ok(self.position)
+    }
+}
+
+/// A writer that buffers data until it's flushed and then writes it to a sink that can't seek
+///
+/// Seeking is only possible within data that wasn't flushed yet
+pub(crate) struct StreamWriter<'sink, W: Write> {
+    /// Sink
+    sink: &'sink mut W,
+    /// Data that wasn't flushed yet
+    buffer: Vec<u8>,
+    /// Position of the start of the buffer
+    buffer_offset: u64,
+    /// Current position
+    position: u64,
+}
+
+impl<'sink, W: Write> StreamWriter<'sink, W> {
+    /// Create a new `StreamWriter` instance
+    pub(crate) fn new(sink: &'sink mut W) -> Self {
+        StreamWriter {
+            sink,
+            buffer: Vec::new(),
+            buffer_offset: 0,
+            position: 0,
+        }
+    }
+}
+
+impl<'sink, W: Write> Write for StreamWriter<'sink, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.position < self.buffer_offset {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "cannot write to data that was already flushed to the stream",
+            ));
+        }
+
+        let start = (self.position - self.buffer_offset) as usize;
+        let end = start + buf.len();
+        if end > self.buffer.len() {
+            self.buffer.resize(end, 0);
+        }
+        self.buffer[start..end].copy_from_slice(buf);
+        self.position += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.write_all(&self.buffer)?;
+        self.buffer_offset += self.buffer.len() as u64;
+        self.buffer.clear();
+        self.sink.flush()
+    }
+}
+
+impl<'sink, W: Write> Seek for StreamWriter<'sink, W> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let end = self.buffer_offset + self.buffer.len() as u64;
+        let target = resolve_seek(pos, self.position, end)?;
+        if target < self.buffer_offset {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "cannot seek to data that was already flushed to the stream",
+            ));
+        }
+        self.position = target;
+        Ok(self.position)
+    }
+}
@@ -0,0 +1,574 @@
+//! Zen packages
+//!
+//! Cooked UE5 packages loaded from IoStore containers by the zen loader
+//! don't use the legacy package summary, see [`ZenAsset`]
+
+use std::fmt::{Debug, Formatter};
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{ReadBytesExt, LE};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use unreal_asset_base::{
+    containers::{Chain, NameMap, SharedResource},
+    custom_version::CustomVersion,
+    engine_version::{get_object_versions, EngineVersion},
+    error::Error,
+    flags::{EBulkDataFlags, EObjectFlags, EPackageFlags},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::{ArchiveReader, ArchiveTrait, RawReader},
+    types::{fname::EMappedNameType, FName},
+};
+
+use crate::UE4_ASSET_MAGIC;
+
+/// Zen package version
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, TryFromPrimitive, IntoPrimitive,
+)]
+#[repr(u32)]
+pub enum EZenPackageVersion {
+    /// Initial version, 5.0 and 5.1
+    Initial,
+    /// Bulk data map after the name map, 5.2
+    DataResourceTable,
+    /// Imported package names and dependency bundles instead of graph data
+    ImportedPackageNames,
+    /// Extra dependencies
+    ExtraDependencies,
+}
+
+/// Versioning info of a versioned zen package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZenPackageVersioningInfo {
+    /// Zen package version
+    pub zen_version: EZenPackageVersion,
+    /// Object version
+    pub object_version: ObjectVersion,
+    /// UE5 object version
+    pub object_version_ue5: ObjectVersionUE5,
+    /// Licensee version
+    pub licensee_version: i32,
+    /// Custom versions
+    pub custom_versions: Vec<CustomVersion>,
+}
+
+/// Type of a [`PackageObjectIndex`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EPackageObjectIndexType {
+    /// Export of this package
+    Export,
+    /// Import of a script object
+    ScriptImport,
+    /// Import of a public export of another package
+    PackageImport,
+    /// Null index
+    Null,
+}
+
+/// Reference to an object in a zen package, `FPackageObjectIndex`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PackageObjectIndex {
+    /// Raw value, the type is stored in the upper 2 bits
+    pub value: u64,
+}
+
+impl PackageObjectIndex {
+    /// Amount of bits used for the index
+    const INDEX_BITS: u64 = 62;
+    /// Mask of the index bits
+    const INDEX_MASK: u64 = (1 << Self::INDEX_BITS) - 1;
+
+    /// Null index
+    pub const NULL: Self = PackageObjectIndex { value: u64::MAX };
+
+    /// Get the index type
+    pub fn index_type(&self) -> EPackageObjectIndexType {
+        match self.value >> Self::INDEX_BITS {
+            0 => EPackageObjectIndexType::Export,
+            1 => EPackageObjectIndexType::ScriptImport,
+            2 => EPackageObjectIndexType::PackageImport,
+            _ => EPackageObjectIndexType::Null,
+        }
+    }
+
+    /// Check if this index is null
+    pub fn is_null(&self) -> bool {
+        self.index_type() == EPackageObjectIndexType::Null
+    }
+
+    /// Get the export map index if this is an export
+    pub fn export_index(&self) -> Option<usize> {
+        match self.index_type() {
+            EPackageObjectIndexType::Export => Some((self.value & Self::INDEX_MASK) as usize),
+            _ => None,
+        }
+    }
+
+    /// Get the imported package index and the imported public export hash index if this is a package import
+    pub fn package_import(&self) -> Option<(u32, u32)> {
+        match self.index_type() {
+            EPackageObjectIndexType::PackageImport => {
+                let index = self.value & Self::INDEX_MASK;
+                Some(((index >> 32) as u32, index as u32))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Export map entry of a zen package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZenExportMapEntry {
+    /// Offset of the export in the cooked legacy package
+    pub cooked_serial_offset: u64,
+    /// Export data size
+    pub cooked_serial_size: u64,
+    /// Object name
+    pub object_name: FName,
+    /// Outer object
+    pub outer_index: PackageObjectIndex,
+    /// Object class
+    pub class_index: PackageObjectIndex,
+    /// Object super struct
+    pub super_index: PackageObjectIndex,
+    /// Object template
+    pub template_index: PackageObjectIndex,
+    /// Hash of the export path, 0 for exports that aren't public
+    pub public_export_hash: u64,
+    /// Object flags
+    pub object_flags: EObjectFlags,
+    /// Export filter flags
+    pub filter_flags: u8,
+}
+
+/// Export bundle command type
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum EExportCommandType {
+    /// Create the export
+    Create,
+    /// Serialize the export
+    Serialize,
+}
+
+/// Export bundle entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExportBundleEntry {
+    /// Export map index
+    pub local_export_index: u32,
+    /// Command type
+    pub command_type: EExportCommandType,
+}
+
+/// Export bundle header
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExportBundleHeader {
+    /// Bundle offset in the export data
+    pub serial_offset: u64,
+    /// Index of the first bundle entry
+    pub first_entry_index: u32,
+    /// Amount of bundle entries
+    pub entry_count: u32,
+}
+
+/// Dependency between two export bundles of the same package
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InternalArc {
+    /// Bundle that must be loaded first
+    pub from_export_bundle_index: u32,
+    /// Dependent bundle
+    pub to_export_bundle_index: u32,
+}
+
+/// Dependency of an export bundle on an import
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalArc {
+    /// Import map index
+    pub from_import_index: u32,
+    /// Dependent bundle
+    pub to_export_bundle_index: u32,
+}
+
+/// Bulk data map entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BulkDataMapEntry {
+    /// Payload offset
+    pub serial_offset: i64,
+    /// Payload offset in the non-optional file, if the payload is duplicated
+    pub duplicate_serial_offset: i64,
+    /// Payload size
+    pub serial_size: i64,
+    /// Bulk data flags
+    pub flags: EBulkDataFlags,
+}
+
+/// Cooked UE5 package in the zen loader format
+///
+/// Zen packages store the header and the export data in a single file,
+/// the header is described by a `FZenPackageSummary` instead of the legacy package summary.
+/// Only the header is parsed, export data can be read with [`ZenAsset::read_export_data`]
+pub struct ZenAsset<C: Read + Seek> {
+    /// Raw reader
+    raw_reader: RawReader<C>,
+    /// Versioning info, `None` for unversioned packages
+    pub versioning_info: Option<ZenPackageVersioningInfo>,
+    /// Zen package version
+    pub zen_version: EZenPackageVersion,
+    /// Header size, export data starts right after the header
+    pub header_size: u32,
+    /// Package name
+    pub name: FName,
+    /// Package flags
+    pub package_flags: EPackageFlags,
+    /// Header size of the cooked legacy package
+    pub cooked_header_size: u32,
+    /// Hash version of the name map hashes
+    pub name_map_hash_version: u64,
+    /// Bulk data map, since [`EZenPackageVersion::DataResourceTable`]
+    pub bulk_data_map: Vec<BulkDataMapEntry>,
+    /// Hashes of the public exports imported from other packages
+    pub imported_public_export_hashes: Vec<u64>,
+    /// Import map
+    pub import_map: Vec<PackageObjectIndex>,
+    /// Export map
+    pub export_map: Vec<ZenExportMapEntry>,
+    /// Export bundle entries
+    pub export_bundle_entries: Vec<ExportBundleEntry>,
+    /// Export bundle headers
+    pub export_bundle_headers: Vec<ExportBundleHeader>,
+    /// Dependencies between export bundles of this package
+    pub internal_arcs: Vec<InternalArc>,
+    /// Dependencies on imports, grouped by imported package
+    ///
+    /// Imported package ids are stored in the container header,
+    /// groups are in the order of the package store entry
+    pub external_arcs: Vec<Vec<ExternalArc>>,
+}
+
+impl<C: Read + Seek> ZenAsset<C> {
+    /// Read a zen package
+    ///
+    /// The engine version is used for unversioned packages,
+    /// versioned packages use the versions they were saved with
+    pub fn new(data: C, engine_version: EngineVersion) -> Result<Self, Error> {
+        let (object_version, object_version_ue5) = get_object_versions(engine_version);
+        let mut raw_reader = RawReader::new(
+            Chain::new(data, None),
+            object_version,
+            object_version_ue5,
+            true,
+            NameMap::new(),
+        );
+
+        let has_versioning_info = raw_reader.read_u32::<LE>()?;
+        if has_versioning_info.swap_bytes() == UE4_ASSET_MAGIC {
+            return Err(Error::invalid_file(
+                "File is a legacy package, not a zen package".to_string(),
+            ));
+        }
+        if has_versioning_info > 1 {
+            return Err(Error::invalid_file(format!(
+                "Invalid zen package versioning info flag {has_versioning_info}"
+            )));
+        }
+
+        let header_size = raw_reader.read_u32::<LE>()?;
+        if header_size as u64 > raw_reader.data_length()? {
+            return Err(Error::invalid_file(format!(
+                "Zen package header size {header_size} is larger than the file"
+            )));
+        }
+        let name = (raw_reader.read_u32::<LE>()?, raw_reader.read_u32::<LE>()?);
+        let package_flags = EPackageFlags::from_bits(raw_reader.read_u32::<LE>()?)
+            .ok_or_else(|| Error::invalid_file("Invalid package flags".to_string()))?;
+        let cooked_header_size = raw_reader.read_u32::<LE>()?;
+        let imported_public_export_hashes_offset = raw_reader.read_i32::<LE>()?;
+        let import_map_offset = raw_reader.read_i32::<LE>()?;
+        let export_map_offset = raw_reader.read_i32::<LE>()?;
+        let export_bundle_entries_offset = raw_reader.read_i32::<LE>()?;
+        let graph_data_offset = raw_reader.read_i32::<LE>()?;
+
+        let offsets = [
+            imported_public_export_hashes_offset,
+            import_map_offset,
+            export_map_offset,
+            export_bundle_entries_offset,
+            graph_data_offset,
+            header_size as i32,
+        ];
+        if offsets[0] < 0 || offsets.windows(2).any(|e| e[0] > e[1]) {
+            return Err(Error::invalid_file(format!(
+                "Invalid zen package header offsets {offsets:?}"
+            )));
+        }
+
+        let versioning_info = match has_versioning_info {
+            1 => {
+                let zen_version = EZenPackageVersion::try_from(raw_reader.read_u32::<LE>()?)?;
+                let object_version = ObjectVersion::try_from(raw_reader.read_i32::<LE>()?)?;
+                let object_version_ue5 = ObjectVersionUE5::try_from(raw_reader.read_i32::<LE>()?)?;
+                let licensee_version = raw_reader.read_i32::<LE>()?;
+                let custom_versions =
+                    ArchiveReader::read_array(&mut raw_reader, CustomVersion::read)?;
+
+                raw_reader.object_version = object_version;
+                raw_reader.object_version_ue5 = object_version_ue5;
+                Some(ZenPackageVersioningInfo {
+                    zen_version,
+                    object_version,
+                    object_version_ue5,
+                    licensee_version,
+                    custom_versions,
+                })
+            }
+            _ => None,
+        };
+        let zen_version = match &versioning_info {
+            Some(versioning_info) => versioning_info.zen_version,
+            None if engine_version >= EngineVersion::VER_UE5_2 => {
+                EZenPackageVersion::DataResourceTable
+            }
+            None => EZenPackageVersion::Initial,
+        };
+        if zen_version >= EZenPackageVersion::ImportedPackageNames {
+            return Err(Error::unimplemented(format!(
+                "Zen package version {zen_version:?} is unimplemented"
+            )));
+        }
+
+        let (names, name_map_hash_version) = raw_reader.read_name_batch(false)?;
+        let mut name_map = raw_reader.name_map.clone();
+        name_map.get_mut().add_name_references(names);
+
+        let mut bulk_data_map = Vec::new();
+        if zen_version >= EZenPackageVersion::DataResourceTable {
+            let bulk_data_map_size = raw_reader.read_i64::<LE>()?;
+            let count = bulk_data_map_size / 32;
+            if bulk_data_map_size < 0 || bulk_data_map_size % 32 != 0 {
+                return Err(Error::invalid_file(format!(
+                    "Invalid bulk data map size {bulk_data_map_size}"
+                )));
+            }
+            for _ in 0..count {
+                let serial_offset = raw_reader.read_i64::<LE>()?;
+                let duplicate_serial_offset = raw_reader.read_i64::<LE>()?;
+                let serial_size = raw_reader.read_i64::<LE>()?;
+                let flags = EBulkDataFlags::from_bits_retain(raw_reader.read_u32::<LE>()?);
+                let _pad = raw_reader.read_u32::<LE>()?;
+                bulk_data_map.push(BulkDataMapEntry {
+                    serial_offset,
+                    duplicate_serial_offset,
+                    serial_size,
+                    flags,
+                });
+            }
+        }
+
+        raw_reader.seek(SeekFrom::Start(imported_public_export_hashes_offset as u64))?;
+        let count = (import_map_offset - imported_public_export_hashes_offset) / 8;
+        let imported_public_export_hashes =
+            raw_reader.read_array_with_length(count, |e| Ok(e.read_u64::<LE>()?))?;
+
+        raw_reader.seek(SeekFrom::Start(import_map_offset as u64))?;
+        let count = (export_map_offset - import_map_offset) / 8;
+        let import_map = raw_reader.read_array_with_length(count, |e| {
+            Ok(PackageObjectIndex {
+                value: e.read_u64::<LE>()?,
+            })
+        })?;
+
+        raw_reader.seek(SeekFrom::Start(export_map_offset as u64))?;
+        let count = (export_bundle_entries_offset - export_map_offset) / 72;
+        let export_map = raw_reader.read_array_with_length(count, |e| {
+            let read_index = |e: &mut RawReader<C>| -> Result<_, Error> {
+                Ok(PackageObjectIndex {
+                    value: e.read_u64::<LE>()?,
+                })
+            };
+
+            let cooked_serial_offset = e.read_u64::<LE>()?;
+            let cooked_serial_size = e.read_u64::<LE>()?;
+            let object_name = (e.read_u32::<LE>()?, e.read_u32::<LE>()?);
+            let outer_index = read_index(e)?;
+            let class_index = read_index(e)?;
+            let super_index = read_index(e)?;
+            let template_index = read_index(e)?;
+            let public_export_hash = e.read_u64::<LE>()?;
+            let object_flags = EObjectFlags::from_bits_retain(e.read_u32::<LE>()?);
+            let filter_flags = e.read_u8()?;
+            let mut pad = [0u8; 3];
+            e.read_exact(&mut pad)?;
+
+            Ok(ZenExportMapEntry {
+                cooked_serial_offset,
+                cooked_serial_size,
+                object_name: mapped_name(object_name, &name_map)?,
+                outer_index,
+                class_index,
+                super_index,
+                template_index,
+                public_export_hash,
+                object_flags,
+                filter_flags,
+            })
+        })?;
+
+        raw_reader.seek(SeekFrom::Start(export_bundle_entries_offset as u64))?;
+        let count = (graph_data_offset - export_bundle_entries_offset) / 8;
+        let export_bundle_entries = raw_reader.read_array_with_length(count, |e| {
+            Ok(ExportBundleEntry {
+                local_export_index: e.read_u32::<LE>()?,
+                command_type: EExportCommandType::try_from(e.read_u32::<LE>()?)?,
+            })
+        })?;
+
+        // the bundle count is stored in the package store entry,
+        // but the bundles cover every bundle entry
+        raw_reader.seek(SeekFrom::Start(graph_data_offset as u64))?;
+        let mut export_bundle_headers = Vec::new();
+        let mut bundled_entries = 0usize;
+        while bundled_entries < export_bundle_entries.len() {
+            let header = ExportBundleHeader {
+                serial_offset: raw_reader.read_u64::<LE>()?,
+                first_entry_index: raw_reader.read_u32::<LE>()?,
+                entry_count: raw_reader.read_u32::<LE>()?,
+            };
+            if header.entry_count == 0 {
+                return Err(Error::invalid_file("Empty export bundle".to_string()));
+            }
+            bundled_entries += header.entry_count as usize;
+            export_bundle_headers.push(header);
+        }
+
+        let internal_arcs = ArchiveReader::read_array(&mut raw_reader, |e| {
+            Ok(InternalArc {
+                from_export_bundle_index: e.read_u32::<LE>()?,
+                to_export_bundle_index: e.read_u32::<LE>()?,
+            })
+        })?;
+
+        let mut external_arcs = Vec::new();
+        while raw_reader.position() < header_size as u64 {
+            external_arcs.push(ArchiveReader::read_array(&mut raw_reader, |e| {
+                Ok(ExternalArc {
+                    from_import_index: e.read_u32::<LE>()?,
+                    to_export_bundle_index: e.read_u32::<LE>()?,
+                })
+            })?);
+        }
+
+        Ok(ZenAsset {
+            name: mapped_name(name, &name_map)?,
+            raw_reader,
+            versioning_info,
+            zen_version,
+            header_size,
+            package_flags,
+            cooked_header_size,
+            name_map_hash_version,
+            bulk_data_map,
+            imported_public_export_hashes,
+            import_map,
+            export_map,
+            export_bundle_entries,
+            export_bundle_headers,
+            internal_arcs,
+            external_arcs,
+        })
+    }
+
+    /// Get the name map
+    pub fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.raw_reader.name_map.clone()
+    }
+
+    /// Get the offset of an export's data in the package
+    ///
+    /// Export data is stored right after the header in export bundle order
+    pub fn export_data_offset(&self, export_index: usize) -> Option<u64> {
+        let mut offset = self.header_size as u64;
+        for entry in &self.export_bundle_entries {
+            if entry.command_type != EExportCommandType::Serialize {
+                continue;
+            }
+            if entry.local_export_index as usize == export_index {
+                return Some(offset);
+            }
+            offset += self
+                .export_map
+                .get(entry.local_export_index as usize)?
+                .cooked_serial_size;
+        }
+        None
+    }
+
+    /// Read the raw data of an export
+    pub fn read_export_data(&mut self, export_index: usize) -> Result<Vec<u8>, Error> {
+        let offset = self.export_data_offset(export_index).ok_or_else(|| {
+            Error::invalid_file(format!("Export {export_index} isn't serialized"))
+        })?;
+        let size = self
+            .export_map
+            .get(export_index)
+            .ok_or_else(|| {
+                Error::invalid_file(format!("Export {export_index} isn't in the export map"))
+            })?
+            .cooked_serial_size;
+        if offset.saturating_add(size) > self.raw_reader.data_length()? {
+            return Err(Error::invalid_file(format!(
+                "Export {export_index} data is out of bounds"
+            )));
+        }
+
+        self.raw_reader.seek(SeekFrom::Start(offset))?;
+        let mut data = vec![0u8; size as usize];
+        self.raw_reader.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Create an `FName` from a serialized `FMappedName`, the name type is stored in the upper 2 bits of the index
+fn mapped_name(
+    (index, number): (u32, u32),
+    name_map: &SharedResource<NameMap>,
+) -> Result<FName, Error> {
+    let ty = EMappedNameType::try_from((index >> 30) as u16)?;
+    let index = (index & ((1 << 30) - 1)) as i32;
+    if ty == EMappedNameType::Package && index as usize >= name_map.get_ref().len() {
+        return Err(Error::invalid_file(format!(
+            "Name index {index} is out of bounds"
+        )));
+    }
+    Ok(FName::new_with_type(
+        index,
+        number as i32,
+        ty,
+        name_map.clone(),
+    ))
+}
+
+impl<C: Read + Seek> Debug for ZenAsset<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZenAsset")
+            .field("versioning_info", &self.versioning_info)
+            .field("zen_version", &self.zen_version)
+            .field("header_size", &self.header_size)
+            .field("name", &self.name)
+            .field("package_flags", &self.package_flags)
+            .field("cooked_header_size", &self.cooked_header_size)
+            .field("name_map_hash_version", &self.name_map_hash_version)
+            .field("bulk_data_map", &self.bulk_data_map)
+            .field(
+                "imported_public_export_hashes",
+                &self.imported_public_export_hashes,
+            )
+            .field("import_map", &self.import_map)
+            .field("export_map", &self.export_map)
+            .field("export_bundle_entries", &self.export_bundle_entries)
+            .field("export_bundle_headers", &self.export_bundle_headers)
+            .field("internal_arcs", &self.internal_arcs)
+            .field("external_arcs", &self.external_arcs)
+            .finish()
+    }
+}
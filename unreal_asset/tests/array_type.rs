@@ -0,0 +1,126 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    error::PropertyError,
+    exports::ExportNormalTrait,
+    properties::{
+        array_property::ArrayProperty, int_property::IntProperty, str_property::StrProperty,
+        Property,
+    },
+    types::FName,
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+mod shared;
+
+fn int_property(value: i32) -> Property {
+    IntProperty {
+        name: FName::from_slice("0"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value,
+    }
+    .into()
+}
+
+/// Write an asset with `array` added to its first normal export and read the array back
+fn round_trip(mut array: ArrayProperty) -> Result<ArrayProperty, Error> {
+    let mut asset = shared::read_broad_brush()?;
+    array.name = asset.add_fname(&array.name.get_owned_content());
+    let name = array.name.get_owned_content();
+    let export = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .find_map(|e| e.get_normal_export_mut())
+        .unwrap();
+    export.properties.push(array.into());
+
+    let written = shared::write_asset(&asset)?;
+
+    let asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let array = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| &e.properties)
+        .find_map(|e| cast!(Property, ArrayProperty, e).filter(|e| e.name == name.as_str()))
+        .unwrap()
+        .clone();
+    Ok(array)
+}
+
+#[test]
+fn explicit_array_type() -> Result<(), Error> {
+    // the type of empty arrays can only come from the explicit type
+    let array = round_trip(ArrayProperty::from_arr(
+        FName::from_slice("Empty"),
+        Ancestry::default(),
+        Some(FName::from_slice("IntProperty")),
+        Vec::new(),
+    ))?;
+    assert!(array.value.is_empty());
+    assert!(array.array_type.is_some_and(|e| e == "IntProperty"));
+
+    let array = round_trip(ArrayProperty::from_arr(
+        FName::from_slice("Values"),
+        Ancestry::default(),
+        Some(FName::from_slice("IntProperty")),
+        vec![int_property(1), int_property(2)],
+    ))?;
+    assert!(array
+        .array_type
+        .as_ref()
+        .is_some_and(|e| e == "IntProperty"));
+    assert_eq!(
+        array
+            .value
+            .iter()
+            .filter_map(|e| cast!(Property, IntProperty, e).map(|e| e.value))
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    // without an explicit type it's taken from the elements
+    let array = round_trip(ArrayProperty::from_arr(
+        FName::from_slice("Inferred"),
+        Ancestry::default(),
+        None,
+        vec![int_property(3)],
+    ))?;
+    assert!(array.array_type.is_some_and(|e| e == "IntProperty"));
+
+    Ok(())
+}
+
+#[test]
+fn mismatched_array_type() -> Result<(), Error> {
+    let string = StrProperty {
+        name: FName::from_slice("1"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: Some("Value".to_string()),
+    };
+    assert!(matches!(
+        round_trip(ArrayProperty::from_arr(
+            FName::from_slice("Mismatched"),
+            Ancestry::default(),
+            Some(FName::from_slice("IntProperty")),
+            vec![int_property(1), string.into()],
+        )),
+        Err(Error::Property(PropertyError::InvalidArrayType(_)))
+    ));
+
+    Ok(())
+}
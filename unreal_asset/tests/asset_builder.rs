@@ -0,0 +1,119 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_builder::AssetBuilder,
+    cast,
+    engine_version::EngineVersion,
+    exports::{BaseExport, ExportBaseTrait, ExportNormalTrait, NormalExport},
+    flags::{EObjectFlags, EPackageFlags},
+    properties::{int_property::IntProperty, Property},
+    types::PackageIndex,
+    unversioned::Ancestry,
+    Asset, Error, Import,
+};
+
+mod shared;
+
+fn build_asset() -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let mut builder = AssetBuilder::new()
+        .engine_version(EngineVersion::VER_UE4_27)
+        .package_flags(EPackageFlags::PKG_FILTER_EDITOR_ONLY)
+        .folder_name("/Game/Items/Pickaxe");
+
+    let core_uobject = builder.add_fname("/Script/CoreUObject");
+    let package = builder.add_fname("Package");
+    let engine = builder.add_fname("/Script/Engine");
+    let engine_index = builder.add_import(Import::new(
+        core_uobject.clone(),
+        package,
+        PackageIndex::new(0),
+        engine,
+        false,
+    ));
+
+    let class = builder.add_fname("Class");
+    let data_asset = builder.add_fname("DataAsset");
+    let class_index = builder.add_import(Import::new(
+        core_uobject,
+        class,
+        engine_index,
+        data_asset,
+        false,
+    ));
+
+    builder.add_fname("None");
+    builder.add_fname("IntProperty");
+    let property = IntProperty {
+        name: builder.add_fname("Count"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: 42,
+    };
+
+    let export = NormalExport {
+        base_export: BaseExport {
+            class_index,
+            object_name: builder.add_fname("Pickaxe"),
+            object_flags: EObjectFlags::RF_PUBLIC | EObjectFlags::RF_STANDALONE,
+            ..Default::default()
+        },
+        extras: vec![0; 4],
+        properties: vec![property.into()],
+    };
+    assert_eq!(builder.add_export(export), PackageIndex::new(1));
+
+    builder.build()
+}
+
+#[test]
+fn asset_builder() -> Result<(), Error> {
+    let asset = build_asset()?;
+    assert_eq!(asset.folder_name, "/Game/Items/Pickaxe");
+    assert_eq!(asset.generations.len(), 1);
+    assert_eq!(asset.package_guid, build_asset()?.package_guid);
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let written = cursor.get_ref().clone();
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::UNKNOWN,
+        None,
+    )?;
+    assert!(reparsed.warnings.is_empty());
+    assert_eq!(reparsed.imports.len(), 2);
+    assert_eq!(reparsed.asset_data.exports.len(), 1);
+    assert_eq!(reparsed.package_guid, asset.package_guid);
+    assert_eq!(
+        reparsed.asset_data.exports[0]
+            .get_base_export()
+            .object_name
+            .get_owned_content(),
+        "Pickaxe"
+    );
+
+    let properties = &reparsed.asset_data.exports[0]
+        .get_normal_export()
+        .expect("Export was not read as a normal export")
+        .properties;
+    assert_eq!(properties.len(), 1);
+    assert_eq!(
+        cast!(Property, IntProperty, &properties[0]).map(|e| e.value),
+        Some(42)
+    );
+
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn asset_builder_no_engine_version() {
+    assert!(matches!(
+        AssetBuilder::new().build(),
+        Err(Error::NoEngineVersion)
+    ));
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_registry_data::AssetRegistryObject,
+    engine_version::EngineVersion,
+    flags::EPackageFlags,
+    registry::objects::asset_bundle_data::{parse_asset_bundle_tag, ASSET_BUNDLE_DATA_TAG},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! editor_format_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Misc_426/RaceSimDataAsset"
+        )
+    };
+}
+
+const EDITOR_FORMAT_ASSET: &[u8] = include_bytes!(concat!(editor_format_asset!(), ".uasset"));
+const EDITOR_FORMAT_ASSET_BULK: &[u8] = include_bytes!(concat!(editor_format_asset!(), ".uexp"));
+
+fn registry_object() -> AssetRegistryObject {
+    AssetRegistryObject {
+        object_path: Some("Augment_BroadBrush_C".to_string()),
+        object_class_name: Some("BlueprintGeneratedClass".to_string()),
+        tags: vec![
+            (
+                Some("ParentClass".to_string()),
+                Some("/Script/Astro.Augment".to_string()),
+            ),
+            (Some("NumReplicatedProperties".to_string()), None),
+        ],
+    }
+}
+
+#[test]
+fn asset_registry_data() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    let registry_data = asset.asset_data.asset_registry_data.as_mut().unwrap();
+    assert!(registry_data.objects.is_empty());
+
+    registry_data.objects.push(registry_object());
+    let written = shared::write_asset(&asset)?;
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(
+        asset
+            .asset_data
+            .asset_registry_data
+            .as_ref()
+            .unwrap()
+            .objects,
+        vec![registry_object()]
+    );
+    assert!(asset.registry_tail.is_empty());
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn asset_registry_dependency_data() -> Result<(), Error> {
+    // the dependency data offset requires `VER_UE4_ASSETREGISTRY_DEPENDENCYFLAGS`
+    let mut asset = Asset::new(
+        Cursor::new(EDITOR_FORMAT_ASSET),
+        Some(Cursor::new(EDITOR_FORMAT_ASSET_BULK)),
+        EngineVersion::VER_UE4_27,
+        None,
+    )?;
+
+    // editor packages start registry data with the offset of the dependency data
+    asset
+        .asset_data
+        .package_flags
+        .remove(EPackageFlags::PKG_FILTER_EDITOR_ONLY);
+    asset
+        .asset_data
+        .asset_registry_data
+        .as_mut()
+        .unwrap()
+        .objects
+        .push(registry_object());
+    let dependency_data = vec![0u8; 8];
+    asset.registry_tail = dependency_data.clone();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (written, written_bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        Some(Cursor::new(written_bulk.as_slice())),
+        EngineVersion::VER_UE4_27,
+        None,
+    )?;
+    assert_eq!(
+        asset
+            .asset_data
+            .asset_registry_data
+            .as_ref()
+            .unwrap()
+            .objects,
+        vec![registry_object()]
+    );
+    assert_eq!(asset.registry_tail, dependency_data);
+    shared::verify_binary_equality(&written, Some(&written_bulk), &mut asset)?;
+
+    // the dependency data offset points right after the objects
+    let key = b"NumReplicatedProperties\0";
+    let objects_end = written.windows(key.len()).rposition(|e| e == key).unwrap() + key.len() + 4;
+    let mut registry_start = (objects_end as i64).to_le_bytes().to_vec();
+    registry_start.extend(1i32.to_le_bytes());
+    assert!(written
+        .windows(registry_start.len())
+        .any(|e| e == registry_start));
+
+    asset.strip_editor_only_data();
+    assert!(asset.registry_tail.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn asset_bundle_tag() -> Result<(), Error> {
+    let expected = HashMap::from([
+        (
+            "Menu".to_string(),
+            vec![
+                "/Game/UI/Menu.Menu".to_string(),
+                "/Game/UI/Icons.Icons:Icon".to_string(),
+            ],
+        ),
+        ("Game".to_string(), vec!["/Game/Maps/Map.Map".to_string()]),
+    ]);
+
+    // soft object paths before 5.1, top level asset paths after
+    for value in [
+        r#"(Bundles=((BundleName="Menu",BundleAssets=(/Game/UI/Menu.Menu,/Game/UI/Icons.Icons:Icon)),(BundleName="Game",BundleAssets=(/Game/Maps/Map.Map))))"#,
+        r#"(Bundles=((BundleName="Menu",BundleAssets=("/Game/UI/Menu.Menu","/Game/UI/Icons.Icons:Icon")),(BundleName="Game",BundleAssets=("/Game/Maps/Map.Map"))))"#,
+        r#"(Bundles=((BundleName="Menu",BundleAssets=((AssetPathName="/Game/UI/Menu.Menu",SubPathString=""),(AssetPathName="/Game/UI/Icons.Icons",SubPathString="Icon"))),(BundleName="Game",BundleAssets=((AssetPathName="/Game/Maps/Map.Map",SubPathString="")))))"#,
+        r#"(Bundles=((BundleName="Menu",AssetPaths=((PackageName="/Game/UI/Menu",AssetName="Menu"),(AssetPath=(PackageName="/Game/UI/Icons",AssetName="Icons"),SubPathString="Icon"))),(BundleName="Game",AssetPaths=((PackageName="/Game/Maps/Map",AssetName="Map")))))"#,
+    ] {
+        assert_eq!(parse_asset_bundle_tag(value)?, expected, "{value}");
+    }
+
+    // 5.1 exports top level asset paths as quoted strings, bundle names are FNames
+    let value = r#"(Bundles=((BundleName="Equipped",AssetPaths=("/Game/Weapons/Pistol/B_Pistol.B_Pistol_C","/Game/Weapons/Pistol/SK_Pistol.SK_Pistol")),(BundleName="Client",AssetPaths=("/Game/UI/Hud/W_Ammo.W_Ammo_C"))))"#;
+    assert_eq!(
+        parse_asset_bundle_tag(value)?,
+        HashMap::from([
+            (
+                "Equipped".to_string(),
+                vec![
+                    "/Game/Weapons/Pistol/B_Pistol.B_Pistol_C".to_string(),
+                    "/Game/Weapons/Pistol/SK_Pistol.SK_Pistol".to_string(),
+                ]
+            ),
+            (
+                "Client".to_string(),
+                vec!["/Game/UI/Hud/W_Ammo.W_Ammo_C".to_string()]
+            ),
+        ])
+    );
+
+    assert!(parse_asset_bundle_tag("(Bundles=())")?.is_empty());
+    assert!(parse_asset_bundle_tag("()")?.is_empty());
+    for value in [
+        "(Bundles=((BundleName=\"Menu\",BundleAssets=(/Game/UI/Menu.Menu))",
+        "(Bundles=((BundleAssets=(/Game/UI/Menu.Menu))))",
+        "(Bundles=((BundleName=\"Menu)))",
+        "(Bundles=()) trailing",
+    ] {
+        assert!(parse_asset_bundle_tag(value).is_err(), "{value}");
+    }
+
+    // nesting is limited instead of overflowing the stack
+    let nested = |depth: usize| format!("{}{}", "(".repeat(depth), ")".repeat(depth));
+    assert!(parse_asset_bundle_tag(&nested(32))?.is_empty());
+    assert!(parse_asset_bundle_tag(&nested(100_000)).is_err());
+    assert!(parse_asset_bundle_tag(&"(".repeat(100_000)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn asset_bundle_registry_object() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let mut object = registry_object();
+    assert_eq!(object.get_asset_bundles()?, None);
+    object.tags.push((
+        Some(ASSET_BUNDLE_DATA_TAG.to_string()),
+        Some(r#"(Bundles=((BundleName="Game",BundleAssets=(/Game/Maps/Map.Map))))"#.to_string()),
+    ));
+    let registry_data = asset.asset_data.asset_registry_data.as_mut().unwrap();
+    registry_data.objects.push(object);
+    let written = shared::write_asset(&asset)?;
+
+    let asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let object = &asset
+        .asset_data
+        .asset_registry_data
+        .as_ref()
+        .unwrap()
+        .objects[0];
+    assert_eq!(
+        object.get_asset_bundles()?,
+        Some(HashMap::from([(
+            "Game".to_string(),
+            vec!["/Game/Maps/Map.Map".to_string()]
+        )]))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_registry_counts() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    let registry_data = asset.asset_data.asset_registry_data.as_mut().unwrap();
+    registry_data.objects.push(registry_object());
+    let written = shared::write_asset(&asset)?;
+
+    // counts directly precede the first object path and the first tag key,
+    // registry data follows the name map which holds the same strings
+    let count_offset = |value: &[u8]| {
+        let string = [
+            ((value.len() + 1) as i32).to_le_bytes().as_slice(),
+            value,
+            b"\0",
+        ]
+        .concat();
+        written
+            .windows(string.len())
+            .rposition(|e| e == string)
+            .unwrap()
+            - 4
+    };
+    let object_count_offset = count_offset(b"Augment_BroadBrush_C");
+    let tag_count_offset = count_offset(b"ParentClass");
+
+    for offset in [object_count_offset, tag_count_offset] {
+        let mut data = written.clone();
+        data[offset..offset + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+        assert!(Asset::new(
+            Cursor::new(data.as_slice()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )
+        .is_err());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_data::AssetWarningKind, cast, engine_version::EngineVersion, exports::ExportBaseTrait,
+    reader::ArchiveTrait, Asset, Error, Export,
+};
+
+mod shared;
+
+#[test]
+fn asset_warnings() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+    assert!(asset.warnings.is_empty());
+
+    // corrupt the first property name of the first export
+    let base_export = asset.asset_data.exports[0].get_base_export();
+    let serial_offset = base_export.serial_offset as usize;
+    let class_index = base_export.class_index;
+
+    let mut data = shared::BROAD_BRUSH.to_vec();
+    data[serial_offset..serial_offset + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+
+    let corrupted = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)?;
+    assert_eq!(corrupted.warnings.len(), 1);
+
+    let warning = &corrupted.warnings[0];
+    assert_eq!(warning.export_index, 0);
+    assert_eq!(warning.kind, AssetWarningKind::ExportParse);
+    assert_eq!(
+        warning.class_type,
+        asset
+            .get_import(class_index)
+            .map(|e| e.object_name.get_owned_content())
+    );
+    assert!(!warning.error.to_string().is_empty());
+    assert!(cast!(Export, RawExport, &corrupted.asset_data.exports[0]).is_some());
+
+    Ok(())
+}
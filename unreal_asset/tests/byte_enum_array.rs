@@ -0,0 +1,64 @@
+use unreal_asset::{
+    properties::{
+        array_property::ArrayProperty,
+        int_property::{ByteProperty, BytePropertyValue},
+        Property,
+    },
+    types::FName,
+    unversioned::Ancestry,
+    Error,
+};
+
+fn byte_property(value: BytePropertyValue) -> Property {
+    ByteProperty {
+        name: FName::from_slice("0"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        enum_type: None,
+        value,
+    }
+    .into()
+}
+
+#[test]
+fn byte_enum_array() -> Result<(), Error> {
+    let enum_values = [
+        (FName::from_slice("EAbility::Dash"), 0),
+        (FName::from_slice("EAbility::Jump"), 1),
+        (FName::from_slice("EAbility::EAbility_MAX"), 2),
+    ];
+
+    let array = ArrayProperty::from_arr(
+        FName::from_slice("Abilities"),
+        Ancestry::default(),
+        Some(FName::from_slice("ByteProperty")),
+        vec![
+            byte_property(BytePropertyValue::Byte(1)),
+            byte_property(BytePropertyValue::Byte(0)),
+            byte_property(BytePropertyValue::FName(FName::from_slice(
+                "EAbility::Jump",
+            ))),
+        ],
+    );
+
+    let names = array
+        .get_enum_names(&enum_values)?
+        .iter()
+        .map(|e| e.get_owned_content())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["EAbility::Jump", "EAbility::Dash", "EAbility::Jump"]
+    );
+
+    let invalid = ArrayProperty::from_arr(
+        FName::from_slice("Abilities"),
+        Ancestry::default(),
+        Some(FName::from_slice("ByteProperty")),
+        vec![byte_property(BytePropertyValue::Byte(5))],
+    );
+    assert!(invalid.get_enum_names(&enum_values).is_err());
+
+    Ok(())
+}
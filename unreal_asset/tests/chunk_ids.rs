@@ -0,0 +1,60 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn single_chunk_id() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    // packages stored a single chunk id before it was changed to an array
+    let object_version = ObjectVersion::VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE;
+    asset.asset_data.object_version = object_version;
+
+    // a package without chunks is written with INDEX_NONE
+    assert!(asset.get_chunk_ids().is_empty());
+    let reparsed = write_and_reparse(&asset, object_version)?;
+    assert_eq!(reparsed.asset_data.object_version, object_version);
+    assert_eq!(reparsed.get_chunk_ids(), [-1]);
+
+    // only the first chunk id is stored
+    asset.set_chunk_ids(vec![7, 8]);
+    let reparsed = write_and_reparse(&asset, object_version)?;
+    assert_eq!(reparsed.get_chunk_ids(), [7]);
+
+    Ok(())
+}
+
+#[test]
+fn chunk_id_array() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    let object_version = asset.asset_data.object_version;
+
+    asset.set_chunk_ids(vec![7, 8]);
+    let reparsed = write_and_reparse(&asset, object_version)?;
+    assert_eq!(reparsed.get_chunk_ids(), [7, 8]);
+
+    Ok(())
+}
+
+fn write_and_reparse(
+    asset: &Asset<Cursor<&[u8]>>,
+    object_version: ObjectVersion,
+) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let written = shared::write_asset(asset)?;
+
+    let mut reparsed = Asset::new_with_versions(
+        Cursor::new(written.clone()),
+        None,
+        object_version,
+        ObjectVersionUE5::UNKNOWN,
+        asset.asset_data.custom_versions.clone(),
+        None,
+    )?;
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+    Ok(reparsed)
+}
@@ -0,0 +1,55 @@
+use std::io::Cursor;
+
+use unreal_asset::{cast, engine_version::EngineVersion, Asset, Error, Export};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+#[test]
+fn class_default_subobjects() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let class_export = asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| cast!(Export, ClassExport, e))
+        .expect("No class export");
+    let subobjects = class_export.get_default_subobjects(&asset.asset_data.exports);
+
+    let native = subobjects
+        .iter()
+        .filter(|e| !e.construction_script)
+        .map(|e| e.name.get_owned_content())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        native,
+        ["Explosion", "ProjectileMovement", "SimpleHealth", "UseComp"]
+    );
+
+    let construction_script = subobjects
+        .iter()
+        .filter(|e| e.construction_script)
+        .collect::<Vec<_>>();
+    assert_eq!(construction_script.len(), 8);
+    for subobject in construction_script {
+        assert!(subobject.name.get_content(|e| e.ends_with("_GEN_VARIABLE")));
+        assert!(subobject.class_index.is_import());
+    }
+
+    Ok(())
+}
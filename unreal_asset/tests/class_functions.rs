@@ -0,0 +1,49 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast, engine_version::EngineVersion, exports::ExportBaseTrait, Asset, Error, Export,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+#[test]
+fn class_functions() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let class_export = asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| cast!(Export, ClassExport, e))
+        .expect("No class export");
+    let functions = class_export.functions();
+    assert_eq!(functions.len(), 9);
+
+    for (name, index) in functions {
+        assert!(class_export.struct_export.children.contains(&index));
+
+        let function_export = asset.get_export(index).expect("Missing function export");
+        assert!(matches!(function_export, Export::FunctionExport(_)));
+        assert!(function_export
+            .get_base_export()
+            .object_name
+            .eq_content(&name));
+    }
+
+    Ok(())
+}
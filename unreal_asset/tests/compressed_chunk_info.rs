@@ -0,0 +1,109 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    base::UE4_ASSET_MAGIC,
+    compression::{
+        read_compressed, write_compressed, CompressedChunkInfo, CompressedChunkTable,
+        CompressionMethod,
+    },
+    error::ErrorKind,
+    Error,
+};
+
+const PACKAGE_FILE_TAG: i64 = UE4_ASSET_MAGIC.swap_bytes() as i64;
+
+#[test]
+fn compressed_chunk_table() -> Result<(), Error> {
+    let table = CompressedChunkTable::new(
+        0x20000,
+        vec![
+            CompressedChunkInfo::new(0x8000, 0x20000),
+            CompressedChunkInfo::new(0x1234, 0x4000),
+        ],
+    );
+    assert_eq!(table.summary, CompressedChunkInfo::new(0x9234, 0x24000));
+
+    let mut cursor = Cursor::new(Vec::new());
+    table.write(&mut cursor)?;
+    assert_eq!(cursor.get_ref().len(), 16 * 4);
+
+    cursor.set_position(0);
+    assert_eq!(CompressedChunkTable::read(&mut cursor)?, table);
+
+    // tables without a chunk size store the package file tag twice
+    let mut cursor = Cursor::new(Vec::new());
+    CompressedChunkInfo::new(PACKAGE_FILE_TAG, PACKAGE_FILE_TAG).write(&mut cursor)?;
+    CompressedChunkInfo::new(0x10, 0x20).write(&mut cursor)?;
+    CompressedChunkInfo::new(0x10, 0x20).write(&mut cursor)?;
+
+    cursor.set_position(0);
+    let table = CompressedChunkTable::read(&mut cursor)?;
+    assert_eq!(table.chunk_size, 0x20000);
+    assert_eq!(table.chunks, vec![CompressedChunkInfo::new(0x10, 0x20)]);
+
+    // invalid tag
+    let mut cursor = Cursor::new(vec![0u8; 32]);
+    assert!(CompressedChunkTable::read(&mut cursor).is_err());
+
+    // uncompressed sizes which overflow, are negative or need more chunks than the data holds
+    for uncompressed_size in [i64::MAX, -1, 0x20000 * 0x1000000] {
+        let mut cursor = Cursor::new(Vec::new());
+        CompressedChunkInfo::new(PACKAGE_FILE_TAG, 0x20000).write(&mut cursor)?;
+        CompressedChunkInfo::new(0x10, uncompressed_size).write(&mut cursor)?;
+        CompressedChunkInfo::new(0x10, 0x20).write(&mut cursor)?;
+
+        cursor.set_position(0);
+        assert!(
+            CompressedChunkTable::read(&mut cursor).is_err(),
+            "{uncompressed_size}"
+        );
+    }
+
+    // the chunks must match the uncompressed size when writing
+    let mut table = CompressedChunkTable::new(0x20000, vec![CompressedChunkInfo::new(0x10, 0x20)]);
+    table.summary.uncompressed_size = 0x40000;
+    let error = table.write(&mut Cursor::new(Vec::new())).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidWrite);
+
+    Ok(())
+}
+
+#[test]
+fn compressed_chunks_round_trip() -> Result<(), Error> {
+    let input = (0..0x1000u32)
+        .flat_map(|e| (e % 251).to_le_bytes())
+        .collect::<Vec<_>>();
+
+    for name in ["None", "Zlib", "Gzip", "LZ4"] {
+        let mut cursor = Cursor::new(Vec::new());
+        write_compressed(&mut cursor, CompressionMethod::new(name), &input, 0x1800, 6)?;
+
+        cursor.set_position(0);
+        let table = CompressedChunkTable::read(&mut cursor)?;
+        assert_eq!(table.chunks.len(), 3, "{name}");
+        assert_eq!(table.summary.uncompressed_size, input.len() as i64);
+
+        cursor.set_position(0);
+        let decompressed = read_compressed(&mut cursor, CompressionMethod::new(name))?;
+        assert_eq!(decompressed, input, "{name}");
+    }
+
+    // truncated chunk data
+    let mut cursor = Cursor::new(Vec::new());
+    write_compressed(&mut cursor, CompressionMethod::Zlib, &input, 0x1800, 6)?;
+    let mut data = cursor.into_inner();
+    data.truncate(data.len() - 1);
+    assert!(read_compressed(&mut Cursor::new(data), CompressionMethod::Zlib).is_err());
+
+    let error = write_compressed(
+        &mut Cursor::new(Vec::new()),
+        CompressionMethod::None,
+        &input,
+        0,
+        0,
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidWrite);
+
+    Ok(())
+}
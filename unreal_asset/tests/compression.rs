@@ -0,0 +1,60 @@
+use unreal_asset::{
+    compression::{compress, decompress, CompressionMethod},
+    Error,
+};
+
+#[test]
+fn compress_round_trip() -> Result<(), Error> {
+    let input = (0..0x3000u32)
+        .flat_map(|e| (e % 251).to_le_bytes())
+        .collect::<Vec<_>>();
+
+    for name in ["None", "Zlib", "Gzip", "LZ4"] {
+        for level in [0, 6, 9, 100] {
+            let compressed = compress(CompressionMethod::new(name), &input, level)?;
+            if name != "None" && level > 0 {
+                assert!(compressed.len() < input.len(), "{name} didn't compress");
+            }
+
+            let mut decompressed = vec![0u8; input.len()];
+            decompress(CompressionMethod::new(name), &compressed, &mut decompressed)?;
+            assert_eq!(decompressed, input, "{name} level {level}");
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn compress_unknown_method() {
+    assert!(matches!(
+        compress(CompressionMethod::new("Foo"), &[0; 4], 0),
+        Err(Error::UnknownCompressionMethod(name)) if &*name == "Foo"
+    ));
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd() -> Result<(), Error> {
+    let method = CompressionMethod::new("Zstd");
+    assert_eq!(method, CompressionMethod::Zstd);
+    assert_eq!(method.to_string(), "Zstd");
+
+    let input = (0..0x3000u32)
+        .flat_map(|e| (e % 251).to_le_bytes())
+        .collect::<Vec<_>>();
+    let compressed = compress(method.clone(), &input, 3)?;
+    assert!(compressed.len() < input.len());
+
+    let mut decompressed = vec![0u8; input.len()];
+    decompress(method.clone(), &compressed, &mut decompressed)?;
+    assert_eq!(decompressed, input);
+
+    // the frame must produce exactly the expected size
+    let mut too_large = vec![0u8; input.len() + 1];
+    assert!(decompress(method.clone(), &compressed, &mut too_large).is_err());
+    let mut too_small = vec![0u8; input.len() - 1];
+    assert!(decompress(method, &compressed, &mut too_small).is_err());
+
+    Ok(())
+}
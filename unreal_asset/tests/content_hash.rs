@@ -0,0 +1,48 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast, containers::NameMap, engine_version::EngineVersion, exports::ExportNormalTrait,
+    properties::Property, Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn content_hash() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+    let hash = asset.content_hash();
+    // the hash must stay the same between builds and platforms
+    assert_eq!(hash, 0x163c6f87ad40794e);
+
+    // different name map order and package guid
+    let name_map = NameMap::new();
+    name_map.clone().get_mut().add_fname("SomeUnrelatedName");
+    let mut reordered = Asset::new_with_name_map(
+        Cursor::new(shared::BROAD_BRUSH),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        name_map,
+    )?;
+    reordered.package_guid = [1u8; 16].into();
+    assert_eq!(reordered.content_hash(), hash);
+
+    let mut cursor = Cursor::new(Vec::new());
+    reordered.write_data(&mut cursor, None)?;
+    let resaved = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    assert_eq!(resaved.content_hash(), hash);
+
+    let mut modified = resaved;
+    let property = modified
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| e.get_normal_export_mut())
+        .flat_map(|e| e.properties.iter_mut())
+        .find_map(|e| cast!(Property, IntProperty, e))
+        .expect("No IntProperty");
+    property.value += 1;
+    assert_ne!(modified.content_hash(), hash);
+
+    Ok(())
+}
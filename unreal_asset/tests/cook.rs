@@ -0,0 +1,210 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{raw_export::RawExport, ExportBaseTrait, ExportNormalTrait},
+    flags::EPackageFlags,
+    properties::{Property, PropertyDataTrait},
+    types::PackageIndex,
+    Asset, Error, Export,
+};
+
+mod shared;
+
+const CLASS_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/DebugMenu.uasset"
+));
+
+fn read(data: &[u8]) -> Result<Asset<Cursor<&[u8]>>, Error> {
+    Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)
+}
+
+fn cook_and_reparse<C: std::io::Read + std::io::Seek>(
+    asset: &mut Asset<C>,
+) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    asset.cook(EngineVersion::VER_UE4_23)?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    Asset::new(cursor, Some(bulk_cursor), EngineVersion::VER_UE4_23, None)
+}
+
+fn set_editor_only(export: &mut Export) {
+    let base_export = export.get_base_export_mut();
+    base_export.not_for_client = true;
+    base_export.not_for_server = true;
+}
+
+/// Object name and outer name of every export
+fn export_outers<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Vec<(String, String)> {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .map(|e| {
+            let base_export = e.get_base_export();
+            let outer = match asset.get_export(base_export.outer_index) {
+                Some(outer) => outer.get_base_export().object_name.get_owned_content(),
+                None => String::new(),
+            };
+            (base_export.object_name.get_owned_content(), outer)
+        })
+        .collect()
+}
+
+#[test]
+fn cook() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert!(!asset.asset_data.use_event_driven_loader);
+
+    asset.cook(EngineVersion::VER_UE4_23)?;
+    assert_eq!(
+        asset.asset_data.custom_versions,
+        EngineVersion::VER_UE4_23.default_custom_versions()
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let cooked = Asset::new(cursor, Some(bulk_cursor), EngineVersion::VER_UE4_23, None)?;
+    assert!(cooked.asset_data.use_event_driven_loader);
+    assert!(cooked
+        .asset_data
+        .package_flags
+        .contains(EPackageFlags::PKG_FILTER_EDITOR_ONLY));
+    assert_eq!(
+        cooked.asset_data.exports.len(),
+        asset.asset_data.exports.len()
+    );
+    assert!(shared::verify_all_exports_parsed(&cooked));
+
+    Ok(())
+}
+
+#[test]
+fn cook_editor_only_exports() -> Result<(), Error> {
+    let mut asset = read(shared::BROAD_BRUSH)?;
+
+    // the class default object references the item catalog data it's the outer of
+    set_editor_only(&mut asset.asset_data.exports[2]);
+    asset.asset_data.exports[1]
+        .get_base_export_mut()
+        .create_before_create_dependencies
+        .push(PackageIndex::new(3));
+
+    let cooked = cook_and_reparse(&mut asset)?;
+    assert_eq!(cooked.asset_data.exports.len(), 2);
+    assert!(shared::verify_all_exports_parsed(&cooked));
+
+    let default_object = &cooked.asset_data.exports[1];
+    assert!(default_object
+        .get_base_export()
+        .create_before_create_dependencies
+        .is_empty());
+    let catalog_data = default_object
+        .get_normal_export()
+        .unwrap()
+        .properties
+        .iter()
+        .find(|e| e.get_name() == "CatalogData")
+        .and_then(|e| cast!(Property, ObjectProperty, e))
+        .unwrap();
+    assert_eq!(catalog_data.value, PackageIndex::new(0));
+
+    // subobjects are removed with their outer
+    let mut asset = read(shared::BROAD_BRUSH)?;
+    set_editor_only(&mut asset.asset_data.exports[1]);
+    let cooked = cook_and_reparse(&mut asset)?;
+    assert_eq!(cooked.asset_data.exports.len(), 1);
+    let class = cast!(Export, ClassExport, &cooked.asset_data.exports[0]).unwrap();
+    assert_eq!(class.class_default_object, PackageIndex::new(0));
+
+    Ok(())
+}
+
+#[test]
+fn cook_editor_only_function() -> Result<(), Error> {
+    let mut asset = read(CLASS_ASSET)?;
+    let class_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| matches!(e, Export::ClassExport(_)))
+        .unwrap();
+    let function_index = *cast!(Export, ClassExport, &asset.asset_data.exports[class_index])
+        .unwrap()
+        .func_map
+        .values()
+        .next()
+        .unwrap();
+    let function_name = asset
+        .get_export(function_index)
+        .unwrap()
+        .get_base_export()
+        .object_name
+        .get_owned_content();
+
+    // the function and its properties are removed, later exports move down
+    let is_removed = |mut index: PackageIndex| {
+        while index.is_export() {
+            if index == function_index {
+                return true;
+            }
+            index = asset
+                .get_export(index)
+                .unwrap()
+                .get_base_export()
+                .outer_index;
+        }
+        false
+    };
+    let expected_outers = export_outers(&asset)
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !is_removed(PackageIndex::new(*i as i32 + 1)))
+        .map(|(_, e)| e)
+        .collect::<Vec<_>>();
+    set_editor_only(&mut asset.asset_data.exports[function_index.index as usize - 1]);
+
+    let cooked = cook_and_reparse(&mut asset)?;
+    assert!(shared::verify_all_exports_parsed(&cooked));
+    assert_eq!(export_outers(&cooked), expected_outers);
+
+    let class = cooked
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| cast!(Export, ClassExport, e))
+        .unwrap();
+    assert!(class
+        .func_map
+        .values()
+        .chain(&class.struct_export.children)
+        .all(|e| cooked
+            .get_export(*e)
+            .is_some_and(|e| e.get_base_export().object_name != function_name)));
+
+    Ok(())
+}
+
+#[test]
+fn cook_raw_export() -> Result<(), Error> {
+    let mut asset = read(shared::BROAD_BRUSH)?;
+    set_editor_only(&mut asset.asset_data.exports[1]);
+    asset.asset_data.exports[0] = RawExport {
+        base_export: asset.asset_data.exports[0].get_base_export().clone(),
+        data: Vec::new(),
+    }
+    .into();
+
+    // references of raw exports can't be remapped
+    assert!(asset.cook(EngineVersion::VER_UE4_23).is_err());
+    assert_eq!(asset.asset_data.exports.len(), 3);
+    assert!(!asset.asset_data.use_event_driven_loader);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Versioned/Assault_M1A1Thompson_WW2_DrumSuppressor"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+#[test]
+fn custom_version_friendly_names() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_27,
+        None,
+    )?;
+
+    // legacy file versions -3 to -5 use the guids format, which serializes friendly names
+    asset.legacy_file_version = -5;
+    for (i, custom_version) in asset.asset_data.custom_versions.iter_mut().enumerate() {
+        custom_version.friendly_name = Some(format!("CustomVersion{i}"));
+    }
+    let custom_versions = asset.asset_data.custom_versions.clone();
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let written = cursor.into_inner();
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_27,
+        None,
+    )?;
+    assert_eq!(
+        asset.asset_data.custom_versions.len(),
+        custom_versions.len()
+    );
+    for (custom_version, expected) in asset
+        .asset_data
+        .custom_versions
+        .iter()
+        .zip(&custom_versions)
+    {
+        assert_eq!(custom_version.guid, expected.guid);
+        assert_eq!(custom_version.friendly_name, expected.friendly_name);
+    }
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_archive_writer::AssetArchiveWriter,
+    containers::Chain,
+    engine_version::{get_object_versions, EngineVersion},
+    flags::{EBulkDataFlags, EObjectDataResourceFlags},
+    object_version::ObjectVersionUE5,
+    reader::{RawReader, RawWriter},
+    types::{bulk_data::FByteBulkData, object_data_resource::FObjectDataResource, PackageIndex},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/ue5/PublicHousingPlot_Root"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".umap"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(test_asset!(), ".uexp"));
+
+#[test]
+fn data_resources() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert!(asset.asset_data.data_resources.is_none());
+
+    let data_resources = vec![
+        FObjectDataResource {
+            flags: EObjectDataResourceFlags::NONE,
+            serial_offset: 0,
+            duplicate_serial_offset: -1,
+            serial_size: 16,
+            raw_size: 16,
+            outer_index: PackageIndex::new(1),
+            legacy_bulk_data_flags: EBulkDataFlags::BULKDATA_PAYLOAD_AT_END_OF_FILE,
+        },
+        FObjectDataResource {
+            flags: EObjectDataResourceFlags::STREAMING | EObjectDataResourceFlags::MEMORY_MAPPED,
+            serial_offset: 128,
+            duplicate_serial_offset: -1,
+            serial_size: 64,
+            raw_size: 256,
+            outer_index: PackageIndex::new(2),
+            legacy_bulk_data_flags: EBulkDataFlags::BULKDATA_PAYLOAD_AT_END_OF_FILE
+                | EBulkDataFlags::BULKDATA_PAYLOAD_IN_SEPERATE_FILE,
+        },
+    ];
+    assert!(!data_resources[0].is_in_separate_file());
+    assert!(data_resources[1].is_in_separate_file());
+
+    asset.asset_data.object_version_ue5 = ObjectVersionUE5::DATA_RESOURCES;
+    asset.asset_data.data_resources = Some(data_resources.clone());
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (written, written_bulk) = (cursor.get_ref().clone(), bulk_cursor.get_ref().clone());
+
+    let (object_version, _) = get_object_versions(EngineVersion::VER_UE5_1);
+    let mut reparsed = Asset::new_with_versions(
+        cursor,
+        Some(bulk_cursor),
+        object_version,
+        ObjectVersionUE5::DATA_RESOURCES,
+        EngineVersion::VER_UE5_1.default_custom_versions(),
+        None,
+    )?;
+    assert_eq!(reparsed.asset_data.data_resources, Some(data_resources));
+    shared::verify_binary_equality(&written, Some(&written_bulk), &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn bulk_data_resources() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET.to_vec()),
+        Some(Cursor::new(TEST_BULK.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    asset.asset_data.object_version_ue5 = ObjectVersionUE5::DATA_RESOURCES;
+    asset.asset_data.data_resources = Some(vec![
+        FObjectDataResource {
+            flags: EObjectDataResourceFlags::NONE,
+            serial_offset: 0,
+            duplicate_serial_offset: -1,
+            serial_size: 4,
+            raw_size: 4,
+            outer_index: PackageIndex::new(1),
+            legacy_bulk_data_flags: EBulkDataFlags::BULKDATA_SINGLE_USE,
+        },
+        FObjectDataResource {
+            flags: EObjectDataResourceFlags::NONE,
+            serial_offset: 32,
+            duplicate_serial_offset: -1,
+            serial_size: 8,
+            raw_size: 8,
+            outer_index: PackageIndex::new(1),
+            legacy_bulk_data_flags: EBulkDataFlags::BULKDATA_PAYLOAD_AT_END_OF_FILE,
+        },
+    ]);
+
+    // inline payload, followed by a payload at the end of the file and an invalid index
+    let data = [
+        0i32.to_le_bytes().as_slice(),
+        &[1, 2, 3, 4],
+        &1i32.to_le_bytes(),
+        &2i32.to_le_bytes(),
+    ]
+    .concat();
+    asset.raw_reader = RawReader::new(
+        Chain::new(Cursor::new(data.clone()), None),
+        asset.asset_data.object_version,
+        asset.asset_data.object_version_ue5,
+        asset.asset_data.use_event_driven_loader,
+        asset.get_name_map(),
+    );
+
+    let inline = FByteBulkData::read(&mut asset)?;
+    assert_eq!(inline.data_resource_index, Some(0));
+    assert_eq!(inline.header.size_on_disk, 4);
+    assert_eq!(inline.data, Some(vec![1, 2, 3, 4]));
+
+    let at_end = FByteBulkData::read(&mut asset)?;
+    assert_eq!(at_end.data_resource_index, Some(1));
+    assert_eq!(at_end.header.offset_in_file, 32);
+    assert!(at_end.data.is_none());
+
+    assert!(FByteBulkData::read(&mut asset).is_err());
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut raw_writer = RawWriter::new(
+        &mut cursor,
+        asset.asset_data.object_version,
+        asset.asset_data.object_version_ue5,
+        asset.asset_data.use_event_driven_loader,
+        asset.get_name_map(),
+    );
+    let mut writer = AssetArchiveWriter::new(
+        &mut raw_writer,
+        &asset.asset_data,
+        &asset.imports,
+        asset.get_name_map(),
+    );
+    inline.write(&mut writer)?;
+    at_end.write(&mut writer)?;
+    assert!(FByteBulkData::default().write(&mut writer).is_err());
+
+    // the data resource must exist and match the inline payload length
+    let missing = FByteBulkData {
+        data_resource_index: Some(2),
+        ..at_end.clone()
+    };
+    assert!(missing.write(&mut writer).is_err());
+    let resized = FByteBulkData {
+        data: Some(vec![1, 2, 3]),
+        ..inline.clone()
+    };
+    assert!(resized.write(&mut writer).is_err());
+    assert_eq!(cursor.into_inner(), data[..data.len() - 4]);
+
+    Ok(())
+}
@@ -5,7 +5,8 @@ use unreal_asset::{
     cast,
     engine_version::EngineVersion,
     exports::{data_table_export::DataTableExport, Export},
-    properties::{Property, PropertyDataTrait},
+    properties::{int_property::IntProperty, Property, PropertyDataTrait},
+    types::FName,
     Asset, Error,
 };
 
@@ -90,3 +91,130 @@ fn data_tables() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn data_table_rows() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let new_name = asset.add_fname("TestRow");
+
+    let data_table_export: &mut DataTableExport =
+        cast!(Export, DataTableExport, &mut asset.asset_data.exports[0])
+            .expect("First export is not a DataTableExport");
+    let row_names = data_table_export
+        .table
+        .data
+        .iter()
+        .map(|e| e.name.to_string())
+        .collect::<Vec<_>>();
+    assert!(row_names.len() >= 2);
+
+    let first_row = data_table_export.get_row(&row_names[0]).unwrap().clone();
+    assert_eq!(&first_row, &data_table_export.table.data[0]);
+    assert!(data_table_export.get_row("NonExistentRow").is_none());
+
+    // a row with a different struct type is rejected
+    let mut bad_row = first_row.clone();
+    bad_row.struct_type = Some(new_name.clone());
+    assert!(data_table_export
+        .insert_row(new_name.clone(), bad_row)
+        .is_err());
+
+    // a row with a different property type is rejected
+    let mut bad_row = first_row.clone();
+    let property = &mut bad_row.value[0];
+    *property = IntProperty {
+        name: property.get_name(),
+        ancestry: first_row.ancestry.clone(),
+        property_guid: None,
+        duplication_index: 0,
+        value: 0,
+    }
+    .into();
+    assert!(data_table_export
+        .insert_row(new_name.clone(), bad_row)
+        .is_err());
+    assert_eq!(data_table_export.table.data.len(), row_names.len());
+
+    // names are compared by their content, not by their name map index
+    let mut dummy_row = first_row.clone();
+    dummy_row.struct_type = first_row
+        .struct_type
+        .as_ref()
+        .map(|e| FName::new_dummy(e.get_owned_content(), e.get_number()));
+    for property in &mut dummy_row.value {
+        let name = property.get_name();
+        *property.get_name_mut() = FName::new_dummy(name.get_owned_content(), name.get_number());
+    }
+    assert!(data_table_export
+        .insert_row(new_name.clone(), dummy_row)?
+        .is_none());
+    assert!(data_table_export.remove_row("TestRow").is_some());
+
+    // modify, append and remove rows
+    for property in &mut data_table_export.get_row_mut(&row_names[1]).unwrap().value {
+        if let Some(bool_prop) = cast!(Property, BoolProperty, property) {
+            bool_prop.value = !bool_prop.value;
+        }
+    }
+    let modified_row = data_table_export.get_row(&row_names[1]).unwrap().clone();
+    assert!(data_table_export
+        .insert_row(new_name.clone(), first_row.clone())?
+        .is_none());
+    let removed = data_table_export.remove_row(&row_names[0]).unwrap();
+    assert_eq!(removed, first_row);
+    assert!(data_table_export.remove_row(&row_names[0]).is_none());
+
+    let mut modified = Cursor::new(Vec::new());
+    asset.write_data(&mut modified, None)?;
+    let modified = modified.into_inner();
+
+    let mut parsed_back = Asset::new(
+        Cursor::new(modified.as_slice()),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    shared::verify_binary_equality(&modified, None, &mut parsed_back)?;
+
+    let data_table_export: &mut DataTableExport = cast!(
+        Export,
+        DataTableExport,
+        &mut parsed_back.asset_data.exports[0]
+    )
+    .expect("First export is not a DataTableExport after serializing and deserializing");
+    // row order is preserved, appended rows come last
+    let parsed_row_names = data_table_export
+        .table
+        .data
+        .iter()
+        .map(|e| e.name.to_string())
+        .collect::<Vec<_>>();
+    let mut expected_row_names = row_names[1..].to_vec();
+    expected_row_names.push(String::from("TestRow"));
+    assert_eq!(parsed_row_names, expected_row_names);
+    assert_eq!(
+        data_table_export.get_row(&row_names[1]).unwrap().value,
+        modified_row.value
+    );
+    assert_eq!(
+        data_table_export.get_row("TestRow").unwrap().value,
+        first_row.value
+    );
+
+    // inserting an existing row replaces it in place
+    let new_name = data_table_export.table.data.last().unwrap().name.clone();
+    let replaced = data_table_export.insert_row(new_name, modified_row.clone())?;
+    assert_eq!(replaced.unwrap().value, first_row.value);
+    assert_eq!(
+        data_table_export.table.data.last().unwrap().value,
+        modified_row.value
+    );
+    assert_eq!(data_table_export.table.data.len(), expected_row_names.len());
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use unreal_asset::{exports::ExportBaseTrait, reader::ArchiveTrait, Error};
+
+mod shared;
+
+#[test]
+fn to_dot() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+
+    let dot = asset.to_dot();
+    assert!(dot.starts_with("digraph asset {\n"));
+    assert!(dot.ends_with("}\n"));
+
+    let lines = dot.lines().collect::<Vec<_>>();
+    let export_nodes = lines
+        .iter()
+        .filter(|e| e.trim_start().starts_with("export_") && !e.contains("->"))
+        .count();
+    assert_eq!(export_nodes, asset.asset_data.exports.len());
+
+    let outer_edges = lines
+        .iter()
+        .filter(|e| e.contains("label=\"outer\""))
+        .count();
+    let outers = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter(|e| e.get_base_export().outer_index.index != 0)
+        .count();
+    assert_eq!(outer_edges, outers);
+
+    // every edge points to a node
+    for line in lines.iter().filter(|e| e.contains("->")) {
+        let target = line.split("->").nth(1).unwrap().split_whitespace().next();
+        let target = target.unwrap();
+        assert!(lines
+            .iter()
+            .any(|e| e.trim_start().starts_with(&format!("{} [label", target))));
+    }
+
+    // the first export is a class, its class is an import
+    let base_export = asset.asset_data.exports[0].get_base_export();
+    let class = asset.get_import(base_export.class_index).unwrap();
+    assert!(lines[1].contains(&format!(
+        "export_1 [label=\"{}\\n{}\"]",
+        base_export.object_name.get_owned_content(),
+        class.object_name.get_owned_content()
+    )));
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use unreal_asset::{engine_version::EngineVersion, Error};
+
+mod shared;
+
+#[test]
+fn ensure_depends_map() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    // existing depends map is kept as-is
+    asset.ensure_depends_map();
+    shared::verify_binary_equality(shared::BROAD_BRUSH, None, &mut asset)?;
+
+    // new exports get an empty entry
+    let export = asset.asset_data.exports[0].clone();
+    asset.asset_data.exports.push(export);
+    asset.ensure_depends_map();
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
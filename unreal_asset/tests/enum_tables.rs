@@ -0,0 +1,47 @@
+use unreal_asset::{
+    exports::{
+        enum_export::{ECppForm, EnumExport, UEnum},
+        BaseExport, NormalExport,
+    },
+    Error,
+};
+
+mod shared;
+
+#[test]
+fn enum_tables() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert!(asset.enum_tables().is_empty());
+
+    let names = vec![
+        (asset.add_fname("EAbility::Dash"), 0),
+        (asset.add_fname("EAbility::Jump"), 1),
+        (asset.add_fname("EAbility::EAbility_MAX"), 2),
+    ];
+    let object_name = asset.add_fname("EAbility");
+    asset.asset_data.exports.push(
+        EnumExport {
+            normal_export: NormalExport {
+                base_export: BaseExport {
+                    object_name,
+                    ..Default::default()
+                },
+                extras: Vec::new(),
+                properties: Vec::new(),
+            },
+            value: UEnum {
+                names,
+                cpp_form: ECppForm::Namespaced,
+            },
+        }
+        .into(),
+    );
+
+    let enum_tables = asset.enum_tables();
+    assert_eq!(enum_tables.len(), 1);
+    let values = &enum_tables["EAbility"];
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[&1], "EAbility::Jump");
+
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_builder::AssetBuilder,
+    cast,
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    exports::{BaseExport, ExportNormalTrait, NormalExport},
+    flags::{EObjectFlags, EPackageFlags},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        enum_property::{EnumProperty, EnumUnderlyingType},
+        Property,
+    },
+    types::{FName, PackageIndex},
+    unversioned::{
+        properties::{
+            enum_property::UsmapEnumPropertyData, shallow_property::UsmapShallowPropertyData,
+            EPropertyType, UsmapProperty, UsmapPropertyData,
+        },
+        Ancestry, EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion,
+        UsmapSchema,
+    },
+    Asset, Error, Import,
+};
+
+mod shared;
+
+const EXTRAPOLATIONS: [&str; 6] = [
+    "RCCE_Cycle",
+    "RCCE_CycleWithOffset",
+    "RCCE_Oscillate",
+    "RCCE_Linear",
+    "RCCE_Constant",
+    "RCCE_None",
+];
+
+fn extrapolation(inner_type: EPropertyType) -> UsmapPropertyData {
+    UsmapEnumPropertyData {
+        inner_property: Box::new(
+            UsmapShallowPropertyData {
+                property_type: inner_type,
+            }
+            .into(),
+        ),
+        name: "ERichCurveExtrapolation".to_string(),
+    }
+    .into()
+}
+
+/// Mappings for a `DataAsset` with an int and a byte backed enum
+fn mappings() -> Usmap {
+    let mut properties = IndexedMap::new();
+    for (i, (name, inner_type)) in [
+        ("PreInfinityExtrap", EPropertyType::IntProperty),
+        ("PostInfinityExtrap", EPropertyType::ByteProperty),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        properties.insert(
+            (name.to_string(), 0),
+            UsmapProperty {
+                name: name.to_string(),
+                schema_index: i as u16,
+                array_size: 1,
+                array_index: 0,
+                property_data: extrapolation(inner_type),
+            },
+        );
+    }
+
+    let mut schemas = IndexedMap::new();
+    schemas.insert(
+        "DataAsset".to_string(),
+        UsmapSchema {
+            name: "DataAsset".to_string(),
+            super_type: String::new(),
+            prop_count: properties.len() as u16,
+            module_path: None,
+            properties,
+        },
+    );
+
+    let mut enum_map = IndexedMap::new();
+    enum_map.insert(
+        "ERichCurveExtrapolation".to_string(),
+        EXTRAPOLATIONS.iter().map(|e| e.to_string()).collect(),
+    );
+
+    Usmap {
+        version: EUsmapVersion::Initial,
+        name_map: Vec::new(),
+        enum_map,
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+/// Build an unversioned asset with one export holding the given extrapolation values
+fn build_asset(values: [(Option<&str>, Option<i64>); 2]) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let mut builder = AssetBuilder::new()
+        .engine_version(EngineVersion::VER_UE4_27)
+        .package_flags(
+            EPackageFlags::PKG_FILTER_EDITOR_ONLY | EPackageFlags::PKG_UNVERSIONED_PROPERTIES,
+        )
+        .folder_name("/Game/Curve");
+
+    let core_uobject = builder.add_fname("/Script/CoreUObject");
+    let package = builder.add_fname("Package");
+    let engine = builder.add_fname("/Script/Engine");
+    let engine_index = builder.add_import(Import::new(
+        core_uobject.clone(),
+        package,
+        PackageIndex::new(0),
+        engine,
+        false,
+    ));
+
+    let class = builder.add_fname("Class");
+    let data_asset = builder.add_fname("DataAsset");
+    let class_index = builder.add_import(Import::new(
+        core_uobject,
+        class,
+        engine_index,
+        data_asset.clone(),
+        false,
+    ));
+
+    builder.add_fname("None");
+    let mut properties = Vec::new();
+    for ((name, inner_type), (value, unknown_index)) in [
+        ("PreInfinityExtrap", "IntProperty"),
+        ("PostInfinityExtrap", "ByteProperty"),
+    ]
+    .into_iter()
+    .zip(values)
+    {
+        properties.push(
+            EnumProperty {
+                name: builder.add_fname(name),
+                ancestry: Ancestry::new(data_asset.clone()),
+                property_guid: None,
+                duplication_index: 0,
+                enum_type: Some(FName::new_dummy("ERichCurveExtrapolation".to_string(), 0)),
+                inner_type: Some(FName::new_dummy(inner_type.to_string(), 0)),
+                value: value.map(|e| FName::new_dummy(e.to_string(), 0)),
+                unknown_index,
+            }
+            .into(),
+        );
+    }
+
+    let export = NormalExport {
+        base_export: BaseExport {
+            class_index,
+            object_name: builder.add_fname("Curve"),
+            object_flags: EObjectFlags::RF_PUBLIC | EObjectFlags::RF_STANDALONE,
+            ..Default::default()
+        },
+        extras: vec![0; 4],
+        properties,
+    };
+    builder.add_export(export);
+
+    let mut asset = builder.build()?;
+    asset.asset_data.mappings = Some(mappings());
+    Ok(asset)
+}
+
+fn write(asset: &Asset<Cursor<Vec<u8>>>) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    Ok(cursor.into_inner())
+}
+
+fn read(data: &[u8]) -> Result<Asset<Cursor<&[u8]>>, Error> {
+    let asset = Asset::new(
+        Cursor::new(data),
+        None,
+        EngineVersion::VER_UE4_27,
+        Some(mappings()),
+    )?;
+    assert!(asset.warnings.is_empty());
+    Ok(asset)
+}
+
+/// Get the enum properties of the only export
+fn enum_properties(asset: &Asset<Cursor<&[u8]>>) -> Vec<EnumProperty> {
+    asset.asset_data.exports[0]
+        .get_normal_export()
+        .expect("Export was not read as a normal export")
+        .properties
+        .iter()
+        .filter_map(|e| cast!(Property, EnumProperty, e).cloned())
+        .collect()
+}
+
+#[test]
+fn enum_underlying_type() {
+    assert_eq!(
+        EnumUnderlyingType::from_property_type("ByteProperty"),
+        Some(EnumUnderlyingType::Byte)
+    );
+    assert_eq!(
+        EnumUnderlyingType::from_property_type("UInt16Property"),
+        Some(EnumUnderlyingType::UInt16)
+    );
+    assert_eq!(EnumUnderlyingType::from_property_type("NameProperty"), None);
+
+    assert_eq!(EnumUnderlyingType::Int8.size(), 1);
+    assert_eq!(EnumUnderlyingType::UInt16.size(), 2);
+    assert_eq!(EnumUnderlyingType::Int.size(), 4);
+    assert_eq!(EnumUnderlyingType::UInt64.size(), 8);
+}
+
+#[test]
+fn unversioned_enum_roundtrip() -> Result<(), Error> {
+    let asset = build_asset([(Some("RCCE_Linear"), None), (None, None)])?;
+    let written = write(&asset)?;
+    let mut reparsed = read(&written)?;
+    let properties = enum_properties(&reparsed);
+
+    assert_eq!(properties.len(), 2);
+    assert_eq!(
+        properties[0].underlying_type(),
+        Some(EnumUnderlyingType::Int)
+    );
+    assert_eq!(
+        properties[0].value.as_ref().map(|e| e.get_owned_content()),
+        Some("RCCE_Linear".to_string())
+    );
+    assert_eq!(
+        properties[1].underlying_type(),
+        Some(EnumUnderlyingType::Byte)
+    );
+    assert_eq!(properties[1].value, None);
+    assert_eq!(properties[1].unknown_index, None);
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    // indices missing from the mappings survive a round trip
+    let asset = build_asset([(None, Some(1000)), (None, Some(200))])?;
+    let written = write(&asset)?;
+    let mut reparsed = read(&written)?;
+    let properties = enum_properties(&reparsed);
+
+    assert_eq!(properties[0].value, None);
+    assert_eq!(properties[0].unknown_index, Some(1000));
+    assert_eq!(properties[1].value, None);
+    assert_eq!(properties[1].unknown_index, Some(200));
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    Ok(())
+}
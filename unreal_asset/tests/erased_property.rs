@@ -0,0 +1,116 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    exports::ExportNormalTrait,
+    properties::{
+        erased_property::ErasedProperty,
+        int_property::{BoolProperty, IntProperty},
+        Ancestry, Property, PropertyDataTrait, PropertyTrait,
+    },
+    reader::{ArchiveWriter, RawWriter},
+    types::fname::ToSerializedName,
+    Error,
+};
+
+mod shared;
+
+#[test]
+fn erased_property() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let int_property = IntProperty {
+        name: asset.add_fname("Count"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: 7,
+    };
+    let bool_property = BoolProperty {
+        name: asset.add_fname("Enabled"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: true,
+    };
+    let parsed = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter().cloned())
+        .collect::<Vec<_>>();
+    assert!(!parsed.is_empty());
+
+    let mut erased_cursor = Cursor::new(Vec::new());
+    let mut erased_writer = RawWriter::new(
+        &mut erased_cursor,
+        asset.asset_data.object_version,
+        asset.asset_data.object_version_ue5,
+        asset.asset_data.use_event_driven_loader,
+        asset.get_name_map(),
+    );
+    let mut erased_sizes = Vec::new();
+    for property in erase(&int_property, &bool_property, &parsed) {
+        erased_sizes.push(property.write(&mut erased_writer, true)?);
+    }
+
+    // writing through the trait object matches writing the properties directly
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::new(
+        &mut cursor,
+        asset.asset_data.object_version,
+        asset.asset_data.object_version_ue5,
+        asset.asset_data.use_event_driven_loader,
+        asset.get_name_map(),
+    );
+    let mut sizes = vec![
+        PropertyTrait::write(&int_property, &mut writer, true)?,
+        PropertyTrait::write(&bool_property, &mut writer, true)?,
+    ];
+    for property in &parsed {
+        sizes.push(PropertyTrait::write(property, &mut writer, true)?);
+    }
+    assert_eq!(erased_sizes, sizes);
+    assert_eq!(erased_cursor.into_inner(), cursor.into_inner());
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::new(
+        &mut cursor,
+        asset.asset_data.object_version,
+        asset.asset_data.object_version_ue5,
+        asset.asset_data.use_event_driven_loader,
+        asset.get_name_map(),
+    );
+    let mut serialized_names = vec![String::from("IntProperty"), String::from("BoolProperty")];
+    serialized_names.extend(parsed.iter().map(|e| e.to_serialized_name()));
+    let mut names = vec![int_property.name.clone(), bool_property.name.clone()];
+    names.extend(parsed.iter().map(PropertyDataTrait::get_name));
+    for (i, property) in erase(&int_property, &bool_property, &parsed)
+        .into_iter()
+        .enumerate()
+    {
+        assert_eq!(property.get_name(), names[i]);
+        assert_eq!(
+            property.to_fname(&mut writer).get_owned_content(),
+            serialized_names[i]
+        );
+    }
+
+    Ok(())
+}
+
+/// Store concrete properties and the `Property` enum side by side
+fn erase<W: ArchiveWriter>(
+    int_property: &IntProperty,
+    bool_property: &BoolProperty,
+    parsed: &[Property],
+) -> Vec<Box<dyn ErasedProperty<W>>> {
+    let mut properties: Vec<Box<dyn ErasedProperty<W>>> = vec![
+        Box::new(int_property.clone()),
+        Box::new(bool_property.clone()),
+    ];
+    for property in parsed {
+        properties.push(Box::new(property.clone()));
+    }
+    properties
+}
@@ -0,0 +1,21 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, error::ErrorKind, Asset};
+
+mod shared;
+
+#[test]
+fn error_kind() {
+    // corrupt the package tag
+    let mut data = shared::BROAD_BRUSH.to_vec();
+    data[0] = 0;
+    let error = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // make the asset unversioned by clearing the file version
+    let mut data = shared::BROAD_BRUSH.to_vec();
+    data[12..16].copy_from_slice(&0i32.to_le_bytes());
+    let error = Asset::new(Cursor::new(data), None, EngineVersion::UNKNOWN, None).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::EngineVersionRequired);
+    assert_eq!(error.kind().code(), "engine_version_required");
+}
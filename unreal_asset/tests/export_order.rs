@@ -0,0 +1,73 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{ExportBaseTrait, ExportNormalTrait},
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn exports_out_of_offset_order() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+
+    // (serial size, serial offset) of every export
+    let ranges = asset
+        .asset_data
+        .exports
+        .iter()
+        .map(|e| {
+            let base_export = e.get_base_export();
+            (base_export.serial_size, base_export.serial_offset)
+        })
+        .collect::<Vec<_>>();
+    let data_start = ranges.iter().map(|(_, offset)| *offset).min().unwrap() as usize;
+    let data_end = ranges
+        .iter()
+        .map(|(size, offset)| size + offset)
+        .max()
+        .unwrap() as usize;
+
+    // move the first export's data after all other exports
+    let mut data = shared::BROAD_BRUSH[..data_start].to_vec();
+    let mut new_offsets = Vec::new();
+    for (size, offset) in ranges.iter().skip(1).chain(ranges.iter().take(1)) {
+        new_offsets.push((*offset, data.len() as i64));
+        data.extend_from_slice(&shared::BROAD_BRUSH[*offset as usize..(offset + size) as usize]);
+    }
+    data.extend_from_slice(&shared::BROAD_BRUSH[data_end..]);
+
+    // patch serial offsets in the export map, which directly follow serial sizes
+    for (size, offset) in &ranges {
+        let (_, new_offset) = new_offsets.iter().find(|(old, _)| old == offset).unwrap();
+
+        let mut entry = size.to_le_bytes().to_vec();
+        entry.extend_from_slice(&offset.to_le_bytes());
+        let position = data[..data_start]
+            .windows(entry.len())
+            .position(|e| e == entry)
+            .unwrap();
+        data[position + 8..position + 16].copy_from_slice(&new_offset.to_le_bytes());
+    }
+
+    let reordered = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)?;
+    assert!(shared::verify_all_exports_parsed(&reordered));
+
+    for (original, reordered) in asset
+        .asset_data
+        .exports
+        .iter()
+        .zip(reordered.asset_data.exports.iter())
+    {
+        let (Some(original), Some(reordered)) =
+            (original.get_normal_export(), reordered.get_normal_export())
+        else {
+            continue;
+        };
+        assert_eq!(original.properties, reordered.properties);
+        assert_eq!(original.extras, reordered.extras);
+    }
+
+    Ok(())
+}
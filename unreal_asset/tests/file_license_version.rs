@@ -0,0 +1,19 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+#[test]
+fn file_license_version() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    asset.set_file_license_version(3);
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let asset = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    assert_eq!(asset.get_file_license_version(), 3);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, types::FName, types::PackageIndex,
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/BloodStained/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "m01SIP_000_Gimmick.umap"));
+
+#[test]
+fn find_export_by_name_in_outer() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+
+    let name = FName::from_slice("AttachComponent");
+    let outers = asset
+        .asset_data
+        .exports
+        .iter()
+        .map(|e| e.get_base_export())
+        .filter(|e| e.object_name.eq_content(&name))
+        .map(|e| e.outer_index)
+        .collect::<Vec<_>>();
+    assert_eq!(outers.len(), 6);
+
+    let first = asset
+        .find_export_by_name(&name)
+        .expect("Failed to find export");
+    assert_eq!(
+        asset
+            .get_export(first)
+            .unwrap()
+            .get_base_export()
+            .outer_index,
+        outers[0]
+    );
+
+    for outer in outers {
+        let index = asset
+            .find_export_by_name_in_outer(&name, Some(outer))
+            .expect("Failed to find export in outer");
+        let export = asset.get_export(index).unwrap().get_base_export();
+        assert!(export.object_name.eq_content(&name));
+        assert_eq!(export.outer_index, outer);
+    }
+
+    assert_eq!(
+        asset.find_export_by_name_in_outer(&name, Some(PackageIndex::new(0))),
+        None
+    );
+    assert_eq!(
+        asset.find_export_by_name(&FName::new_dummy("AttachComponent".to_string(), 1)),
+        None
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+use std::io::Cursor;
+
+use unreal_asset::{types::FName, Asset, Error};
+
+mod shared;
+
+/// Add a name to the name map of an asset, splitting off the number suffix
+fn add_name(asset: &Asset<Cursor<&[u8]>>, value: &str) -> FName {
+    asset.get_name_map().get_mut().add_fname_from_display(value)
+}
+
+#[test]
+fn fname_eq_content() -> Result<(), Error> {
+    let first = shared::read_broad_brush()?;
+    let second = shared::read_broad_brush()?;
+    second
+        .get_name_map()
+        .get_mut()
+        .add_name_reference("Padding".to_string(), false);
+
+    let a = add_name(&first, "Foo_1");
+    let b = add_name(&second, "Foo_1");
+    let dummy = FName::from_display("Foo_1");
+
+    // `==` compares name map indices, which differ between the assets
+    assert!(a != b);
+    assert!(a != dummy);
+    assert!(a.eq_content(&b));
+    assert!(b.eq_content(&dummy));
+    assert!(dummy.eq_content(&a));
+
+    // the number is part of the name
+    assert!(!a.eq_content(&FName::from_display("Foo_0")));
+    assert!(!a.eq_content(&FName::from_display("Foo")));
+    assert!(!a.eq_content(&add_name(&second, "Bar_1")));
+
+    Ok(())
+}
+
+#[test]
+fn fname_cmp_content() -> Result<(), Error> {
+    let first = shared::read_broad_brush()?;
+    let second = shared::read_broad_brush()?;
+
+    let a = add_name(&first, "Foo_1");
+    let b = add_name(&second, "Foo_1");
+    assert_eq!(a.cmp_content(&b), Ordering::Equal);
+    assert_eq!(
+        a.cmp_content(&FName::from_display("Foo_1")),
+        Ordering::Equal
+    );
+
+    let mut names = vec![
+        add_name(&second, "Foo_1"),
+        FName::from_display("Bar"),
+        add_name(&first, "Foo"),
+        FName::from_display("Foo_0"),
+        add_name(&second, "Bar_3"),
+        add_name(&first, "Baz"),
+    ];
+    names.sort_by(FName::cmp_content);
+    assert_eq!(
+        names.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec!["Bar", "Bar_3", "Baz", "Foo", "Foo_0", "Foo_1"]
+    );
+
+    names.dedup_by(|a, b| a.eq_content(b));
+    assert_eq!(names.len(), 6);
+    names.push(add_name(&first, "Foo_1"));
+    names.dedup_by(|a, b| a.eq_content(b));
+    assert_eq!(names.len(), 6);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{ExportBaseTrait, ExportNormalTrait},
+    properties::Property,
+    types::{FName, PackageIndex},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! soft_path_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/Zone_Caves"
+        )
+    };
+}
+
+const SOFT_PATH_ASSET: &[u8] = include_bytes!(concat!(soft_path_asset!(), ".umap"));
+const SOFT_PATH_BULK: &[u8] = include_bytes!(concat!(soft_path_asset!(), ".uexp"));
+
+/// (display string, content, number)
+const NAMES: [(&str, &str, i32); 3] = [("Foo", "Foo", 0), ("Foo_0", "Foo", 1), ("Foo_1", "Foo", 2)];
+
+#[test]
+fn fname_display() {
+    for (display, content, number) in NAMES {
+        let name = FName::from_display(display);
+        assert_eq!(name.get_owned_content(), content);
+        assert_eq!(name.get_number(), number);
+        assert_eq!(name.to_string(), display);
+    }
+    assert_eq!(FName::from_display("Foo").get_number_suffix(), None);
+    assert_eq!(FName::from_display("Foo_0").get_number_suffix(), Some(0));
+
+    // suffixes with leading zeros and non-numeric suffixes are part of the content
+    for display in ["Foo_01", "Foo_", "Foo_Bar", "_0"] {
+        let name = FName::from_display(display);
+        assert_eq!(name.get_number(), 0);
+        assert_eq!(name.to_string(), display);
+    }
+    assert_eq!(FName::from_display("_0").get_owned_content(), "_0");
+}
+
+#[test]
+fn fname_numbers() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert!(asset.asset_data.exports.len() >= NAMES.len());
+
+    for (i, (display, _, _)) in NAMES.iter().enumerate() {
+        let name = asset
+            .get_name_map()
+            .get_mut()
+            .add_fname_from_display(display);
+        asset.asset_data.exports[i]
+            .get_base_export_mut()
+            .object_name = name;
+    }
+    // all numbers share a single name map entry
+    assert_eq!(
+        asset
+            .get_name_map()
+            .get_ref()
+            .get_name_map_index_list()
+            .iter()
+            .filter(|e| e.starts_with("Foo"))
+            .count(),
+        1
+    );
+
+    let written = shared::write_asset(&asset)?;
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    for (i, (display, content, number)) in NAMES.iter().enumerate() {
+        let name = &reparsed.asset_data.exports[i].get_base_export().object_name;
+        assert_eq!(name.get_owned_content(), *content);
+        assert_eq!(name.get_number(), *number);
+        assert_eq!(name.to_string(), *display);
+        // paths include the outer chain
+        let path = reparsed.index_to_path(PackageIndex::new(i as i32 + 1));
+        assert!(path.unwrap().ends_with(display));
+    }
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn soft_path_number_suffix() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(SOFT_PATH_ASSET),
+        Some(Cursor::new(SOFT_PATH_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let name = asset
+        .get_name_map()
+        .get_mut()
+        .add_fname_from_display("Foo_0");
+    let property = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| e.get_normal_export_mut())
+        .flat_map(|e| e.properties.iter_mut())
+        .find_map(|e| match e {
+            Property::SoftObjectProperty(property) => Some(property),
+            _ => None,
+        })
+        .unwrap();
+    property.value.asset_path.asset_name = name.clone();
+
+    let mut paths = Vec::new();
+    asset.rewrite_soft_paths(|path| {
+        paths.push(path.to_string());
+        Some(path.to_string())
+    });
+    assert!(paths.iter().any(|e| e.ends_with(".Foo_0")));
+
+    let property = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .find_map(|e| match e {
+            Property::SoftObjectProperty(property) => Some(property),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(property.value.asset_path.asset_name, name);
+
+    Ok(())
+}
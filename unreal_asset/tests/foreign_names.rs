@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::PropertyDataTrait,
+    types::{FName, PackageIndex},
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn foreign_names() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    let mut other = shared::read_broad_brush()?;
+
+    let foreign_name = other.add_fname("ForeignPropertyName");
+    let normal_export = asset
+        .get_export_mut(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("Export is not a normal export");
+    *normal_export.properties[0].get_name_mut() = foreign_name;
+
+    // names of another name map can't be written
+    let mut cursor = Cursor::new(Vec::new());
+    let error = asset.write_data(&mut cursor, None).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("fname not in name map: ForeignPropertyName"));
+
+    // dummy names can't be written either
+    let normal_export = asset
+        .get_export_mut(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export_mut())
+        .unwrap();
+    let foreign_name = std::mem::replace(
+        normal_export.properties[0].get_name_mut(),
+        FName::from_slice("ForeignPropertyName"),
+    );
+    assert!(asset.write_data(&mut cursor, None).is_err());
+
+    // rebuilding the name map adds the names of other name maps
+    let normal_export = asset
+        .get_export_mut(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export_mut())
+        .unwrap();
+    *normal_export.properties[0].get_name_mut() = foreign_name;
+    asset.rebuild_name_map();
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let reparsed = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(reparsed
+        .search_name_reference("ForeignPropertyName")
+        .is_some());
+
+    Ok(())
+}
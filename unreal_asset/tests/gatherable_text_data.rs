@@ -0,0 +1,75 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    types::gatherable_text_data::{FTextSourceData, FTextSourceSiteContext, GatherableTextData},
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn gatherable_text_data() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert!(asset.asset_data.gatherable_text_data.is_none());
+
+    let gatherable_text_data = vec![
+        GatherableTextData {
+            namespace_name: Some("Augments".to_string()),
+            source_data: FTextSourceData {
+                source_string: Some("Broad Brush".to_string()),
+            },
+            source_site_contexts: vec![FTextSourceSiteContext {
+                key_name: Some("6B0D2C8D4A1E".to_string()),
+                site_description: Some("Augment_BroadBrush.DisplayName".to_string()),
+                is_editor_only: false,
+                is_optional: false,
+            }],
+        },
+        GatherableTextData {
+            namespace_name: None,
+            source_data: FTextSourceData {
+                source_string: Some("Widens the terrain tool brush".to_string()),
+            },
+            source_site_contexts: vec![
+                FTextSourceSiteContext {
+                    key_name: Some("D1A7E1F94C2B".to_string()),
+                    site_description: Some("Augment_BroadBrush.Description".to_string()),
+                    is_editor_only: false,
+                    is_optional: false,
+                },
+                FTextSourceSiteContext {
+                    key_name: Some("D1A7E1F94C2B".to_string()),
+                    site_description: Some("Augment_BroadBrush.Tooltip".to_string()),
+                    is_editor_only: true,
+                    is_optional: true,
+                },
+            ],
+        },
+    ];
+    asset.asset_data.gatherable_text_data = Some(gatherable_text_data.clone());
+
+    let written = shared::write_asset(&asset)?;
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+    assert_eq!(
+        reparsed.asset_data.gatherable_text_data,
+        Some(gatherable_text_data)
+    );
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    // stripping editor-only data removes the gatherable text
+    reparsed.strip_editor_only_data();
+    let mut cursor = Cursor::new(Vec::new());
+    reparsed.write_data(&mut cursor, None)?;
+    let stripped = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    assert!(stripped.asset_data.gatherable_text_data.is_none());
+
+    Ok(())
+}
@@ -1,6 +1,19 @@
 use std::io::Cursor;
 
-use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+use unreal_asset::{
+    custom_version::FFortniteMainBranchObjectVersion,
+    engine_version::EngineVersion,
+    properties::{
+        vector_property::{BoxProperty, IntPointProperty, VectorProperty},
+        world_tile_property::{FWorldTileInfo, FWorldTileLayer},
+    },
+    types::{
+        vector::{Vector, Vector2},
+        FName,
+    },
+    unversioned::Ancestry,
+    Asset, Error,
+};
 
 #[allow(clippy::duplicate_mod)]
 #[path = "../shared.rs"]
@@ -29,3 +42,74 @@ fn versioned() -> Result<(), Error> {
     }
     Ok(())
 }
+
+fn vector_property(x: f64, y: f64, z: f64) -> VectorProperty {
+    VectorProperty {
+        name: FName::default(),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: Vector::new(x.into(), y.into(), z.into()),
+    }
+}
+
+#[test]
+fn versioned_world_tile_info() -> Result<(), Error> {
+    // this asset doesn't register FFortniteMainBranchObjectVersion,
+    // so the engine version must not imply a 3D tile offset
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSETS[0]),
+        None,
+        EngineVersion::VER_UE4_27,
+        None,
+    )?;
+    assert!(
+        asset
+            .asset_data
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            < FFortniteMainBranchObjectVersion::WorldCompositionTile3DOffset as i32
+    );
+
+    asset.asset_data.world_tile_info = Some(FWorldTileInfo {
+        position: Vector::new(16, -32, 0),
+        bounds: BoxProperty {
+            name: FName::default(),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            duplication_index: 0,
+            v1: vector_property(-1.0, -2.0, -3.0),
+            v2: vector_property(1.0, 2.0, 3.0),
+            is_valid: true,
+        },
+        layer: FWorldTileLayer {
+            name: Some(String::from("Layer")),
+            reserved_0: 0,
+            reserved_1: IntPointProperty {
+                name: FName::default(),
+                ancestry: Ancestry::default(),
+                property_guid: None,
+                duplication_index: 0,
+                value: Vector2::new(0, 0),
+            },
+            streaming_distance: Some(50000),
+            distance_streaming_enabled: Some(true),
+        },
+        hide_in_tile_view: Some(false),
+        parent_tile_package_name: Some(String::new()),
+        lod_list: Some(Vec::new()),
+        z_order: Some(0),
+    });
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let reparsed = Asset::new(cursor, None, EngineVersion::VER_UE4_27, None)?;
+    let world_tile_info = reparsed.asset_data.world_tile_info.unwrap();
+    assert_eq!(world_tile_info.position, Vector::new(16, -32, 0));
+    assert_eq!(world_tile_info.bounds.v2.value.z, 3.0);
+    assert_eq!(world_tile_info.layer.name.as_deref(), Some("Layer"));
+    assert_eq!(world_tile_info.z_order, Some(0));
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, types::GenerationInfo, Asset, Error};
+
+mod shared;
+
+#[test]
+fn generations() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    assert_eq!(asset.latest_generation(), Some(&GenerationInfo::new(3, 63)));
+    assert_eq!(asset.generation_growth(), vec![GenerationInfo::new(3, 63)]);
+
+    // older generations are written as they are, the latest one is updated to the current counts
+    asset.generations = vec![
+        GenerationInfo::new(1, 20),
+        GenerationInfo::new(2, 45),
+        GenerationInfo::new(0, 0),
+    ];
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let reparsed = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(
+        reparsed.generations,
+        vec![
+            GenerationInfo::new(1, 20),
+            GenerationInfo::new(2, 45),
+            GenerationInfo::new(3, 63),
+        ]
+    );
+    assert_eq!(
+        reparsed.latest_generation(),
+        Some(&GenerationInfo::new(3, 63))
+    );
+    assert_eq!(
+        reparsed.generation_growth(),
+        vec![
+            GenerationInfo::new(1, 20),
+            GenerationInfo::new(1, 25),
+            GenerationInfo::new(1, 18),
+        ]
+    );
+
+    Ok(())
+}
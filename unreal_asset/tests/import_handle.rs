@@ -0,0 +1,49 @@
+use unreal_asset::{reader::ArchiveTrait, types::PackageIndex, Error, Import};
+
+mod shared;
+
+#[test]
+fn import_handle() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    let import_count = asset.imports.len() as i32;
+
+    let import = Import::new(
+        asset.add_fname("/Script/CoreUObject"),
+        asset.add_fname("Class"),
+        PackageIndex::new(0),
+        asset.add_fname("HandleTestClass"),
+        false,
+    );
+    let handle = asset.add_import_handle(import);
+    assert_eq!(
+        asset.resolve_import_handle(&handle)?,
+        PackageIndex::new(-import_count - 1)
+    );
+
+    // unrelated edits don't invalidate the handle
+    asset.rebuild_name_map();
+    assert_eq!(
+        asset.resolve_import_handle(&handle)?,
+        PackageIndex::new(-import_count - 1)
+    );
+
+    // removing an import before it moves the handle's import
+    asset.imports.remove(0);
+    assert!(asset.resolve_import_handle(&handle).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn get_import_out_of_range() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+    let import_count = asset.imports.len() as i32;
+
+    assert!(asset.get_import(PackageIndex::new(-import_count)).is_some());
+    assert!(asset
+        .get_import(PackageIndex::new(-import_count - 1))
+        .is_none());
+    assert!(asset.get_import(PackageIndex::new(i32::MIN)).is_none());
+
+    Ok(())
+}
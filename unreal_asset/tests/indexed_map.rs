@@ -99,3 +99,68 @@ fn mutable_iterator() {
         index += 1;
     }
 }
+
+#[test]
+fn key_value_iterators() {
+    let mut indexed_map = IndexedMap::new();
+
+    indexed_map.insert("Test1".to_string(), 1);
+    indexed_map.insert("Test2".to_string(), 2);
+    indexed_map.insert("Test3".to_string(), 3);
+
+    assert_eq!(
+        indexed_map.keys().collect::<Vec<_>>(),
+        vec!["Test1", "Test2", "Test3"]
+    );
+
+    for value in indexed_map.values_mut() {
+        *value *= 10;
+    }
+    assert_eq!(
+        indexed_map.values().copied().collect::<Vec<_>>(),
+        vec![10, 20, 30]
+    );
+
+    let pairs = indexed_map
+        .iter_key_value()
+        .map(|(key, value)| (key.as_str(), *value))
+        .collect::<Vec<_>>();
+    assert_eq!(pairs, vec![("Test1", 10), ("Test2", 20), ("Test3", 30)]);
+    assert_eq!(
+        indexed_map.iter_key_value().next_back(),
+        Some((&"Test3".to_string(), &30))
+    );
+}
+
+#[test]
+fn retain() {
+    let mut indexed_map = IndexedMap::new();
+
+    indexed_map.insert("Test1".to_string(), 1);
+    indexed_map.insert("Test2".to_string(), 2);
+    indexed_map.insert("Test3".to_string(), 3);
+    indexed_map.insert("Test4".to_string(), 4);
+
+    indexed_map.retain(|key, value| {
+        *value += 1;
+        key != "Test2"
+    });
+
+    assert_eq!(indexed_map.len(), 3);
+    assert!(!indexed_map.contains_key(&"Test2".to_string()));
+    assert_eq!(indexed_map.get_by_key("Test3"), Some(&4));
+    assert_eq!(indexed_map.get_by_index(1), Some(&4));
+    assert_eq!(
+        indexed_map.iter_key_value().collect::<Vec<_>>(),
+        vec![
+            (&"Test1".to_string(), &2),
+            (&"Test3".to_string(), &4),
+            (&"Test4".to_string(), &5)
+        ]
+    );
+
+    // entries can still be removed after retaining
+    indexed_map.remove_by_key("Test1");
+    indexed_map.retain(|_, value| *value > 4);
+    assert_eq!(indexed_map.keys().collect::<Vec<_>>(), vec!["Test4"]);
+}
@@ -0,0 +1,62 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast, engine_version::EngineVersion, flags::EPackageFlags, Asset, Error, Export,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const LEVEL_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Staging_T2.umap"));
+const OTHER_ASSET: &[u8] = shared::BROAD_BRUSH;
+
+#[test]
+fn is_level() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(LEVEL_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(asset
+        .asset_data
+        .package_flags
+        .contains(EPackageFlags::PKG_CONTAINS_MAP));
+    assert!(asset.is_level());
+
+    // the level export is enough without the package flag
+    asset
+        .asset_data
+        .package_flags
+        .remove(EPackageFlags::PKG_CONTAINS_MAP);
+    assert!(asset.is_level());
+
+    // the package flag is enough without the level export
+    asset
+        .asset_data
+        .package_flags
+        .insert(EPackageFlags::PKG_CONTAINS_MAP);
+    asset
+        .asset_data
+        .exports
+        .retain(|e| cast!(Export, LevelExport, e).is_none());
+    assert!(asset.is_level());
+
+    let asset = Asset::new(
+        Cursor::new(OTHER_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(!asset.is_level());
+
+    Ok(())
+}
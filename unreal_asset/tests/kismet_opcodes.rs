@@ -0,0 +1,39 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    error::KismetError,
+    kismet::{supported_opcodes, EExprToken},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::RawReader,
+    Error, KismetExpression,
+};
+
+fn read_expression(data: &[u8]) -> Result<KismetExpression, Error> {
+    let mut reader = RawReader::new(
+        Chain::new(Cursor::new(data), None),
+        ObjectVersion::VER_UE4_AUTOMATIC_VERSION,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        NameMap::new(),
+    );
+    KismetExpression::new(&mut reader)
+}
+
+#[test]
+fn kismet_opcodes() -> Result<(), Error> {
+    assert!(supported_opcodes().contains(&EExprToken::ExNothing));
+    assert!(!supported_opcodes().contains(&EExprToken::ExMax));
+
+    read_expression(&[EExprToken::ExNothing.into()])?;
+
+    // 0x03 is not a valid token, 0xff is a valid token without an expression
+    for opcode in [0x03u8, EExprToken::ExMax.into()] {
+        match read_expression(&[opcode]) {
+            Err(Error::Kismet(KismetError::UnsupportedOpcode(e))) => assert_eq!(e, opcode),
+            e => panic!("Expected unsupported opcode error, got {e:?}"),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions, cast, engine_version::EngineVersion, types::PackageIndex, Asset, Error,
+    Export,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/BloodStained/m01SIP_000_Gimmick.umap"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(test_asset!());
+
+#[test]
+fn lazy_exports() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let mut lazy = Asset::new_lazy(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+
+    assert_eq!(lazy.imports, asset.imports);
+    assert_eq!(
+        lazy.asset_data.exports.len(),
+        asset.asset_data.exports.len()
+    );
+    assert!(lazy
+        .asset_data
+        .exports
+        .iter()
+        .all(|e| cast!(Export, BaseExport, e).is_some()));
+
+    // exports can be parsed in any order
+    for i in (1..=asset.asset_data.exports.len()).rev().step_by(3) {
+        let index = PackageIndex::new(i as i32);
+        assert_eq!(lazy.parse_export(index)?, &asset.asset_data.exports[i - 1]);
+        assert_eq!(lazy.parse_export(index)?, &asset.asset_data.exports[i - 1]);
+    }
+    assert!(cast!(Export, BaseExport, &lazy.asset_data.exports[1]).is_some());
+
+    assert!(lazy.parse_export(PackageIndex::new(0)).is_err());
+    assert!(lazy.parse_export(PackageIndex::new(-1)).is_err());
+    assert!(lazy
+        .parse_export(PackageIndex::new(asset.asset_data.exports.len() as i32 + 1))
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn lazy_exports_with_options() -> Result<(), Error> {
+    let options = |max_exports| AssetOptions {
+        max_exports: Some(max_exports),
+        ..Default::default()
+    };
+    let mut lazy = Asset::new_lazy_with_options(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+        options(usize::MAX),
+    )?;
+    lazy.parse_export(PackageIndex::new(1))?;
+
+    // the options are applied when parsing the header
+    assert!(Asset::new_lazy_with_options(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+        options(lazy.asset_data.exports.len() - 1),
+    )
+    .is_err());
+
+    Ok(())
+}
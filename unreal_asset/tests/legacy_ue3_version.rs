@@ -0,0 +1,23 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+#[test]
+fn legacy_ue3_version() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    // this asset doesn't use the usual 864
+    assert_eq!(asset.asset_data.legacy_ue3_version, Some(0));
+    assert_eq!(&shared::BROAD_BRUSH[8..12], &0i32.to_le_bytes());
+
+    asset.asset_data.legacy_ue3_version = Some(700);
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let asset = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    assert_eq!(asset.asset_data.legacy_ue3_version, Some(700));
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, flags::EPackageFlags, Asset, Error, Guid};
+
+mod shared;
+
+#[test]
+fn localization_id() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    // cooked packages don't store a localization id
+    assert_eq!(asset.asset_data.localization_id, None);
+    assert_eq!(asset.asset_data.get_localization_id(), None);
+
+    let guid = Guid::from_ints(0x3B5C7D21, 0x4E8A9F10, 0x00C0FFEE, 0x12345678);
+    asset
+        .asset_data
+        .package_flags
+        .remove(EPackageFlags::PKG_FILTER_EDITOR_ONLY);
+    asset.asset_data.set_localization_id(guid);
+    assert_eq!(
+        asset.asset_data.localization_id.as_deref(),
+        Some("3B5C7D214E8A9F1000C0FFEE12345678")
+    );
+    let written = shared::write_asset(&asset)?;
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+    assert_eq!(asset.asset_data.get_localization_id(), Some(guid));
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    // ids that aren't guids are kept as they are
+    asset.asset_data.localization_id = Some("+B5C7D214E8A9F1000C0FFEE12345678".to_string());
+    assert_eq!(asset.asset_data.get_localization_id(), None);
+    asset.asset_data.localization_id = Some("NotAGuid".to_string());
+    assert_eq!(asset.asset_data.get_localization_id(), None);
+    let written = shared::write_asset(&asset)?;
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(
+        asset.asset_data.localization_id.as_deref(),
+        Some("NotAGuid")
+    );
+
+    asset.strip_editor_only_data();
+    assert_eq!(asset.asset_data.localization_id, None);
+    assert!(shared::write_asset(&asset)? == shared::BROAD_BRUSH);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use unreal_asset::{
+    exports::{ExportBaseTrait, ExportNormalTrait},
+    properties::material_input_property::MaterialExpression,
+    types::{FName, PackageIndex},
+    Error, Import,
+};
+
+mod shared;
+
+fn material_input(expression_name: FName) -> MaterialExpression {
+    MaterialExpression {
+        name: FName::from_slice("BaseColor"),
+        extras: vec![0; 20],
+        output_index: 0,
+        input_name: FName::from_slice("None"),
+        expression_name,
+    }
+}
+
+#[test]
+fn resolve_material_input() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let expression_index = asset.asset_data.exports.len() - 1;
+    let expression_name = asset.asset_data.exports[expression_index]
+        .get_base_export()
+        .object_name
+        .clone();
+
+    // the export isn't a material expression yet
+    assert!(asset
+        .resolve_material_input(&material_input(expression_name.clone()))
+        .is_none());
+
+    let class_package = asset.add_fname("/Script/Engine");
+    let class_name = asset.add_fname("Class");
+    let object_name = asset.add_fname("MaterialExpressionAdd");
+    let class_index = asset.add_import(Import::new(
+        class_package,
+        class_name,
+        PackageIndex::new(0),
+        object_name,
+        false,
+    ));
+    asset.asset_data.exports[expression_index]
+        .get_base_export_mut()
+        .class_index = class_index;
+
+    let expression = asset
+        .resolve_material_input(&material_input(expression_name))
+        .unwrap();
+    assert_eq!(expression.get_base_export().class_index, class_index);
+    assert_eq!(
+        expression.get_normal_export(),
+        asset.asset_data.exports[expression_index].get_normal_export()
+    );
+
+    // unconnected inputs
+    let none = asset.add_fname("None");
+    assert!(asset
+        .resolve_material_input(&material_input(none))
+        .is_none());
+    let missing = asset.add_fname("MaterialExpressionAdd_1");
+    assert!(asset
+        .resolve_material_input(&material_input(missing))
+        .is_none());
+
+    Ok(())
+}
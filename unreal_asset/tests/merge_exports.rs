@@ -0,0 +1,398 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{raw_export::RawExport, ExportBaseTrait, ExportNormalTrait, FunctionExport},
+    properties::{
+        movies::{
+            movie_scene_eval_template_ptr_property::MovieSceneEvalTemplatePtrProperty,
+            movie_scene_sequence_instance_data_ptr_property::MovieSceneSequenceInstanceDataPtrProperty,
+        },
+        object_property::ObjectProperty,
+        Ancestry, Property,
+    },
+    reader::ArchiveTrait,
+    types::{PackageIndex, PackageIndexContainer},
+    Asset, Error, Export,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    ($name:literal) => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/",
+            $name,
+            ".uasset"
+        )
+    };
+}
+
+const HOST_ASSET: &[u8] = shared::BROAD_BRUSH;
+const DONOR_ASSET: &[u8] = include_bytes!(test_asset!("LargeResourceCanister_IT"));
+const CLASS_ASSET: &[u8] = include_bytes!(test_asset!("DebugMenu"));
+
+fn index_name<C: std::io::Read + std::io::Seek>(asset: &Asset<C>, index: PackageIndex) -> String {
+    match index.is_import() {
+        true => asset
+            .get_import(index)
+            .unwrap()
+            .object_name
+            .get_owned_content(),
+        false => asset
+            .get_export(index)
+            .unwrap()
+            .get_base_export()
+            .object_name
+            .get_owned_content(),
+    }
+}
+
+#[test]
+fn merge_exports() -> Result<(), Error> {
+    let mut host = Asset::new(
+        Cursor::new(HOST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let donor = Asset::new(
+        Cursor::new(DONOR_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let export_count = host.asset_data.exports.len();
+    let import_count = host.imports.len();
+    let name_count = host.get_name_map().get_ref().len();
+
+    // the outer of the item catalog data is the class default object, which isn't merged
+    assert!(host
+        .merge_exports_from(&donor, &[PackageIndex::new(3)])
+        .is_err());
+    assert!(host
+        .merge_exports_from(&donor, &[PackageIndex::new(100)])
+        .is_err());
+    assert_eq!(host.asset_data.exports.len(), export_count);
+    assert_eq!(host.imports.len(), import_count);
+    assert_eq!(host.get_name_map().get_ref().len(), name_count);
+
+    let donor_indices = [
+        PackageIndex::new(1),
+        PackageIndex::new(2),
+        PackageIndex::new(3),
+    ];
+    let merged = host.merge_exports_from(&donor, &donor_indices)?;
+    assert_eq!(
+        merged,
+        (1..=3)
+            .map(|i| PackageIndex::new((export_count + i) as i32))
+            .collect::<Vec<_>>()
+    );
+
+    for (donor_index, host_index) in donor_indices.iter().zip(&merged) {
+        let donor_export = donor.get_export(*donor_index).unwrap().get_base_export();
+        let host_export = host.get_export(*host_index).unwrap().get_base_export();
+        assert_eq!(
+            index_name(&donor, *donor_index),
+            index_name(&host, *host_index)
+        );
+        assert_eq!(
+            index_name(&donor, donor_export.class_index),
+            index_name(&host, host_export.class_index)
+        );
+    }
+    // the class default object's class is the merged class
+    assert_eq!(
+        host.get_export(merged[1])
+            .unwrap()
+            .get_base_export()
+            .class_index,
+        merged[0]
+    );
+    assert_eq!(
+        host.get_export(merged[2])
+            .unwrap()
+            .get_base_export()
+            .outer_index,
+        merged[1]
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    host.write_data(&mut cursor, None)?;
+    let written = cursor.into_inner();
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+    assert_eq!(reparsed.asset_data.exports.len(), export_count + 3);
+    assert_eq!(
+        reparsed
+            .get_export(merged[1])
+            .and_then(|e| e.get_normal_export())
+            .map(|e| e.properties.len()),
+        donor
+            .get_export(donor_indices[1])
+            .and_then(|e| e.get_normal_export())
+            .map(|e| e.properties.len())
+    );
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    // merging again reuses the imports copied the first time
+    let import_count = host.imports.len();
+    host.merge_exports_from(&donor, &donor_indices)?;
+    assert_eq!(host.imports.len(), import_count);
+
+    Ok(())
+}
+
+#[test]
+fn merge_nested_property_references() -> Result<(), Error> {
+    let mut host = shared::read_broad_brush()?;
+    let mut donor = Asset::new(
+        Cursor::new(DONOR_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // references held by movie scene properties and by properties nested in them
+    let import = PackageIndex::new(-1);
+    let export = PackageIndex::new(1);
+    let properties: Vec<Property> = vec![
+        MovieSceneSequenceInstanceDataPtrProperty {
+            name: donor.add_fname("InstanceData"),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: import,
+        }
+        .into(),
+        MovieSceneEvalTemplatePtrProperty {
+            name: donor.add_fname("Template"),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: vec![ObjectProperty {
+                name: donor.add_fname("Object"),
+                ancestry: Ancestry::default(),
+                property_guid: None,
+                duplication_index: 0,
+                value: export,
+            }
+            .into()],
+        }
+        .into(),
+    ];
+    donor
+        .get_export_mut(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export_mut())
+        .unwrap()
+        .properties
+        .extend(properties);
+
+    let donor_indices = [
+        PackageIndex::new(1),
+        PackageIndex::new(2),
+        PackageIndex::new(3),
+    ];
+    let merged = host.merge_exports_from(&donor, &donor_indices)?;
+
+    let mut references = Vec::new();
+    for property in host
+        .get_export(merged[1])
+        .and_then(|e| e.get_normal_export())
+        .unwrap()
+        .properties
+        .iter()
+        .rev()
+        .take(2)
+    {
+        property
+            .clone()
+            .traverse_package_indices(&mut |e| references.push(*e));
+    }
+    assert_eq!(references.len(), 2);
+    assert_eq!(references[0], merged[0]);
+    assert_eq!(index_name(&host, references[1]), index_name(&donor, import));
+
+    Ok(())
+}
+
+#[test]
+fn import_export() -> Result<(), Error> {
+    let mut host = Asset::new(
+        Cursor::new(HOST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let donor = Asset::new(
+        Cursor::new(DONOR_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let export_count = host.asset_data.exports.len();
+    let import_count = host.imports.len();
+    assert!(host.import_export(&donor, PackageIndex::new(100)).is_err());
+    assert_eq!(host.asset_data.exports.len(), export_count);
+
+    // references of raw exports can't be remapped
+    let mut raw_donor = Asset::new(
+        Cursor::new(DONOR_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    raw_donor.asset_data.exports[0] = RawExport {
+        base_export: raw_donor.asset_data.exports[0].get_base_export().clone(),
+        data: Vec::new(),
+    }
+    .into();
+    assert!(host
+        .import_export(&raw_donor, PackageIndex::new(3))
+        .is_err());
+    assert_eq!(host.asset_data.exports.len(), export_count);
+    assert_eq!(host.imports.len(), import_count);
+
+    // the item catalog data references its outer, the class default object,
+    // which references the class it's an instance of
+    let donor_index = PackageIndex::new(3);
+    let index = host.import_export(&donor, donor_index)?;
+    assert_eq!(index, PackageIndex::new(export_count as i32 + 1));
+    assert_eq!(host.asset_data.exports.len(), export_count + 3);
+    assert_eq!(index_name(&donor, donor_index), index_name(&host, index));
+
+    let export = host.get_export(index).unwrap().get_base_export();
+    let outer = host
+        .get_export(export.outer_index)
+        .unwrap()
+        .get_base_export();
+    assert_eq!(
+        index_name(&donor, PackageIndex::new(2)),
+        index_name(&host, export.outer_index)
+    );
+    assert_eq!(
+        index_name(&donor, PackageIndex::new(1)),
+        index_name(&host, outer.class_index)
+    );
+    assert!(outer.class_index.is_export());
+
+    let mut cursor = Cursor::new(Vec::new());
+    host.write_data(&mut cursor, None)?;
+    let written = cursor.into_inner();
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    Ok(())
+}
+
+/// Get the names of the exports and imports referenced by a function's kismet bytecode
+fn bytecode_references<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+    function: &FunctionExport,
+) -> Vec<String> {
+    let mut references = Vec::new();
+    function
+        .struct_export
+        .script_bytecode
+        .clone()
+        .traverse_package_indices(&mut |e| references.push(*e));
+    references
+        .into_iter()
+        .filter(|e| e.index != 0)
+        .map(|e| index_name(asset, e))
+        .collect()
+}
+
+#[test]
+fn merge_class_exports() -> Result<(), Error> {
+    let mut host = Asset::new(
+        Cursor::new(HOST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let donor = Asset::new(
+        Cursor::new(CLASS_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // the uber graph frame property is a child of the class, which isn't merged
+    let import_count = host.imports.len();
+    let name_count = host.get_name_map().get_ref().len();
+    assert!(host
+        .merge_exports_from(&donor, &[PackageIndex::new(1)])
+        .is_err());
+    assert_eq!(host.imports.len(), import_count);
+    assert_eq!(host.get_name_map().get_ref().len(), name_count);
+
+    let donor_index = PackageIndex::new(2);
+    let index = host.import_export(&donor, donor_index)?;
+    let donor_class = cast!(Export, ClassExport, donor.get_export(donor_index).unwrap()).unwrap();
+    let class = cast!(Export, ClassExport, host.get_export(index).unwrap()).unwrap();
+
+    // children and functions point to the copies of the donor's exports
+    assert!(class.struct_export.children.iter().all(|e| e.is_export()));
+    let names = |asset: &Asset<_>, indices: &[PackageIndex]| {
+        indices
+            .iter()
+            .map(|e| index_name(asset, *e))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(&donor, &donor_class.struct_export.children),
+        names(&host, &class.struct_export.children)
+    );
+    let donor_functions = donor_class.func_map.values().copied().collect::<Vec<_>>();
+    let functions = class.func_map.values().copied().collect::<Vec<_>>();
+    assert_eq!(names(&donor, &donor_functions), names(&host, &functions));
+
+    // so do the references in the kismet bytecode of the functions
+    for (donor_function, function) in donor_functions.iter().zip(&functions) {
+        let donor_function = cast!(
+            Export,
+            FunctionExport,
+            donor.get_export(*donor_function).unwrap()
+        )
+        .unwrap();
+        let function = cast!(Export, FunctionExport, host.get_export(*function).unwrap()).unwrap();
+        assert_eq!(
+            bytecode_references(&donor, donor_function),
+            bytecode_references(&host, function)
+        );
+    }
+
+    let mut cursor = Cursor::new(Vec::new());
+    host.write_data(&mut cursor, None)?;
+    let written = cursor.into_inner();
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    Ok(())
+}
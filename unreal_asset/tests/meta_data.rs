@@ -0,0 +1,84 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{
+        meta_data_export::{MetaDataExport, ObjectMetaData},
+        BaseExport, NormalExport,
+    },
+    types::PackageIndex,
+    Asset, Error, Export, Import,
+};
+
+mod shared;
+
+#[test]
+fn meta_data() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert_eq!(asset.meta_data(), None);
+
+    // cooked assets don't have meta data, add a meta data export to the asset
+    let core_uobject = Import::new(
+        asset.add_fname("/Script/CoreUObject"),
+        asset.add_fname("Package"),
+        PackageIndex::new(0),
+        asset.add_fname("/Script/CoreUObject"),
+        false,
+    );
+    let core_uobject = asset.add_import(core_uobject);
+    let meta_data_class = Import::new(
+        asset.add_fname("/Script/CoreUObject"),
+        asset.add_fname("Class"),
+        core_uobject,
+        asset.add_fname("MetaData"),
+        false,
+    );
+    let class_index = asset.add_import(meta_data_class);
+
+    let tooltip = (
+        asset.add_fname("ToolTip"),
+        Some(String::from("Sprays paint")),
+    );
+    let category = (asset.add_fname("Category"), Some(String::from("Augments")));
+    let object_name = asset.add_fname("PackageMetaData");
+    asset.asset_data.exports.push(
+        MetaDataExport {
+            normal_export: NormalExport {
+                base_export: BaseExport {
+                    class_index,
+                    object_name,
+                    ..Default::default()
+                },
+                extras: Vec::new(),
+                properties: Vec::new(),
+            },
+            object_meta_data: vec![ObjectMetaData {
+                object: PackageIndex::new(1),
+                values: vec![tooltip, category],
+            }],
+            root_meta_data: Some(Vec::new()),
+        }
+        .into(),
+    );
+    asset.ensure_depends_map();
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let reparsed = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+
+    let meta_data_export = reparsed
+        .asset_data
+        .exports
+        .last()
+        .and_then(|e| cast!(Export, MetaDataExport, e));
+    assert!(meta_data_export.is_some());
+
+    let meta_data = reparsed.meta_data().unwrap();
+    assert_eq!(meta_data.len(), 1);
+    let values = &meta_data[&PackageIndex::new(1)];
+    assert_eq!(values["ToolTip"], "Sprays paint");
+    assert_eq!(values["Category"], "Augments");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use unreal_asset::{
+    properties::movies::movie_scene_evaluation::{
+        EvaluationTreeEntryHandle, FEntityAndMetaDataIndex, FEntry,
+        MovieSceneEvaluationFieldEntityTree, MovieSceneEvaluationTreeNode,
+        MovieSceneEvaluationTreeNodeHandle, TEvaluationTreeEntryContainer,
+        TMovieSceneEvaluationTree,
+    },
+    types::movie::{ERangeBoundTypes, FFrameNumberRange, FFrameNumberRangeBound, FrameNumber},
+};
+
+fn handle(entry_index: i32) -> EvaluationTreeEntryHandle {
+    EvaluationTreeEntryHandle { entry_index }
+}
+
+fn node(lower: i32, upper: i32, children_id: i32, data_id: i32) -> MovieSceneEvaluationTreeNode {
+    let bound = |value| FFrameNumberRangeBound {
+        ty: ERangeBoundTypes::Inclusive,
+        value: FrameNumber::new(value),
+    };
+    MovieSceneEvaluationTreeNode {
+        range: FFrameNumberRange {
+            lower_bound: bound(lower),
+            upper_bound: bound(upper),
+        },
+        parent: MovieSceneEvaluationTreeNodeHandle {
+            children_handle: handle(-1),
+            index: -1,
+        },
+        children_id: handle(children_id),
+        data_id: handle(data_id),
+    }
+}
+
+fn entry(start_index: i32, size: i32) -> FEntry {
+    FEntry {
+        start_index,
+        size,
+        capacity: size,
+    }
+}
+
+fn entity(entity_index: i32) -> FEntityAndMetaDataIndex {
+    FEntityAndMetaDataIndex {
+        entity_index,
+        meta_data_index: -1,
+    }
+}
+
+#[test]
+fn movie_scene_entity_tree() {
+    // root [0, 100] with children [0, 50] and [50, 100]
+    let tree = MovieSceneEvaluationFieldEntityTree {
+        serialized_data: TMovieSceneEvaluationTree::new(
+            node(0, 100, 0, -1),
+            TEvaluationTreeEntryContainer::new(
+                vec![entry(0, 2)],
+                vec![node(0, 50, -1, 0), node(50, 100, -1, 1)],
+            ),
+            TEvaluationTreeEntryContainer::new(
+                vec![entry(0, 1), entry(1, 2)],
+                vec![entity(0), entity(1), entity(2)],
+            ),
+        ),
+    };
+
+    let entities = tree.get_entities();
+    assert_eq!(entities.len(), 2);
+
+    assert_eq!(entities[0].0.lower_bound.value, FrameNumber::new(0));
+    assert_eq!(entities[0].1, &[entity(0)]);
+
+    assert_eq!(entities[1].0.lower_bound.value, FrameNumber::new(50));
+    assert_eq!(entities[1].1, &[entity(1), entity(2)]);
+}
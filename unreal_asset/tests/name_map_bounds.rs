@@ -0,0 +1,61 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, error::ErrorKind, Asset, Error};
+
+mod shared;
+
+#[test]
+fn name_map_bounds() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+
+    let name_count = asset.get_name_map().get_ref().len() as i32;
+    assert_eq!(
+        asset.try_get_name_reference(0),
+        Some(String::from("/Game/Components_Small/Augment_BroadBrush"))
+    );
+    assert_eq!(
+        asset.try_get_name_reference(name_count - 1).as_deref(),
+        asset
+            .get_name_map()
+            .get_ref()
+            .try_get_name_reference(name_count - 1)
+    );
+    for index in [-1, name_count, i32::MAX, i32::MIN] {
+        assert_eq!(asset.try_get_name_reference(index), None);
+        assert_eq!(
+            asset.get_name_map().get_ref().try_get_name_reference(index),
+            None
+        );
+    }
+
+    // the import table follows the name map, which ends with `Zinc_C` and its hash
+    let import_offset = shared::BROAD_BRUSH
+        .windows(7)
+        .position(|e| e == b"Zinc_C\0")
+        .expect("Failed to find the last name")
+        + 7
+        + 4;
+
+    // point the class package of the first import past the end of the name map
+    for index in [name_count, i32::MAX, -1] {
+        let mut data = shared::BROAD_BRUSH.to_vec();
+        data[import_offset..import_offset + 4].copy_from_slice(&index.to_le_bytes());
+
+        let error =
+            Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None).unwrap_err();
+        assert!(matches!(error, Error::FName(_)));
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    // a truncated name map is an error as well
+    let truncated = shared::BROAD_BRUSH[..import_offset - 8].to_vec();
+    assert!(Asset::new(
+        Cursor::new(truncated),
+        None,
+        EngineVersion::VER_UE4_23,
+        None
+    )
+    .is_err());
+
+    Ok(())
+}
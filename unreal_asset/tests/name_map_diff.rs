@@ -0,0 +1,32 @@
+use unreal_asset::Error;
+
+mod shared;
+
+#[test]
+fn name_map_diff() -> Result<(), Error> {
+    let original = shared::read_broad_brush()?;
+    let mut edited = shared::read_broad_brush()?;
+
+    let original_name_map = original.get_name_map();
+    let edited_name_map = edited.get_name_map();
+    let (only_in_original, only_in_edited) =
+        original_name_map.get_ref().diff(&edited_name_map.get_ref());
+    assert!(only_in_original.is_empty());
+    assert!(only_in_edited.is_empty());
+
+    edited.add_fname("NewName_0");
+    edited.add_fname("NewName_1");
+    edited.add_fname("None");
+
+    let (only_in_original, only_in_edited) =
+        original_name_map.get_ref().diff(&edited_name_map.get_ref());
+    assert!(only_in_original.is_empty());
+    assert_eq!(only_in_edited, vec!["NewName_0", "NewName_1"]);
+
+    let (only_in_edited, only_in_original) =
+        edited_name_map.get_ref().diff(&original_name_map.get_ref());
+    assert!(only_in_original.is_empty());
+    assert_eq!(only_in_edited, vec!["NewName_0", "NewName_1"]);
+
+    Ok(())
+}
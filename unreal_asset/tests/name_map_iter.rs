@@ -0,0 +1,29 @@
+use unreal_asset::Error;
+
+mod shared;
+
+#[test]
+fn name_map_iter() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+
+    let name_count = asset.get_name_map().get_ref().len();
+    let names = asset.name_map_iter(|iter| {
+        iter.map(|(index, name)| (index, name.to_string()))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(names.len(), name_count);
+    for (expected_index, (index, name)) in names.iter().enumerate() {
+        assert_eq!(*index, expected_index as i32);
+        assert_eq!(
+            asset.get_name_reference(*index, |e| e.to_string()),
+            name.as_str()
+        );
+    }
+
+    assert_eq!(
+        asset.search_name_reference("None"),
+        asset.name_map_iter(|mut iter| iter.find(|(_, name)| *name == "None").map(|(i, _)| i))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use std::io::Cursor;
+
+use unreal_asset::{containers::NameMap, engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+/// Read the name count from the header of a 4.23 asset
+fn header_name_count(data: &[u8]) -> i32 {
+    let read_i32 = |offset: usize| i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+
+    // tag, legacy file version, legacy ue3 version, file version and licensee version
+    let custom_version_count = read_i32(20);
+    // custom versions and the header offset
+    let folder_name_offset = 24 + custom_version_count as usize * 20 + 4;
+    let folder_name_len = read_i32(folder_name_offset);
+    // folder name and package flags
+    read_i32(folder_name_offset + 4 + folder_name_len as usize + 4)
+}
+
+#[test]
+fn serialized_len() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert_eq!(
+        header_name_count(shared::BROAD_BRUSH) as usize,
+        asset.get_name_map().get_ref().len()
+    );
+
+    for i in 0..2 {
+        let name_map = asset.get_name_map();
+        let len = name_map.get_ref().serialized_len()?;
+        assert_eq!(len as usize, name_map.get_ref().len());
+
+        // the header and the latest generation match the names that were written
+        let data = shared::write_asset(&asset)?;
+        assert_eq!(header_name_count(&data), len);
+
+        let reparsed = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)?;
+        assert_eq!(
+            reparsed.get_name_map().get_ref().get_name_map_index_list(),
+            name_map.get_ref().get_name_map_index_list()
+        );
+        if let Some(generation) = reparsed.latest_generation() {
+            assert_eq!(generation.name_count, len);
+        }
+
+        asset.add_fname(&format!("AddedName{i}"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unexpectedly_large() {
+    let mut name_map = NameMap::new();
+    for i in 0..NameMap::LARGE_LEN {
+        name_map.get_mut().add_fname(&i.to_string());
+    }
+    assert!(!name_map.get_ref().is_unexpectedly_large());
+
+    name_map.get_mut().add_fname("OneTooMany");
+    assert!(name_map.get_ref().is_unexpectedly_large());
+}
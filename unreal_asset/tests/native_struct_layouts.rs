@@ -0,0 +1,125 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions,
+    cast,
+    containers::IndexedMap,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        int_property::IntProperty, str_property::NameProperty, struct_property::StructProperty,
+        Property, PropertyDataTrait,
+    },
+    types::native_struct_layout::{NativeStructField, NativeStructLayout},
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+mod shared;
+
+fn item_data_layouts() -> IndexedMap<String, NativeStructLayout> {
+    IndexedMap::from([(
+        "ItemData".to_string(),
+        NativeStructLayout::new(vec![
+            NativeStructField::new("Count", "IntProperty"),
+            NativeStructField::new("Id", "NameProperty"),
+        ]),
+    )])
+}
+
+fn read_item_data<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Option<&StructProperty> {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| e.get_normal_export())
+        .and_then(|e| {
+            e.properties
+                .iter()
+                .find(|e| e.get_name().get_owned_content() == "Item")
+        })
+        .and_then(|e| cast!(Property, StructProperty, e))
+}
+
+#[test]
+fn native_struct_layouts() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    asset.asset_data.native_struct_layouts = item_data_layouts();
+    asset.add_fname("StructProperty");
+
+    let property = StructProperty {
+        name: asset.add_fname("Item"),
+        ancestry: Ancestry::default(),
+        struct_type: Some(asset.add_fname("ItemData")),
+        struct_guid: Some([0u8; 16].into()),
+        property_guid: None,
+        duplication_index: 0,
+        serialize_none: true,
+        value: vec![
+            IntProperty {
+                name: asset.add_fname("Count"),
+                ancestry: Ancestry::default(),
+                property_guid: None,
+                duplication_index: 0,
+                value: 42,
+            }
+            .into(),
+            NameProperty {
+                name: asset.add_fname("Id"),
+                ancestry: Ancestry::default(),
+                property_guid: None,
+                duplication_index: 0,
+                value: asset.add_fname("Pickaxe"),
+            }
+            .into(),
+        ],
+    };
+
+    let export = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .find_map(|e| e.get_normal_export_mut())
+        .expect("No normal export");
+    export.properties.push(property.into());
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let written = cursor.get_ref().clone();
+
+    // without the layout the fields are expected to be tagged properties
+    let asset = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(!asset.warnings.is_empty());
+
+    let mut asset = Asset::new_with_options(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        AssetOptions {
+            native_struct_layouts: item_data_layouts(),
+            ..Default::default()
+        },
+    )?;
+    assert!(asset.warnings.is_empty());
+
+    let read = read_item_data(&asset).expect("Struct property was not read back");
+    assert_eq!(read.value.len(), 2);
+    assert_eq!(
+        cast!(Property, IntProperty, &read.value[0]).map(|e| e.value),
+        Some(42)
+    );
+    assert_eq!(
+        cast!(Property, NameProperty, &read.value[1]).map(|e| e.value.get_owned_content()),
+        Some("Pickaxe".to_string())
+    );
+
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    Ok(())
+}
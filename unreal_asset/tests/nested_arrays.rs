@@ -0,0 +1,97 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{array_property::ArrayProperty, Property, PropertyDataTrait},
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+#[test]
+fn array_of_structs_with_arrays() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(shared::BROAD_BRUSH.to_vec()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // `ConstructionRecipe` is a struct containing the `Ingredients` array of structs,
+    // wrap it into an array to get an array of structs that each contain an array
+    let properties = &asset.asset_data.exports[1]
+        .get_normal_export()
+        .unwrap()
+        .properties;
+    let recipe = properties
+        .iter()
+        .find_map(|e| cast!(Property, StructProperty, e).filter(|e| e.name == "ConstructionRecipe"))
+        .unwrap()
+        .clone();
+
+    let recipes_name = asset.add_fname("Recipes");
+    let struct_property_name = asset.add_fname("StructProperty");
+    let elements = (0..3)
+        .map(|_| {
+            let mut element = recipe.clone();
+            element.name = recipes_name.clone();
+            element.struct_guid = Some(Guid([1; 16]));
+            element.into()
+        })
+        .collect();
+    let recipes = ArrayProperty::from_arr(
+        recipes_name,
+        recipe.ancestry.clone(),
+        Some(struct_property_name),
+        elements,
+    );
+    asset.asset_data.exports[1]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties
+        .push(recipes.into());
+
+    let written = shared::write_asset(&asset)?;
+    let reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let properties = &reparsed.asset_data.exports[1]
+        .get_normal_export()
+        .unwrap()
+        .properties;
+    let recipes = properties
+        .iter()
+        .find_map(|e| cast!(Property, ArrayProperty, e).filter(|e| e.name == "Recipes"))
+        .unwrap();
+    assert_eq!(recipes.value.len(), 3);
+
+    let ingredients = |e: &Property| {
+        let element = cast!(Property, StructProperty, e).unwrap();
+        assert_eq!(element.struct_guid, Some(Guid([1; 16])));
+        element
+            .value
+            .iter()
+            .find_map(|e| cast!(Property, ArrayProperty, e))
+            .map(|e| e.value.len())
+    };
+    let expected = recipe
+        .value
+        .iter()
+        .find(|e| e.get_name() == "Ingredients")
+        .and_then(|e| cast!(Property, ArrayProperty, e))
+        .map(|e| e.value.len());
+    for element in &recipes.value {
+        assert_eq!(ingredients(element), expected);
+    }
+
+    // the nested lengths must survive another round trip unchanged
+    assert_eq!(shared::write_asset(&reparsed)?, written);
+
+    Ok(())
+}
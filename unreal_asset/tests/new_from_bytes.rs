@@ -0,0 +1,25 @@
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+#[test]
+fn new_from_bytes() -> Result<(), Error> {
+    let mut asset = Asset::new_from_bytes(TEST_ASSET, Some(TEST_BULK), EngineVersion::VER_UE4_25)?;
+
+    assert!(asset.asset_data.use_event_driven_loader);
+    shared::verify_binary_equality(TEST_ASSET, Some(TEST_BULK), &mut asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions,
+    engine_version::{get_object_versions, EngineVersion},
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn new_with_versions() -> Result<(), Error> {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_23);
+    let custom_versions = EngineVersion::VER_UE4_23.default_custom_versions();
+
+    let mut asset = Asset::new_with_versions(
+        Cursor::new(shared::BROAD_BRUSH),
+        None,
+        object_version,
+        object_version_ue5,
+        custom_versions.clone(),
+        None,
+    )?;
+
+    assert_eq!(asset.asset_data.object_version, object_version);
+    assert_eq!(asset.asset_data.custom_versions, custom_versions);
+    assert!(shared::verify_all_exports_parsed(&asset));
+    shared::verify_binary_equality(shared::BROAD_BRUSH, None, &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn new_with_versions_and_options() -> Result<(), Error> {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_23);
+
+    let asset = Asset::new_with_versions_and_options(
+        Cursor::new(shared::BROAD_BRUSH),
+        None,
+        object_version,
+        object_version_ue5,
+        EngineVersion::VER_UE4_23.default_custom_versions(),
+        None,
+        AssetOptions {
+            track_property_offsets: true,
+            ..Default::default()
+        },
+    )?;
+    assert!(!asset.property_offsets.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::ResolvedReference, engine_version::EngineVersion, exports::ExportBaseTrait,
+    types::PackageIndex, Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+#[test]
+fn object_paths() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert_eq!(
+        asset.index_to_path(PackageIndex::new(-10)).as_deref(),
+        Some("/Script/Engine.Actor")
+    );
+    assert_eq!(
+        asset.index_to_path(PackageIndex::new(-47)).as_deref(),
+        Some("/Script/FSD.Default__DetPack:Explosion")
+    );
+    // only the first subobject below an object of the package is separated by ':'
+    assert_eq!(
+        asset.index_to_path(PackageIndex::new(21)).as_deref(),
+        Some("BP_DetPack_Charge_C:SimpleConstructionScript_0.SCS_Node_0")
+    );
+    assert_eq!(
+        asset.index_to_path(PackageIndex::new(10)).as_deref(),
+        Some("BP_DetPack_Charge_C")
+    );
+    assert_eq!(asset.index_to_path(PackageIndex::new(0)), None);
+    assert_eq!(asset.path_to_index("/Script/Engine.Missing"), None);
+
+    for i in 0..asset.asset_data.exports.len() {
+        let index = PackageIndex::new(i as i32 + 1);
+        let path = asset.index_to_path(index).expect("Export path");
+        assert_eq!(asset.path_to_index(&path), Some(index));
+    }
+    for i in 0..asset.imports.len() {
+        let index = PackageIndex::new(-(i as i32) - 1);
+        let path = asset.index_to_path(index).expect("Import path");
+        assert_eq!(asset.path_to_index(&path), Some(index));
+    }
+
+    let paths = asset.object_paths();
+    assert_eq!(
+        paths.len(),
+        asset.imports.len() + asset.asset_data.exports.len()
+    );
+    for (path, index) in paths {
+        assert_eq!(asset.index_to_path(index), Some(path));
+    }
+
+    // objects with a circular outer chain have no path
+    asset.asset_data.exports[9]
+        .get_base_export_mut()
+        .outer_index = PackageIndex::new(21);
+    assert_eq!(asset.index_to_path(PackageIndex::new(21)), None);
+    let paths = asset.object_paths();
+    assert!(!paths.values().any(|e| e.index == 10 || e.index == 21));
+    assert_eq!(
+        paths.get("Default__BP_DetPack_Charge_C").copied(),
+        Some(PackageIndex::new(11))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resolve_references() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    assert_eq!(
+        asset.resolve(PackageIndex::new(0)),
+        Some(ResolvedReference::Null)
+    );
+    assert_eq!(
+        asset.resolve(PackageIndex::new(-10)),
+        Some(ResolvedReference::Import(&asset.imports[9]))
+    );
+    assert_eq!(
+        asset.resolve(PackageIndex::new(1)),
+        Some(ResolvedReference::Export(&asset.asset_data.exports[0]))
+    );
+    assert_eq!(
+        asset.resolve(PackageIndex::new(-(asset.imports.len() as i32) - 1)),
+        None
+    );
+    assert_eq!(
+        asset.resolve(PackageIndex::new(asset.asset_data.exports.len() as i32 + 1)),
+        None
+    );
+
+    assert_eq!(
+        asset.resolve_name(PackageIndex::new(-47)).as_deref(),
+        Some("/Script/FSD.Default__DetPack:Explosion")
+    );
+    assert_eq!(asset.resolve_name(PackageIndex::new(0)), None);
+
+    // the package name comes from the name map, the folder name isn't set in cooked packages
+    assert_eq!(asset.folder_name, "None");
+    assert_eq!(
+        asset.get_package_name().as_deref(),
+        Some("/Game/WeaponsNTools/DetPack/BP_DetPack_Charge")
+    );
+    for i in 0..asset.asset_data.exports.len() {
+        let index = PackageIndex::new(i as i32 + 1);
+        let path = asset.index_to_path(index).expect("Export path");
+        assert_eq!(
+            asset.resolve_name(index),
+            Some(format!(
+                "/Game/WeaponsNTools/DetPack/BP_DetPack_Charge.{path}"
+            ))
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use unreal_asset::{
+    compression::{decompress, CompressionMethod},
+    Error,
+};
+
+#[cfg(not(feature = "oodle"))]
+#[test]
+fn oodle_disabled() {
+    let method = CompressionMethod::new("Oodle");
+    assert_eq!(method, CompressionMethod::Unknown("Oodle".into()));
+    assert!(matches!(
+        decompress(method, &[0; 4], &mut [0; 4]),
+        Err(Error::UnknownCompressionMethod(_))
+    ));
+}
+
+#[cfg(feature = "oodle")]
+#[test]
+fn oodle_not_available() {
+    use unreal_asset::compression::oodle;
+
+    let method = CompressionMethod::new("Oodle");
+    assert_eq!(method, CompressionMethod::Oodle);
+    assert_eq!(method.to_string(), "Oodle");
+
+    assert!(matches!(
+        oodle::load_library("/nonexistent/liboo2core.so"),
+        Err(Error::OodleNotAvailable(_))
+    ));
+
+    if !oodle::is_loaded() {
+        // the failed lookup is cached, later calls fail the same way
+        for _ in 0..2 {
+            match decompress(method.clone(), &[0; 4], &mut [0; 4]) {
+                Err(Error::OodleNotInitialized) => assert!(!oodle::is_loaded()),
+                Err(Error::Oodle) => assert!(oodle::is_loaded()),
+                e => panic!("Expected oodle to be unavailable, got {e:?}"),
+            }
+        }
+    }
+}
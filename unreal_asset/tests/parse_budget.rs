@@ -0,0 +1,171 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions,
+    containers::{Chain, NameMap},
+    engine_version::EngineVersion,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        per_platform_property::PerPlatformIntProperty, raw_struct_property::RawStructProperty,
+        Ancestry,
+    },
+    reader::RawReader,
+    types::FName,
+    unversioned::Usmap,
+    Asset, Error,
+};
+
+mod shared;
+
+fn read(data: &[u8], options: AssetOptions) -> Result<Asset<Cursor<&[u8]>>, Error> {
+    Asset::new_with_options(
+        Cursor::new(data),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options,
+    )
+}
+
+/// Offset of the export count in the package summary, it's followed by the export offset and the import count
+fn export_count_offset(export_count: usize, import_count: usize) -> usize {
+    shared::BROAD_BRUSH
+        .windows(12)
+        .position(|e| {
+            e[..4] == (export_count as i32).to_le_bytes()
+                && e[8..] == (import_count as i32).to_le_bytes()
+        })
+        .unwrap()
+}
+
+#[test]
+fn absurd_counts() -> Result<(), Error> {
+    let asset = read(shared::BROAD_BRUSH, AssetOptions::default())?;
+    let offset = export_count_offset(asset.asset_data.exports.len(), asset.imports.len());
+
+    for export_count in [i32::MAX, 0x100000, -1] {
+        let mut data = shared::BROAD_BRUSH.to_vec();
+        data[offset..offset + 4].copy_from_slice(&export_count.to_le_bytes());
+        assert!(
+            matches!(
+                read(&data, AssetOptions::default()),
+                Err(Error::InvalidFile(_))
+            ),
+            "{export_count}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_budget() -> Result<(), Error> {
+    let asset = read(shared::BROAD_BRUSH, AssetOptions::default())?;
+    let export_count = asset.asset_data.exports.len();
+    let name_count = asset.get_name_map().get_ref().len();
+
+    let mut asset = read(
+        shared::BROAD_BRUSH,
+        AssetOptions {
+            max_exports: Some(export_count),
+            max_names: Some(name_count),
+            max_allocation: Some(0x1000000),
+            ..Default::default()
+        },
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+    shared::verify_binary_equality(shared::BROAD_BRUSH, None, &mut asset)?;
+
+    for options in [
+        AssetOptions {
+            max_exports: Some(export_count - 1),
+            ..Default::default()
+        },
+        AssetOptions {
+            max_names: Some(name_count - 1),
+            ..Default::default()
+        },
+        AssetOptions {
+            max_allocation: Some(0x100),
+            ..Default::default()
+        },
+    ] {
+        assert!(matches!(
+            read(shared::BROAD_BRUSH, options),
+            Err(Error::InvalidFile(_))
+        ));
+    }
+
+    Ok(())
+}
+
+fn raw_reader(data: &[u8]) -> RawReader<Cursor<&[u8]>> {
+    RawReader::new(
+        Chain::new(Cursor::new(data), None),
+        ObjectVersion::VER_UE4_AUTOMATIC_VERSION,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        NameMap::new(),
+    )
+}
+
+#[test]
+fn absurd_property_counts() -> Result<(), Error> {
+    let name = FName::from_slice("Test");
+
+    // counts are only trusted as far as the data goes
+    for (num_entries, expected) in [(i32::MAX, None), (-1, Some(0)), (1, Some(1))] {
+        let mut data = num_entries.to_le_bytes().to_vec();
+        data.extend(7i32.to_le_bytes());
+        let property = PerPlatformIntProperty::new(
+            &mut raw_reader(&data),
+            name.clone(),
+            Ancestry::default(),
+            false,
+            0,
+            0,
+        );
+        assert_eq!(
+            property.ok().map(|e| e.value.len()),
+            expected,
+            "{num_entries}"
+        );
+    }
+
+    // sizes are checked against the bytes left before allocating
+    for length in [i64::MAX, -1, 5] {
+        let property = RawStructProperty::new(
+            &mut raw_reader(&[0; 4]),
+            name.clone(),
+            Ancestry::default(),
+            false,
+            0,
+            length,
+        );
+        assert!(matches!(property, Err(Error::InvalidFile(_))), "{length}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn absurd_usmap_sizes() {
+    let mut usmap = vec![0x30, 0xc4];
+    // initial version, uncompressed
+    usmap.extend([0, 0]);
+    usmap.extend(u32::MAX.to_le_bytes());
+    usmap.extend(u32::MAX.to_le_bytes());
+    assert!(matches!(
+        Usmap::new(Cursor::new(usmap)),
+        Err(Error::InvalidFile(_))
+    ));
+
+    // no names followed by an enum count the data can't hold
+    let mut data = 0i32.to_le_bytes().to_vec();
+    data.extend(u32::MAX.to_le_bytes());
+    let mut usmap = vec![0x30, 0xc4, 0, 0];
+    usmap.extend((data.len() as u32).to_le_bytes());
+    usmap.extend((data.len() as u32).to_le_bytes());
+    usmap.extend(data);
+    assert!(Usmap::new(Cursor::new(usmap)).is_err());
+}
@@ -0,0 +1,74 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, error::ErrorKind, exports::ExportBaseTrait, Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Misc_426/RaceSimDataAsset"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(test_asset!(), ".uexp"));
+
+#[test]
+fn preload_dependency_bounds() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_26,
+        None,
+    )?;
+
+    let preload_dependency_count = asset
+        .asset_data
+        .exports
+        .iter()
+        .map(|e| {
+            let base_export = e.get_base_export();
+            base_export
+                .serialization_before_serialization_dependencies
+                .len()
+                + base_export.create_before_serialization_dependencies.len()
+                + base_export.serialization_before_create_dependencies.len()
+                + base_export.create_before_create_dependencies.len()
+        })
+        .sum::<usize>() as i32;
+    assert!(preload_dependency_count > 0);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (mut data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    // preload dependencies are the last header section, find their count and offset in the summary
+    let preload_dependency_offset = data.len() as i32 - preload_dependency_count * 4;
+    let summary_entry = [
+        preload_dependency_count.to_le_bytes(),
+        preload_dependency_offset.to_le_bytes(),
+    ]
+    .concat();
+    let position = data
+        .windows(summary_entry.len())
+        .position(|e| e == summary_entry)
+        .expect("Preload dependency count not found in the package summary");
+
+    // claim fewer preload dependencies than the exports reference
+    data[position..position + 4].copy_from_slice(&(preload_dependency_count - 1).to_le_bytes());
+    let error = Asset::new(
+        Cursor::new(data),
+        Some(Cursor::new(bulk)),
+        EngineVersion::VER_UE4_26,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(error.to_string().contains("preload dependencies"));
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions,
+    cast,
+    engine_version::EngineVersion,
+    exports::{Export, ExportNormalTrait},
+    properties::{
+        str_property::{TextHistoryType, TextProperty, TransformType},
+        struct_property::StructProperty,
+        Property,
+    },
+    reader::archive_reader::DEFAULT_MAX_PROPERTY_DEPTH,
+    unversioned::Ancestry,
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+/// Nesting depth of the structs added to the test asset
+const DEPTH: usize = DEFAULT_MAX_PROPERTY_DEPTH;
+
+/// Write the test asset with structs nested `DEPTH` levels deep added to its first normal export
+fn nested_asset() -> Result<(Vec<u8>, usize), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let name = asset.add_fname("Nested");
+    let struct_type = asset.add_fname("NestedStruct");
+    let nested = || {
+        StructProperty::dummy(
+            name.clone(),
+            Ancestry::default(),
+            struct_type.clone(),
+            Some(Guid::default()),
+        )
+    };
+    let mut property = nested();
+    for _ in 1..DEPTH {
+        let mut parent = nested();
+        parent.value.push(property.into());
+        property = parent;
+    }
+
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| e.get_normal_export().is_some())
+        .unwrap();
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties
+        .push(property.into());
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    Ok((cursor.into_inner(), export_index))
+}
+
+fn read(data: &[u8], options: AssetOptions) -> Result<Asset<Cursor<&[u8]>>, Error> {
+    Asset::new_with_options(
+        Cursor::new(data),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options,
+    )
+}
+
+fn is_depth_error(error: &Error) -> bool {
+    matches!(error, Error::InvalidFile(msg) if &**msg == "max property nesting depth exceeded")
+}
+
+/// Run `f` on a thread with enough stack for deeply nested properties in debug builds
+fn with_large_stack(f: impl FnOnce() -> Result<(), Error> + Send + 'static) -> Result<(), Error> {
+    std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+#[test]
+fn property_depth() -> Result<(), Error> {
+    with_large_stack(property_depth_inner)
+}
+
+fn property_depth_inner() -> Result<(), Error> {
+    let (data, export_index) = nested_asset()?;
+
+    let asset = read(&data, AssetOptions::default())?;
+    assert!(asset.warnings.is_empty());
+    let mut depth = 0;
+    let mut properties = &asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .unwrap()
+        .properties;
+    while let Some(property) = properties
+        .iter()
+        .find_map(|e| cast!(Property, StructProperty, e).filter(|e| e.name == "Nested"))
+    {
+        depth += 1;
+        properties = &property.value;
+    }
+    assert_eq!(depth, DEPTH);
+
+    let options = AssetOptions {
+        max_property_depth: DEPTH - 1,
+        ..Default::default()
+    };
+    let asset = read(&data, options.clone())?;
+    assert!(matches!(
+        asset.asset_data.exports[export_index],
+        Export::RawExport(_)
+    ));
+    assert!(is_depth_error(&asset.warnings[0].error));
+
+    let error = read(
+        &data,
+        AssetOptions {
+            strict_exports: true,
+            ..options
+        },
+    )
+    .unwrap_err();
+    match error {
+        Error::ExportParse(_, _, error) => assert!(is_depth_error(&error)),
+        e => panic!("Unexpected error {e}"),
+    }
+
+    let options = AssetOptions {
+        max_property_depth: DEPTH,
+        ..Default::default()
+    };
+    assert!(read(&data, options.clone())?.warnings.is_empty());
+
+    // property offsets of the nested structs are read within the same depth limit
+    let asset = read(
+        &data,
+        AssetOptions {
+            track_property_offsets: true,
+            ..options
+        },
+    )?;
+    assert!(asset.warnings.is_empty());
+    let path = vec!["Nested"; DEPTH].join(".");
+    assert!(asset.property_offsets.keys().any(|(_, e)| *e == path));
+
+    Ok(())
+}
+
+#[test]
+fn text_depth() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    asset.add_fname("TextProperty");
+
+    let name = asset.add_fname("NestedText");
+    let mut text = TextProperty {
+        name,
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        culture_invariant_string: Some(String::from("text")),
+        namespace: Some(String::new()),
+        table_id: None,
+        flags: 0,
+        history_type: TextHistoryType::Base,
+        source_text: None,
+        transform_type: None,
+        arguments: None,
+        value: Some(String::from("text")),
+    };
+    for _ in 0..8 {
+        text = TextProperty {
+            history_type: TextHistoryType::Transform,
+            source_text: Some(Box::new(text.clone())),
+            transform_type: Some(TransformType::ToUpper),
+            culture_invariant_string: None,
+            namespace: None,
+            value: None,
+            ..text
+        };
+    }
+
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| e.get_normal_export().is_some())
+        .unwrap();
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties
+        .push(text.into());
+
+    let data = shared::write_asset(&asset)?;
+
+    let options = AssetOptions {
+        max_property_depth: 4,
+        ..Default::default()
+    };
+    assert!(read(shared::BROAD_BRUSH, options.clone())?
+        .warnings
+        .is_empty());
+    let asset = read(&data, options)?;
+    assert!(matches!(
+        asset.asset_data.exports[export_index],
+        Export::RawExport(_)
+    ));
+    assert!(is_depth_error(&asset.warnings[0].error));
+
+    let asset = read(
+        &data,
+        AssetOptions {
+            max_property_depth: 16,
+            ..Default::default()
+        },
+    )?;
+    assert!(asset.warnings.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions,
+    asset_data::AssetWarningKind,
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{Property, PropertyDataTrait},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Staging_T2"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".umap"));
+const BOOL_ASSET: &[u8] = shared::BROAD_BRUSH;
+
+#[test]
+fn property_offsets() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(asset.property_offsets.is_empty());
+
+    let asset = Asset::new_with_options(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        AssetOptions {
+            track_property_offsets: true,
+            ..Default::default()
+        },
+    )?;
+
+    for (i, export) in asset.asset_data.exports.iter().enumerate() {
+        let Some(normal_export) = export.get_normal_export() else {
+            continue;
+        };
+        let export_index = PackageIndex::from_export(i as i32)?;
+
+        let mut last_end = 0;
+        for property in &normal_export.properties {
+            let name = property.get_name();
+            let mut path = name.get_owned_content();
+            if name.get_number() > 0 {
+                path = format!("{}_{}", path, name.get_number() - 1);
+            }
+            if property.get_duplication_index() != 0 {
+                path = format!("{}[{}]", path, property.get_duplication_index());
+            }
+
+            let range = asset
+                .property_offsets
+                .get(&(export_index, path))
+                .expect("Missing property offsets");
+            assert!(range.start >= last_end);
+            assert!(range.end <= TEST_ASSET.len() as u64);
+            last_end = range.end;
+        }
+    }
+
+    // nested struct properties are recorded as well
+    assert!(asset
+        .property_offsets
+        .keys()
+        .any(|(_, path)| path.contains('.')));
+
+    // so are the elements of struct arrays, back to back inside the array value
+    let mut arrays = 0;
+    for (i, export) in asset.asset_data.exports.iter().enumerate() {
+        let Some(normal_export) = export.get_normal_export() else {
+            continue;
+        };
+        let export_index = PackageIndex::from_export(i as i32)?;
+
+        for array in normal_export
+            .properties
+            .iter()
+            .filter_map(|e| cast!(Property, ArrayProperty, e))
+            .filter(|e| {
+                e.name.get_number() == 0
+                    && e.duplication_index == 0
+                    && matches!(e.value.first(), Some(Property::StructProperty(_)))
+            })
+        {
+            let path = array.name.get_owned_content();
+            let array_range = &asset.property_offsets[&(export_index, path.clone())];
+
+            let mut last_end = None;
+            for j in 0..array.value.len() {
+                let range = asset
+                    .property_offsets
+                    .get(&(export_index, format!("{path}.{j}")))
+                    .expect("Missing array element offsets");
+                assert!(range.start > array_range.start && range.end <= array_range.end);
+                assert!(last_end.is_none_or(|e| e == range.start));
+                last_end = Some(range.end);
+            }
+            assert_eq!(last_end, Some(array_range.end));
+            arrays += 1;
+        }
+    }
+    assert!(arrays > 0);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_property_length() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(BOOL_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let name_map = asset.get_name_map();
+    let name_index = |name: &str| name_map.get_ref().search_name_reference(name).unwrap() as i64;
+
+    // bool values are stored in the tag, so the export parses with any tag length
+    let tag = [
+        name_index("UseSquareIconBackground").to_le_bytes(),
+        name_index("BoolProperty").to_le_bytes(),
+    ]
+    .concat();
+    let length_offset = BOOL_ASSET
+        .windows(tag.len())
+        .position(|e| e == tag)
+        .unwrap()
+        + tag.len();
+
+    for length in [-1, i32::MIN] {
+        let mut data = BOOL_ASSET.to_vec();
+        data[length_offset..length_offset + 4].copy_from_slice(&length.to_le_bytes());
+
+        let asset = Asset::new_with_options(
+            Cursor::new(data.as_slice()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+            AssetOptions {
+                track_property_offsets: true,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(asset.warnings.len(), 1, "{length}");
+        assert_eq!(asset.warnings[0].kind, AssetWarningKind::PropertyOffsets);
+        assert!(matches!(asset.warnings[0].error, Error::InvalidFile(_)));
+        assert!(asset
+            .asset_data
+            .exports
+            .iter()
+            .all(|e| e.get_normal_export().is_some()));
+    }
+
+    Ok(())
+}
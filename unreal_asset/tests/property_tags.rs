@@ -0,0 +1,47 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportNormalTrait, properties::PropertyDataTrait,
+    types::fname::ToSerializedName, Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Staging_T2"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".umap"));
+
+#[test]
+fn property_tags() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let mut checked = 0;
+    for (i, export) in asset.asset_data.exports.iter().enumerate() {
+        let Some(normal_export) = export.get_normal_export() else {
+            continue;
+        };
+
+        let tags = asset.dump_export_property_tags(i, Cursor::new(TEST_ASSET))?;
+        assert_eq!(tags.len(), normal_export.properties.len());
+
+        for (tag, property) in tags.iter().zip(&normal_export.properties) {
+            assert_eq!(tag.name, property.get_name());
+            assert_eq!(tag.property_type, property.to_serialized_name().as_str());
+            assert_eq!(tag.duplication_index, property.get_duplication_index());
+        }
+        checked += 1;
+    }
+    assert!(checked > 0);
+
+    Ok(())
+}
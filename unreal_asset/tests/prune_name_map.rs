@@ -0,0 +1,150 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{raw_export::RawExport, ExportBaseTrait, ExportNormalTrait},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! split_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/Zone_Caves"
+        )
+    };
+}
+
+const SPLIT_ASSET: &[u8] = include_bytes!(concat!(split_asset!(), ".umap"));
+const SPLIT_BULK: &[u8] = include_bytes!(concat!(split_asset!(), ".uexp"));
+const SINGLE_ASSET: &[u8] = shared::BROAD_BRUSH;
+
+/// Write an asset, returns the asset and bulk data
+fn write(asset: &Asset<Cursor<&[u8]>>) -> Result<(Vec<u8>, Option<Vec<u8>>), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = asset
+        .asset_data
+        .use_event_driven_loader
+        .then(|| Cursor::new(Vec::new()));
+    asset.write_data(&mut cursor, bulk_cursor.as_mut())?;
+    Ok((cursor.into_inner(), bulk_cursor.map(Cursor::into_inner)))
+}
+
+#[test]
+fn prune_name_map() -> Result<(), Error> {
+    for (data, bulk, engine_version) in [
+        (SINGLE_ASSET, None, EngineVersion::VER_UE4_23),
+        (SPLIT_ASSET, Some(SPLIT_BULK), EngineVersion::VER_UE5_1),
+    ] {
+        let mut asset = Asset::new(
+            Cursor::new(data),
+            bulk.map(Cursor::new),
+            engine_version,
+            None,
+        )?;
+        let names = asset
+            .get_name_map()
+            .get_ref()
+            .get_name_map_index_list()
+            .to_vec();
+
+        for i in 0..16 {
+            asset.add_fname(&format!("Orphan{i}"));
+        }
+        asset
+            .override_name_map_hashes
+            .insert("Orphan0".to_string(), 0);
+        let (bloated, _) = write(&asset)?;
+        assert!(bloated.len() > data.len());
+
+        asset.prune_name_map()?;
+        assert!(asset
+            .override_name_map_hashes
+            .get_by_key("Orphan0")
+            .is_none());
+
+        // the used names keep their order
+        let pruned_names = asset
+            .get_name_map()
+            .get_ref()
+            .get_name_map_index_list()
+            .to_vec();
+        assert!(pruned_names.iter().all(|e| !e.starts_with("Orphan")));
+        let original_order = names
+            .iter()
+            .filter(|e| pruned_names.contains(e))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(&pruned_names[..original_order.len()], original_order);
+
+        let (written, written_bulk) = write(&asset)?;
+        assert!(written.len() < bloated.len());
+        assert!(written.len() <= data.len());
+
+        let mut reparsed = Asset::new(
+            Cursor::new(written.as_slice()),
+            written_bulk.as_deref().map(Cursor::new),
+            engine_version,
+            None,
+        )?;
+        assert!(shared::verify_all_exports_parsed(&reparsed));
+        assert_eq!(
+            reparsed.asset_data.exports.len(),
+            asset.asset_data.exports.len()
+        );
+        shared::verify_binary_equality(&written, written_bulk.as_deref(), &mut reparsed)?;
+
+        // pruning an asset without unused names doesn't change it
+        reparsed.prune_name_map()?;
+        shared::verify_binary_equality(&written, written_bulk.as_deref(), &mut reparsed)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn prune_name_map_raw_export() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    // raw data references names by index, which can't be renumbered
+    let (i, export) = asset
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .find(|(_, e)| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .unwrap();
+    let base_export = export.get_base_export().clone();
+    let start = base_export.serial_offset as usize;
+    let data = SINGLE_ASSET[start..start + base_export.serial_size as usize].to_vec();
+    asset.asset_data.exports[i] = RawExport { base_export, data }.into();
+
+    let names = asset
+        .get_name_map()
+        .get_ref()
+        .get_name_map_index_list()
+        .to_vec();
+    assert!(asset.prune_name_map().is_err());
+    assert!(asset.detach_name_map().is_err());
+    assert_eq!(
+        asset.get_name_map().get_ref().get_name_map_index_list(),
+        names
+    );
+
+    // the asset is unchanged, so the raw export still parses after writing
+    let (written, _) = write(&asset)?;
+    let reparsed = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+
+    Ok(())
+}
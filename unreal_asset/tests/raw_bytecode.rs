@@ -0,0 +1,134 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions,
+    cast,
+    engine_version::EngineVersion,
+    exports::StructExport,
+    reader::{ArchiveTrait, RawWriter},
+    Asset, Error, Export, KismetExpression,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/DebugMenu"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+fn struct_exports<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+) -> impl Iterator<Item = &StructExport> {
+    asset.asset_data.exports.iter().filter_map(|e| match e {
+        Export::FunctionExport(e) => Some(&e.struct_export),
+        Export::ClassExport(e) => Some(&e.struct_export),
+        _ => None,
+    })
+}
+
+#[test]
+fn raw_bytecode() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(struct_exports(&asset).all(|e| e.raw_bytecode().is_none()));
+
+    let mut asset = Asset::new_with_options(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        AssetOptions {
+            retain_raw_bytecode: true,
+            ..Default::default()
+        },
+    )?;
+    shared::verify_binary_equality(TEST_ASSET, None, &mut asset)?;
+
+    let mut checked = 0;
+    for struct_export in struct_exports(&asset) {
+        let (Some(bytecode), Some(raw)) =
+            (&struct_export.script_bytecode, struct_export.raw_bytecode())
+        else {
+            panic!("bytecode was not parsed or raw bytecode was not retained");
+        };
+
+        let mut data = Vec::new();
+        let mut cursor = Cursor::new(&mut data);
+        let mut writer = RawWriter::new(
+            &mut cursor,
+            asset.get_object_version(),
+            asset.get_object_version_ue5(),
+            false,
+            asset.get_name_map(),
+        );
+        for expression in bytecode {
+            KismetExpression::write(expression, &mut writer)?;
+        }
+        assert_eq!(data, raw);
+        checked += 1;
+    }
+    assert!(checked > 0);
+
+    Ok(())
+}
+
+#[test]
+fn bytecode_offsets() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let mut jumps = 0;
+    for struct_export in struct_exports(&asset) {
+        let Some(bytecode) = &struct_export.script_bytecode else {
+            panic!("bytecode was not parsed");
+        };
+        let offsets = struct_export.bytecode_offsets();
+        assert_eq!(offsets.len(), bytecode.len());
+        assert!(offsets.first().is_none_or(|e| *e == 0));
+        assert!(offsets.windows(2).all(|e| e[0] < e[1]));
+        assert!(offsets
+            .last()
+            .is_none_or(|e| (*e as i32) < struct_export.script_bytecode_size));
+
+        for (offset, expression) in offsets.iter().zip(bytecode) {
+            assert_eq!(
+                struct_export.expression_at_offset(*offset),
+                Some(expression)
+            );
+        }
+        assert!(struct_export
+            .expression_at_offset(struct_export.script_bytecode_size as u32)
+            .is_none());
+
+        // jump targets are always statement boundaries
+        for expression in bytecode {
+            let target = match (
+                cast!(KismetExpression, ExJump, expression),
+                cast!(KismetExpression, ExJumpIfNot, expression),
+            ) {
+                (Some(jump), _) => jump.code_offset,
+                (_, Some(jump)) => jump.code_offset,
+                _ => continue,
+            };
+            assert!(struct_export.expression_at_offset(target).is_some());
+            jumps += 1;
+        }
+    }
+    assert!(jumps > 0);
+
+    Ok(())
+}
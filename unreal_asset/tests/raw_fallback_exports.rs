@@ -0,0 +1,33 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, reader::ArchiveTrait, Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn raw_fallback_exports() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+    assert!(asset.raw_fallback_exports().is_empty());
+
+    // corrupt the first property name of the first export
+    let base_export = asset.asset_data.exports[0].get_base_export();
+    let serial_offset = base_export.serial_offset as usize;
+    let class_index = base_export.class_index;
+
+    let mut data = shared::BROAD_BRUSH.to_vec();
+    data[serial_offset..serial_offset + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+
+    let corrupted = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)?;
+    let raw_exports = corrupted.raw_fallback_exports();
+    assert_eq!(raw_exports.len(), 1);
+    assert_eq!(raw_exports[0].0.index, 1);
+
+    let class_name = asset
+        .get_import(class_index)
+        .map(|e| e.object_name.get_owned_content());
+    assert_eq!(Some(raw_exports[0].1.clone()), class_name);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+#[test]
+fn registry_tail() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert!(asset.registry_tail.is_empty());
+    shared::verify_binary_equality(shared::BROAD_BRUSH, None, &mut asset)?;
+
+    let registry_tail = vec![0x01, 0x00, 0x00, 0x00, 0xde, 0xad, 0xbe, 0xef];
+    asset.registry_tail = registry_tail.clone();
+
+    let written = shared::write_asset(&asset)?;
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(asset.registry_tail, registry_tail);
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    // the tail can't extend past the end of the data
+    let tail_end = written
+        .windows(registry_tail.len())
+        .position(|e| e == registry_tail)
+        .unwrap()
+        + registry_tail.len();
+    assert!(matches!(
+        Asset::new(
+            Cursor::new(&written[..tail_end - 4]),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        ),
+        Err(Error::InvalidFile(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    section_layout::{HeaderSection, SectionLayout},
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn section_layout() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+
+    // the default layout is the regular write order
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data_with_layout(&mut cursor, None, &SectionLayout::default())?;
+    assert!(cursor.get_ref().as_slice() == shared::BROAD_BRUSH);
+
+    let mut sections = SectionLayout::DEFAULT_ORDER.to_vec();
+    sections.reverse();
+    let layout = SectionLayout::new(sections);
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data_with_layout(&mut cursor, None, &layout)?;
+    assert!(cursor.get_ref().as_slice() != shared::BROAD_BRUSH);
+
+    let reordered = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reordered));
+    assert!(reordered.asset_data.exports == asset.asset_data.exports);
+    assert!(reordered.imports == asset.imports);
+
+    // writing the reordered asset with the default layout restores the original
+    let mut cursor = Cursor::new(Vec::new());
+    reordered.write_data(&mut cursor, None)?;
+    assert!(cursor.get_ref().as_slice() == shared::BROAD_BRUSH);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_section_layout() -> Result<(), Error> {
+    let asset = shared::read_broad_brush()?;
+
+    let layout = SectionLayout::new(vec![HeaderSection::NameMap, HeaderSection::NameMap]);
+    assert!(!layout.is_valid());
+
+    let mut cursor = Cursor::new(Vec::new());
+    assert!(asset
+        .write_data_with_layout(&mut cursor, None, &layout)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "serde")]
+
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    types::{fname::FNameContainer, FName},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/")
+    };
+}
+
+/// Asset data, bulk data and engine version
+type TestAsset = (&'static [u8], Option<&'static [u8]>, EngineVersion);
+
+const TEST_ASSETS: [TestAsset; 3] = [
+    (shared::BROAD_BRUSH, None, EngineVersion::VER_UE4_23),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "general/Misc_426/RaceSimDataAsset.uasset"
+        )),
+        Some(include_bytes!(concat!(
+            assets_folder!(),
+            "general/Misc_426/RaceSimDataAsset.uexp"
+        ))),
+        EngineVersion::VER_UE4_26,
+    ),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "unknown_properties/BP_DetPack_Charge.uasset"
+        )),
+        Some(include_bytes!(concat!(
+            assets_folder!(),
+            "unknown_properties/BP_DetPack_Charge.uexp"
+        ))),
+        EngineVersion::VER_UE4_25,
+    ),
+];
+
+#[test]
+fn json_round_trip() -> Result<(), Error> {
+    for (asset_data, bulk_data, engine_version) in TEST_ASSETS {
+        let asset = Asset::new(
+            Cursor::new(asset_data),
+            bulk_data.map(Cursor::new),
+            engine_version,
+            None,
+        )?;
+
+        let json = serde_json::to_string(&asset).expect("Failed to serialize asset");
+        let mut deserialized: Asset<Cursor<Vec<u8>>> =
+            serde_json::from_str(&json).expect("Failed to deserialize asset");
+
+        shared::verify_binary_equality(asset_data, bulk_data, &mut deserialized)?;
+
+        let reserialized = serde_json::to_string(&deserialized).expect("Failed to serialize asset");
+        assert_eq!(json, reserialized);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn fname_strings() {
+    let numbered = FName::new_dummy("Name".to_string(), 4);
+    let literal = FName::new_dummy("Name_3".to_string(), 0);
+    let plain = FName::from_slice("Name");
+
+    assert_eq!(serde_json::to_value(&numbered).unwrap(), "Name_3");
+    assert_eq!(serde_json::to_value(&plain).unwrap(), "Name");
+    assert_eq!(
+        serde_json::to_value(&literal).unwrap(),
+        serde_json::json!({ "name": "Name_3", "number": 0 })
+    );
+
+    for name in [numbered, literal, plain] {
+        let json = serde_json::to_string(&name).unwrap();
+        let deserialized: FName = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, name);
+    }
+}
+
+/// Name map indices of every `FName` in an asset
+fn name_indices<C: std::io::Read + std::io::Seek>(asset: &mut Asset<C>) -> Vec<Option<i32>> {
+    let mut indices = Vec::new();
+    asset.traverse_fnames(&mut |name| {
+        indices.push(match *name {
+            FName::Backed { index, .. } => Some(index),
+            FName::Dummy { .. } => None,
+        })
+    });
+    indices
+}
+
+#[test]
+fn duplicate_name_map_entries() -> Result<(), Error> {
+    let asset_data = include_bytes!(concat!(
+        assets_folder!(),
+        "duplicate_name_map_entries/BIOME_AzureWeald.uasset"
+    ));
+    let bulk_data = include_bytes!(concat!(
+        assets_folder!(),
+        "duplicate_name_map_entries/BIOME_AzureWeald.uexp"
+    ));
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let json = serde_json::to_string(&asset).expect("Failed to serialize asset");
+    let mut deserialized: Asset<Cursor<Vec<u8>>> =
+        serde_json::from_str(&json).expect("Failed to deserialize asset");
+
+    // names keep the duplicate entry they were read from
+    assert_eq!(name_indices(&mut deserialized), name_indices(&mut asset));
+    shared::verify_binary_equality(asset_data, Some(bulk_data), &mut deserialized)?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    custom_version::{FCoreObjectVersion, FEditorObjectVersion},
+    engine_version::EngineVersion,
+    Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Versioned/Assault_M1A1Thompson_WW2_DrumSuppressor"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+#[test]
+fn set_custom_version() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_27,
+        None,
+    )?;
+    let custom_version_count = asset.asset_data.custom_versions.len();
+    let editor_version = asset
+        .asset_data
+        .get_custom_version::<FEditorObjectVersion>()
+        .version;
+
+    asset
+        .asset_data
+        .set_custom_version(FCoreObjectVersion::EnumProperties);
+    assert_eq!(
+        asset
+            .asset_data
+            .get_custom_version::<FCoreObjectVersion>()
+            .version,
+        FCoreObjectVersion::EnumProperties as i32
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let asset = Asset::new(cursor, None, EngineVersion::VER_UE4_27, None)?;
+    assert_eq!(
+        asset
+            .asset_data
+            .get_custom_version::<FCoreObjectVersion>()
+            .version,
+        FCoreObjectVersion::EnumProperties as i32
+    );
+    // other custom versions are left untouched
+    assert_eq!(
+        asset
+            .asset_data
+            .get_custom_version::<FEditorObjectVersion>()
+            .version,
+        editor_version
+    );
+    assert!(asset.asset_data.custom_versions.len() >= custom_version_count);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, types::PackageIndex, Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn set_export_class() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let import_index = |asset: &Asset<_>, class_name: &str| {
+        let i = asset
+            .imports
+            .iter()
+            .position(|e| e.class_name == class_name)
+            .unwrap();
+        PackageIndex::new(-(i as i32) - 1)
+    };
+    let package = import_index(&asset, "Package");
+    let class = import_index(&asset, "Class");
+    let export = PackageIndex::new(2);
+
+    assert!(asset.set_export_class(export, package).is_err());
+    assert!(asset
+        .set_export_class(PackageIndex::new(100), class)
+        .is_err());
+
+    asset.set_export_class(export, class)?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let reparsed = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    assert_eq!(
+        reparsed
+            .get_export(export)
+            .unwrap()
+            .get_base_export()
+            .class_index,
+        class
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::io::{Cursor, Read, Seek};
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{int_property::BoolProperty, Property, PropertyDataTrait},
+    types::FName,
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+mod shared;
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/BloodStained/m01SIP_000_Gimmick.umap"
+));
+
+fn bool_property(value: bool) -> Property {
+    BoolProperty {
+        name: FName::default(),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value,
+    }
+    .into()
+}
+
+/// Get the values of a bool property on all exports which have it
+fn bool_values<C: Read + Seek>(asset: &Asset<C>, name: &str) -> Vec<bool> {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .filter(|e| e.get_name() == name)
+        .filter_map(|e| cast!(Property, BoolProperty, e).map(|e| e.value))
+        .collect()
+}
+
+#[test]
+fn set_property_on_exports() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let addressable = bool_values(&asset, "bNetAddressable");
+    assert!(addressable.iter().all(|e| *e));
+
+    // replaces the property where it exists
+    assert_eq!(
+        asset.set_property_on_exports(
+            "PBSoundControlComponent",
+            "bNetAddressable",
+            bool_property(false)
+        ),
+        10
+    );
+    // appends a new one
+    assert_eq!(
+        asset.set_property_on_exports(
+            "PBSoundControlComponent",
+            "bAutoActivate",
+            bool_property(true)
+        ),
+        10
+    );
+    assert_eq!(
+        asset.set_property_on_exports("NotAClass", "bAutoActivate", bool_property(true)),
+        0
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let written = cursor.into_inner();
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    // some of the components didn't serialize the property, it was appended to those
+    let modified = bool_values(&asset, "bNetAddressable");
+    assert_eq!(modified.iter().filter(|e| !**e).count(), 10);
+    assert!(modified.len() > addressable.len());
+    assert!(modified.len() < addressable.len() + 10);
+    assert_eq!(bool_values(&asset, "bAutoActivate"), vec![true; 10]);
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    Ok(())
+}
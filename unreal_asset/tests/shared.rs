@@ -1,6 +1,36 @@
 use std::io::{Cursor, Read, Seek};
 
-use unreal_asset::{cast, engine_version::EngineVersion, Asset, Error, Export};
+use unreal_asset::{
+    cast, engine_version::EngineVersion, exports::ExportNormalTrait, properties::Property,
+    types::fname::FNameContainer, types::FName, unversioned::Usmap, Asset, Error, Export,
+};
+
+/// Augment_BroadBrush from the Astroneer prebulk assets, a small cooked 4.23 blueprint
+/// most tests start from
+#[allow(dead_code)]
+pub(crate) const BROAD_BRUSH: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+/// Read [`BROAD_BRUSH`]
+#[allow(dead_code)]
+pub(crate) fn read_broad_brush() -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(
+        Cursor::new(BROAD_BRUSH),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )
+}
+
+/// Write an asset that doesn't use a separate bulk file
+#[allow(dead_code)]
+pub(crate) fn write_asset<C: Read + Seek>(asset: &Asset<C>) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    Ok(cursor.into_inner())
+}
 
 #[allow(dead_code)]
 pub(crate) fn verify_reparse<C: Read + Seek>(
@@ -61,3 +91,68 @@ pub(crate) fn verify_all_exports_parsed<C: Read + Seek>(asset: &Asset<C>) -> boo
 
     true
 }
+
+/// Replace every `FName` in a property tree with a dummy holding the same content,
+/// so trees read from different name maps can be compared
+#[allow(dead_code)]
+fn detach_fnames(properties: &[Property]) -> Vec<Property> {
+    let mut properties = properties.to_vec();
+    for property in properties.iter_mut() {
+        property.traverse_fnames(&mut |name| {
+            *name = FName::new_dummy(name.get_owned_content(), name.get_number());
+        });
+    }
+    properties
+}
+
+/// Write an asset with unversioned properties, re-read it with the same mappings
+/// and check that every normal export's property tree survived.
+///
+/// Unversioned headers may order fragments differently from the original,
+/// so the property trees are compared instead of the bytes.
+#[allow(dead_code)]
+pub(crate) fn verify_unversioned_reparse<C: Read + Seek>(
+    asset: &mut Asset<C>,
+    engine_version: EngineVersion,
+    mappings: &Usmap,
+) -> Result<(), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+
+    let mut bulk_cursor = None;
+    if asset.asset_data.use_event_driven_loader {
+        bulk_cursor = Some(Cursor::new(Vec::new()));
+    }
+    asset.write_data(&mut cursor, bulk_cursor.as_mut())?;
+
+    cursor.set_position(0);
+    if let Some(bulk_cursor) = bulk_cursor.as_mut() {
+        bulk_cursor.set_position(0);
+    }
+
+    let reparsed = Asset::new(cursor, bulk_cursor, engine_version, Some(mappings.clone()))?;
+    assert!(reparsed.asset_data.has_unversioned_properties());
+    assert_eq!(
+        asset.asset_data.exports.len(),
+        reparsed.asset_data.exports.len()
+    );
+
+    for (original, reparsed) in asset
+        .asset_data
+        .exports
+        .iter()
+        .zip(reparsed.asset_data.exports.iter())
+    {
+        let (Some(original), Some(reparsed)) =
+            (original.get_normal_export(), reparsed.get_normal_export())
+        else {
+            continue;
+        };
+
+        assert_eq!(
+            detach_fnames(&original.properties),
+            detach_fnames(&reparsed.properties)
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::io::Cursor;
+
+use unreal_asset::{containers::NameMap, engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSETS: [&[u8]; 2] = [
+    shared::BROAD_BRUSH,
+    include_bytes!(concat!(assets_folder!(), "Staging_T2.umap")),
+];
+
+#[test]
+fn shared_name_map() -> Result<(), Error> {
+    let name_map = NameMap::new();
+
+    let mut assets = Vec::new();
+    let mut name_count = 0;
+    for test_asset in TEST_ASSETS {
+        let asset = Asset::new(
+            Cursor::new(test_asset),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )?;
+        name_count += asset.get_name_map().get_ref().len();
+
+        assets.push(Asset::new_with_name_map(
+            Cursor::new(test_asset),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+            name_map.clone(),
+        )?);
+    }
+
+    // names are deduplicated between the assets
+    assert!(name_map.get_ref().len() < name_count);
+    for asset in &assets {
+        assert!(asset.get_name_map() == name_map);
+        assert!(shared::verify_all_exports_parsed(asset));
+    }
+
+    for (asset, test_asset) in assets.iter_mut().zip(TEST_ASSETS) {
+        shared::verify_reparse(asset, EngineVersion::VER_UE4_23)?;
+
+        // only the names the asset uses are written
+        let shared_written = shared::write_asset(asset)?;
+        let reparsed = Asset::new(
+            Cursor::new(shared_written.as_slice()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )?;
+        assert!(reparsed.get_name_map().get_ref().len() < name_map.get_ref().len());
+
+        asset.detach_name_map()?;
+        assert!(asset.get_name_map().get_ref().len() < name_map.get_ref().len());
+
+        let mut cursor = Cursor::new(Vec::new());
+        asset.write_data(&mut cursor, None)?;
+        assert_eq!(cursor.get_ref(), &shared_written);
+        let reparsed = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+        assert!(shared::verify_all_exports_parsed(&reparsed));
+        assert_eq!(
+            reparsed.asset_data.exports.len(),
+            Asset::new(
+                Cursor::new(test_asset),
+                None,
+                EngineVersion::VER_UE4_23,
+                None
+            )?
+            .asset_data
+            .exports
+            .len()
+        );
+    }
+
+    Ok(())
+}
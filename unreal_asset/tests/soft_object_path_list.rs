@@ -0,0 +1,155 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        array_property::ArrayProperty,
+        object_property::SoftObjectPath,
+        soft_path_property::{
+            SoftAssetPathProperty, SoftClassPathProperty, SoftObjectPathProperty,
+            SoftObjectPathPropertyValue,
+        },
+        Property,
+    },
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/Zone_Caves"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".umap"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(test_asset!(), ".uexp"));
+
+fn collect_soft_object_paths(property: &Property, paths: &mut Vec<SoftObjectPath>) {
+    match property {
+        Property::SoftObjectProperty(property) => paths.push(property.value.clone()),
+        // soft object path structs are serialized through the list as well
+        Property::SoftAssetPathProperty(SoftAssetPathProperty { value, .. })
+        | Property::SoftClassPathProperty(SoftClassPathProperty { value, .. })
+        | Property::SoftObjectPathProperty(SoftObjectPathProperty { value, .. }) => {
+            if let SoftObjectPathPropertyValue::New(path) = value {
+                paths.push(path.clone());
+            }
+        }
+        Property::ArrayProperty(property) => {
+            for entry in &property.value {
+                collect_soft_object_paths(entry, paths);
+            }
+        }
+        Property::StructProperty(property) => {
+            for entry in &property.value {
+                collect_soft_object_paths(entry, paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn soft_object_paths<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Vec<SoftObjectPath> {
+    let mut paths = Vec::new();
+    for export in &asset.asset_data.exports {
+        if let Some(normal_export) = export.get_normal_export() {
+            for property in &normal_export.properties {
+                collect_soft_object_paths(property, &mut paths);
+            }
+        }
+    }
+    paths
+}
+
+#[test]
+fn soft_object_path_list() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert!(asset.asset_data.soft_object_path_list.is_empty());
+
+    let paths = soft_object_paths(&asset);
+    assert!(!paths.is_empty());
+
+    let mut list: Vec<SoftObjectPath> = Vec::new();
+    for path in paths.iter().rev() {
+        if !list.contains(path) {
+            list.push(path.clone());
+        }
+    }
+
+    // arrays of soft object paths store each element as an index into the list
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export().is_some_and(|e| {
+                e.properties
+                    .iter()
+                    .any(|p| matches!(p, Property::SoftObjectProperty(_)))
+            })
+        })
+        .unwrap();
+    let properties = &asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .unwrap()
+        .properties;
+    let element = properties
+        .iter()
+        .find(|p| matches!(p, Property::SoftObjectProperty(_)))
+        .cloned()
+        .unwrap();
+    let ancestry = match &element {
+        Property::SoftObjectProperty(property) => property.ancestry.clone(),
+        _ => unreachable!(),
+    };
+    let array = Property::ArrayProperty(ArrayProperty::from_arr(
+        asset.add_fname("SoftObjectPaths"),
+        ancestry,
+        Some(asset.add_fname("SoftObjectProperty")),
+        vec![element.clone(), element],
+    ));
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties
+        .push(array);
+
+    let paths = soft_object_paths(&asset);
+
+    // every path has to be in the list
+    asset.asset_data.soft_object_path_list = list[1..].to_vec();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    assert!(asset
+        .write_data(&mut cursor, Some(&mut bulk_cursor))
+        .is_err());
+
+    asset.asset_data.soft_object_path_list = list.clone();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (written, written_bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        Some(Cursor::new(written_bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+    assert_eq!(reparsed.asset_data.soft_object_path_list, list);
+    assert_eq!(soft_object_paths(&reparsed), paths);
+    shared::verify_binary_equality(&written, Some(&written_bulk), &mut reparsed)?;
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        object_property::{SoftObjectPath, TopLevelAssetPath},
+        soft_path_property::SoftObjectPathPropertyValue,
+        Property,
+    },
+    types::FName,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/Zone_Caves"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".umap"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(test_asset!(), ".uexp"));
+
+fn names(
+    package_name: Option<FName>,
+    asset_name: Option<FName>,
+) -> (Option<String>, Option<String>) {
+    (
+        package_name.map(|e| e.to_string()),
+        asset_name.map(|e| e.to_string()),
+    )
+}
+
+fn legacy_path(path: &str) -> SoftObjectPath {
+    SoftObjectPath {
+        asset_path: TopLevelAssetPath::new(None, FName::from_display(path)),
+        sub_path_string: None,
+    }
+}
+
+#[test]
+fn soft_object_path_names() -> Result<(), Error> {
+    // 5.1 stores the package name and asset name separately
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    let path = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .find_map(|e| match e {
+            Property::SoftObjectProperty(property) => Some(property.value.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let package_name = path.asset_path.package_name.clone().unwrap();
+    assert!(package_name.get_content(|e| e.starts_with('/')));
+    assert_eq!(path.package_name(), Some(package_name));
+    assert_eq!(path.asset_name(), Some(path.asset_path.asset_name.clone()));
+
+    let value = SoftObjectPathPropertyValue::New(path.clone());
+    assert_eq!(value.package_name(), path.package_name());
+    assert_eq!(value.asset_name(), path.asset_name());
+    shared::verify_binary_equality(TEST_ASSET, Some(TEST_BULK), &mut asset)?;
+
+    // package paths have no asset name
+    let package_path = SoftObjectPath {
+        asset_path: TopLevelAssetPath::new(
+            Some(FName::from_slice("/Game/Maps/Map")),
+            FName::from_slice("None"),
+        ),
+        sub_path_string: None,
+    };
+    assert_eq!(
+        names(package_path.package_name(), package_path.asset_name()),
+        (Some("/Game/Maps/Map".to_string()), None)
+    );
+
+    // older versions store the whole path in the asset name
+    for (path, expected) in [
+        ("/Game/Maps/Map.Map", (Some("/Game/Maps/Map"), Some("Map"))),
+        (
+            "/Game/Maps/Map.Map_0",
+            (Some("/Game/Maps/Map"), Some("Map_0")),
+        ),
+        ("/Game/Maps/Map", (Some("/Game/Maps/Map"), None)),
+        ("None", (None, None)),
+    ] {
+        let expected = (
+            expected.0.map(str::to_string),
+            expected.1.map(str::to_string),
+        );
+        let path = legacy_path(path);
+        assert_eq!(names(path.package_name(), path.asset_name()), expected);
+    }
+    assert_eq!(
+        legacy_path("/Game/Maps/Map.Map_0")
+            .asset_name()
+            .unwrap()
+            .get_number(),
+        1
+    );
+
+    let value = SoftObjectPathPropertyValue::Old(Some("/Game/Maps/Map.Map:Actor".to_string()));
+    assert_eq!(
+        names(value.package_name(), value.asset_name()),
+        (Some("/Game/Maps/Map".to_string()), Some("Map".to_string()))
+    );
+    let value = SoftObjectPathPropertyValue::Old(None);
+    assert_eq!(
+        names(value.package_name(), value.asset_name()),
+        (None, None)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/duplicate_name_map_entries/"
+        )
+    };
+}
+
+const ASSET_FILE: &[u8] = include_bytes!(concat!(assets_folder!(), "BIOME_AzureWeald.uasset"));
+const ASSET_BULK_FILE: &[u8] = include_bytes!(concat!(assets_folder!(), "BIOME_AzureWeald.uexp"));
+
+#[test]
+fn soft_paths() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(ASSET_FILE),
+        Some(Cursor::new(ASSET_BULK_FILE)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    let mut paths = Vec::new();
+    asset.rewrite_soft_paths(|path| {
+        paths.push(path.to_string());
+        None
+    });
+    assert_eq!(paths.len(), 12);
+
+    asset.rewrite_soft_paths(|path| {
+        path.strip_prefix("/Game/UI/")
+            .map(|rest| format!("/Game/Interface/{rest}"))
+    });
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let mut reparsed = Asset::new(cursor, Some(bulk_cursor), EngineVersion::VER_UE4_25, None)?;
+
+    let mut rewritten_paths = Vec::new();
+    reparsed.rewrite_soft_paths(|path| {
+        rewritten_paths.push(path.to_string());
+        None
+    });
+
+    let expected_paths = paths
+        .iter()
+        .map(|path| path.replace("/Game/UI/", "/Game/Interface/"))
+        .collect::<Vec<_>>();
+    assert_eq!(rewritten_paths, expected_paths);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::io::{Cursor, Write};
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+macro_rules! split_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/Zone_Caves"
+        )
+    };
+}
+
+const SPLIT_ASSET: &[u8] = include_bytes!(concat!(split_asset!(), ".umap"));
+const SPLIT_BULK: &[u8] = include_bytes!(concat!(split_asset!(), ".uexp"));
+const SINGLE_ASSET: &[u8] = shared::BROAD_BRUSH;
+
+/// A sink that can't seek and records how many times it was flushed
+#[derive(Default)]
+struct Sink {
+    data: Vec<u8>,
+    flushes: usize,
+}
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.data.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn streaming_write() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(SINGLE_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let mut sink = Sink::default();
+    asset.write_data_streaming(&mut sink, None)?;
+    assert_eq!(sink.data, cursor.into_inner());
+    // the data is written export by export
+    assert!(sink.flushes > asset.asset_data.exports.len());
+
+    Ok(())
+}
+
+#[test]
+fn streaming_write_split() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(SPLIT_ASSET),
+        Some(Cursor::new(SPLIT_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let mut sink = Vec::new();
+    let mut bulk_sink = Vec::new();
+    asset.write_data_streaming(&mut sink, Some(&mut bulk_sink))?;
+    assert_eq!(sink, cursor.into_inner());
+    assert_eq!(bulk_sink, bulk_cursor.into_inner());
+
+    assert_eq!(sink, SPLIT_ASSET);
+    assert_eq!(bulk_sink, SPLIT_BULK);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions, engine_version::EngineVersion, exports::ExportBaseTrait,
+    reader::ArchiveTrait, Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn strict_exports() -> Result<(), Error> {
+    let options = AssetOptions {
+        strict_exports: true,
+        ..Default::default()
+    };
+    let asset = Asset::new_with_options(
+        Cursor::new(shared::BROAD_BRUSH),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options.clone(),
+    )?;
+
+    // corrupt the first property name of the first export
+    let base_export = asset.asset_data.exports[0].get_base_export();
+    let serial_offset = base_export.serial_offset as usize;
+    let class_name = asset
+        .get_import(base_export.class_index)
+        .map(|e| e.object_name.get_owned_content())
+        .unwrap();
+
+    let mut data = shared::BROAD_BRUSH.to_vec();
+    data[serial_offset..serial_offset + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+
+    let error = Asset::new_with_options(
+        Cursor::new(data),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options,
+    )
+    .unwrap_err();
+    match error {
+        Error::ExportParse(export_index, error_class_name, _) => {
+            assert_eq!(export_index, 1);
+            assert_eq!(&*error_class_name, class_name);
+        }
+        e => panic!("Unexpected error {e}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    flags::EStrippedData,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::{RawReader, RawWriter},
+    types::FStripDataFlags,
+    Error,
+};
+
+fn read(data: &[u8]) -> Result<FStripDataFlags, Error> {
+    let mut reader = RawReader::new(
+        Chain::new(Cursor::new(data), None),
+        ObjectVersion::VER_UE4_AUTOMATIC_VERSION,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        NameMap::new(),
+    );
+    FStripDataFlags::read(&mut reader)
+}
+
+fn write(flags: &FStripDataFlags) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::new(
+        &mut cursor,
+        ObjectVersion::VER_UE4_AUTOMATIC_VERSION,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        NameMap::new(),
+    );
+    flags.write(&mut writer)?;
+    Ok(cursor.into_inner())
+}
+
+#[test]
+fn strip_data_flags() -> Result<(), Error> {
+    // global flags come first, then the class specific flags
+    let flags = read(&[0x01, 0x04])?;
+    assert_eq!(flags.global_strip_flags, EStrippedData::EDITOR);
+    assert_eq!(flags.class_strip_flags, 0x04);
+    assert!(flags.is_editor_data_stripped());
+    assert!(!flags.is_data_stripped_for_server());
+    assert!(flags.is_class_data_stripped(0x04));
+    assert!(!flags.is_class_data_stripped(0x01));
+    assert_eq!(write(&flags)?, [0x01, 0x04]);
+
+    let flags = FStripDataFlags {
+        global_strip_flags: EStrippedData::EDITOR | EStrippedData::SERVER,
+        class_strip_flags: 0,
+    };
+    assert_eq!(read(&write(&flags)?)?, flags);
+    assert!(flags.is_data_stripped_for_server());
+
+    // unknown global flags are kept
+    let flags = read(&[0x84, 0x00])?;
+    assert_eq!(flags.global_strip_flags.bits(), 0x84);
+    assert_eq!(write(&flags)?, [0x84, 0x00]);
+
+    let flags = FStripDataFlags::default();
+    assert!(!flags.is_editor_data_stripped());
+    assert_eq!(write(&flags)?, [0x00, 0x00]);
+
+    assert!(read(&[0x01]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_data::PropertyDefinition,
+    engine_version::EngineVersion,
+    exports::{
+        properties::fproperty::{FProperty, FPropertyTrait},
+        ExportBaseTrait,
+    },
+    types::{fname::ToSerializedName, PackageIndex},
+    Asset, Error, Export,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "DebugMenu.uasset"));
+
+const FPROPERTY_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/npc_onop/NPC_Onop_IO_Bech.uasset"
+));
+const FPROPERTY_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/npc_onop/NPC_Onop_IO_Bech.uexp"
+));
+
+#[test]
+fn uproperty_definitions() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let class_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| matches!(e, Export::ClassExport(_)))
+        .map(|e| PackageIndex::new(e as i32 + 1))
+        .expect("No class export");
+
+    let struct_export = asset
+        .asset_data
+        .get_struct_export(class_index)
+        .expect("No struct export");
+    assert!(struct_export.loaded_properties.is_empty());
+
+    let properties = asset
+        .asset_data
+        .get_struct_properties(class_index)
+        .expect("No struct export");
+    assert_eq!(properties.len(), 74);
+
+    for property in properties {
+        let PropertyDefinition::UProperty(property_export) = property else {
+            panic!("Expected a UProperty definition");
+        };
+        assert_eq!(property_export.get_base_export().outer_index, class_index);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn fproperty_inner_properties() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(FPROPERTY_ASSET),
+        Some(Cursor::new(FPROPERTY_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let mut inner_types = Vec::new();
+    for export in &asset.asset_data.exports {
+        let Export::FunctionExport(function_export) = export else {
+            continue;
+        };
+
+        for property in &function_export.struct_export.loaded_properties {
+            let FProperty::FArrayProperty(array_property) = property else {
+                assert!(property.get_inner_properties().is_empty());
+                continue;
+            };
+
+            let inner_properties = property.get_inner_properties();
+            assert_eq!(inner_properties, vec![array_property.inner.as_ref()]);
+            assert_eq!(
+                property.get_name(),
+                &array_property.get_generic_property().name
+            );
+            inner_types.push(inner_properties[0].to_serialized_name());
+        }
+    }
+
+    assert_eq!(inner_types.len(), 10);
+    assert_eq!(
+        inner_types.iter().filter(|e| *e == "TextProperty").count(),
+        5
+    );
+    assert_eq!(
+        inner_types
+            .iter()
+            .filter(|e| *e == "ObjectProperty")
+            .count(),
+        5
+    );
+
+    Ok(())
+}
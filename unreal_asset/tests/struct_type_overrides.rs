@@ -0,0 +1,62 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::AssetOptions, asset_data::StructTypeOverrides, containers::IndexedMap,
+    engine_version::EngineVersion, Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/DebugMenu"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+fn read_asset(options: AssetOptions) -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new_with_options(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options,
+    )
+}
+
+#[test]
+fn struct_type_overrides() -> Result<(), Error> {
+    let asset = read_asset(AssetOptions::default())?;
+    let overrides = asset.asset_data.get_struct_type_overrides();
+    assert_eq!(
+        overrides.array_struct_type.get_by_key("Keys"),
+        Some(&"RichCurveKey".to_string())
+    );
+
+    // re-parsing with saved overrides keeps the same context
+    let reparsed = read_asset(AssetOptions {
+        struct_type_overrides: Some(overrides.clone()),
+        ..Default::default()
+    })?;
+    assert_eq!(reparsed.asset_data.get_struct_type_overrides(), overrides);
+
+    // overrides passed in options replace the built-in ones
+    let custom = StructTypeOverrides {
+        map_key: IndexedMap::from([("Ids".to_string(), "Guid".to_string())]),
+        ..Default::default()
+    };
+    let mut asset = read_asset(AssetOptions {
+        struct_type_overrides: Some(custom.clone()),
+        ..Default::default()
+    })?;
+    assert_eq!(asset.asset_data.get_struct_type_overrides(), custom);
+
+    asset
+        .asset_data
+        .set_struct_type_overrides(overrides.clone());
+    assert_eq!(asset.asset_data.get_struct_type_overrides(), overrides);
+
+    Ok(())
+}
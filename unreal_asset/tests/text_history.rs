@@ -0,0 +1,173 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        str_property::{FormatArgumentValue, TextHistoryType, TextProperty, TransformType},
+        Property, PropertyDataTrait,
+    },
+    unversioned::Ancestry,
+    Asset, Error, Export,
+};
+
+mod shared;
+
+fn base_text(asset: &mut Asset<Cursor<&[u8]>>, value: &str) -> TextProperty {
+    TextProperty {
+        name: asset.add_fname("FormattedText"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        culture_invariant_string: Some(value.to_string()),
+        namespace: Some(String::new()),
+        table_id: None,
+        flags: 0,
+        history_type: TextHistoryType::Base,
+        source_text: None,
+        transform_type: None,
+        arguments: None,
+        value: Some(value.to_string()),
+    }
+}
+
+#[test]
+fn text_history() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    asset.add_fname("TextProperty");
+
+    let format_text = base_text(&mut asset, "{0} of {1}");
+    let source_text = base_text(&mut asset, "augment");
+    let transformed_text = TextProperty {
+        history_type: TextHistoryType::Transform,
+        source_text: Some(Box::new(source_text)),
+        transform_type: Some(TransformType::ToUpper),
+        culture_invariant_string: None,
+        namespace: None,
+        value: None,
+        ..format_text.clone()
+    };
+    let property = TextProperty {
+        property_guid: Some([0u8; 16].into()),
+        history_type: TextHistoryType::OrderedFormat,
+        source_text: Some(Box::new(format_text)),
+        arguments: Some(vec![
+            FormatArgumentValue::Int(3),
+            FormatArgumentValue::Double(0.5.into()),
+            FormatArgumentValue::Text(Box::new(transformed_text)),
+        ]),
+        culture_invariant_string: None,
+        namespace: None,
+        value: None,
+        ..base_text(&mut asset, "")
+    };
+
+    let export = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .find_map(|e| e.get_normal_export_mut())
+        .expect("No normal export");
+    export.properties.push(property.clone().into());
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let asset = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    let read = asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| e.get_normal_export())
+        .and_then(|e| {
+            e.properties
+                .iter()
+                .find(|e| e.get_name().get_owned_content() == "FormattedText")
+        })
+        .and_then(|e| cast!(Property, TextProperty, e))
+        .expect("Text property was not read back");
+
+    assert_eq!(read.history_type, TextHistoryType::OrderedFormat);
+    assert_eq!(
+        read.source_text.as_ref().and_then(|e| e.value.as_deref()),
+        Some("{0} of {1}")
+    );
+
+    let arguments = read.arguments.as_ref().expect("Missing format arguments");
+    assert_eq!(arguments.len(), 3);
+    assert_eq!(arguments[0], FormatArgumentValue::Int(3));
+    assert_eq!(arguments[1], FormatArgumentValue::Double(0.5.into()));
+
+    let FormatArgumentValue::Text(transformed) = &arguments[2] else {
+        panic!("Expected a text argument");
+    };
+    assert_eq!(transformed.history_type, TextHistoryType::Transform);
+    assert_eq!(transformed.transform_type, Some(TransformType::ToUpper));
+    assert_eq!(
+        transformed
+            .source_text
+            .as_ref()
+            .and_then(|e| e.value.as_deref()),
+        Some("augment")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_argument_count() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    asset.add_fname("TextProperty");
+
+    const ARGUMENT: i64 = 0x1122334455667788;
+    let property = TextProperty {
+        history_type: TextHistoryType::OrderedFormat,
+        source_text: Some(Box::new(base_text(&mut asset, "{0}"))),
+        arguments: Some(vec![FormatArgumentValue::Int(ARGUMENT)]),
+        culture_invariant_string: None,
+        namespace: None,
+        value: None,
+        ..base_text(&mut asset, "")
+    };
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| e.get_normal_export().is_some())
+        .expect("No normal export");
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties
+        .push(property.into());
+
+    let data = shared::write_asset(&asset)?;
+
+    // the argument count is followed by the argument type and value
+    let count_offset = data
+        .windows(8)
+        .position(|e| e == ARGUMENT.to_le_bytes())
+        .expect("Argument was not written")
+        - 1
+        - 4;
+    assert_eq!(data[count_offset..count_offset + 4], 1i32.to_le_bytes());
+
+    for count in [-1, i32::MIN, i32::MAX] {
+        let mut data = data.clone();
+        data[count_offset..count_offset + 4].copy_from_slice(&count.to_le_bytes());
+
+        let asset = Asset::new(
+            Cursor::new(data.as_slice()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )?;
+        assert!(
+            matches!(asset.asset_data.exports[export_index], Export::RawExport(_)),
+            "{count}"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{
+        texture_2d_export::{FTexture2DMipMap, FTexturePlatformData, Texture2DExport},
+        ExportBaseTrait,
+    },
+    flags::{EBulkDataFlags, EStrippedData},
+    types::{
+        bulk_data::{FByteBulkData, FByteBulkDataHeader},
+        FStripDataFlags, PackageIndex,
+    },
+    Asset, Error, Export, Guid, Import,
+};
+
+mod shared;
+
+fn mip(size: i32, bulk_data: FByteBulkData) -> FTexture2DMipMap {
+    FTexture2DMipMap {
+        cooked: true,
+        bulk_data,
+        size_x: size,
+        size_y: size,
+        size_z: 1,
+    }
+}
+
+fn inline_bulk_data(data: Vec<u8>) -> FByteBulkData {
+    FByteBulkData {
+        header: FByteBulkDataHeader {
+            flags: EBulkDataFlags::BULKDATA_SINGLE_USE,
+            element_count: data.len() as i64,
+            size_on_disk: data.len() as i64,
+            offset_in_file: 0,
+        },
+        data_resource_index: None,
+        data: Some(data),
+    }
+}
+
+#[test]
+fn texture_2d() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let core_uobject = asset.add_fname("/Script/CoreUObject");
+    let package = asset.add_fname("Package");
+    let engine = asset.add_fname("/Script/Engine");
+    let engine_package = asset.add_import(Import::new(
+        core_uobject.clone(),
+        package,
+        PackageIndex::new(0),
+        engine,
+        false,
+    ));
+
+    let class = asset.add_fname("Class");
+    let texture_2d = asset.add_fname("Texture2D");
+    let texture_class = asset.add_import(Import::new(
+        core_uobject,
+        class,
+        engine_package,
+        texture_2d,
+        false,
+    ));
+
+    let (export_index, mut normal_export) = asset
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| cast!(Export, NormalExport, e).map(|e| (i, e.clone())))
+        .unwrap();
+    normal_export.base_export.class_index = texture_class;
+
+    let separate_bulk_data = FByteBulkData {
+        header: FByteBulkDataHeader {
+            flags: EBulkDataFlags::BULKDATA_PAYLOAD_AT_END_OF_FILE
+                | EBulkDataFlags::BULKDATA_PAYLOAD_IN_SEPERATE_FILE
+                | EBulkDataFlags::BULKDATA_NO_OFFSET_FIX_UP,
+            element_count: 4,
+            size_on_disk: 4,
+            offset_in_file: 2,
+        },
+        data_resource_index: None,
+        data: None,
+    };
+
+    let pixel_format_name = asset.add_fname("PF_B8G8R8A8");
+    let object_guid: Guid = [7u8; 16].into();
+    let texture = Texture2DExport {
+        normal_export,
+        object_guid: Some(object_guid),
+        texture_strip_flags: FStripDataFlags {
+            global_strip_flags: EStrippedData::EDITOR,
+            class_strip_flags: 0,
+        },
+        strip_flags: FStripDataFlags::default(),
+        platform_data: vec![FTexturePlatformData {
+            pixel_format_name,
+            size_x: 4,
+            size_y: 4,
+            packed_data: 1,
+            pixel_format: Some("PF_B8G8R8A8".to_string()),
+            opt_data: None,
+            first_mip_to_serialize: 0,
+            mips: vec![
+                mip(4, separate_bulk_data),
+                mip(2, inline_bulk_data((0..16).collect())),
+                mip(1, inline_bulk_data(vec![0xff; 4])),
+            ],
+            is_virtual: false,
+        }],
+    };
+    asset.asset_data.exports[export_index] = texture.into();
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let written = cursor.get_ref().clone();
+
+    // an inline payload size larger than the file fails to parse before allocating
+    let inline_payload = (0..16).collect::<Vec<u8>>();
+    let size_offset = written
+        .windows(inline_payload.len())
+        .position(|e| e == inline_payload)
+        .unwrap()
+        - 8
+        - 4;
+    for size in [i32::MAX, -1] {
+        let mut data = written.clone();
+        data[size_offset..size_offset + 4].copy_from_slice(&size.to_le_bytes());
+        let asset = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)?;
+        assert!(matches!(
+            asset.asset_data.exports[export_index],
+            Export::RawExport(_)
+        ));
+    }
+
+    let mut reparsed = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    let texture = cast!(
+        Export,
+        Texture2DExport,
+        &reparsed.asset_data.exports[export_index]
+    )
+    .expect("Texture2D export was not parsed")
+    .clone();
+    assert_eq!(texture.get_base_export().class_index, texture_class);
+    assert_eq!(texture.object_guid, Some(object_guid));
+    assert_eq!(texture.platform_data.len(), 1);
+
+    let mips = texture.mips();
+    assert_eq!(
+        mips.iter()
+            .map(|e| (e.width, e.height, e.depth))
+            .collect::<Vec<_>>(),
+        vec![(4, 4, 1), (2, 2, 1), (1, 1, 1)]
+    );
+    assert!(mips
+        .iter()
+        .all(|e| e.pixel_format.as_deref() == Some("PF_B8G8R8A8")));
+
+    assert!(mips[0].bulk_data.data.is_none());
+    assert!(reparsed
+        .read_bulk_data_payload::<Cursor<Vec<u8>>>(&mips[0].bulk_data, None)
+        .is_err());
+    let mut ubulk = Cursor::new((0..8).collect::<Vec<u8>>());
+    assert_eq!(
+        reparsed.read_bulk_data_payload(&mips[0].bulk_data, Some(&mut ubulk))?,
+        vec![2, 3, 4, 5]
+    );
+    // payloads past the end of the file
+    let mut ubulk = Cursor::new((0..5).collect::<Vec<u8>>());
+    assert!(reparsed
+        .read_bulk_data_payload(&mips[0].bulk_data, Some(&mut ubulk))
+        .is_err());
+
+    assert_eq!(
+        reparsed.read_bulk_data_payload::<Cursor<Vec<u8>>>(&mips[1].bulk_data, None)?,
+        (0..16).collect::<Vec<u8>>()
+    );
+    assert_eq!(
+        reparsed.read_bulk_data_payload::<Cursor<Vec<u8>>>(&mips[2].bulk_data, None)?,
+        vec![0xff; 4]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    types::thumbnail_table::{Thumbnail, ThumbnailCompression, ThumbnailEntry, ThumbnailTable},
+    Asset, Error,
+};
+
+mod shared;
+
+fn thumbnail_data(width: i32, height: i32, image: &[u8]) -> Vec<u8> {
+    [
+        width.to_le_bytes().as_slice(),
+        height.to_le_bytes().as_slice(),
+        (image.len() as i32).to_le_bytes().as_slice(),
+        image,
+    ]
+    .concat()
+}
+
+#[test]
+fn thumbnail_table() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    assert!(asset.asset_data.thumbnail_table.is_none());
+
+    let table = ThumbnailTable {
+        entries: vec![
+            ThumbnailEntry {
+                object_class_name: "Blueprint".to_string(),
+                object_path: "Augment_BroadBrush".to_string(),
+                data: thumbnail_data(2, 2, b"\x89PNG\r\n\x1a\n"),
+            },
+            ThumbnailEntry {
+                object_class_name: "Texture2D".to_string(),
+                object_path: "Augment_BroadBrush_Icon".to_string(),
+                data: thumbnail_data(0, 0, &[]),
+            },
+        ],
+    };
+    asset.asset_data.thumbnail_table = Some(table.clone());
+
+    let written = shared::write_asset(&asset)?;
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+    assert_eq!(reparsed.asset_data.thumbnail_table, Some(table.clone()));
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    let thumbnail_table = reparsed.asset_data.thumbnail_table.as_mut().unwrap();
+    assert_eq!(
+        thumbnail_table
+            .get("Augment_BroadBrush_Icon")
+            .map(|e| e.object_class_name.as_str()),
+        Some("Texture2D")
+    );
+    assert!(thumbnail_table.get("Missing").is_none());
+
+    let replacement = thumbnail_data(1, 1, b"\xff\xd8\xff");
+    assert_eq!(
+        thumbnail_table.replace("Augment_BroadBrush", replacement.clone()),
+        Some(table.entries[0].data.clone())
+    );
+    assert!(thumbnail_table.replace("Missing", Vec::new()).is_none());
+    assert_eq!(
+        thumbnail_table.remove("Augment_BroadBrush_Icon"),
+        Some(table.entries[1].clone())
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    reparsed.write_data(&mut cursor, None)?;
+    let edited = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    let thumbnail_table = edited.asset_data.thumbnail_table.as_ref().unwrap();
+    assert_eq!(thumbnail_table.entries.len(), 1);
+    assert_eq!(thumbnail_table.entries[0].data, replacement);
+
+    // removing the table entirely restores the original asset
+    let mut edited = edited;
+    edited.asset_data.thumbnail_table = None;
+    let mut cursor = Cursor::new(Vec::new());
+    edited.write_data(&mut cursor, None)?;
+    assert!(cursor.get_ref().as_slice() == shared::BROAD_BRUSH);
+
+    Ok(())
+}
+
+#[test]
+fn thumbnail_image() -> Result<(), Error> {
+    let png = b"\x89PNG\r\n\x1a\n";
+    let mut entry = ThumbnailEntry {
+        object_class_name: "Blueprint".to_string(),
+        object_path: "Augment_BroadBrush".to_string(),
+        data: thumbnail_data(64, 32, png),
+    };
+    let thumbnail = entry.thumbnail()?;
+    assert_eq!(
+        thumbnail,
+        Thumbnail {
+            width: 64,
+            height: 32,
+            compression: ThumbnailCompression::Png,
+            image_data: png.to_vec(),
+        }
+    );
+    assert_eq!(entry.image_data()?, png);
+    assert_eq!(thumbnail.to_data(), entry.data);
+
+    // jpeg thumbnails are marked by a negative height
+    let jpeg = b"\xff\xd8\xff";
+    entry.data = thumbnail_data(64, -32, jpeg);
+    let thumbnail = entry.thumbnail()?;
+    assert_eq!(thumbnail.height, 32);
+    assert_eq!(thumbnail.compression, ThumbnailCompression::Jpeg);
+    assert_eq!(thumbnail.image_data, jpeg);
+    assert_eq!(thumbnail.to_data(), entry.data);
+
+    // the image length must match the image bytes
+    let mut truncated = thumbnail_data(64, 32, png);
+    truncated.pop();
+    entry.data = truncated;
+    assert!(entry.thumbnail().is_err());
+    entry.data = vec![0; 8];
+    assert!(entry.image_data().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_thumbnail_table() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    let png = b"\x89PNG\r\n\x1a\n";
+    asset.asset_data.thumbnail_table = Some(ThumbnailTable {
+        entries: vec![ThumbnailEntry {
+            object_class_name: "Blueprint".to_string(),
+            object_path: "Augment_BroadBrush".to_string(),
+            data: thumbnail_data(2, 2, png),
+        }],
+    });
+
+    let written = shared::write_asset(&asset)?;
+
+    // the image length directly precedes the image
+    let image_length_offset = written
+        .windows(png.len())
+        .position(|e| e == png)
+        .expect("Thumbnail was not written")
+        - 4;
+    // the table starts with the entry count, followed by the first object class name
+    let class_name = [10i32.to_le_bytes().as_slice(), b"Blueprint\0"].concat();
+    let count_offset = written
+        .windows(class_name.len())
+        .position(|e| e == class_name)
+        .expect("Thumbnail table was not written")
+        - 4;
+
+    for (offset, value) in [
+        (image_length_offset, i32::MAX),
+        (image_length_offset, png.len() as i32 + 1),
+        (image_length_offset, -1),
+        (count_offset, i32::MAX),
+        (count_offset, -1),
+    ] {
+        let mut data = written.clone();
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        assert!(
+            Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None).is_err(),
+            "{offset} {value}"
+        );
+    }
+
+    Ok(())
+}
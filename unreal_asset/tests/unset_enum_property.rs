@@ -0,0 +1,51 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast, engine_version::EngineVersion, exports::ExportNormalTrait, properties::Property, Asset,
+    Error,
+};
+
+mod shared;
+
+#[test]
+fn unset_enum_property() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+
+    let (export_index, property_index) = asset
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .find_map(|(i, export)| {
+            let normal_export = export.get_normal_export()?;
+            normal_export
+                .properties
+                .iter()
+                .position(|e| cast!(Property, EnumProperty, e).is_some())
+                .map(|e| (i, e))
+        })
+        .expect("No enum property");
+
+    let property = &mut asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties[property_index];
+    cast!(Property, EnumProperty, property).unwrap().value = None;
+
+    let written = shared::write_asset(&asset)?;
+
+    let mut asset = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let property = &asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .unwrap()
+        .properties[property_index];
+    assert_eq!(cast!(Property, EnumProperty, property).unwrap().value, None);
+    shared::verify_binary_equality(&written, None, &mut asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    flags::EPackageFlags,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{int_property::IntProperty, Property, PropertyDataTrait},
+    unversioned::{
+        properties::{
+            array_property::UsmapArrayPropertyData, shallow_property::UsmapShallowPropertyData,
+            struct_property::UsmapStructPropertyData, EPropertyType, UsmapProperty,
+            UsmapPropertyData,
+        },
+        EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion, UsmapSchema,
+    },
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "ResourceProgressCurve.uasset"));
+
+fn schema(
+    name: &str,
+    super_type: &str,
+    properties: Vec<(&str, UsmapPropertyData)>,
+) -> (String, UsmapSchema) {
+    let mut map = IndexedMap::new();
+    for (i, (property_name, property_data)) in properties.into_iter().enumerate() {
+        map.insert(
+            (property_name.to_string(), i as u32),
+            UsmapProperty {
+                name: property_name.to_string(),
+                schema_index: i as u16,
+                array_size: 1,
+                array_index: 0,
+                property_data,
+            },
+        );
+    }
+
+    (
+        name.to_string(),
+        UsmapSchema {
+            name: name.to_string(),
+            super_type: super_type.to_string(),
+            prop_count: map.len() as u16,
+            module_path: None,
+            properties: map,
+        },
+    )
+}
+
+fn shallow(property_type: EPropertyType) -> UsmapPropertyData {
+    UsmapShallowPropertyData { property_type }.into()
+}
+
+/// Minimal mappings covering the `CurveFloat` export of `ResourceProgressCurve`
+fn curve_float_mappings() -> Usmap {
+    let mut schemas = IndexedMap::new();
+    for (name, schema) in [
+        schema(
+            "CurveFloat",
+            "",
+            vec![
+                (
+                    "FloatCurve",
+                    UsmapStructPropertyData {
+                        struct_type: "RichCurve".to_string(),
+                    }
+                    .into(),
+                ),
+                ("bIsEventCurve", shallow(EPropertyType::BoolProperty)),
+            ],
+        ),
+        schema(
+            "RichCurve",
+            "RealCurve",
+            vec![(
+                "Keys",
+                UsmapArrayPropertyData {
+                    inner_type: Box::new(
+                        UsmapStructPropertyData {
+                            struct_type: "RichCurveKey".to_string(),
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            )],
+        ),
+        schema(
+            "RealCurve",
+            "",
+            vec![
+                ("DefaultValue", shallow(EPropertyType::FloatProperty)),
+                ("PreInfinityExtrap", shallow(EPropertyType::ByteProperty)),
+                ("PostInfinityExtrap", shallow(EPropertyType::ByteProperty)),
+            ],
+        ),
+    ] {
+        schemas.insert(name, schema);
+    }
+
+    Usmap {
+        version: EUsmapVersion::Initial,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+#[test]
+fn unversioned_roundtrip() -> Result<(), Error> {
+    let mappings = curve_float_mappings();
+
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        Some(mappings.clone()),
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    asset.asset_data.package_flags |= EPackageFlags::PKG_UNVERSIONED_PROPERTIES;
+    shared::verify_unversioned_reparse(&mut asset, EngineVersion::VER_UE4_23, &mappings)?;
+
+    Ok(())
+}
+
+#[test]
+fn unversioned_int_roundtrip() -> Result<(), Error> {
+    let mut mappings = curve_float_mappings();
+    let curve_float = mappings.schemas.get_by_key_mut("CurveFloat").unwrap();
+    curve_float.properties.insert(
+        ("Version".to_string(), 0),
+        UsmapProperty {
+            name: "Version".to_string(),
+            schema_index: 2,
+            array_size: 1,
+            array_index: 0,
+            property_data: shallow(EPropertyType::IntProperty),
+        },
+    );
+    curve_float.prop_count += 1;
+
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        Some(mappings.clone()),
+    )?;
+    let name = asset.add_fname("Version");
+    let properties = &mut asset.asset_data.exports[0]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties;
+    let ancestry = properties[0].get_ancestry().clone();
+    *properties = vec![IntProperty {
+        name,
+        ancestry,
+        property_guid: None,
+        duplication_index: 0,
+        value: 7,
+    }
+    .into()];
+
+    // unversioned properties are written without a tag
+    asset.asset_data.package_flags |= EPackageFlags::PKG_UNVERSIONED_PROPERTIES;
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let written = cursor.into_inner();
+
+    let mut reparsed = Asset::new(
+        Cursor::new(written.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        Some(mappings),
+    )?;
+    assert!(reparsed.warnings.is_empty());
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+
+    let properties = &reparsed.asset_data.exports[0]
+        .get_normal_export()
+        .unwrap()
+        .properties;
+    assert_eq!(properties.len(), 1);
+    match &properties[0] {
+        Property::IntProperty(property) => {
+            assert_eq!(property.name.get_owned_content(), "Version");
+            assert_eq!(property.value, 7);
+        }
+        property => panic!("Expected an IntProperty, got {:?}", property),
+    }
+    shared::verify_binary_equality(&written, None, &mut reparsed)?;
+
+    Ok(())
+}
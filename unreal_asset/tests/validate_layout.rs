@@ -0,0 +1,125 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, section_layout::LayoutIssue, Asset,
+    Error,
+};
+
+mod shared;
+
+macro_rules! split_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/Zone_Caves"
+        )
+    };
+}
+
+const SPLIT_ASSET: &[u8] = include_bytes!(concat!(split_asset!(), ".umap"));
+const SPLIT_BULK: &[u8] = include_bytes!(concat!(split_asset!(), ".uexp"));
+const SINGLE_ASSET: &[u8] = shared::BROAD_BRUSH;
+
+/// Get the export indices in the order their data is stored
+fn data_order(asset: &Asset<Cursor<&[u8]>>) -> Vec<usize> {
+    let mut order = (0..asset.asset_data.exports.len()).collect::<Vec<_>>();
+    order.sort_by_key(|e| asset.asset_data.exports[*e].get_base_export().serial_offset);
+    order
+}
+
+#[test]
+fn validate_layout() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(SINGLE_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(asset.validate_layout(), vec![]);
+
+    let asset = Asset::new(
+        Cursor::new(SPLIT_ASSET),
+        Some(Cursor::new(SPLIT_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert_eq!(asset.validate_layout(), vec![]);
+
+    Ok(())
+}
+
+#[test]
+fn validate_layout_issues() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(SINGLE_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let order = data_order(&asset);
+    let (first, second, last) = (order[0], order[1], order[order.len() - 1]);
+
+    // shrinking an export leaves a gap before the next one
+    let export = asset.asset_data.exports[first].get_base_export_mut();
+    export.serial_size -= 4;
+    let gap_offset = export.serial_offset + export.serial_size;
+    assert_eq!(
+        asset.validate_layout(),
+        vec![LayoutIssue::Gap {
+            offset: gap_offset,
+            size: 4
+        }]
+    );
+
+    // growing it overlaps the next one
+    asset.asset_data.exports[first]
+        .get_base_export_mut()
+        .serial_size += 8;
+    assert_eq!(
+        asset.validate_layout(),
+        vec![LayoutIssue::Overlap {
+            export_index: second,
+            other_export_index: first
+        }]
+    );
+    asset.asset_data.exports[first]
+        .get_base_export_mut()
+        .serial_size -= 4;
+
+    // the last export can't extend into bulk data
+    asset.asset_data.exports[last]
+        .get_base_export_mut()
+        .serial_size += 1;
+    assert_eq!(
+        asset.validate_layout(),
+        vec![LayoutIssue::OutOfBounds { export_index: last }]
+    );
+    asset.asset_data.exports[last]
+        .get_base_export_mut()
+        .serial_size -= 1;
+
+    // nor start inside the header
+    let export = asset.asset_data.exports[first].get_base_export_mut();
+    export.serial_offset -= 1;
+    export.serial_size += 1;
+    assert_eq!(
+        asset.validate_layout(),
+        vec![LayoutIssue::OutOfBounds {
+            export_index: first
+        }]
+    );
+    let export = asset.asset_data.exports[first].get_base_export_mut();
+    export.serial_offset += 1;
+    export.serial_size -= 1;
+
+    asset.asset_data.exports[second]
+        .get_base_export_mut()
+        .serial_size = -1;
+    assert!(asset
+        .validate_layout()
+        .contains(&LayoutIssue::InvalidRange {
+            export_index: second
+        }));
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::io::Cursor;
+
+use unreal_asset::{asset::AssetOptions, engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+fn options() -> AssetOptions {
+    AssetOptions {
+        verify_tail_magic: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn verify_tail_magic() -> Result<(), Error> {
+    Asset::new_with_options(
+        Cursor::new(shared::BROAD_BRUSH),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options(),
+    )?;
+
+    let truncated = &shared::BROAD_BRUSH[..shared::BROAD_BRUSH.len() - 4];
+    // without the check the truncated file still parses
+    Asset::new(
+        Cursor::new(truncated),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let strict = Asset::new_with_options(
+        Cursor::new(truncated),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        options(),
+    );
+    assert!(matches!(strict, Err(Error::InvalidFile(_))));
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast, engine_version::EngineVersion, exports::ExportBaseTrait, reader::ArchiveTrait, Asset,
+    Error, Export,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Staging_T2"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".umap"));
+
+#[test]
+fn world_settings() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // astroneer uses a native world settings subclass
+    let world_settings = asset.world_settings().expect("no world settings");
+    let class_index = asset
+        .get_export(world_settings)
+        .unwrap()
+        .get_base_export()
+        .class_index;
+    let class_name = asset.get_import(class_index).unwrap().object_name;
+    assert_eq!(class_name.get_owned_content(), "AstroSettings");
+
+    // without level actors only `WorldSettings` itself can be recognized
+    for export in &mut asset.asset_data.exports {
+        if let Some(level_export) = cast!(Export, LevelExport, export) {
+            level_export.actors.clear();
+        }
+    }
+    assert_eq!(asset.world_settings(), None);
+
+    let world_settings_name = asset.add_fname("WorldSettings");
+    let import_index = (-class_index.index - 1) as usize;
+    asset.imports[import_index].object_name = world_settings_name;
+    assert_eq!(asset.world_settings(), Some(world_settings));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use unreal_asset::{
+    properties::{
+        vector_property::{BoxProperty, IntPointProperty, VectorProperty},
+        world_tile_property::{FWorldTileInfo, FWorldTileLayer},
+    },
+    types::{
+        vector::{Vector, Vector2},
+        FName,
+    },
+    unversioned::Ancestry,
+};
+
+fn vector_property(x: f64, y: f64, z: f64) -> VectorProperty {
+    VectorProperty {
+        name: FName::default(),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: Vector::new(x.into(), y.into(), z.into()),
+    }
+}
+
+#[test]
+fn world_tile_bounds() {
+    let mut tile_info = FWorldTileInfo {
+        position: Vector::new(0, 0, 0),
+        bounds: BoxProperty {
+            name: FName::default(),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            duplication_index: 0,
+            v1: vector_property(0.0, 0.0, 0.0),
+            v2: vector_property(0.0, 0.0, 0.0),
+            is_valid: false,
+        },
+        layer: FWorldTileLayer {
+            name: None,
+            reserved_0: 0,
+            reserved_1: IntPointProperty {
+                name: FName::default(),
+                ancestry: Ancestry::default(),
+                property_guid: None,
+                duplication_index: 0,
+                value: Vector2::new(0, 0),
+            },
+            streaming_distance: None,
+            distance_streaming_enabled: None,
+        },
+        hide_in_tile_view: None,
+        parent_tile_package_name: None,
+        lod_list: None,
+        z_order: None,
+    };
+
+    let min = Vector::new((-512.0).into(), (-256.0).into(), 0.0.into());
+    let max = Vector::new(512.0.into(), 256.0.into(), 128.0.into());
+    tile_info.set_bounds(min.clone(), max.clone());
+
+    assert_eq!(tile_info.get_bounds_min(), &min);
+    assert_eq!(tile_info.get_bounds_max(), &max);
+    assert_eq!(tile_info.bounds.v1.value, min);
+    assert_eq!(tile_info.bounds.v2.value, max);
+    assert!(tile_info.bounds.is_valid);
+}
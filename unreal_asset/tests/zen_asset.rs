@@ -0,0 +1,220 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::{get_object_versions, EngineVersion},
+    enums::HASH_VERSION_CITYHASH64,
+    flags::EPackageFlags,
+    zen_asset::{
+        EExportCommandType, EPackageObjectIndexType, EZenPackageVersion, ExternalArc,
+        PackageObjectIndex, ZenAsset,
+    },
+    Error,
+};
+
+mod shared;
+
+const NAMES: [&str; 3] = ["/Game/Test", "Test", "Widget"];
+const EXPORT_DATA: [&[u8]; 2] = [&[1, 2, 3], &[4, 5]];
+const PACKAGE_IMPORT: u64 = 2 << 62 | 1 << 32;
+
+fn name_batch(names: &[&str]) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend((names.len() as u32).to_le_bytes());
+    data.extend((names.iter().map(|e| e.len()).sum::<usize>() as u32).to_le_bytes());
+    data.extend(HASH_VERSION_CITYHASH64.to_le_bytes());
+    for _ in names {
+        data.extend(0u64.to_le_bytes());
+    }
+    for name in names {
+        data.extend((name.len() as u16).to_be_bytes());
+    }
+    for name in names {
+        data.extend(name.as_bytes());
+    }
+    data
+}
+
+fn export_map_entry(data: &mut Vec<u8>, name: (u32, u32), outer: u64, size: u64) {
+    data.extend(0u64.to_le_bytes());
+    data.extend(size.to_le_bytes());
+    data.extend(name.0.to_le_bytes());
+    data.extend(name.1.to_le_bytes());
+    data.extend(outer.to_le_bytes());
+    data.extend(PACKAGE_IMPORT.to_le_bytes());
+    data.extend(u64::MAX.to_le_bytes());
+    data.extend(u64::MAX.to_le_bytes());
+    data.extend(0u64.to_le_bytes());
+    data.extend(0u32.to_le_bytes());
+    data.extend([0u8; 4]);
+}
+
+/// Build a zen package with two exports, the second one being nested in the first one
+fn zen_package(zen_version: Option<EZenPackageVersion>, engine_version: EngineVersion) -> Vec<u8> {
+    let mut data = vec![0u8; 44];
+    if let Some(zen_version) = zen_version {
+        let (object_version, object_version_ue5) = get_object_versions(engine_version);
+        data.extend(u32::from(zen_version).to_le_bytes());
+        data.extend(i32::from(object_version).to_le_bytes());
+        data.extend(i32::from(object_version_ue5).to_le_bytes());
+        data.extend(0i32.to_le_bytes());
+        data.extend(0i32.to_le_bytes());
+    }
+    data.extend(name_batch(&NAMES));
+    if engine_version >= EngineVersion::VER_UE5_2 {
+        data.extend(32i64.to_le_bytes());
+        data.extend([0u8; 32]);
+    }
+
+    let imported_public_export_hashes_offset = data.len();
+    data.extend(0x1234u64.to_le_bytes());
+    let import_map_offset = data.len();
+    data.extend(PACKAGE_IMPORT.to_le_bytes());
+    let export_map_offset = data.len();
+    export_map_entry(&mut data, (1, 0), u64::MAX, 3);
+    export_map_entry(&mut data, (2, 2), 0, 2);
+    let export_bundle_entries_offset = data.len();
+    for (index, command) in [(0u32, 0u32), (1, 0), (0, 1), (1, 1)] {
+        data.extend(index.to_le_bytes());
+        data.extend(command.to_le_bytes());
+    }
+
+    let graph_data_offset = data.len();
+    data.extend(0u64.to_le_bytes());
+    data.extend(0u32.to_le_bytes());
+    data.extend(4u32.to_le_bytes());
+    // internal arcs
+    data.extend(0i32.to_le_bytes());
+    // external arcs of the imported package
+    data.extend(1i32.to_le_bytes());
+    data.extend(0u32.to_le_bytes());
+    data.extend(0u32.to_le_bytes());
+
+    let header_size = data.len();
+    let summary = [
+        zen_version.is_some() as u32,
+        header_size as u32,
+        0,
+        0,
+        EPackageFlags::PKG_FILTER_EDITOR_ONLY.bits(),
+        0x1000,
+        imported_public_export_hashes_offset as u32,
+        import_map_offset as u32,
+        export_map_offset as u32,
+        export_bundle_entries_offset as u32,
+        graph_data_offset as u32,
+    ];
+    for (i, value) in summary.into_iter().enumerate() {
+        data[i * 4..i * 4 + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    for export_data in EXPORT_DATA {
+        data.extend(export_data);
+    }
+    data
+}
+
+#[test]
+fn zen_asset() -> Result<(), Error> {
+    for (zen_version, engine_version) in [
+        (None, EngineVersion::VER_UE5_1),
+        (None, EngineVersion::VER_UE5_2),
+        (Some(EZenPackageVersion::Initial), EngineVersion::VER_UE5_0),
+        (
+            Some(EZenPackageVersion::DataResourceTable),
+            EngineVersion::VER_UE5_2,
+        ),
+    ] {
+        let data = zen_package(zen_version, engine_version);
+        let mut asset = ZenAsset::new(Cursor::new(data.as_slice()), engine_version)?;
+
+        assert_eq!(
+            asset.versioning_info.as_ref().map(|e| e.zen_version),
+            zen_version
+        );
+        assert_eq!(
+            asset.bulk_data_map.len(),
+            (engine_version >= EngineVersion::VER_UE5_2) as usize
+        );
+        assert_eq!(asset.name.get_owned_content(), "/Game/Test");
+        assert_eq!(asset.package_flags, EPackageFlags::PKG_FILTER_EDITOR_ONLY);
+        assert_eq!(asset.get_name_map().get_ref().len(), NAMES.len());
+        assert_eq!(asset.imported_public_export_hashes, vec![0x1234]);
+        assert_eq!(asset.import_map[0].package_import(), Some((1, 0)));
+
+        let names = asset
+            .export_map
+            .iter()
+            .map(|e| e.object_name.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Test", "Widget_1"]);
+        assert!(asset.export_map[0].outer_index.is_null());
+        assert_eq!(asset.export_map[1].outer_index.export_index(), Some(0));
+        assert_eq!(
+            asset.export_map[0].class_index.index_type(),
+            EPackageObjectIndexType::PackageImport
+        );
+
+        assert_eq!(asset.export_bundle_entries.len(), 4);
+        assert_eq!(
+            asset.export_bundle_entries[2].command_type,
+            EExportCommandType::Serialize
+        );
+        assert_eq!(asset.export_bundle_headers.len(), 1);
+        assert!(asset.internal_arcs.is_empty());
+        assert_eq!(
+            asset.external_arcs,
+            vec![vec![ExternalArc {
+                from_import_index: 0,
+                to_export_bundle_index: 0
+            }]]
+        );
+
+        for (i, export_data) in EXPORT_DATA.iter().enumerate() {
+            assert_eq!(&asset.read_export_data(i)?, export_data);
+        }
+        assert!(asset.read_export_data(2).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn zen_asset_invalid() {
+    assert!(matches!(
+        ZenAsset::new(Cursor::new(shared::BROAD_BRUSH), EngineVersion::VER_UE5_1),
+        Err(Error::InvalidFile(_))
+    ));
+
+    let data = zen_package(
+        Some(EZenPackageVersion::ImportedPackageNames),
+        EngineVersion::VER_UE5_2,
+    );
+    assert!(matches!(
+        ZenAsset::new(Cursor::new(data.as_slice()), EngineVersion::VER_UE5_2),
+        Err(Error::Unimplemented(_))
+    ));
+
+    // export data past the end of the file
+    let mut data = zen_package(None, EngineVersion::VER_UE5_1);
+    data.pop();
+    let mut asset = ZenAsset::new(Cursor::new(data.as_slice()), EngineVersion::VER_UE5_1).unwrap();
+    assert!(asset.read_export_data(0).is_ok());
+    assert!(asset.read_export_data(1).is_err());
+
+    // export bundle entries serializing an export that isn't in the export map
+    let mut data = zen_package(None, EngineVersion::VER_UE5_1);
+    let export_bundle_entries_offset =
+        u32::from_le_bytes(data[36..40].try_into().unwrap()) as usize;
+    let entry = export_bundle_entries_offset + 2 * 8;
+    data[entry..entry + 4].copy_from_slice(&5u32.to_le_bytes());
+    let mut asset = ZenAsset::new(Cursor::new(data.as_slice()), EngineVersion::VER_UE5_1).unwrap();
+    assert!(matches!(
+        asset.read_export_data(5),
+        Err(Error::InvalidFile(_))
+    ));
+
+    assert_eq!(
+        PackageObjectIndex::NULL.index_type(),
+        EPackageObjectIndexType::Null
+    );
+}
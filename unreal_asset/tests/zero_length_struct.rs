@@ -0,0 +1,63 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{struct_property::StructProperty, Property, PropertyDataTrait},
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+mod shared;
+
+#[test]
+fn zero_length_struct() -> Result<(), Error> {
+    let mut asset = shared::read_broad_brush()?;
+    asset.add_fname("StructProperty");
+
+    // Vector is custom serialized, an empty one has no value to write
+    let property = StructProperty {
+        name: asset.add_fname("EmptyOffset"),
+        ancestry: Ancestry::default(),
+        struct_type: Some(asset.add_fname("Vector")),
+        struct_guid: Some([0u8; 16].into()),
+        property_guid: None,
+        duplication_index: 0,
+        serialize_none: false,
+        value: Vec::new(),
+    };
+
+    let export = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .find_map(|e| e.get_normal_export_mut())
+        .expect("No normal export");
+    export.properties.push(property.into());
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let asset = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+    let read = asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| e.get_normal_export())
+        .and_then(|e| {
+            e.properties
+                .iter()
+                .find(|e| e.get_name().get_owned_content() == "EmptyOffset")
+        })
+        .and_then(|e| cast!(Property, StructProperty, e))
+        .expect("Struct property was not read back");
+
+    assert!(read.value.is_empty());
+    assert!(read
+        .struct_type
+        .as_ref()
+        .is_some_and(|e| e.get_owned_content() == "Vector"));
+
+    Ok(())
+}
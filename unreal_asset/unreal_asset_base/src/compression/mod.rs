@@ -1,10 +1,16 @@
-//! Unreal decompression
+//! Unreal compression and decompression
 
-use std::io::Read;
+use std::io::{Read, Seek, SeekFrom, Write};
 
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use flate2::bufread::{GzDecoder, ZlibDecoder};
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
 
-use crate::Error;
+use crate::{Error, UE4_ASSET_MAGIC};
+
+#[cfg(feature = "oodle")]
+pub mod oodle;
 
 /// Compression method
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
@@ -18,6 +24,12 @@ pub enum CompressionMethod {
     Gzip,
     /// Lz4 compression
     Lz4,
+    /// Oodle compression
+    #[cfg(feature = "oodle")]
+    Oodle,
+    /// Zstandard compression
+    #[cfg(feature = "zstd")]
+    Zstd,
     /// Unknown compression format
     Unknown(Box<str>),
 }
@@ -30,6 +42,10 @@ impl CompressionMethod {
             "Zlib" => Self::Zlib,
             "Gzip" => Self::Gzip,
             "LZ4" => Self::Lz4,
+            #[cfg(feature = "oodle")]
+            "Oodle" => Self::Oodle,
+            #[cfg(feature = "zstd")]
+            "Zstd" => Self::Zstd,
             _ => Self::Unknown(name.to_string().into_boxed_str()),
         }
     }
@@ -42,6 +58,10 @@ impl ToString for CompressionMethod {
             CompressionMethod::Zlib => String::from("Zlib"),
             CompressionMethod::Gzip => String::from("Gzip"),
             CompressionMethod::Lz4 => String::from("LZ4"),
+            #[cfg(feature = "oodle")]
+            CompressionMethod::Oodle => String::from("Oodle"),
+            #[cfg(feature = "zstd")]
+            CompressionMethod::Zstd => String::from("Zstd"),
             CompressionMethod::Unknown(e) => e.to_string(),
         }
     }
@@ -64,6 +84,321 @@ pub fn decompress(
             lz4_flex::block::decompress_into(compressed, decompressed)?;
             Ok(())
         }
+        #[cfg(feature = "oodle")]
+        CompressionMethod::Oodle => oodle::decompress(compressed, decompressed),
+        #[cfg(feature = "zstd")]
+        CompressionMethod::Zstd => {
+            // zstd frames don't always store their size, the data must fill the output exactly
+            let mut decoder = zstd::stream::read::Decoder::with_buffer(compressed)?;
+            let mut read = 0;
+            while read < decompressed.len() {
+                match decoder.read(&mut decompressed[read..])? {
+                    0 => break,
+                    len => read += len,
+                }
+            }
+            if read < decompressed.len() {
+                return Err(Error::invalid_file(format!(
+                    "Zstd data decompressed to {} bytes, expected {}",
+                    read,
+                    decompressed.len()
+                )));
+            }
+            if decoder.read(&mut [0u8; 1])? != 0 {
+                return Err(Error::invalid_file(format!(
+                    "Zstd data decompressed to more than the expected {} bytes",
+                    decompressed.len()
+                )));
+            }
+            Ok(())
+        }
+        CompressionMethod::Unknown(name) => Err(Error::UnknownCompressionMethod(name)),
+    }
+}
+
+/// Compress data with the given compression method
+///
+/// `level` is the compression level from 0 to 9 for Zlib and Gzip and from 0 to 22 for Zstd,
+/// it's ignored by other methods.
+/// Lz4 data is compressed as a raw block, which is what [`decompress`] expects.
+/// Methods that can't be encoded return [`Error::UnknownCompressionMethod`]
+pub fn compress(method: CompressionMethod, input: &[u8], level: u32) -> Result<Vec<u8>, Error> {
+    match method {
+        CompressionMethod::None => Ok(input.to_vec()),
+        CompressionMethod::Zlib => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(level.min(9)));
+            encoder.write_all(input)?;
+            Ok(encoder.finish()?)
+        }
+        CompressionMethod::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level.min(9)));
+            encoder.write_all(input)?;
+            Ok(encoder.finish()?)
+        }
+        CompressionMethod::Lz4 => Ok(lz4_flex::block::compress(input)),
+        #[cfg(feature = "oodle")]
+        CompressionMethod::Oodle => Err(Error::UnknownCompressionMethod(
+            method.to_string().into_boxed_str(),
+        )),
+        #[cfg(feature = "zstd")]
+        CompressionMethod::Zstd => Ok(zstd::bulk::compress(input, level.min(22) as i32)?),
         CompressionMethod::Unknown(name) => Err(Error::UnknownCompressionMethod(name)),
     }
 }
+
+/// Package file tag as stored in a little endian [`CompressedChunkInfo`]
+const PACKAGE_FILE_TAG: i64 = UE4_ASSET_MAGIC.swap_bytes() as i64;
+
+/// Default compression chunk size, used by tables which don't specify a chunk size
+pub const LOADING_COMPRESSION_CHUNK_SIZE: i64 = 0x20000;
+
+/// Compressed chunk info
+///
+/// Compressed and uncompressed size pair stored before compressed data
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompressedChunkInfo {
+    /// Compressed size
+    pub compressed_size: i64,
+    /// Uncompressed size
+    pub uncompressed_size: i64,
+}
+
+impl CompressedChunkInfo {
+    /// Size of a serialized `CompressedChunkInfo`
+    pub const SERIALIZED_SIZE: u64 = 16;
+
+    /// Create a new `CompressedChunkInfo` instance
+    pub fn new(compressed_size: i64, uncompressed_size: i64) -> Self {
+        CompressedChunkInfo {
+            compressed_size,
+            uncompressed_size,
+        }
+    }
+
+    /// Read a `CompressedChunkInfo` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let compressed_size = reader.read_i64::<LE>()?;
+        let uncompressed_size = reader.read_i64::<LE>()?;
+
+        Ok(CompressedChunkInfo {
+            compressed_size,
+            uncompressed_size,
+        })
+    }
+
+    /// Write a `CompressedChunkInfo` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_i64::<LE>(self.compressed_size)?;
+        writer.write_i64::<LE>(self.uncompressed_size)?;
+        Ok(())
+    }
+}
+
+/// Compressed chunk table
+///
+/// Stored before compressed data, describes the size of every compressed chunk
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct CompressedChunkTable {
+    /// Uncompressed size of a single chunk
+    pub chunk_size: i64,
+    /// Total compressed and uncompressed size of all chunks
+    pub summary: CompressedChunkInfo,
+    /// Chunks
+    pub chunks: Vec<CompressedChunkInfo>,
+}
+
+impl CompressedChunkTable {
+    /// Create a new `CompressedChunkTable` from a list of chunks
+    pub fn new(chunk_size: i64, chunks: Vec<CompressedChunkInfo>) -> Self {
+        let summary = chunks
+            .iter()
+            .fold(CompressedChunkInfo::default(), |summary, chunk| {
+                CompressedChunkInfo::new(
+                    summary.compressed_size + chunk.compressed_size,
+                    summary.uncompressed_size + chunk.uncompressed_size,
+                )
+            });
+
+        CompressedChunkTable {
+            chunk_size,
+            summary,
+            chunks,
+        }
+    }
+
+    /// Get the amount of chunks the summary's uncompressed size is split into
+    ///
+    /// Shared by reading and writing, so both agree on the table length.
+    /// Returns `None` if the chunk size or the uncompressed size is invalid
+    pub fn chunk_count(&self) -> Option<i64> {
+        if self.chunk_size <= 0 || self.summary.uncompressed_size < 0 {
+            return None;
+        }
+
+        self.summary
+            .uncompressed_size
+            .checked_add(self.chunk_size - 1)
+            .map(|e| e / self.chunk_size)
+    }
+
+    /// Read a `CompressedChunkTable` from a reader
+    ///
+    /// The chunk count is checked against the bytes left in the reader before reading the chunks
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let package_file_tag = CompressedChunkInfo::read(reader)?;
+        if package_file_tag.compressed_size != PACKAGE_FILE_TAG {
+            return Err(Error::invalid_file(format!(
+                "Invalid compressed chunk table tag {:#x}",
+                package_file_tag.compressed_size
+            )));
+        }
+
+        // older tables store the package file tag in place of the chunk size
+        let chunk_size = match package_file_tag.uncompressed_size {
+            PACKAGE_FILE_TAG => LOADING_COMPRESSION_CHUNK_SIZE,
+            chunk_size => chunk_size,
+        };
+
+        let mut table = CompressedChunkTable {
+            chunk_size,
+            summary: CompressedChunkInfo::read(reader)?,
+            chunks: Vec::new(),
+        };
+        let chunk_count = table.chunk_count().ok_or_else(|| {
+            Error::invalid_file(format!(
+                "Invalid compressed chunk table with chunk size {} and uncompressed size {}",
+                table.chunk_size, table.summary.uncompressed_size
+            ))
+        })?;
+
+        let position = reader.stream_position()?;
+        let remaining = reader.seek(SeekFrom::End(0))?.saturating_sub(position);
+        reader.seek(SeekFrom::Start(position))?;
+        if chunk_count as u64 > remaining / CompressedChunkInfo::SERIALIZED_SIZE {
+            return Err(Error::invalid_file(format!(
+                "Compressed chunk table has {chunk_count} chunks, but only {remaining} bytes are left"
+            )));
+        }
+
+        table.chunks = (0..chunk_count)
+            .map(|_| CompressedChunkInfo::read(reader))
+            .collect::<Result<_, _>>()?;
+        Ok(table)
+    }
+
+    /// Write a `CompressedChunkTable` to a writer
+    ///
+    /// Fails if the amount of chunks doesn't match the summary's uncompressed size
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let chunk_count = self.chunk_count().ok_or_else(|| {
+            Error::invalid_write(format!(
+                "Invalid compressed chunk table with chunk size {} and uncompressed size {}",
+                self.chunk_size, self.summary.uncompressed_size
+            ))
+        })?;
+        if self.chunks.len() as i64 != chunk_count {
+            return Err(Error::invalid_write(format!(
+                "Compressed chunk table has {} chunks, but its uncompressed size needs {chunk_count}",
+                self.chunks.len()
+            )));
+        }
+
+        CompressedChunkInfo::new(PACKAGE_FILE_TAG, self.chunk_size).write(writer)?;
+        self.summary.write(writer)?;
+        for chunk in &self.chunks {
+            chunk.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read a [`CompressedChunkTable`] and the chunks following it and decompress them
+///
+/// Chunk sizes are checked against the table and the bytes left in the reader before decompressing
+pub fn read_compressed<R: Read + Seek>(
+    reader: &mut R,
+    method: CompressionMethod,
+) -> Result<Vec<u8>, Error> {
+    let table = CompressedChunkTable::read(reader)?;
+
+    let position = reader.stream_position()?;
+    let remaining = reader.seek(SeekFrom::End(0))?.saturating_sub(position);
+    reader.seek(SeekFrom::Start(position))?;
+
+    let mut compressed_total = 0u64;
+    let mut uncompressed_total = 0u64;
+    for chunk in &table.chunks {
+        if chunk.compressed_size < 0
+            || chunk.uncompressed_size < 0
+            || chunk.uncompressed_size > table.chunk_size
+        {
+            return Err(Error::invalid_file(format!(
+                "Invalid compressed chunk with compressed size {} and uncompressed size {}",
+                chunk.compressed_size, chunk.uncompressed_size
+            )));
+        }
+        compressed_total += chunk.compressed_size as u64;
+        uncompressed_total += chunk.uncompressed_size as u64;
+    }
+    if uncompressed_total != table.summary.uncompressed_size as u64 {
+        return Err(Error::invalid_file(format!(
+            "Compressed chunks decompress to {uncompressed_total} bytes, but the table summary has {}",
+            table.summary.uncompressed_size
+        )));
+    }
+    if compressed_total > remaining {
+        return Err(Error::invalid_file(format!(
+            "Compressed chunks have {compressed_total} bytes, but only {remaining} bytes are left"
+        )));
+    }
+
+    let mut data = vec![0u8; uncompressed_total as usize];
+    let mut offset = 0;
+    let mut compressed = Vec::new();
+    for chunk in &table.chunks {
+        compressed.resize(chunk.compressed_size as usize, 0);
+        reader.read_exact(&mut compressed)?;
+
+        let end = offset + chunk.uncompressed_size as usize;
+        decompress(method.clone(), &compressed, &mut data[offset..end])?;
+        offset = end;
+    }
+
+    Ok(data)
+}
+
+/// Split data into chunks, compress them and write them after a [`CompressedChunkTable`]
+///
+/// `level` is passed to [`compress`] for every chunk
+pub fn write_compressed<W: Write>(
+    writer: &mut W,
+    method: CompressionMethod,
+    data: &[u8],
+    chunk_size: i64,
+    level: u32,
+) -> Result<(), Error> {
+    if chunk_size <= 0 {
+        return Err(Error::invalid_write(format!(
+            "Invalid compression chunk size {chunk_size}"
+        )));
+    }
+
+    let compressed = data
+        .chunks(chunk_size as usize)
+        .map(|e| compress(method.clone(), e, level))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let chunks = compressed
+        .iter()
+        .zip(data.chunks(chunk_size as usize))
+        .map(|(compressed, uncompressed)| {
+            CompressedChunkInfo::new(compressed.len() as i64, uncompressed.len() as i64)
+        })
+        .collect();
+    CompressedChunkTable::new(chunk_size, chunks).write(writer)?;
+
+    for chunk in compressed {
+        writer.write_all(&chunk)?;
+    }
+    Ok(())
+}
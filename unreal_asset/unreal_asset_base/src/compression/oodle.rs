@@ -0,0 +1,144 @@
+//! Oodle decompression
+//!
+//! Oodle can't be redistributed, the `oo2core` library is loaded at runtime instead.
+//! Use [`load_library`] to load it from a game's install directory,
+//! otherwise the default library names are searched for once on first use.
+
+use std::ffi::{c_void, OsStr};
+use std::sync::{Arc, Mutex};
+
+use libloading::Library;
+
+use crate::Error;
+
+/// `OodleLZ_Decompress` function signature
+type OodleLzDecompress = unsafe extern "C" fn(
+    comp_buf: *const u8,
+    comp_buf_size: isize,
+    raw_buf: *mut u8,
+    raw_len: isize,
+    fuzz_safe: i32,
+    check_crc: i32,
+    verbosity: i32,
+    dec_buf_base: *mut u8,
+    dec_buf_size: isize,
+    callback: *const c_void,
+    callback_user_data: *mut c_void,
+    decoder_memory: *mut c_void,
+    decoder_memory_size: isize,
+    thread_phase: i32,
+) -> isize;
+
+/// `OodleLZ_Decode_Unthreaded` thread phase
+const DECODE_UNTHREADED: i32 = 3;
+
+/// Library names searched for when no library was loaded explicitly
+#[cfg(windows)]
+const DEFAULT_LIBRARY_NAMES: &[&str] = &["oo2core_9_win64.dll", "oo2core_8_win64.dll"];
+/// Library names searched for when no library was loaded explicitly
+#[cfg(not(windows))]
+const DEFAULT_LIBRARY_NAMES: &[&str] = &[
+    "liboo2corelinux64.so.9",
+    "liboo2corelinux64.so",
+    "liboo2core.so",
+];
+
+/// Loaded oodle library
+struct Oodle {
+    /// Library handle, keeps `decompress` valid
+    _library: Library,
+    /// `OodleLZ_Decompress` function
+    decompress: OodleLzDecompress,
+}
+
+/// State of the oodle library
+enum State {
+    /// The default library names weren't searched for yet
+    Unsearched,
+    /// None of the default library names were found, they aren't searched for again
+    NotFound,
+    /// A library is loaded
+    Loaded(Arc<Oodle>),
+}
+
+/// Currently loaded oodle library
+static OODLE: Mutex<State> = Mutex::new(State::Unsearched);
+
+/// Open an oodle library and look up `OodleLZ_Decompress`
+fn open(path: &OsStr) -> Result<Oodle, Error> {
+    let not_available = |e: libloading::Error| {
+        Error::OodleNotAvailable(format!("{}: {e}", path.to_string_lossy()).into_boxed_str())
+    };
+
+    // SAFETY: loading oo2core doesn't run any initialization with preconditions
+    let library = unsafe { Library::new(path) }.map_err(not_available)?;
+    // SAFETY: `OodleLzDecompress` matches the exported function signature
+    let decompress = *unsafe { library.get::<OodleLzDecompress>(b"OodleLZ_Decompress\0") }
+        .map_err(not_available)?;
+
+    Ok(Oodle {
+        _library: library,
+        decompress,
+    })
+}
+
+/// Load the oodle library from a path, replacing any previously loaded library
+pub fn load_library(path: impl AsRef<OsStr>) -> Result<(), Error> {
+    let oodle = open(path.as_ref())?;
+    *OODLE.lock().unwrap_or_else(|e| e.into_inner()) = State::Loaded(Arc::new(oodle));
+    Ok(())
+}
+
+/// Check if an oodle library is loaded
+pub fn is_loaded() -> bool {
+    matches!(
+        *OODLE.lock().unwrap_or_else(|e| e.into_inner()),
+        State::Loaded(_)
+    )
+}
+
+/// Decompress an oodle compressed buffer
+///
+/// If no library was loaded with [`load_library`], the default library names are tried once,
+/// [`Error::OodleNotInitialized`] is returned if none of them were found
+pub fn decompress(compressed: &[u8], decompressed: &mut [u8]) -> Result<(), Error> {
+    // the lock is only held to get the library, decompression runs without it
+    let oodle = {
+        let mut state = OODLE.lock().unwrap_or_else(|e| e.into_inner());
+        if let State::Unsearched = *state {
+            *state = DEFAULT_LIBRARY_NAMES
+                .iter()
+                .find_map(|name| open(OsStr::new(name)).ok())
+                .map_or(State::NotFound, |e| State::Loaded(Arc::new(e)));
+        }
+        match &*state {
+            State::Loaded(oodle) => oodle.clone(),
+            State::Unsearched | State::NotFound => return Err(Error::OodleNotInitialized),
+        }
+    };
+
+    // SAFETY: buffers are valid for their lengths and oodle doesn't write past `raw_len`
+    let decompressed_count = unsafe {
+        (oodle.decompress)(
+            compressed.as_ptr(),
+            compressed.len() as isize,
+            decompressed.as_mut_ptr(),
+            decompressed.len() as isize,
+            1,
+            0,
+            0,
+            std::ptr::null_mut(),
+            0,
+            std::ptr::null(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            0,
+            DECODE_UNTHREADED,
+        )
+    };
+
+    if decompressed_count != decompressed.len() as isize {
+        return Err(Error::Oodle);
+    }
+    Ok(())
+}
@@ -1,92 +1,117 @@
-//! Chain for chaining two `Read` + `Seek` implementations
+//! Chain for chaining `Read` + `Seek` implementations
 
-use std::io::{Read, Result, Seek, SeekFrom};
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
 
-/// Chain for chaining two `Read` + `Seek` implementations
+/// Segment of a [`Chain`]
+struct Segment<C: Read + Seek> {
+    /// Segment reader
+    reader: C,
+    /// Offset of the segment's start in the chain
+    start: u64,
+    /// Segment length
+    len: u64,
+}
+
+/// Chain for chaining `Read` + `Seek` implementations
+///
+/// Packages can be split into multiple files, e.g. `.uasset`, `.uexp` and `.uptnl`,
+/// a chain reads them as if they were one file
 pub struct Chain<C: Read + Seek> {
-    first: C,
-    second: Option<C>,
-    first_len: u64,
-    second_len: u64,
+    segments: Vec<Segment<C>>,
+    /// Index of the segment whose reader is positioned at `pos`
+    current: Option<usize>,
+    len: u64,
     pos: u64,
 }
 
 impl<C: Read + Seek> Chain<C> {
-    /// Create a new chain
-    pub fn new(mut first: C, mut second: Option<C>) -> Self {
-        // ignore errors for now
-        let first_len = first.seek(SeekFrom::End(0)).unwrap_or_default();
-        first.rewind().unwrap_or_default();
-        let second_len = match second.as_mut() {
-            Some(sec) => {
-                let len = sec.seek(SeekFrom::End(0)).unwrap_or_default();
-                sec.rewind().unwrap_or_default();
-                len
-            }
-            None => 0,
-        };
+    /// Create a new chain of a file and an optional file following it
+    pub fn new(first: C, second: Option<C>) -> Self {
+        Self::from_segments(std::iter::once(first).chain(second))
+    }
+
+    /// Create a new chain from segments in file order
+    pub fn from_segments(readers: impl IntoIterator<Item = C>) -> Self {
+        let mut segments = Vec::new();
+        let mut len = 0;
+        for mut reader in readers {
+            // ignore errors for now
+            let segment_len = reader.seek(SeekFrom::End(0)).unwrap_or_default();
+            reader.rewind().unwrap_or_default();
+            segments.push(Segment {
+                reader,
+                start: len,
+                len: segment_len,
+            });
+            len += segment_len;
+        }
+
         Self {
-            first,
-            second,
-            first_len,
-            second_len,
+            segments,
+            current: None,
+            len,
             pos: 0,
         }
     }
+
+    /// Get the amount of segments
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Get the offset of a segment's start in the chain
+    pub fn segment_start(&self, index: usize) -> Option<u64> {
+        self.segments.get(index).map(|e| e.start)
+    }
+
+    /// Get the index of the segment containing `offset`, `None` past the end of the last segment
+    fn segment_at(&self, offset: u64) -> Option<usize> {
+        self.segments.iter().position(|e| offset < e.start + e.len)
+    }
 }
 
 impl<C: Read + Seek> Read for Chain<C> {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
-        match self.second.as_mut() {
-            Some(sec) => {
-                let len_read = match self.pos >= self.first_len {
-                    true => sec.read(buf)?,
-                    false => {
-                        let len = buf.len();
-                        let to_end = (self.first_len - self.pos) as usize;
-                        match to_end >= len {
-                            true => self.first.read(buf)?,
-                            false => {
-                                let mut first = vec![0; to_end];
-                                let mut second = vec![0; len - to_end];
-                                self.first.read_exact(&mut first)?;
-                                sec.read_exact(&mut second)?;
-                                first.append(&mut second);
-                                first.as_slice().read(buf)?
-                            }
-                        }
-                    }
-                };
-                self.pos += len_read as u64;
-                Ok(len_read)
-            }
-            None => self.first.read(buf),
+        let Some(index) = self.segment_at(self.pos) else {
+            return Ok(0);
+        };
+
+        let segment = &mut self.segments[index];
+        if self.current != Some(index) {
+            segment
+                .reader
+                .seek(SeekFrom::Start(self.pos - segment.start))?;
+            self.current = Some(index);
         }
+
+        // reads don't cross segments, the next read continues in the next segment
+        let to_end = segment.start + segment.len - self.pos;
+        let len = buf.len().min(to_end as usize);
+        let len_read = segment.reader.read(&mut buf[..len])?;
+        self.pos += len_read as u64;
+        Ok(len_read)
     }
 }
 
 impl<C: Read + Seek> Seek for Chain<C> {
     fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
-        match self.second.as_mut() {
-            Some(sec) => match pos {
-                SeekFrom::Start(offset) => {
-                    self.pos = match offset < self.first_len {
-                        true => self.first.seek(pos)?,
-                        false => {
-                            self.first_len + sec.seek(SeekFrom::Start(offset - self.first_len))?
-                        }
-                    };
-                    Ok(offset)
-                }
-                SeekFrom::End(offset) => self.seek(SeekFrom::Start(
-                    ((self.first_len + self.second_len) as i64 + offset) as u64,
-                )),
-                SeekFrom::Current(offset) => {
-                    self.seek(SeekFrom::Start((self.pos as i64 + offset) as u64))
-                }
-            },
-            None => self.first.seek(pos),
+        let pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
         }
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        if pos != self.pos {
+            self.current = None;
+            self.pos = pos;
+        }
+        Ok(pos)
     }
 }
 
@@ -123,3 +148,32 @@ fn seek() {
     assert_eq!(read_at(SeekFrom::End(-4)).unwrap(), 4);
     assert!(read_at(SeekFrom::End(-12)).is_err());
 }
+
+#[test]
+fn segments() {
+    use std::io::Cursor;
+    let mut chain = Chain::from_segments([
+        Cursor::new(vec![0, 1, 2]),
+        Cursor::new(vec![]),
+        Cursor::new(vec![3, 4]),
+        Cursor::new(vec![5, 6, 7, 8]),
+    ]);
+    assert_eq!(chain.segment_count(), 4);
+    assert_eq!(chain.segment_start(2), Some(3));
+    assert_eq!(chain.segment_start(3), Some(5));
+    assert_eq!(chain.segment_start(4), None);
+
+    let mut buf = [0; 5];
+    chain.seek(SeekFrom::Start(2)).unwrap();
+    chain.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [2, 3, 4, 5, 6]);
+
+    chain.seek(SeekFrom::End(-1)).unwrap();
+    let mut v = Vec::new();
+    chain.read_to_end(&mut v).unwrap();
+    assert_eq!(v, [8]);
+    assert_eq!(chain.stream_position().unwrap(), 9);
+
+    chain.seek(SeekFrom::Start(20)).unwrap();
+    assert!(chain.read_exact(&mut buf[..1]).is_err());
+}
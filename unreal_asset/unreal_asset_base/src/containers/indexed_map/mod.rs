@@ -535,6 +535,46 @@ where
 
 impl<K, V> FusedIterator for Keys<'_, K, V> where K: Eq + Hash {}
 
+#[derive(Clone)]
+pub struct KeyValues<'map, K, V>
+where
+    K: Eq + Hash,
+{
+    inner: IndexedMapIndexIterator<'map, K, V>,
+}
+
+impl<'map, K, V> Iterator for KeyValues<'map, K, V>
+where
+    K: Eq + Hash,
+{
+    type Item = (&'map K, &'map V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, k, v)| (k, v))
+    }
+}
+
+impl<'map, K, V> DoubleEndedIterator for KeyValues<'map, K, V>
+where
+    K: Eq + Hash,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, k, v)| (k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for KeyValues<'_, K, V>
+where
+    K: Eq + Hash,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for KeyValues<'_, K, V> where K: Eq + Hash {}
+
 impl<'map, K, V> IndexedMap<K, V>
 where
     K: Eq + Hash,
@@ -663,6 +703,23 @@ where
         Keys { inner: self.iter() }
     }
 
+    /// Returns an iterator over key-value pairs in insertion index order
+    pub fn iter_key_value(&'map self) -> KeyValues<'map, K, V> {
+        KeyValues { inner: self.iter() }
+    }
+
+    /// Retains only the entries for which `f` returns `true`
+    ///
+    /// Insertion order is preserved, insertion indices of the retained entries are renumbered from 0
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        let entries = std::mem::replace(self, IndexedMap::new());
+        for (_, key, mut value) in entries {
+            if f(&key, &mut value) {
+                self.insert(key, value);
+            }
+        }
+    }
+
     /// Removes a value by store place
     /// If the value existed in the store and the key did not have any
     /// references to it, the Some((index, key, value)) pair is returned,
@@ -858,3 +915,34 @@ where
         Self::from_iter(value)
     }
 }
+
+/// Serialized as a sequence of key-value pairs, keys don't have to be strings
+#[cfg(feature = "serde")]
+impl<K, V> serde::Serialize for IndexedMap<K, V>
+where
+    K: Eq + Hash + serde::Serialize,
+    V: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter_key_value())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for IndexedMap<K, V>
+where
+    K: Eq + Hash + serde::Deserialize<'de>,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Vec::<(K, V)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
@@ -8,6 +8,7 @@ use crate::containers::{
     shared_resource::{CyclicSharedResource, SharedResource, SharedResourceWeakRef},
 };
 use crate::types::fname::{EMappedNameType, FName};
+use crate::Error;
 
 /// Asset name map
 #[derive(Debug, Clone)]
@@ -63,17 +64,41 @@ impl NameMap {
         name.hash(&mut s);
 
         let hash = s.finish();
-        self.name_map_index_list.push(name.clone());
+        self.name_map_index_list.push(name);
         self.name_map_lookup
             .insert(hash, (self.name_map_index_list.len() - 1) as i32);
         (self.name_map_index_list.len() - 1) as i32
     }
 
+    /// Add FName references in bulk
+    ///
+    /// Names are appended without searching for duplicates,
+    /// same as calling `add_name_reference` with `force_add_duplicates` for every name
+    pub fn add_name_references(&mut self, names: Vec<String>) {
+        self.name_map_index_list.reserve(names.len());
+
+        for name in names {
+            let mut s = DefaultHasher::new();
+            name.hash(&mut s);
+
+            self.name_map_index_list.push(name);
+            self.name_map_lookup
+                .insert(s.finish(), (self.name_map_index_list.len() - 1) as i32);
+        }
+    }
+
     /// Get all FNames
     pub fn get_name_map_index_list(&self) -> &[String] {
         &self.name_map_index_list
     }
 
+    /// Iterate over all names with their FName map indices
+    pub fn iter(&self) -> NameMapIter<'_> {
+        NameMapIter {
+            names: self.name_map_index_list.iter().enumerate(),
+        }
+    }
+
     /// Get a name reference by an FName map index
     pub fn get_name_reference(&self, index: i32) -> &str {
         // to avoid the panic this could return an option instead
@@ -87,6 +112,14 @@ impl NameMap {
         &self.name_map_index_list[index as usize]
     }
 
+    /// Get a name reference by an FName map index, or `None` if the index is out of range
+    pub fn try_get_name_reference(&self, index: i32) -> Option<&str> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.name_map_index_list.get(index))
+            .map(|name| name.as_str())
+    }
+
     /// Get a name reference by an FName map index as a `String`
     pub fn get_owned_name(&self, index: i32) -> String {
         self.get_name_reference(index).to_string()
@@ -118,10 +151,61 @@ impl NameMap {
         self.create_fname(index, number)
     }
 
+    /// Add an `FName` from its string form, splitting off the number suffix
+    ///
+    /// `Name_0` is added as `Name` with a number of 1, see [`FName::from_display`]
+    pub fn add_fname_from_display(&mut self, value: &str) -> FName {
+        let name = FName::from_display(value);
+        name.get_content(|content| self.add_fname_with_number(content, name.get_number()))
+    }
+
+    /// Compute the difference between two name maps
+    ///
+    /// Returns the names only present in this name map and the names only present in `other`,
+    /// each in name map order
+    pub fn diff(&self, other: &NameMap) -> (Vec<String>, Vec<String>) {
+        let only_in = |a: &NameMap, b: &NameMap| {
+            a.name_map_index_list
+                .iter()
+                .filter(|e| b.search_name_reference(e).is_none())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        (only_in(self, other), only_in(other, self))
+    }
+
     /// Returns if the name map is empty
     pub fn is_empty(&self) -> bool {
         self.name_map_index_list.is_empty()
     }
+
+    /// Get the amount of names in the name map
+    pub fn len(&self) -> usize {
+        self.name_map_index_list.len()
+    }
+
+    /// Amount of names above which a name map is considered unexpectedly large
+    ///
+    /// Cooked assets rarely have more than a few thousand names
+    pub const LARGE_LEN: usize = 0x10000;
+
+    /// Returns if the name map has more than [`NameMap::LARGE_LEN`] names
+    pub fn is_unexpectedly_large(&self) -> bool {
+        self.name_map_index_list.len() > Self::LARGE_LEN
+    }
+
+    /// Get the amount of names in the name map as it gets serialized
+    ///
+    /// Returns an error if the name map is too large to be indexed by an `i32`
+    pub fn serialized_len(&self) -> Result<i32, Error> {
+        i32::try_from(self.name_map_index_list.len()).map_err(|_| {
+            Error::invalid_write(format!(
+                "Name map has {} names, which is more than can be serialized",
+                self.name_map_index_list.len()
+            ))
+        })
+    }
 }
 
 impl CyclicSharedResource<NameMap> for NameMap {
@@ -138,3 +222,25 @@ impl PartialEq for NameMap {
 }
 
 impl Eq for NameMap {}
+
+/// Iterator over the names of a [`NameMap`] with their FName map indices
+pub struct NameMapIter<'a> {
+    /// Names with their positions
+    names: std::iter::Enumerate<std::slice::Iter<'a, String>>,
+}
+
+impl<'a> Iterator for NameMapIter<'a> {
+    type Item = (i32, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.names
+            .next()
+            .map(|(index, name)| (index as i32, name.as_str()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.names.size_hint()
+    }
+}
+
+impl ExactSizeIterator for NameMapIter<'_> {}
@@ -106,6 +106,13 @@ impl<T> SharedResource<T> {
     }
 }
 
+impl<T: ?Sized> SharedResource<T> {
+    /// Check if two `SharedResource`s point to the same value
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.deref(), other.deref())
+    }
+}
+
 impl<T: CyclicSharedResource<T> + Clone> SharedResource<T> {
     /// Clone this shared resource with the value inside of it
     pub fn clone_resource(&self) -> SharedResource<T> {
@@ -1,5 +1,7 @@
 //! CRC implementation
 
+use std::hash::Hasher;
+
 use lazy_static::lazy_static;
 use naive_cityhash::cityhash64;
 
@@ -188,6 +190,45 @@ pub fn cityhash64_to_lower(string: &str) -> u64 {
     cityhash64(aligned)
 }
 
+/// [`Hasher`] that computes a cityhash64 of all written data
+///
+/// Integers are written as little endian and `usize` is written as `u64`,
+/// so the hash does not depend on the platform or on the process.
+#[derive(Debug, Default, Clone)]
+pub struct CityHasher {
+    data: Vec<u8>,
+}
+
+impl Hasher for CityHasher {
+    fn finish(&self) -> u64 {
+        cityhash64(&self.data)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
 fn to_upper(character: u16) -> u16 {
     if character.saturating_sub('a' as u16) < 26u16 {
         (character as u8 as char).to_uppercase().next().unwrap() as u16
@@ -15,6 +15,8 @@ use crate::Guid;
 /// CustomVersions are engine "sub-versions"
 /// They are used to parse some propeties differently
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SerializedCustomVersion"))]
 pub struct CustomVersion {
     /// Custom veresion guid
     pub guid: Guid,
@@ -27,9 +29,25 @@ pub struct CustomVersion {
     /// # Example
     /// UE4_27 -> 13
     /// UE4_23 -> 12
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     pub version_mappings: &'static [(EngineVersion, i32)],
 }
 
+/// Serialized `CustomVersion`, the friendly name and version mappings are looked up from the guid
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedCustomVersion {
+    guid: Guid,
+    version: i32,
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedCustomVersion> for CustomVersion {
+    fn from(value: SerializedCustomVersion) -> Self {
+        CustomVersion::new(value.guid, value.version)
+    }
+}
+
 type VersionInfo = (String, Option<&'static [(EngineVersion, i32)]>);
 
 #[rustfmt::skip]
@@ -216,8 +234,10 @@ pub enum FFortniteMainBranchObjectVersion {
     /// Refactored cull distances to account for HLOD, explicit override and globals in priority
     /// Introduced: ObjectVersion.VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
     CullDistanceRefactorRemovedDefaultDistance,
+    /// HLODs are never culled by distance by default
     /// Introduced: ObjectVersion.VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
     CullDistanceRefactorNeverCullHlodsByDefault,
+    /// ALOD actors are never culled by distance by default
     /// Introduced: ObjectVersion.VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
     CullDistanceRefactorNeverCullAlodactorsByDefault,
 
@@ -439,8 +459,10 @@ pub enum FFortniteMainBranchObjectVersion {
     /// true for nodes predating this change.
     GravityOverrideDefinedInWorldSpace,
 
+    /// One past the latest version, used to calculate [`Self::LatestVersion`]
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
+    /// Latest known version
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion = (FFortniteMainBranchObjectVersion::VersionPlusOne as i32) + 1,
 }
@@ -617,8 +639,10 @@ pub enum FFrameworkObjectVersion {
     /// Introduced: ObjectVersion.VER_UE4_ADDED_PACKAGE_OWNER
     StoringUCSSerializationIndex,
 
+    /// One past the latest version, used to calculate [`Self::LatestVersion`]
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
+    /// Latest known version
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion = (FFrameworkObjectVersion::VersionPlusOne as i32) + 1,
 }
@@ -652,20 +676,26 @@ pub enum FCoreObjectVersion {
     /// Introduced: ObjectVersion.VER_UE4_OLDEST_LOADABLE_PACKAGE
     BeforeCustomVersionWasAdded = 0,
 
+    /// Material expression inputs are serialized natively instead of as tagged properties
     /// Introduced: ObjectVersion.VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG
     MaterialInputNativeSerialize,
 
+    /// Enum entry values are serialized as i64 instead of u8
     /// Introduced: ObjectVersion.VER_UE4_ADDED_SEARCHABLE_NAMES
     EnumProperties,
 
+    /// Skeletal mesh materials store a flag telling if the editor only imported material slot name follows
     /// Introduced: ObjectVersion.VER_UE4_FIX_WIDE_STRING_CRC
     SkeletalMaterialEditorDataStripping,
 
+    /// Struct exports serialize their properties as FProperties (`loaded_properties`) instead of UProperty children
     /// Introduced: ObjectVersion.VER_UE4_ADDED_PACKAGE_OWNER
     FProperties,
 
+    /// One past the latest version, used to calculate [`Self::LatestVersion`]
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
+    /// Latest known version
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion = (FCoreObjectVersion::VersionPlusOne as i32) + 1,
 }
@@ -851,8 +881,10 @@ pub enum FEditorObjectVersion {
     /// Introduced: ObjectVersion.VER_UE4_NON_OUTER_PACKAGE_IMPORT
     SkeletalMeshSourceDataSupport16bitOfMaterialNumber,
 
+    /// One past the latest version, used to calculate [`Self::LatestVersion`]
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
+    /// Latest known version
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion = (FEditorObjectVersion::VersionPlusOne as i32) + 1,
 }
@@ -956,8 +988,10 @@ pub enum FAnimPhysObjectVersion {
     /// Introduced: ObjectVersion.VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
     GeometryCacheAssetDeprecation,
 
+    /// One past the latest version, used to calculate [`Self::LatestVersion`]
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
+    /// Latest known version
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion = (FAnimPhysObjectVersion::VersionPlusOne as i32) + 1,
 }
@@ -1160,8 +1194,10 @@ pub enum FReleaseObjectVersion {
     /// Introduced: ObjectVersion.VER_UE4_CORRECT_LICENSEE_FLAG
     GeometryCollectionCacheRemovesMassToLocal,
 
+    /// One past the latest version, used to calculate [`Self::LatestVersion`]
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
+    /// Latest known version
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion = (FReleaseObjectVersion::VersionPlusOne as i32) + 1,
 }
@@ -1249,8 +1285,10 @@ pub enum FSequencerObjectVersion {
     /// Introduced: EngineVersion.VER_UE4_27
     SpawnableImprovements,
 
+    /// Latest known version
     /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION
     LatestVersion,
+    /// One past the latest version
     /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
     VersionPlusOne,
 }
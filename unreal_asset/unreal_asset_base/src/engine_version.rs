@@ -13,6 +13,7 @@ use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 #[derive(
     Debug, Hash, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, IntoPrimitive, TryFromPrimitive,
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i32)]
 #[allow(non_camel_case_types)]
 pub enum EngineVersion {
@@ -91,6 +92,15 @@ pub enum EngineVersion {
     VER_UE4_AUTOMATIC_VERSION_PLUS_ONE,
 }
 
+impl EngineVersion {
+    /// Get the custom versions this engine version ships with
+    ///
+    /// These are the same custom versions that get applied when setting an asset's engine version
+    pub fn default_custom_versions(self) -> Vec<CustomVersion> {
+        CustomVersion::get_default_custom_version_container(self)
+    }
+}
+
 lazy_static! {
     static ref OBJECT_VERSION_TO_ENGINE_VERSION: Vec<(ObjectVersion, EngineVersion)> = Vec::from([
         (
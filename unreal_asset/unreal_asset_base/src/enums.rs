@@ -7,6 +7,7 @@ pub const HASH_VERSION_CITYHASH64: u64 = 0x00000000C1640000;
 
 /// Array dimension
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i32)]
 pub enum EArrayDim {
     /// Not an array
@@ -19,6 +20,7 @@ pub enum EArrayDim {
 
 /// Property lifetime conditions
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ELifetimeCondition {
     /// This property has no condition, and will send anytime it changes
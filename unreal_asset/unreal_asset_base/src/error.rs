@@ -20,6 +20,9 @@ pub enum KismetError {
     /// Unknown kismet expression
     #[error("{0}")]
     UnknownExpression(Box<str>),
+    /// Kismet opcode is not supported
+    #[error("Unsupported kismet opcode {0:#04x}")]
+    UnsupportedOpcode(u8),
 }
 
 impl KismetError {
@@ -293,6 +296,49 @@ impl<T: TryFromPrimitive> From<TryFromPrimitiveError<T>> for IoStoreError {
     }
 }
 
+/// Kind of an [`Error`], used to handle errors without matching on messages
+///
+/// Codes returned by [`ErrorKind::code`] are stable and can be used by external tooling
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An I/O operation failed
+    Io,
+    /// The data is malformed or doesn't match the expected layout
+    InvalidData,
+    /// The data is compressed with a method that is unknown or not available
+    UnsupportedCompression,
+    /// The asset is unversioned and needs an engine version to be specified
+    EngineVersionRequired,
+    /// The asset has unversioned properties and needs usmap mappings
+    MappingsRequired,
+    /// The data is encrypted and no encryption key was provided
+    EncryptionKeyRequired,
+    /// A requested file doesn't exist
+    NotFound,
+    /// The data uses a feature this library doesn't implement
+    Unimplemented,
+    /// The data is inconsistent and can't be written
+    InvalidWrite,
+}
+
+impl ErrorKind {
+    /// Get a stable machine-readable code for this error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::Io => "io",
+            ErrorKind::InvalidData => "invalid_data",
+            ErrorKind::UnsupportedCompression => "unsupported_compression",
+            ErrorKind::EngineVersionRequired => "engine_version_required",
+            ErrorKind::MappingsRequired => "mappings_required",
+            ErrorKind::EncryptionKeyRequired => "encryption_key_required",
+            ErrorKind::NotFound => "not_found",
+            ErrorKind::Unimplemented => "unimplemented",
+            ErrorKind::InvalidWrite => "invalid_write",
+        }
+    }
+}
+
 /// Error type
 #[derive(Error, Debug)]
 pub enum Error {
@@ -326,6 +372,9 @@ pub enum Error {
     /// The file is invalid
     #[error("{0}")]
     InvalidFile(Box<str>),
+    /// The data is inconsistent and can't be written
+    #[error("{0}")]
+    InvalidWrite(Box<str>),
     /// A package index is invalid
     #[error("{0}")]
     InvalidPackageIndex(Box<str>),
@@ -335,6 +384,12 @@ pub enum Error {
     /// Part of the library is not implemented
     #[error("{0}")]
     Unimplemented(Box<str>),
+    /// An unversioned asset was read without specifying an engine version
+    #[error("Cannot begin serialization of an unversioned asset before an engine version is manually specified")]
+    NoEngineVersion,
+    /// An export failed to parse
+    #[error("Failed to parse export {0} of class {1}: {2}")]
+    ExportParse(i32, Box<str>, #[source] Box<Error>),
     /// A `KismetError` occured
     #[error(transparent)]
     Kismet(#[from] KismetError),
@@ -363,6 +418,9 @@ pub enum Error {
     /// Oodle library not initialized
     #[error("Oodle decompression library is not initialized")]
     OodleNotInitialized,
+    /// Oodle library couldn't be loaded
+    #[error("Oodle decompression library is not available: {0}")]
+    OodleNotAvailable(Box<str>),
 
     /// A `ZenError` occured
     #[error(transparent)]
@@ -370,6 +428,62 @@ pub enum Error {
 }
 
 impl Error {
+    /// Get the kind of this error
+    ///
+    /// Export parse errors report the kind of the error that caused them
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Io(_) => ErrorKind::Io,
+            Error::IoStore(IoStoreError::Io(_)) => ErrorKind::Io,
+
+            Error::UnknownCompressionMethod(_)
+            | Error::OodleNotInitialized
+            | Error::OodleNotAvailable(_)
+            | Error::Usmap(UsmapError::UnsupportedCompression(_)) => {
+                ErrorKind::UnsupportedCompression
+            }
+
+            Error::NoEngineVersion | Error::Zen(ZenError::NoObjectVersion) => {
+                ErrorKind::EngineVersionRequired
+            }
+
+            Error::Property(PropertyError::NoMappings)
+            | Error::Property(PropertyError::NoMapping(..))
+            | Error::Property(PropertyError::NoSchema(..))
+            | Error::Zen(ZenError::NoMappings) => ErrorKind::MappingsRequired,
+
+            Error::IoStore(IoStoreError::NoEncryptionKey) => ErrorKind::EncryptionKeyRequired,
+            Error::IoStore(IoStoreError::NoFile(_)) => ErrorKind::NotFound,
+
+            Error::Unimplemented(_) | Error::Kismet(KismetError::UnsupportedOpcode(_)) => {
+                ErrorKind::Unimplemented
+            }
+
+            Error::InvalidWrite(_) => ErrorKind::InvalidWrite,
+
+            Error::ExportParse(_, _, error) => error.kind(),
+
+            Error::FString(_)
+            | Error::Utf8(_)
+            | Error::Utf16(_)
+            | Error::NoData(_)
+            | Error::FName(_)
+            | Error::ArchiveTypeMismatch(..)
+            | Error::Cityhash64Collision(..)
+            | Error::NameBatchHashMismatch(..)
+            | Error::InvalidFile(_)
+            | Error::InvalidPackageIndex(_)
+            | Error::InvalidEnumValue(_)
+            | Error::Kismet(_)
+            | Error::Property(_)
+            | Error::Registry(_)
+            | Error::Usmap(_)
+            | Error::IoStore(_)
+            | Error::Lz4(_)
+            | Error::Oodle => ErrorKind::InvalidData,
+        }
+    }
+
     /// Create an `Error` for a case where expected data was not found
     pub fn no_data(msg: String) -> Self {
         Error::NoData(msg.into_boxed_str())
@@ -380,16 +494,31 @@ impl Error {
         Error::InvalidFile(msg.into_boxed_str())
     }
 
+    /// Create an `Error` when the data can't be written
+    pub fn invalid_write(msg: String) -> Self {
+        Error::InvalidWrite(msg.into_boxed_str())
+    }
+
     /// Create an `Error` when a package index is invalid
     pub fn invalid_package_index(msg: String) -> Self {
         Error::InvalidPackageIndex(msg.into_boxed_str())
     }
 
+    /// Create an `Error` when an export failed to parse
+    pub fn export_parse(export_index: i32, class_name: String, error: Error) -> Self {
+        Error::ExportParse(export_index, class_name.into_boxed_str(), Box::new(error))
+    }
+
     /// Create an `Error` when a part of the library is not implemented
     pub fn unimplemented(msg: String) -> Self {
         Error::Unimplemented(msg.into_boxed_str())
     }
 
+    /// Create an `Error` for a kismet opcode that isn't supported
+    pub fn unsupported_kismet_opcode(token: u8) -> Self {
+        Error::Kismet(KismetError::UnsupportedOpcode(token))
+    }
+
     /// Create an `Error` for a Cityhash64 hash collision
     pub fn cityhash64_collision(hash: u64, value: String) -> Self {
         Error::Cityhash64Collision(hash, value.into_boxed_str())
@@ -5,6 +5,7 @@ use bitflags::bitflags;
 bitflags! {
     /// Object instance flags
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EObjectFlags : u32
     {
         /// No flags
@@ -71,6 +72,7 @@ bitflags! {
 
     /// Package flags
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EPackageFlags : u32
     {
         /// No flags
@@ -123,6 +125,7 @@ bitflags! {
 
     /// Property flags
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EPropertyFlags : u64
     {
         /// None
@@ -230,6 +233,7 @@ bitflags! {
 
     /// Class flags
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EClassFlags : u32
     {
         /// No Flags
@@ -302,6 +306,7 @@ bitflags! {
 
     /// Function flags
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EFunctionFlags : u32 {
         /// None
         const FUNC_NONE = 0x00000000;
@@ -399,6 +404,7 @@ bitflags! {
 
     /// User defined struct flags
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EStructFlags: u32{
         /// No flags
         const NO_FLAGS = 0x00000000;
@@ -474,8 +480,113 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Global data strip flags
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct EStrippedData : u8
+    {
+        /// Nothing was stripped
+        const NONE = 0;
+        /// Editor data was stripped
+        const EDITOR = 1;
+        /// Server data was stripped
+        const SERVER = 2;
+        /// Everything was stripped
+        const ALL = 0xff;
+    }
+}
+
+bitflags! {
+    /// Bulk data flags
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct EBulkDataFlags : u32
+    {
+        /// No flags
+        const BULKDATA_NONE = 0;
+        /// Payload is stored after the export data, or in a separate file
+        const BULKDATA_PAYLOAD_AT_END_OF_FILE = 0x00000001;
+        /// Payload is compressed with zlib
+        const BULKDATA_SERIALIZE_COMPRESSED_ZLIB = 0x00000002;
+        /// Force single element serialization
+        const BULKDATA_FORCE_SINGLE_ELEMENT_SERIALIZATION = 0x00000004;
+        /// Payload is only used once at runtime
+        const BULKDATA_SINGLE_USE = 0x00000008;
+        /// Bulk data is unused and has no payload
+        const BULKDATA_UNUSED = 0x00000020;
+        /// Payload is forced to be stored inline
+        const BULKDATA_FORCE_INLINE_PAYLOAD = 0x00000040;
+        /// Payload is forced to be streamed
+        const BULKDATA_FORCE_STREAM_PAYLOAD = 0x00000080;
+        /// Payload is stored in a separate file, e.g. `.ubulk`
+        const BULKDATA_PAYLOAD_IN_SEPERATE_FILE = 0x00000100;
+        /// Payload is compressed with a bit window
+        const BULKDATA_SERIALIZE_COMPRESSED_BIT_WINDOW = 0x00000200;
+        /// Payload is forced not to be stored inline
+        const BULKDATA_FORCE_NOT_INLINE_PAYLOAD = 0x00000400;
+        /// Payload is stored in the optional `.uptnl` file
+        const BULKDATA_OPTIONAL_PAYLOAD = 0x00000800;
+        /// Payload can be memory mapped
+        const BULKDATA_MEMORY_MAPPED_PAYLOAD = 0x00001000;
+        /// Element count and size are stored as 64 bit integers
+        const BULKDATA_SIZE_64_BIT = 0x00002000;
+        /// Payload is duplicated in the non-optional file
+        const BULKDATA_DUPLICATE_NON_OPTIONAL_PAYLOAD = 0x00004000;
+        /// Bad data version, a 16 bit version follows the header
+        const BULKDATA_BAD_DATA_VERSION = 0x00008000;
+        /// Offset is stored without the bulk data start offset
+        const BULKDATA_NO_OFFSET_FIX_UP = 0x00010000;
+        /// Payload is stored in the workspace domain
+        const BULKDATA_WORKSPACE_DOMAIN_PAYLOAD = 0x00020000;
+        /// Payload can be lazy loaded
+        const BULKDATA_LAZY_LOADABLE = 0x00040000;
+    }
+}
+
+bitflags! {
+    /// Object data resource flags
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct EObjectDataResourceFlags : u32
+    {
+        /// No flags
+        const NONE = 0;
+        /// Stored in the package header
+        const INLINE = 1 << 0;
+        /// Stored in a separate file
+        const STREAMING = 1 << 1;
+        /// Stored in a separate file that is optional
+        const OPTIONAL = 1 << 2;
+        /// Duplicated in the non-optional file
+        const DUPLICATE = 1 << 3;
+        /// Can be memory mapped
+        const MEMORY_MAPPED = 1 << 4;
+        /// Derived data reference
+        const DERIVED_DATA_REFERENCE = 1 << 5;
+    }
+}
+
+impl Default for EBulkDataFlags {
+    fn default() -> Self {
+        EBulkDataFlags::BULKDATA_NONE
+    }
+}
+
+impl Default for EObjectDataResourceFlags {
+    fn default() -> Self {
+        EObjectDataResourceFlags::NONE
+    }
+}
+
 impl Default for EObjectFlags {
     fn default() -> Self {
         EObjectFlags::RF_NO_FLAGS
     }
 }
+
+impl Default for EStrippedData {
+    fn default() -> Self {
+        EStrippedData::NONE
+    }
+}
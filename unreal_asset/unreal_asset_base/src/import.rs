@@ -1,13 +1,14 @@
 //! Asset Import struct
 
-use crate::FNameContainer;
-
 use crate::types::{FName, PackageIndex};
+use crate::Error;
+use crate::FNameContainer;
 
 /// Import struct for an Asset
 ///
 /// This is used for referencing other assets
-#[derive(FNameContainer, Debug, Clone, Eq, PartialEq)]
+#[derive(FNameContainer, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// Class package
     pub class_package: FName,
@@ -44,4 +45,64 @@ impl Import {
             optional,
         }
     }
+
+    /// Compare imports, FName comparison is content-based
+    pub fn eq_content(&self, other: &Import) -> bool {
+        self.class_package.eq_content(&other.class_package)
+            && self.class_name.eq_content(&other.class_name)
+            && self.outer_index == other.outer_index
+            && self.object_name.eq_content(&other.object_name)
+            && self.optional == other.optional
+    }
+}
+
+/// Handle to an import in an import table
+///
+/// Import indices are positions in the import table, so removing or reordering imports
+/// silently invalidates previously obtained indices.
+/// A handle remembers the import it was created for and detects this when resolved
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportHandle {
+    /// Import index at the time the handle was created
+    index: PackageIndex,
+    /// Import the handle was created for
+    import: Import,
+}
+
+impl ImportHandle {
+    /// Create a new `ImportHandle` instance
+    pub fn new(index: PackageIndex, import: Import) -> Self {
+        ImportHandle { index, import }
+    }
+
+    /// Get the import index without checking if it's still valid
+    pub fn get_index_unchecked(&self) -> PackageIndex {
+        self.index
+    }
+
+    /// Get the import this handle was created for
+    pub fn get_import(&self) -> &Import {
+        &self.import
+    }
+
+    /// Resolve this handle against an import table
+    ///
+    /// # Errors
+    ///
+    /// If the import at the handle's index is missing or is a different import
+    pub fn resolve(&self, imports: &[Import]) -> Result<PackageIndex, Error> {
+        let position = -self.index.index - 1;
+        let is_same = self.index.is_import()
+            && imports
+                .get(position as usize)
+                .is_some_and(|e| e.eq_content(&self.import));
+
+        match is_same {
+            true => Ok(self.index),
+            false => Err(Error::invalid_package_index(format!(
+                "Stale import handle {}, the import table was modified",
+                self.index.index
+            ))),
+        }
+    }
 }
@@ -16,12 +16,17 @@ pub mod import;
 pub use import::Import;
 pub mod object_version;
 pub mod reader;
+#[cfg(feature = "serde")]
+pub mod serde_base64;
 pub mod types;
 pub mod unversioned;
 
 pub use unreal_asset_proc_macro::FNameContainer;
 pub use unreal_helpers::Guid;
 
+/// Package file tag, stored at the start and the end of assets and in compressed chunk tables
+pub const UE4_ASSET_MAGIC: u32 = u32::from_be_bytes([0xc1, 0x83, 0x2a, 0x9e]);
+
 /// Cast a Property/Export to a more specific type
 ///
 /// # Examples
@@ -15,6 +15,9 @@ use crate::reader::ArchiveTrait;
 use crate::types::{FName, SerializedNameHeader};
 use crate::Guid;
 
+/// Default maximum nesting depth of properties, see [`ArchiveReader::enter_property`]
+pub const DEFAULT_MAX_PROPERTY_DEPTH: usize = 128;
+
 /// A trait that allows reading from an archive in an asset-specific way
 pub trait ArchiveReader: ArchiveTrait + Read {
     /// Read a `Guid` property
@@ -72,7 +75,12 @@ pub trait ArchiveReader: ArchiveTrait + Read {
             let custom_version_guid = self.read_guid()?;
 
             let version_number = self.read_i32::<LE>()?;
-            new_container.push(CustomVersion::new(custom_version_guid, version_number));
+            let mut custom_version = CustomVersion::new(custom_version_guid, version_number);
+            // guids format also serializes a friendly name
+            if format == ECustomVersionSerializationFormat::Guids {
+                custom_version.friendly_name = self.read_fstring()?;
+            }
+            new_container.push(custom_version);
             existing_versions.insert(custom_version_guid);
         }
 
@@ -126,16 +134,13 @@ pub trait ArchiveReader: ArchiveTrait + Read {
             return Ok((Vec::new(), 0));
         }
 
-        let _strings_length = self.read_u64::<LE>()?;
+        let _strings_length = self.read_u32::<LE>()?;
         let hash_version = self.read_u64::<LE>()?;
 
         let hashes = match hash_version {
             hash if hash == enums::HASH_VERSION_CITYHASH64 => {
-                let mut hashes = Vec::with_capacity(num_strings as usize);
-                for _ in 0..num_strings {
-                    hashes.push(self.read_u64::<LE>()?); // cityhash64 of crc::to_lower_string
-                }
-                Ok(hashes)
+                // cityhash64 of crc::to_lower_string
+                self.read_array_with_length(num_strings, |e| Ok(e.read_u64::<LE>()?))
             }
             _ => Err(Error::unimplemented(format!(
                 "Unimplemented name batch algorithm: {}",
@@ -143,15 +148,26 @@ pub trait ArchiveReader: ArchiveTrait + Read {
             ))),
         }?;
 
-        let mut name_headers = Vec::with_capacity(num_strings as usize);
-        for _ in 0..num_strings {
-            name_headers.push(SerializedNameHeader::read(self)?);
-        }
-
-        let mut name_batch = Vec::with_capacity(num_strings as usize);
+        let name_headers = self.read_array_with_length(num_strings, SerializedNameHeader::read)?;
 
+        // unlike name map strings, name batch strings have neither a terminator nor a hash
+        let mut name_batch = Vec::with_capacity(name_headers.len());
         for name_header in name_headers {
-            name_batch.push(self.read_name_map_string(Some(name_header)).map(|e| e.0)?);
+            let name = match name_header.is_wide {
+                true => {
+                    let buf = self.read_bytes(name_header.len as u64 * 2)?;
+                    String::from_utf16(
+                        &buf.chunks(2)
+                            .map(|e| u16::from_le_bytes([e[0], e[1]]))
+                            .collect::<Vec<_>>(),
+                    )?
+                }
+                false => {
+                    let buf = self.read_bytes(name_header.len as u64)?;
+                    buf.into_iter().map(char::from).collect()
+                }
+            };
+            name_batch.push(name);
         }
 
         if verify_hashes {
@@ -196,7 +212,8 @@ pub trait ArchiveReader: ArchiveTrait + Read {
         length: i32,
         getter: impl Fn(&mut Self) -> Result<T, Error>,
     ) -> Result<Vec<T>, Error> {
-        let mut array = Vec::with_capacity(length as usize);
+        // the length is read from the archive, so don't trust it for preallocation
+        let mut array = Vec::with_capacity(length.clamp(0, 0x1000) as usize);
         for _ in 0..length {
             array.push(getter(self)?);
         }
@@ -214,6 +231,23 @@ pub trait ArchiveReader: ArchiveTrait + Read {
         self.read_array_with_length(length, getter)
     }
 
+    /// Read `length` bytes
+    ///
+    /// The length is checked against the bytes left in the archive before anything is allocated,
+    /// so lengths read from the archive can be passed directly
+    fn read_bytes(&mut self, length: u64) -> Result<Vec<u8>, Error> {
+        let remaining = self.data_length()?.saturating_sub(self.position());
+        if length > remaining {
+            return Err(Error::invalid_file(format!(
+                "Tried to read {length} bytes, but only {remaining} are left"
+            )));
+        }
+
+        let mut data = vec![0u8; length as usize];
+        self.read_exact(&mut data)?;
+        Ok(data)
+    }
+
     /// Read an FString
     fn read_fstring(&mut self) -> Result<Option<String>, Error>;
     /// Read an FString with a `SerializedNameHeader`
@@ -225,6 +259,17 @@ pub trait ArchiveReader: ArchiveTrait + Read {
     fn read_guid(&mut self) -> io::Result<Guid>;
     /// Read `bool`
     fn read_bool(&mut self) -> io::Result<bool>;
+
+    /// Enter a nested property
+    ///
+    /// Fails when the maximum property nesting depth is exceeded,
+    /// every successful call must be followed by [`ArchiveReader::leave_property`].
+    /// Readers which don't track the nesting depth never fail
+    fn enter_property(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    /// Leave a nested property entered with [`ArchiveReader::enter_property`]
+    fn leave_property(&mut self) {}
 }
 
 /// A trait that allows for quick implementation of [`ArchiveReader`] as a passthrough trait for the underlying archive
@@ -263,4 +308,14 @@ where
     fn read_bool(&mut self) -> io::Result<bool> {
         self.get_passthrough().read_bool()
     }
+
+    #[inline(always)]
+    fn enter_property(&mut self) -> Result<(), Error> {
+        self.get_passthrough().enter_property()
+    }
+
+    #[inline(always)]
+    fn leave_property(&mut self) {
+        self.get_passthrough().leave_property()
+    }
 }
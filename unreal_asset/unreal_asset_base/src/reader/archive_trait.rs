@@ -7,7 +7,10 @@ use crate::containers::{IndexedMap, NameMap, SharedResource};
 use crate::custom_version::{CustomVersion, CustomVersionTrait};
 use crate::engine_version::EngineVersion;
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
-use crate::types::{FName, PackageIndex};
+use crate::types::{
+    native_struct_layout::NativeStructLayout, object_data_resource::FObjectDataResource,
+    soft_object_path::SoftObjectPath, FName, PackageIndex,
+};
 use crate::unversioned::Usmap;
 use crate::Import;
 
@@ -62,6 +65,18 @@ pub trait ArchiveTrait: Seek {
     /// Get if the archive uses the event driven loader
     fn use_event_driven_loader(&self) -> bool;
 
+    /// Get if raw script bytecode should be kept even when it was parsed successfully
+    fn retain_raw_bytecode(&self) -> bool {
+        false
+    }
+
+    /// Get the offset of this archive's start in the package
+    ///
+    /// Non-zero when writing the exports of an event driven loader asset to a separate `.uexp`
+    fn get_package_offset(&self) -> u64 {
+        0
+    }
+
     /// Archive data length
     fn data_length(&mut self) -> io::Result<u64> {
         let current_position = self.position();
@@ -108,6 +123,28 @@ pub trait ArchiveTrait: Seek {
     fn get_map_key_override(&self) -> &IndexedMap<String, String>;
     /// Get map value overrides for a `MapProperty`
     fn get_map_value_override(&self) -> &IndexedMap<String, String>;
+    /// Get the registered layout of a natively serialized struct
+    fn get_native_struct_layout(&self, _struct_type: &str) -> Option<&NativeStructLayout> {
+        None
+    }
+    /// Get the package's soft object path list
+    ///
+    /// When it isn't empty soft object paths are serialized as an index into it
+    fn get_soft_object_path_list(&self) -> &[SoftObjectPath] {
+        &[]
+    }
+    /// Get the index of a soft object path in the package's soft object path list
+    fn get_soft_object_path_index(&self, path: &SoftObjectPath) -> Option<usize> {
+        self.get_soft_object_path_list()
+            .iter()
+            .position(|e| e == path)
+    }
+    /// Get the package's data resource table
+    ///
+    /// Since `ObjectVersionUE5::DATA_RESOURCES` bulk data is serialized as an index into it
+    fn get_data_resources(&self) -> &[FObjectDataResource] {
+        &[]
+    }
 
     /// Get archive's engine version
     fn get_engine_version(&self) -> EngineVersion;
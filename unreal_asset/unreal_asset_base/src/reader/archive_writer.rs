@@ -24,14 +24,24 @@ pub trait ArchiveWriter: ArchiveTrait + Write {
         Ok(())
     }
     /// Write an `FName`
+    ///
+    /// The `FName` must be backed by this archive's name map,
+    /// `FName`s of other name maps can be moved to it with `Asset::rebuild_name_map`
     fn write_fname(&mut self, fname: &FName) -> Result<(), Error> {
         match fname {
             FName::Backed {
                 index,
                 number,
                 ty: _,
-                name_map: _,
+                name_map,
             } => {
+                if !name_map.ptr_eq(&self.get_name_map()) {
+                    return Err(Error::no_data(format!(
+                        "fname not in name map: {}",
+                        fname.get_owned_content()
+                    )));
+                }
+
                 self.write_i32::<LE>(*index)?;
                 self.write_i32::<LE>(*number)?;
                 Ok(())
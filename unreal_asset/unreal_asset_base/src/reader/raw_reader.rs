@@ -9,6 +9,7 @@ use crate::custom_version::{CustomVersion, CustomVersionTrait};
 use crate::engine_version::{guess_engine_version, EngineVersion};
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 use crate::reader::{
+    archive_reader::DEFAULT_MAX_PROPERTY_DEPTH,
     archive_trait::{ArchiveTrait, ArchiveType},
     ArchiveReader,
 };
@@ -31,6 +32,10 @@ pub struct RawReader<C: Read + Seek> {
     pub name_map: SharedResource<NameMap>,
     /// Empty map
     empty_map: IndexedMap<String, String>,
+    /// Maximum nesting depth of properties
+    pub max_property_depth: usize,
+    /// Current nesting depth of properties
+    property_depth: usize,
 }
 
 impl<C: Read + Seek> RawReader<C> {
@@ -49,6 +54,8 @@ impl<C: Read + Seek> RawReader<C> {
             use_event_driven_loader,
             name_map,
             empty_map: IndexedMap::new(),
+            max_property_depth: DEFAULT_MAX_PROPERTY_DEPTH,
+            property_depth: 0,
         }
     }
 }
@@ -146,6 +153,20 @@ impl<C: Read + Seek> ArchiveReader for RawReader<C> {
     fn read_bool(&mut self) -> io::Result<bool> {
         self.cursor.read_bool()
     }
+
+    fn enter_property(&mut self) -> Result<(), Error> {
+        if self.property_depth >= self.max_property_depth {
+            return Err(Error::invalid_file(
+                "max property nesting depth exceeded".to_string(),
+            ));
+        }
+        self.property_depth += 1;
+        Ok(())
+    }
+
+    fn leave_property(&mut self) {
+        self.property_depth = self.property_depth.saturating_sub(1);
+    }
 }
 
 impl<C: Read + Seek> Read for RawReader<C> {
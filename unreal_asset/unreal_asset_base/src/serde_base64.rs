@@ -0,0 +1,54 @@
+//! Base64 serialization of raw byte blobs
+//!
+//! Use with `#[serde(with = "unreal_asset_base::serde_base64")]` on `Vec<u8>` fields
+//! and `#[serde(with = "unreal_asset_base::serde_base64::option")]` on `Option<Vec<u8>>` fields
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize bytes as a base64 string
+pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&STANDARD.encode(bytes))
+}
+
+/// Deserialize bytes from a base64 string
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+    STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+}
+
+/// Base64 serialization of optional byte blobs
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Bytes deserialized from a base64 string
+    #[derive(Deserialize)]
+    struct Encoded(#[serde(with = "super")] Vec<u8>);
+
+    /// Bytes serialized as a base64 string
+    struct EncodedRef<'a>(&'a [u8]);
+
+    impl Serialize for EncodedRef<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(self.0, serializer)
+        }
+    }
+
+    /// Serialize optional bytes as a base64 string or null
+    pub fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => serializer.serialize_some(&EncodedRef(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize optional bytes from a base64 string or null
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Ok(Option::<Encoded>::deserialize(deserializer)?.map(|e| e.0))
+    }
+}
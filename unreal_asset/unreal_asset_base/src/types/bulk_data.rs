@@ -0,0 +1,252 @@
+//! Bulk data
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::flags::EBulkDataFlags;
+use crate::object_version::ObjectVersionUE5;
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::Error;
+
+/// Bulk data header
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FByteBulkDataHeader {
+    /// Bulk data flags
+    pub flags: EBulkDataFlags,
+    /// Element count, for byte bulk data this is the uncompressed payload size
+    pub element_count: i64,
+    /// Payload size on disk
+    pub size_on_disk: i64,
+    /// Payload offset in the file, as stored
+    ///
+    /// Relative to the bulk data start offset unless `BULKDATA_NO_OFFSET_FIX_UP` is set
+    pub offset_in_file: i64,
+}
+
+impl FByteBulkDataHeader {
+    /// Read a `FByteBulkDataHeader` from an archive
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Self, Error> {
+        let flags = EBulkDataFlags::from_bits_retain(reader.read_u32::<LE>()?);
+
+        let (element_count, size_on_disk) =
+            match flags.contains(EBulkDataFlags::BULKDATA_SIZE_64_BIT) {
+                true => (reader.read_i64::<LE>()?, reader.read_i64::<LE>()?),
+                false => (
+                    reader.read_i32::<LE>()? as i64,
+                    reader.read_i32::<LE>()? as i64,
+                ),
+            };
+        let offset_in_file = reader.read_i64::<LE>()?;
+
+        if flags.contains(EBulkDataFlags::BULKDATA_BAD_DATA_VERSION) {
+            return Err(Error::unimplemented(
+                "Bulk data with a bad data version is not supported".to_string(),
+            ));
+        }
+
+        Ok(FByteBulkDataHeader {
+            flags,
+            element_count,
+            size_on_disk,
+            offset_in_file,
+        })
+    }
+
+    /// Write a `FByteBulkDataHeader` to an archive
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        writer.write_u32::<LE>(self.flags.bits())?;
+
+        match self.flags.contains(EBulkDataFlags::BULKDATA_SIZE_64_BIT) {
+            true => {
+                writer.write_i64::<LE>(self.element_count)?;
+                writer.write_i64::<LE>(self.size_on_disk)?;
+            }
+            false => {
+                writer.write_i32::<LE>(self.element_count as i32)?;
+                writer.write_i32::<LE>(self.size_on_disk as i32)?;
+            }
+        }
+        writer.write_i64::<LE>(self.offset_in_file)?;
+
+        Ok(())
+    }
+
+    /// Get the payload size on disk, fails if it is negative
+    pub fn payload_size(&self) -> Result<u64, Error> {
+        u64::try_from(self.size_on_disk).map_err(|_| {
+            Error::invalid_file(format!("Invalid bulk data size {}", self.size_on_disk))
+        })
+    }
+
+    /// Check if the payload directly follows the header
+    pub fn is_inline(&self) -> bool {
+        !self
+            .flags
+            .contains(EBulkDataFlags::BULKDATA_PAYLOAD_AT_END_OF_FILE)
+    }
+
+    /// Check if the payload is stored in a separate file, `.uptnl` for optional payloads, otherwise `.ubulk`
+    pub fn is_in_separate_file(&self) -> bool {
+        self.flags
+            .contains(EBulkDataFlags::BULKDATA_PAYLOAD_IN_SEPERATE_FILE)
+    }
+}
+
+/// Byte bulk data
+///
+/// Inline payloads are read with the header, other payloads are only located by it
+///
+/// Since `ObjectVersionUE5::DATA_RESOURCES` the header is stored in the package's
+/// data resource table and only an index into it is serialized
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FByteBulkData {
+    /// Bulk data header
+    pub header: FByteBulkDataHeader,
+    /// Index into the package's data resource table, if the header was stored there
+    pub data_resource_index: Option<i32>,
+    /// Inline payload, as stored on disk
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64::option"))]
+    pub data: Option<Vec<u8>>,
+}
+
+impl FByteBulkData {
+    /// Read a `FByteBulkData` from an archive
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Self, Error> {
+        let (header, data_resource_index) =
+            match reader.get_object_version_ue5() >= ObjectVersionUE5::DATA_RESOURCES {
+                true => {
+                    let index = reader.read_i32::<LE>()?;
+                    let resource = usize::try_from(index)
+                        .ok()
+                        .and_then(|e| reader.get_data_resources().get(e))
+                        .ok_or_else(|| {
+                            Error::invalid_file(format!(
+                                "Bulk data references data resource {index} which does not exist"
+                            ))
+                        })?;
+                    let header = FByteBulkDataHeader {
+                        flags: resource.legacy_bulk_data_flags,
+                        element_count: resource.raw_size,
+                        size_on_disk: resource.serial_size,
+                        offset_in_file: resource.serial_offset,
+                    };
+                    (header, Some(index))
+                }
+                false => (FByteBulkDataHeader::read(reader)?, None),
+            };
+
+        let data = match header.is_inline() {
+            true => Some(reader.read_bytes(header.payload_size()?)?),
+            false => None,
+        };
+
+        Ok(FByteBulkData {
+            header,
+            data_resource_index,
+            data,
+        })
+    }
+
+    /// Write a `FByteBulkData` to an archive
+    ///
+    /// The size on disk of inline payloads is updated to the payload length,
+    /// unless the header is stored in the data resource table,
+    /// then the payload length has to match the data resource's serial size
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        if writer.get_object_version_ue5() >= ObjectVersionUE5::DATA_RESOURCES {
+            let index = self.data_resource_index.ok_or_else(|| {
+                Error::no_data(
+                    "Bulk data has no data resource index, but the package has data resources"
+                        .to_string(),
+                )
+            })?;
+            let serial_size = usize::try_from(index)
+                .ok()
+                .and_then(|e| writer.get_data_resources().get(e))
+                .map(|e| e.serial_size)
+                .ok_or_else(|| {
+                    Error::invalid_file(format!(
+                        "Bulk data references data resource {index} which does not exist"
+                    ))
+                })?;
+
+            // the size on disk is stored in the data resource table
+            if let Some(ref data) = self.data {
+                if data.len() as i64 != serial_size {
+                    return Err(Error::invalid_file(format!(
+                        "Bulk data payload is {} bytes, but data resource {index} has a serial size of {}",
+                        data.len(),
+                        serial_size
+                    )));
+                }
+            }
+
+            writer.write_i32::<LE>(index)?;
+            if let Some(ref data) = self.data {
+                writer.write_all(data)?;
+            }
+            return Ok(());
+        }
+
+        match self.data {
+            Some(ref data) => {
+                let header = FByteBulkDataHeader {
+                    size_on_disk: data.len() as i64,
+                    ..self.header
+                };
+                header.write(writer)?;
+                writer.write_all(data)?;
+            }
+            None => self.header.write(writer)?,
+        }
+
+        Ok(())
+    }
+
+    /// Read the payload as stored on disk
+    ///
+    /// `file` has to be the file the payload is stored in, the package itself
+    /// for payloads at the end of the file or the separate file otherwise,
+    /// `bulk_data_start_offset` is the package's bulk data start offset
+    pub fn read_payload<R: Read + Seek>(
+        &self,
+        file: Option<&mut R>,
+        bulk_data_start_offset: i64,
+    ) -> Result<Vec<u8>, Error> {
+        if let Some(ref data) = self.data {
+            return Ok(data.clone());
+        }
+
+        let file = file.ok_or_else(|| {
+            Error::no_data("Bulk data payload is not inline, but no file was given".to_string())
+        })?;
+
+        let offset = match self
+            .header
+            .flags
+            .contains(EBulkDataFlags::BULKDATA_NO_OFFSET_FIX_UP)
+        {
+            true => self.header.offset_in_file,
+            false => self
+                .header
+                .offset_in_file
+                .saturating_add(bulk_data_start_offset),
+        };
+
+        let size = self.header.payload_size()?;
+        let file_length = file.seek(SeekFrom::End(0))?;
+        if offset < 0 || (offset as u64).saturating_add(size) > file_length {
+            return Err(Error::invalid_file(format!(
+                "Bulk data payload at {offset} with size {size} is outside of the {file_length} byte file"
+            )));
+        }
+
+        file.seek(SeekFrom::Start(offset as u64))?;
+        let mut data = vec![0u8; size as usize];
+        file.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
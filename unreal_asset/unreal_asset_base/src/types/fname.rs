@@ -2,6 +2,7 @@
 //!
 //! They are represented by an index+instance number inside a string table inside the asset file.
 
+use std::cmp::Ordering;
 use std::hash::Hash;
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -29,7 +30,9 @@ pub enum FName {
     Backed {
         /// FName name map index
         index: i32,
-        /// FName instance number
+        /// FName instance number, stored with a bias of 1
+        ///
+        /// 0 means the name has no number, `Name_0` has a number of 1
         number: i32,
         /// FName type
         ///
@@ -42,7 +45,7 @@ pub enum FName {
     Dummy {
         /// FName value
         value: String,
-        /// FName instance number
+        /// FName instance number, stored with a bias of 1
         number: i32,
     },
 }
@@ -149,7 +152,21 @@ impl FName {
         self.get_content(|name| name.ends_with(pat.as_ref()))
     }
 
+    /// Create a new "dummy" `FName` instance from its string form
+    ///
+    /// A number suffix is split off like the engine does, `Name` gets a number of 0,
+    /// `Name_0` a number of 1 and `Name_1` a number of 2.
+    /// Suffixes with leading zeros are part of the content, e.g. `Name_01`
+    pub fn from_display(value: &str) -> Self {
+        match split_fname_number(value) {
+            Some((content, number)) => FName::new_dummy(content.to_string(), number),
+            None => FName::from_slice(value),
+        }
+    }
+
     /// Get this `FName` instance number
+    ///
+    /// The number is stored with a bias of 1, see [`FName::get_number_suffix`] for the displayed number
     pub fn get_number(&self) -> i32 {
         match self {
             FName::Backed {
@@ -162,9 +179,41 @@ impl FName {
         }
     }
 
-    /// Compare `FNames` based on their content
+    /// Get the number this `FName` is displayed with, `Name_0` has a suffix of `Some(0)`
+    ///
+    /// Returns `None` if this `FName` has no number
+    pub fn get_number_suffix(&self) -> Option<i32> {
+        match self.get_number() {
+            number if number > 0 => Some(number - 1),
+            _ => None,
+        }
+    }
+
+    /// Compare `FName`s based on their content and number
+    ///
+    /// Unlike `==` this doesn't depend on the name maps the `FName`s belong to,
+    /// names from different assets and dummy names compare equal if they resolve to the same name
     pub fn eq_content(&self, other: &Self) -> bool {
-        self.get_content(|this| other == this)
+        self.get_number() == other.get_number() && self.get_content(|this| other == this)
+    }
+
+    /// Order `FName`s by their content and then their number
+    ///
+    /// Like [`FName::eq_content`] this doesn't depend on the name maps the `FName`s belong to
+    pub fn cmp_content(&self, other: &Self) -> Ordering {
+        self.get_content(|this| other.get_content(|other| this.cmp(other)))
+            .then_with(|| self.get_number().cmp(&other.get_number()))
+    }
+}
+
+/// `FName`s display as their content with the number suffix, e.g. `Name_0` for a number of 1
+impl std::fmt::Display for FName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get_content(|content| f.write_str(content))?;
+        match self.get_number_suffix() {
+            Some(suffix) => write!(f, "_{}", suffix),
+            None => Ok(()),
+        }
     }
 }
 
@@ -245,9 +294,134 @@ impl std::cmp::PartialEq<&str> for FName {
 
 impl std::cmp::PartialEq<String> for FName {
     fn eq(&self, other: &String) -> bool {
-        self == other
+        self == other.as_str()
+    }
+}
+
+/// Split an `FName` string into its content and number, `Name_3` becomes `("Name", 4)`
+fn split_fname_number(value: &str) -> Option<(&str, i32)> {
+    let (content, suffix) = value.rsplit_once('_')?;
+    if content.is_empty()
+        || suffix.is_empty()
+        || !suffix.bytes().all(|e| e.is_ascii_digit())
+        || (suffix.len() > 1 && suffix.starts_with('0'))
+    {
+        return None;
+    }
+
+    let number = suffix.parse::<i32>().ok()?.checked_add(1)?;
+    Some((content, number))
+}
+
+/// `FName` serialized as an object, used when the string form would be ambiguous
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum FNameRepr {
+    /// Content with a number suffix, e.g. `Name_3`
+    String(String),
+    /// Content, number and name map index, used for names backed by a duplicate name map entry
+    Indexed {
+        /// Content
+        name: String,
+        /// Instance number
+        number: i32,
+        /// Name map index
+        index: i32,
+    },
+    /// Content and number
+    Numbered {
+        /// Content
+        name: String,
+        /// Instance number
+        number: i32,
+    },
+}
+
+#[cfg(feature = "serde")]
+thread_local! {
+    /// Name map that indexed `FName`s are backed by while deserializing
+    static DESERIALIZE_NAME_MAP: std::cell::RefCell<Option<SharedResource<NameMap>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+#[cfg(feature = "serde")]
+impl FName {
+    /// Run a deserialization with `name_map` as the name map of indexed `FName`s
+    ///
+    /// Names backed by a duplicate name map entry serialize with their index,
+    /// inside `f` they deserialize as backed by `name_map` at that index instead of as dummies.
+    /// The name map can be filled after `f` returns.
+    pub fn with_deserialize_name_map<T>(
+        name_map: &SharedResource<NameMap>,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        let previous = DESERIALIZE_NAME_MAP.with(|e| e.borrow_mut().replace(name_map.clone()));
+        let result = f();
+        DESERIALIZE_NAME_MAP.with(|e| *e.borrow_mut() = previous);
+        result
     }
 }
+
+/// `FName`s serialize as their content with a number suffix, e.g. `Name_3` for a number of 4
+///
+/// Names backed by a duplicate name map entry also serialize their index, see [`FName::with_deserialize_name_map`].
+/// Deserialized `FName`s are dummies, they are backed by the name map when deserializing an asset
+#[cfg(feature = "serde")]
+impl serde::Serialize for FName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let name = self.get_owned_content();
+        let number = self.get_number();
+
+        // looking the name up by its content would give another entry
+        if let FName::Backed {
+            index, name_map, ..
+        } = self
+        {
+            if name_map.get_ref().search_name_reference(&name) != Some(*index) {
+                return FNameRepr::Indexed {
+                    name,
+                    number,
+                    index: *index,
+                }
+                .serialize(serializer);
+            }
+        }
+
+        let display = self.to_string();
+        let repr = match split_fname_number(&display) {
+            None if number == 0 => FNameRepr::String(display),
+            Some(split) if split == (name.as_str(), number) => FNameRepr::String(display),
+            _ => FNameRepr::Numbered { name, number },
+        };
+        repr.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match FNameRepr::deserialize(deserializer)? {
+            FNameRepr::String(value) => FName::from_display(&value),
+            FNameRepr::Indexed {
+                name,
+                number,
+                index,
+            } => DESERIALIZE_NAME_MAP
+                .with(|e| e.borrow().clone())
+                .map(|name_map| FName::new(index, number, name_map))
+                .unwrap_or_else(|| FName::new_dummy(name, number)),
+            FNameRepr::Numbered { name, number } => FName::new_dummy(name, number),
+        })
+    }
+}
+
 /// A trait that can be implemented for structs that contain an FName
 ///
 /// This trait will be typically used to traverse the whole asset FName tree
@@ -0,0 +1,128 @@
+//! Gatherable text data
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::Error;
+
+/// Read localization metadata, only empty metadata is supported
+fn read_loc_metadata<Reader: ArchiveReader>(reader: &mut Reader) -> Result<(), Error> {
+    let value_count = reader.read_i32::<LE>()?;
+    if value_count != 0 {
+        return Err(Error::unimplemented(format!(
+            "Localization metadata with {value_count} values is not supported"
+        )));
+    }
+    Ok(())
+}
+
+/// Source string of gatherable text
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FTextSourceData {
+    /// Source string
+    pub source_string: Option<String>,
+}
+
+impl FTextSourceData {
+    /// Read `FTextSourceData` from an archive
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Self, Error> {
+        let source_string = reader.read_fstring()?;
+        read_loc_metadata(reader)?;
+        Ok(FTextSourceData { source_string })
+    }
+
+    /// Write `FTextSourceData` to an archive
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        writer.write_fstring(self.source_string.as_deref())?;
+        // source string metadata
+        writer.write_i32::<LE>(0)?;
+        Ok(())
+    }
+}
+
+/// Location where gatherable text is used
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FTextSourceSiteContext {
+    /// Localization key
+    pub key_name: Option<String>,
+    /// Description of where the text is used, usually an object path and property name
+    pub site_description: Option<String>,
+    /// Is the text editor-only
+    pub is_editor_only: bool,
+    /// Is the text optional
+    pub is_optional: bool,
+}
+
+impl FTextSourceSiteContext {
+    /// Read a `FTextSourceSiteContext` from an archive
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Self, Error> {
+        let key_name = reader.read_fstring()?;
+        let site_description = reader.read_fstring()?;
+        let is_editor_only = reader.read_i32::<LE>()? != 0;
+        let is_optional = reader.read_i32::<LE>()? != 0;
+        // info metadata
+        read_loc_metadata(reader)?;
+        // key metadata
+        read_loc_metadata(reader)?;
+
+        Ok(FTextSourceSiteContext {
+            key_name,
+            site_description,
+            is_editor_only,
+            is_optional,
+        })
+    }
+
+    /// Write a `FTextSourceSiteContext` to an archive
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        writer.write_fstring(self.key_name.as_deref())?;
+        writer.write_fstring(self.site_description.as_deref())?;
+        writer.write_i32::<LE>(self.is_editor_only as i32)?;
+        writer.write_i32::<LE>(self.is_optional as i32)?;
+        // info metadata
+        writer.write_i32::<LE>(0)?;
+        // key metadata
+        writer.write_i32::<LE>(0)?;
+        Ok(())
+    }
+}
+
+/// Localizable text of a package, gathered by the editor for localization
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatherableTextData {
+    /// Localization namespace
+    pub namespace_name: Option<String>,
+    /// Source data
+    pub source_data: FTextSourceData,
+    /// Locations the text is used at
+    pub source_site_contexts: Vec<FTextSourceSiteContext>,
+}
+
+impl GatherableTextData {
+    /// Read `GatherableTextData` from an archive
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Self, Error> {
+        let namespace_name = reader.read_fstring()?;
+        let source_data = FTextSourceData::read(reader)?;
+        let source_site_contexts = reader.read_array(FTextSourceSiteContext::read)?;
+
+        Ok(GatherableTextData {
+            namespace_name,
+            source_data,
+            source_site_contexts,
+        })
+    }
+
+    /// Write `GatherableTextData` to an archive
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        writer.write_fstring(self.namespace_name.as_deref())?;
+        self.source_data.write(writer)?;
+        writer.write_i32::<LE>(self.source_site_contexts.len() as i32)?;
+        for source_site_context in &self.source_site_contexts {
+            source_site_context.write(writer)?;
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,21 @@
 //! Unreal types
 
+pub mod bulk_data;
 pub mod fname;
+pub mod gatherable_text_data;
 use byteorder::{ReadBytesExt, WriteBytesExt};
 pub use fname::FName;
 
 pub mod movie;
+pub mod native_struct_layout;
+pub mod object_data_resource;
+pub mod soft_object_path;
+pub mod thumbnail_table;
 pub mod vector;
 
 use std::hash::Hash;
 
+use crate::flags::EStrippedData;
 use crate::reader::{ArchiveReader, ArchiveWriter};
 use crate::Error;
 use crate::Guid;
@@ -62,6 +69,8 @@ impl SerializedNameHeader {
 ///
 /// When PackageIndex is 0 it makes for a non-existent link.
 #[derive(Debug, Hash, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct PackageIndex {
     /// Index
     pub index: i32,
@@ -104,6 +113,117 @@ impl PackageIndex {
     }
 }
 
+/// A trait that can be implemented for structs that contain a `PackageIndex`
+///
+/// Used to remap references when exports are moved between or removed from assets
+pub trait PackageIndexContainer {
+    /// Traverse this package index container
+    ///
+    /// Traverse function must get called for each `PackageIndex` in this container
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F);
+}
+
+impl PackageIndexContainer for PackageIndex {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        traverse(self);
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for Vec<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        for e in self.iter_mut() {
+            e.traverse_package_indices(traverse);
+        }
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for Box<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.as_mut().traverse_package_indices(traverse)
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for Option<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        if let Some(e) = self {
+            e.traverse_package_indices(traverse);
+        }
+    }
+}
+
+macro_rules! dummy_package_index_container_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl PackageIndexContainer for $ty {
+                fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
+            }
+        )*
+    };
+}
+
+dummy_package_index_container_impl!(
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    bool,
+    String,
+    FName,
+    ordered_float::OrderedFloat<f32>,
+    ordered_float::OrderedFloat<f64>,
+    vector::Vector<ordered_float::OrderedFloat<f64>>,
+    vector::Vector4<ordered_float::OrderedFloat<f64>>,
+    vector::Transform<ordered_float::OrderedFloat<f64>>
+);
+
+/// Strip data flags
+///
+/// Written by cooked exports before data that may have been stripped
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FStripDataFlags {
+    /// Global strip flags
+    pub global_strip_flags: EStrippedData,
+    /// Class specific strip flags
+    pub class_strip_flags: u8,
+}
+
+impl FStripDataFlags {
+    /// Read `FStripDataFlags` from an archive
+    pub fn read<Reader: ArchiveReader + ?Sized>(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(FStripDataFlags {
+            global_strip_flags: EStrippedData::from_bits_retain(reader.read_u8()?),
+            class_strip_flags: reader.read_u8()?,
+        })
+    }
+
+    /// Write `FStripDataFlags` to an archive
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        writer.write_u8(self.global_strip_flags.bits())?;
+        writer.write_u8(self.class_strip_flags)?;
+        Ok(())
+    }
+
+    /// Check if editor data was stripped
+    pub fn is_editor_data_stripped(&self) -> bool {
+        self.global_strip_flags.contains(EStrippedData::EDITOR)
+    }
+
+    /// Check if server data was stripped
+    pub fn is_data_stripped_for_server(&self) -> bool {
+        self.global_strip_flags.contains(EStrippedData::SERVER)
+    }
+
+    /// Check if any of the class specific flags are set
+    pub fn is_class_data_stripped(&self, flags: u8) -> bool {
+        self.class_strip_flags & flags != 0
+    }
+}
+
 /// Create a Guid from 4 u32 values
 // #[rustfmt::skip]
 // pub const fn new_guid(a: u32, b: u32, c: u32, d: u32) -> Guid {
@@ -116,7 +236,8 @@ impl PackageIndex {
 // }
 
 /// Asset generation info
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenerationInfo {
     /// Export count
     pub export_count: i32,
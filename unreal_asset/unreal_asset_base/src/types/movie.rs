@@ -10,6 +10,7 @@ use crate::{
 
 /// Frame number
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrameNumber {
     /// Value
     pub value: i32,
@@ -24,6 +25,7 @@ impl FrameNumber {
 
 /// Frame rate
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrameRate {
     /// Numerator
     pub numerator: i32,
@@ -52,6 +54,7 @@ impl FrameRate {
 
 /// Enum CoreUObject.ERangeBoundTypes
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i8)]
 pub enum ERangeBoundTypes {
     /// Exclusive range
@@ -66,6 +69,7 @@ pub enum ERangeBoundTypes {
 
 /// Frame number bound by range
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FFrameNumberRangeBound {
     /// Binding range
     pub ty: ERangeBoundTypes,
@@ -92,6 +96,7 @@ impl FFrameNumberRangeBound {
 
 /// Frame number range
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FFrameNumberRange {
     /// Lower bound
     pub lower_bound: FFrameNumberRangeBound,
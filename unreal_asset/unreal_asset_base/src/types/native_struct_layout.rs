@@ -0,0 +1,39 @@
+//! Native struct layouts
+
+/// Field of a [`NativeStructLayout`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NativeStructField {
+    /// Field name
+    pub name: String,
+    /// Field type, a property type like `IntProperty` or a struct with custom serialization like `Vector`
+    pub property_type: String,
+}
+
+impl NativeStructField {
+    /// Create a new `NativeStructField` instance
+    pub fn new(name: &str, property_type: &str) -> Self {
+        NativeStructField {
+            name: name.to_string(),
+            property_type: property_type.to_string(),
+        }
+    }
+}
+
+/// Layout of a natively serialized struct
+///
+/// Natively serialized structs are stored as their fields in order, without property tags,
+/// registering a layout allows reading them without mappings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NativeStructLayout {
+    /// Fields in serialization order
+    pub fields: Vec<NativeStructField>,
+}
+
+impl NativeStructLayout {
+    /// Create a new `NativeStructLayout` instance
+    pub fn new(fields: Vec<NativeStructField>) -> Self {
+        NativeStructLayout { fields }
+    }
+}
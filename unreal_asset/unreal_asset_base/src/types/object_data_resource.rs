@@ -0,0 +1,98 @@
+//! Object data resources
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::flags::{EBulkDataFlags, EObjectDataResourceFlags};
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::types::PackageIndex;
+use crate::Error;
+
+/// Data resource table version
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum EObjectDataResourceVersion {
+    /// Invalid
+    Invalid = 0,
+    /// Initial version
+    Initial = 1,
+}
+
+/// Location of a bulk data payload of an object, stored in the data resource table
+///
+/// Since `ObjectVersionUE5::DATA_RESOURCES` bulk data only stores an index into this table
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FObjectDataResource {
+    /// Data resource flags
+    pub flags: EObjectDataResourceFlags,
+    /// Payload offset, relative to the bulk data start offset for payloads in the package
+    pub serial_offset: i64,
+    /// Payload offset in the non-optional file, if the payload is duplicated
+    pub duplicate_serial_offset: i64,
+    /// Payload size on disk
+    pub serial_size: i64,
+    /// Uncompressed payload size
+    pub raw_size: i64,
+    /// Object owning the payload
+    pub outer_index: PackageIndex,
+    /// Bulk data flags the payload was saved with
+    pub legacy_bulk_data_flags: EBulkDataFlags,
+}
+
+impl FObjectDataResource {
+    /// Read a `FObjectDataResource` from an archive
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(FObjectDataResource {
+            flags: EObjectDataResourceFlags::from_bits_retain(reader.read_u32::<LE>()?),
+            serial_offset: reader.read_i64::<LE>()?,
+            duplicate_serial_offset: reader.read_i64::<LE>()?,
+            serial_size: reader.read_i64::<LE>()?,
+            raw_size: reader.read_i64::<LE>()?,
+            outer_index: PackageIndex::new(reader.read_i32::<LE>()?),
+            legacy_bulk_data_flags: EBulkDataFlags::from_bits_retain(reader.read_u32::<LE>()?),
+        })
+    }
+
+    /// Write a `FObjectDataResource` to an archive
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
+        writer.write_u32::<LE>(self.flags.bits())?;
+        writer.write_i64::<LE>(self.serial_offset)?;
+        writer.write_i64::<LE>(self.duplicate_serial_offset)?;
+        writer.write_i64::<LE>(self.serial_size)?;
+        writer.write_i64::<LE>(self.raw_size)?;
+        writer.write_i32::<LE>(self.outer_index.index)?;
+        writer.write_u32::<LE>(self.legacy_bulk_data_flags.bits())?;
+        Ok(())
+    }
+
+    /// Read a data resource table from an archive
+    pub fn read_table<Reader: ArchiveReader>(reader: &mut Reader) -> Result<Vec<Self>, Error> {
+        let version = reader.read_u32::<LE>()?;
+        if version != EObjectDataResourceVersion::Initial as u32 {
+            return Err(Error::unimplemented(format!(
+                "Unsupported data resource table version {version}"
+            )));
+        }
+
+        reader.read_array(Self::read)
+    }
+
+    /// Write a data resource table to an archive
+    pub fn write_table<Writer: ArchiveWriter>(
+        writer: &mut Writer,
+        data_resources: &[Self],
+    ) -> Result<(), Error> {
+        writer.write_u32::<LE>(EObjectDataResourceVersion::Initial as u32)?;
+        writer.write_i32::<LE>(data_resources.len() as i32)?;
+        for data_resource in data_resources {
+            data_resource.write(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Check if the payload is stored in a separate file, `.uptnl` for optional payloads, otherwise `.ubulk`
+    pub fn is_in_separate_file(&self) -> bool {
+        self.flags
+            .intersects(EObjectDataResourceFlags::STREAMING | EObjectDataResourceFlags::OPTIONAL)
+    }
+}
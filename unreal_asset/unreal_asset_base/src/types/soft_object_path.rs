@@ -0,0 +1,191 @@
+//! Soft object paths
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::object_version::ObjectVersionUE5;
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::types::FName;
+use crate::Error;
+use crate::FNameContainer;
+
+// silly `FNameContainer` fix
+mod unreal_asset_base {
+    pub use crate::types;
+}
+
+/// Top level asset path
+#[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TopLevelAssetPath {
+    /// Package name that contains the asset e.g. /Some/Path/Package
+    /// Only present in 5.1 and higher
+    pub package_name: Option<FName>,
+    /// If 5.1 and higher contains the name of the asset within the package
+    /// If less than 5.1 contians the full path to the asset
+    pub asset_name: FName,
+}
+
+impl TopLevelAssetPath {
+    /// Create a new `TopLevelAssetPath` instance
+    pub fn new(package_name: Option<FName>, asset_name: FName) -> Self {
+        TopLevelAssetPath {
+            package_name,
+            asset_name,
+        }
+    }
+
+    /// Read a `TopLevelAssetPath` from an asset
+    pub fn read<Reader: ArchiveReader + ?Sized>(asset: &mut Reader) -> Result<Self, Error> {
+        let package_name = match asset.get_object_version_ue5()
+            >= ObjectVersionUE5::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES
+        {
+            true => Some(asset.read_fname()?),
+            false => None,
+        };
+        let asset_name = asset.read_fname()?;
+
+        Ok(TopLevelAssetPath {
+            package_name,
+            asset_name,
+        })
+    }
+
+    /// Write a `TopLevelAssetPath` to an asset
+    pub fn write<Writer: ArchiveWriter + ?Sized>(&self, asset: &mut Writer) -> Result<(), Error> {
+        if asset.get_object_version_ue5()
+            >= ObjectVersionUE5::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES
+        {
+            let Some(package_name) = self.package_name.as_ref() else {
+                return Err(Error::no_data("ObjectVersionUE5 is >= FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES, but package_name is None".to_string()));
+            };
+
+            asset.write_fname(package_name)?;
+        }
+
+        asset.write_fname(&self.asset_name)?;
+
+        Ok(())
+    }
+}
+
+/// Soft object path
+///
+/// Since `ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST` packages with a soft object path list
+/// store soft object paths in export data as an index into that list
+#[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftObjectPath {
+    /// Asset path
+    pub asset_path: TopLevelAssetPath,
+    /// Sub path string
+    pub sub_path_string: Option<String>,
+}
+
+impl SoftObjectPath {
+    /// Read a `SoftObjectPath` from an asset
+    ///
+    /// Resolves the path from the archive's soft object path list if it has one
+    pub fn new<Reader: ArchiveReader + ?Sized>(asset: &mut Reader) -> Result<Self, Error> {
+        if asset.get_soft_object_path_list().is_empty() {
+            return Self::read_path(asset);
+        }
+
+        let index = asset.read_i32::<LE>()?;
+        let list = asset.get_soft_object_path_list();
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| list.get(index))
+            .cloned()
+            .ok_or_else(|| {
+                Error::invalid_file(format!(
+                    "Soft object path index {} out of range, soft object path list has {} entries",
+                    index,
+                    list.len()
+                ))
+            })
+    }
+
+    /// Read an inline `SoftObjectPath` from an asset, ignoring the soft object path list
+    pub fn read_path<Reader: ArchiveReader + ?Sized>(asset: &mut Reader) -> Result<Self, Error> {
+        let asset_path = TopLevelAssetPath::read(asset)?;
+        let sub_path_string = asset.read_fstring()?;
+
+        Ok(SoftObjectPath {
+            asset_path,
+            sub_path_string,
+        })
+    }
+
+    /// Write a `SoftObjectPath` to an asset
+    ///
+    /// Writes an index into the archive's soft object path list if it has one
+    pub fn write<Writer: ArchiveWriter + ?Sized>(&self, asset: &mut Writer) -> Result<(), Error> {
+        if asset.get_soft_object_path_list().is_empty() {
+            return self.write_path(asset);
+        }
+
+        let Some(index) = asset.get_soft_object_path_index(self) else {
+            return Err(Error::no_data(format!(
+                "soft object path not in soft object path list: {:?}",
+                self
+            )));
+        };
+        asset.write_i32::<LE>(index as i32)?;
+
+        Ok(())
+    }
+
+    /// Get the name of the package containing the asset, e.g. `/Game/Maps/Map`
+    ///
+    /// Before 5.1 the full path is stored in [`TopLevelAssetPath::asset_name`],
+    /// in which case the package name is split off it.
+    /// Returns `None` for null paths
+    pub fn package_name(&self) -> Option<FName> {
+        match self.asset_path.package_name {
+            Some(ref package_name) => (*package_name != "None").then(|| package_name.clone()),
+            None => split_path(&self.asset_path.asset_name.to_string())
+                .0
+                .map(FName::from_display),
+        }
+    }
+
+    /// Get the name of the asset within its package, e.g. `Map`
+    ///
+    /// Returns `None` for null paths and paths to packages
+    pub fn asset_name(&self) -> Option<FName> {
+        match self.asset_path.package_name {
+            Some(_) => {
+                (self.asset_path.asset_name != "None").then(|| self.asset_path.asset_name.clone())
+            }
+            None => split_path(&self.asset_path.asset_name.to_string())
+                .1
+                .map(FName::from_display),
+        }
+    }
+
+    /// Write an inline `SoftObjectPath` to an asset, ignoring the soft object path list
+    pub fn write_path<Writer: ArchiveWriter + ?Sized>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        self.asset_path.write(asset)?;
+        asset.write_fstring(self.sub_path_string.as_deref())?;
+
+        Ok(())
+    }
+}
+
+/// Split a path string like `/Game/Maps/Map.Map:SubObject` into its package name and asset name
+///
+/// The sub path is ignored, empty and `None` paths have neither name
+pub fn split_path(path: &str) -> (Option<&str>, Option<&str>) {
+    let path = path.split_once(':').map_or(path, |(path, _)| path);
+    if path.is_empty() || path == "None" {
+        return (None, None);
+    }
+
+    match path.split_once('.') {
+        Some((package_name, asset_name)) => (Some(package_name), Some(asset_name)),
+        None => (Some(path), None),
+    }
+}
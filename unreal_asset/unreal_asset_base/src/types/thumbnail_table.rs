@@ -0,0 +1,210 @@
+//! Package thumbnail table
+
+use std::io::SeekFrom;
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::Error;
+
+/// Thumbnail of an object in the package
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThumbnailEntry {
+    /// Class name of the object
+    pub object_class_name: String,
+    /// Object path without the package name
+    pub object_path: String,
+    /// Serialized thumbnail, the image size followed by the compressed PNG/JPEG image bytes
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
+    pub data: Vec<u8>,
+}
+
+/// Format of the compressed image of a thumbnail
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ThumbnailCompression {
+    /// PNG image
+    Png,
+    /// JPEG image, marked by a negative image height since UE 5.1
+    Jpeg,
+}
+
+/// Decoded `FObjectThumbnail`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Thumbnail {
+    /// Image width
+    pub width: i32,
+    /// Image height
+    pub height: i32,
+    /// Format of `image_data`
+    pub compression: ThumbnailCompression,
+    /// Compressed image bytes
+    pub image_data: Vec<u8>,
+}
+
+impl Thumbnail {
+    /// Serialize this thumbnail as stored in [`ThumbnailEntry::data`]
+    pub fn to_data(&self) -> Vec<u8> {
+        let height = match self.compression {
+            ThumbnailCompression::Png => self.height,
+            ThumbnailCompression::Jpeg => -self.height,
+        };
+
+        let mut data = Vec::with_capacity(3 * 4 + self.image_data.len());
+        data.extend(self.width.to_le_bytes());
+        data.extend(height.to_le_bytes());
+        data.extend((self.image_data.len() as i32).to_le_bytes());
+        data.extend(&self.image_data);
+        data
+    }
+}
+
+impl ThumbnailEntry {
+    /// Decode the serialized thumbnail
+    pub fn thumbnail(&self) -> Result<Thumbnail, Error> {
+        let (width, height, image_data) = self.split_data()?;
+        let (height, compression) = match height < 0 {
+            true => (-height, ThumbnailCompression::Jpeg),
+            false => (height, ThumbnailCompression::Png),
+        };
+
+        Ok(Thumbnail {
+            width,
+            height,
+            compression,
+            image_data: image_data.to_vec(),
+        })
+    }
+
+    /// Get the compressed image bytes of the thumbnail
+    pub fn image_data(&self) -> Result<&[u8], Error> {
+        self.split_data().map(|(_, _, image_data)| image_data)
+    }
+
+    /// Split the serialized thumbnail into the image width, height and compressed image bytes
+    fn split_data(&self) -> Result<(i32, i32, &[u8]), Error> {
+        let mut reader = self.data.as_slice();
+        let width = reader.read_i32::<LE>()?;
+        let height = reader.read_i32::<LE>()?;
+        let image_length = reader.read_i32::<LE>()?;
+        if image_length < 0 || image_length as usize != reader.len() || height == i32::MIN {
+            return Err(Error::invalid_file(format!(
+                "Thumbnail of {} has an invalid image size {}x{} with length {}",
+                self.object_path, width, height, image_length
+            )));
+        }
+
+        Ok((width, height, reader))
+    }
+}
+
+/// Thumbnails of objects in the package, usually only present in editor packages
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThumbnailTable {
+    /// Thumbnail entries
+    pub entries: Vec<ThumbnailEntry>,
+}
+
+impl ThumbnailTable {
+    /// Read a `ThumbnailTable` from an archive, the archive must be positioned at the table
+    ///
+    /// Returns the table and the offset of the first thumbnail, thumbnail data is stored before the table
+    pub fn read<Reader: ArchiveReader>(reader: &mut Reader) -> Result<(Self, u64), Error> {
+        let table_offset = reader.position();
+        let count = reader.read_i32::<LE>()?;
+        if count < 0 {
+            return Err(Error::invalid_file(format!(
+                "Invalid thumbnail count {count}"
+            )));
+        }
+
+        let locations = reader.read_array_with_length(count, |reader| {
+            let object_class_name = reader.read_fstring()?.unwrap_or_default();
+            let object_path = reader.read_fstring()?.unwrap_or_default();
+            let file_offset = reader.read_i32::<LE>()?;
+            if file_offset < 0 || file_offset as u64 >= table_offset {
+                return Err(Error::invalid_file(format!(
+                    "Thumbnail of {object_path} has an invalid offset {file_offset}"
+                )));
+            }
+            Ok((object_class_name, object_path, file_offset as u64))
+        })?;
+
+        let data_start = locations
+            .iter()
+            .map(|(_, _, offset)| *offset)
+            .min()
+            .unwrap_or(table_offset);
+
+        let mut entries = Vec::with_capacity(locations.len());
+        for (object_class_name, object_path, file_offset) in locations {
+            // image width, image height and compressed image data length
+            reader.seek(SeekFrom::Start(file_offset + 2 * 4))?;
+            let image_length = reader.read_i32::<LE>()?;
+            // thumbnail data is stored before the table
+            if image_length < 0 || 3 * 4 + image_length as u64 > table_offset - file_offset {
+                return Err(Error::invalid_file(format!(
+                    "Thumbnail of {object_path} has an invalid image length {image_length}"
+                )));
+            }
+
+            let mut data = vec![0u8; 3 * 4 + image_length as usize];
+            reader.seek(SeekFrom::Start(file_offset))?;
+            reader.read_exact(&mut data)?;
+
+            entries.push(ThumbnailEntry {
+                object_class_name,
+                object_path,
+                data,
+            });
+        }
+
+        reader.seek(SeekFrom::Start(table_offset))?;
+        Ok((ThumbnailTable { entries }, data_start))
+    }
+
+    /// Write thumbnail data followed by the table, returns the table offset
+    pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<u64, Error> {
+        let mut file_offsets = Vec::with_capacity(self.entries.len());
+        for entry in &self.entries {
+            file_offsets.push(writer.position() as i32);
+            writer.write_all(&entry.data)?;
+        }
+
+        let table_offset = writer.position();
+        writer.write_i32::<LE>(self.entries.len() as i32)?;
+        for (entry, file_offset) in self.entries.iter().zip(file_offsets) {
+            writer.write_fstring(Some(&entry.object_class_name))?;
+            writer.write_fstring(Some(&entry.object_path))?;
+            writer.write_i32::<LE>(file_offset)?;
+        }
+
+        Ok(table_offset)
+    }
+
+    /// Get the thumbnail of an object
+    pub fn get(&self, object_path: &str) -> Option<&ThumbnailEntry> {
+        self.entries.iter().find(|e| e.object_path == object_path)
+    }
+
+    /// Remove the thumbnail of an object, returns the removed thumbnail
+    pub fn remove(&mut self, object_path: &str) -> Option<ThumbnailEntry> {
+        let index = self
+            .entries
+            .iter()
+            .position(|e| e.object_path == object_path)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// Replace the thumbnail data of an object, returns the previous data
+    ///
+    /// Returns `None` and leaves the table unchanged if the object has no thumbnail
+    pub fn replace(&mut self, object_path: &str, data: Vec<u8>) -> Option<Vec<u8>> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|e| e.object_path == object_path)?;
+        Some(std::mem::replace(&mut entry.data, data))
+    }
+}
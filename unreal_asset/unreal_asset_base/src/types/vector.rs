@@ -3,6 +3,7 @@
 
 /// Vector
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector<T> {
     /// X component
     pub x: T,
@@ -21,6 +22,7 @@ impl<T> Vector<T> {
 
 /// Vector2
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2<T> {
     /// X component
     pub x: T,
@@ -37,6 +39,7 @@ impl<T> Vector2<T> {
 
 /// Vector4
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector4<T> {
     /// X component
     pub x: T,
@@ -57,6 +60,7 @@ impl<T> Vector4<T> {
 
 /// RGBA Color
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color<T> {
     /// Red
     pub r: T,
@@ -94,6 +98,7 @@ impl Color<u8> {
 
 /// Transform
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform<T> {
     /// Rotation
     pub rotation: Vector4<T>,
@@ -116,6 +121,7 @@ impl<T> Transform<T> {
 
 /// Plane
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Plane<T> {
     /// X component
     pub x: T,
@@ -6,6 +6,7 @@ use crate::types::FName;
 
 /// Unversioned properties ancestry
 #[derive(FNameContainer, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ancestry {
     /// Ancestry array, last element is immediate parent
     pub ancestry: Vec<FName>,
@@ -17,8 +17,6 @@ use crate::types::FName;
 
 pub mod ancestry;
 pub mod header;
-#[cfg(feature = "oodle")]
-pub(crate) mod oodle;
 pub mod properties;
 pub mod usmap_reader;
 pub mod usmap_writer;
@@ -271,8 +269,7 @@ impl Usmap {
         let compressed_size = reader.read_u32::<LE>()?;
         let decompressed_size = reader.read_u32::<LE>()?;
 
-        let mut compressed_data = vec![0u8; compressed_size as usize];
-        reader.read_exact(&mut compressed_data)?;
+        let compressed_data = reader.read_bytes(compressed_size as u64)?;
 
         let data = match self.compression_method {
             EUsmapCompressionMethod::None => {
@@ -293,12 +290,20 @@ impl Usmap {
                 decompressed_data.into_inner()
             }
             EUsmapCompressionMethod::ZStandard => {
-                let mut decompressed_data = Cursor::new(vec![0u8; decompressed_size as usize]);
-                zstd::stream::copy_decode(
-                    &mut Cursor::new(compressed_data),
-                    &mut decompressed_data,
-                )?;
-                decompressed_data.into_inner()
+                #[cfg(not(feature = "zstd"))]
+                return Err(
+                    UsmapError::unsupported_compression(self.compression_method as u8).into(),
+                );
+
+                #[cfg(feature = "zstd")]
+                {
+                    let mut decompressed_data = Cursor::new(vec![0u8; decompressed_size as usize]);
+                    zstd::stream::copy_decode(
+                        &mut Cursor::new(compressed_data),
+                        &mut decompressed_data,
+                    )?;
+                    decompressed_data.into_inner()
+                }
             }
             EUsmapCompressionMethod::Oodle => {
                 #[cfg(not(feature = "oodle"))]
@@ -308,16 +313,12 @@ impl Usmap {
 
                 #[cfg(feature = "oodle")]
                 {
-                    let compressed = vec![0u8; compressed_size as usize];
-
-                    let decompressed = oodle::decompress(
-                        &compressed,
-                        compressed_size as u64,
-                        decompressed_size as u64,
-                    )
-                    .ok_or_else(|| UsmapError::invalid_compression_data())?;
-
-                    decompressed
+                    let mut decompressed_data = vec![0u8; decompressed_size as usize];
+                    crate::compression::oodle::decompress(
+                        &compressed_data,
+                        &mut decompressed_data,
+                    )?;
+                    decompressed_data
                 }
             }
             EUsmapCompressionMethod::Unknown => {
@@ -337,13 +338,13 @@ impl Usmap {
 
         self.name_map = reader.read_array(|reader| {
             let name_length = reader.read_u8()?;
-            let mut buf = vec![0u8; name_length as usize - 1];
-            reader.read_exact(&mut buf)?;
+            let buf = reader.read_bytes((name_length as u64).saturating_sub(1))?;
             Ok(String::from_utf8(buf)?)
         })?;
 
         let enum_len = reader.read_u32::<LE>()?;
-        self.enum_map = IndexedMap::with_capacity(enum_len as usize);
+        // the count is read from the file, so don't trust it for preallocation
+        self.enum_map = IndexedMap::with_capacity(enum_len.min(0x1000) as usize);
 
         let mut reader = UsmapReader::new(&mut reader, &self.name_map, &self.custom_versions);
 
@@ -361,7 +362,7 @@ impl Usmap {
         }
 
         let schemas_len = reader.read_u32::<LE>()?;
-        self.schemas = IndexedMap::with_capacity(schemas_len as usize);
+        self.schemas = IndexedMap::with_capacity(schemas_len.min(0x1000) as usize);
 
         for _ in 0..schemas_len {
             let schema = UsmapSchema::read(&mut reader)?;
@@ -384,7 +385,7 @@ impl Usmap {
                         Ok(reader.read_fstring()?.unwrap_or_default())
                     })?;
 
-                for (_, _, schema) in self.schemas.iter_mut() {
+                for schema in self.schemas.values_mut() {
                     let index = match num_module_paths > u8::MAX as u16 {
                         true => reader.read_u16::<LE>()?,
                         false => reader.read_u8()? as u16,
@@ -29,6 +29,7 @@ pub enum EExportFilterFlags {
 
 /// Minimal information about an export
 #[derive(FNameContainer, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaseExport {
     /// Class index
     #[container_ignore]
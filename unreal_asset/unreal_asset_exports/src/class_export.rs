@@ -1,5 +1,6 @@
 //! Class export
 
+use std::hash::Hash;
 use std::io::SeekFrom;
 use std::mem::size_of;
 
@@ -7,18 +8,19 @@ use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
     containers::IndexedMap,
-    flags::EClassFlags,
+    flags::{EClassFlags, EObjectFlags},
     object_version::ObjectVersion,
     reader::{ArchiveReader, ArchiveWriter},
     types::{FName, PackageIndex},
     Error, FNameContainer,
 };
 
-use crate::{BaseExport, StructExport};
+use crate::{BaseExport, Export, StructExport};
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// Serialized interface reference
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SerializedInterfaceReference {
     /// Class
     pub class: PackageIndex,
@@ -39,8 +41,22 @@ impl SerializedInterfaceReference {
     }
 }
 
+/// Default subobject of a class
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DefaultSubobject {
+    /// Subobject export index
+    pub export_index: PackageIndex,
+    /// Subobject name
+    pub name: FName,
+    /// Subobject class index
+    pub class_index: PackageIndex,
+    /// Is this subobject a component template added by the blueprint construction script
+    pub construction_script: bool,
+}
+
 /// Class export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassExport {
     /// Base struct export
     pub struct_export: StructExport,
@@ -64,12 +80,33 @@ pub struct ClassExport {
     /// Deprecated force script order
     pub deprecated_force_script_order: bool,
     /// Is cooked
+    ///
+    /// Only `UClass` serializes this flag, enum and struct exports have no cooked flag
     pub cooked: Option<bool>,
     /// Class default object
     #[container_ignore]
     pub class_default_object: PackageIndex,
 }
 
+#[allow(clippy::derived_hash_with_manual_eq)]
+impl Hash for ClassExport {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.struct_export.hash(state);
+        for (name, index) in self.func_map.iter_key_value() {
+            name.hash(state);
+            index.hash(state);
+        }
+        self.class_flags.hash(state);
+        self.class_within.hash(state);
+        self.class_config_name.hash(state);
+        self.interfaces.hash(state);
+        self.class_generated_by.hash(state);
+        self.deprecated_force_script_order.hash(state);
+        self.cooked.hash(state);
+        self.class_default_object.hash(state);
+    }
+}
+
 impl ClassExport {
     /// Read a `ClassExport` from an asset
     pub fn from_base<Reader: ArchiveReader>(
@@ -159,6 +196,65 @@ impl ClassExport {
         })
     }
 
+    /// Get the functions of this class
+    ///
+    /// Returns the function name and the index of its `FunctionExport`
+    /// for every function the class declares, in serialized order
+    pub fn functions(&self) -> Vec<(FName, PackageIndex)> {
+        self.func_map
+            .iter_key_value()
+            .map(|(name, index)| (name.clone(), *index))
+            .collect()
+    }
+
+    /// Get the default subobjects of this class
+    ///
+    /// These are the native default subobjects of the class default object
+    /// and the component templates added by the blueprint construction script
+    ///
+    /// # Arguments
+    ///
+    /// * `exports` - exports of the asset this class is in
+    pub fn get_default_subobjects(&self, exports: &[Export]) -> Vec<DefaultSubobject> {
+        if !self.class_default_object.is_export() {
+            return Vec::new();
+        }
+        let Some(class_default_object) = exports.get(self.class_default_object.index as usize - 1)
+        else {
+            return Vec::new();
+        };
+        // the class default object is an instance of this class
+        let class_index = class_default_object.get_base_export().class_index;
+
+        let mut subobjects = Vec::new();
+        for (i, export) in exports.iter().enumerate() {
+            let base_export = export.get_base_export();
+
+            let native_subobject = base_export.outer_index == self.class_default_object
+                && base_export
+                    .object_flags
+                    .contains(EObjectFlags::RF_DEFAULT_SUB_OBJECT);
+            let construction_script = base_export.outer_index == class_index
+                && base_export
+                    .object_flags
+                    .contains(EObjectFlags::RF_ARCHETYPE_OBJECT)
+                && base_export
+                    .object_name
+                    .get_content(|name| name.ends_with("_GEN_VARIABLE"));
+
+            if native_subobject || construction_script {
+                subobjects.push(DefaultSubobject {
+                    export_index: PackageIndex::new(i as i32 + 1),
+                    name: base_export.object_name.clone(),
+                    class_index: base_export.class_index,
+                    construction_script,
+                });
+            }
+        }
+
+        subobjects
+    }
+
     /// Serialize a `ClassExport` interface
     fn serialize_interfaces<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         asset.write_i32::<LE>(self.interfaces.len() as i32)?;
@@ -199,7 +295,7 @@ impl ExportTrait for ClassExport {
         self.struct_export.write(asset)?;
 
         asset.write_i32::<LE>(self.func_map.len() as i32)?;
-        for (_, name, index) in &self.func_map {
+        for (name, index) in self.func_map.iter_key_value() {
             asset.write_fname(name)?;
             asset.write_i32::<LE>(index.index)?;
         }
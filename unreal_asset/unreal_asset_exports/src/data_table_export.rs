@@ -3,8 +3,9 @@
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
+    error::PropertyError,
     reader::{ArchiveReader, ArchiveWriter},
-    types::FName,
+    types::{fname::ToSerializedName, FName},
     unversioned::Ancestry,
     Error, FNameContainer,
 };
@@ -17,6 +18,7 @@ use crate::{BaseExport, NormalExport};
 
 /// Data table
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataTable {
     /// Data
     pub data: Vec<StructProperty>,
@@ -31,6 +33,7 @@ impl DataTable {
 
 /// Data table export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataTableExport {
     /// Base normal export
     pub normal_export: NormalExport,
@@ -89,6 +92,124 @@ impl DataTableExport {
             table,
         })
     }
+
+    /// Get a row by its row name, numbered names are matched by their `Name_0` display form
+    pub fn get_row(&self, name: &str) -> Option<&StructProperty> {
+        let name = FName::from_display(name);
+        self.table.data.iter().find(|e| e.name.eq_content(&name))
+    }
+
+    /// Get a mutable reference to a row by its row name
+    pub fn get_row_mut(&mut self, name: &str) -> Option<&mut StructProperty> {
+        let name = FName::from_display(name);
+        self.table
+            .data
+            .iter_mut()
+            .find(|e| e.name.eq_content(&name))
+    }
+
+    /// Insert a row, replacing an existing row with the same name in place or appending it
+    ///
+    /// The row must have the same struct type as the other rows and its properties
+    /// must have the same types as the properties with the same names in the other rows.
+    /// Returns the replaced row if there was one.
+    pub fn insert_row(
+        &mut self,
+        name: FName,
+        mut row: StructProperty,
+    ) -> Result<Option<StructProperty>, Error> {
+        let row_name = name.to_string();
+
+        let position = self
+            .table
+            .data
+            .iter()
+            .position(|e| e.name.eq_content(&name));
+
+        for existing in self
+            .table
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != position)
+            .map(|(_, e)| e)
+        {
+            check_row_layout(&row_name, existing, &row)?;
+        }
+
+        row.name = name;
+        match position {
+            Some(position) => Ok(Some(std::mem::replace(&mut self.table.data[position], row))),
+            None => {
+                self.table.data.push(row);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Remove a row by its row name, keeping the order of the other rows
+    pub fn remove_row(&mut self, name: &str) -> Option<StructProperty> {
+        let name = FName::from_display(name);
+        let position = self
+            .table
+            .data
+            .iter()
+            .position(|e| e.name.eq_content(&name))?;
+        Some(self.table.data.remove(position))
+    }
+}
+
+/// Check that a new row is laid out the same as an existing row
+fn check_row_layout(
+    row_name: &str,
+    existing: &StructProperty,
+    row: &StructProperty,
+) -> Result<(), Error> {
+    let same_struct_type = match (&existing.struct_type, &row.struct_type) {
+        (Some(a), Some(b)) => a.eq_content(b),
+        (None, None) => true,
+        _ => false,
+    };
+    if !same_struct_type {
+        let display = |e: &Option<FName>| {
+            e.as_ref()
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| String::from("None"))
+        };
+        return Err(PropertyError::invalid_struct(format!(
+            "Row {} has struct type {} but row {} has struct type {}",
+            row_name,
+            display(&row.struct_type),
+            existing.name,
+            display(&existing.struct_type)
+        ))
+        .into());
+    }
+
+    for property in &row.value {
+        let property_name = property.get_name();
+        let Some(existing_property) = existing
+            .value
+            .iter()
+            .find(|e| e.get_name().eq_content(&property_name))
+        else {
+            continue;
+        };
+
+        let (ty, existing_ty) = (
+            property.to_serialized_name(),
+            existing_property.to_serialized_name(),
+        );
+        if ty != existing_ty {
+            return Err(PropertyError::invalid_struct(format!(
+                "Property {} of row {} is a {} but is a {} in row {}",
+                property_name, row_name, ty, existing_ty, existing.name
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
 }
 
 impl ExportTrait for DataTableExport {
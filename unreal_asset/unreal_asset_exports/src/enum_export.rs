@@ -19,6 +19,7 @@ use crate::{BaseExport, NormalExport};
 
 /// Enum cpp form
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ECppForm {
     /// Regular
@@ -31,6 +32,7 @@ pub enum ECppForm {
 
 /// Enum
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UEnum {
     /// Enum names
     pub names: Vec<(FName, i64)>,
@@ -126,6 +128,7 @@ impl UEnum {
 
 /// Enum export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumExport {
     /// Base normal export
     pub normal_export: NormalExport,
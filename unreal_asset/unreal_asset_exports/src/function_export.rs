@@ -13,6 +13,7 @@ use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// Function export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionExport {
     /// Base struct export
     pub struct_export: StructExport,
@@ -14,6 +14,7 @@ use crate::{BaseExport, NormalExport};
 
 /// Level URL info
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct URL {
     /// Level protocol like "unreal" or "http"
     pub protocol: Option<String>,
@@ -33,6 +34,7 @@ pub struct URL {
 
 /// Level export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LevelExport {
     /// Base normal export
     pub normal_export: NormalExport,
@@ -17,20 +17,23 @@ pub mod data_table_export;
 pub mod enum_export;
 pub mod function_export;
 pub mod level_export;
+pub mod meta_data_export;
 pub mod normal_export;
 pub mod property_export;
 pub mod raw_export;
 pub mod string_table_export;
 pub mod struct_export;
+pub mod texture_2d_export;
 pub mod user_defined_struct_export;
 pub mod world_export;
 
 pub use self::{
     base_export::BaseExport, class_export::ClassExport, data_table_export::DataTableExport,
     enum_export::EnumExport, function_export::FunctionExport, level_export::LevelExport,
-    normal_export::NormalExport, property_export::PropertyExport, raw_export::RawExport,
-    string_table_export::StringTableExport, struct_export::StructExport,
-    user_defined_struct_export::UserDefinedStructExport, world_export::WorldExport,
+    meta_data_export::MetaDataExport, normal_export::NormalExport, property_export::PropertyExport,
+    raw_export::RawExport, string_table_export::StringTableExport, struct_export::StructExport,
+    texture_2d_export::Texture2DExport, user_defined_struct_export::UserDefinedStructExport,
+    world_export::WorldExport,
 };
 
 /// This must be implemented for all Exports
@@ -88,7 +91,8 @@ pub trait ExportTrait: Debug + Clone + PartialEq + Eq {
 
 /// Export
 #[enum_dispatch(ExportTrait, ExportNormalTrait, ExportBaseTrait)]
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[container_nobounds]
 pub enum Export {
     /// Base export
@@ -117,6 +121,8 @@ pub enum Export {
     DataTableExport,
     /// World export
     WorldExport,
+    /// Meta data export
+    MetaDataExport,
+    /// Texture2D export
+    Texture2DExport,
 }
-
-// todo: impl hash for export
@@ -0,0 +1,122 @@
+//! Meta data export
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    custom_version::FEditorObjectVersion,
+    reader::{ArchiveReader, ArchiveWriter},
+    types::{FName, PackageIndex},
+    Error, FNameContainer,
+};
+
+use crate::implement_get;
+use crate::ExportTrait;
+use crate::{BaseExport, NormalExport};
+
+/// Meta data of a single object
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectMetaData {
+    /// Object this meta data belongs to
+    #[container_ignore]
+    pub object: PackageIndex,
+    /// Meta data key/value pairs
+    pub values: Vec<(FName, Option<String>)>,
+}
+
+/// Meta data export
+///
+/// Editor-only export storing key/value meta data such as tooltips and categories
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MetaDataExport {
+    /// Base normal export
+    pub normal_export: NormalExport,
+    /// Per-object meta data
+    pub object_meta_data: Vec<ObjectMetaData>,
+    /// Meta data not associated with any object
+    ///
+    /// Only present if `FEditorObjectVersion` >= `RootMetaDataSupport`
+    pub root_meta_data: Option<Vec<(FName, Option<String>)>>,
+}
+
+implement_get!(MetaDataExport);
+
+/// Read a meta data key/value map
+fn read_meta_data_values<Reader: ArchiveReader>(
+    asset: &mut Reader,
+) -> Result<Vec<(FName, Option<String>)>, Error> {
+    let count = asset.read_i32::<LE>()?;
+    asset.read_array_with_length(count, |asset| {
+        Ok((asset.read_fname()?, asset.read_fstring()?))
+    })
+}
+
+/// Write a meta data key/value map
+fn write_meta_data_values<Writer: ArchiveWriter>(
+    asset: &mut Writer,
+    values: &[(FName, Option<String>)],
+) -> Result<(), Error> {
+    asset.write_i32::<LE>(values.len() as i32)?;
+    for (key, value) in values {
+        asset.write_fname(key)?;
+        asset.write_fstring(value.as_deref())?;
+    }
+    Ok(())
+}
+
+impl MetaDataExport {
+    /// Read a `MetaDataExport` from an asset
+    pub fn from_base<Reader: ArchiveReader>(
+        base: &BaseExport,
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let normal_export = NormalExport::from_base(base, asset)?;
+
+        let object_count = asset.read_i32::<LE>()?;
+        let object_meta_data = asset.read_array_with_length(object_count, |asset| {
+            let object = PackageIndex::new(asset.read_i32::<LE>()?);
+            let values = read_meta_data_values(asset)?;
+            Ok(ObjectMetaData { object, values })
+        })?;
+
+        let root_meta_data = match asset.get_custom_version::<FEditorObjectVersion>().version
+            >= FEditorObjectVersion::RootMetaDataSupport as i32
+        {
+            true => Some(read_meta_data_values(asset)?),
+            false => None,
+        };
+
+        Ok(MetaDataExport {
+            normal_export,
+            object_meta_data,
+            root_meta_data,
+        })
+    }
+}
+
+impl ExportTrait for MetaDataExport {
+    fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
+        self.normal_export.write(asset)?;
+
+        asset.write_i32::<LE>(self.object_meta_data.len() as i32)?;
+        for object_meta_data in &self.object_meta_data {
+            asset.write_i32::<LE>(object_meta_data.object.index)?;
+            write_meta_data_values(asset, &object_meta_data.values)?;
+        }
+
+        if asset.get_custom_version::<FEditorObjectVersion>().version
+            >= FEditorObjectVersion::RootMetaDataSupport as i32
+        {
+            let root_meta_data = self.root_meta_data.as_ref().ok_or_else(|| {
+                Error::no_data(
+                    "FEditorObjectVersion >= RootMetaDataSupport but root_meta_data is None"
+                        .to_string(),
+                )
+            })?;
+            write_meta_data_values(asset, root_meta_data)?;
+        }
+
+        Ok(())
+    }
+}
@@ -14,10 +14,12 @@ use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 ///
 /// This export is usually the base export for all other exports
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalExport {
     /// Base export
     pub base_export: BaseExport,
     /// Extra data
+    #[cfg_attr(feature = "serde", serde(with = "unreal_asset_base::serde_base64"))]
     pub extras: Vec<u8>,
     /// Properties
     pub properties: Vec<Property>,
@@ -18,6 +18,7 @@ macro_rules! parse_simple_property {
     ($prop_name:ident) => {
         /// $prop_name
         #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: FGenericProperty,
@@ -33,10 +34,16 @@ macro_rules! parse_simple_property {
         }
 
         impl FPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &FGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 Ok(())
             }
+
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
         }
     };
 }
@@ -51,6 +58,7 @@ macro_rules! parse_simple_property_index {
     ) => {
         /// $prop_name
         #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: FGenericProperty,
@@ -74,6 +82,10 @@ macro_rules! parse_simple_property_index {
         }
 
         impl FPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &FGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 $(
@@ -81,6 +93,12 @@ macro_rules! parse_simple_property_index {
                 )*
                 Ok(())
             }
+
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                $(
+                    traverse(&mut self.$index_name);
+                )*
+            }
         }
     };
 }
@@ -95,6 +113,7 @@ macro_rules! parse_simple_property_prop {
     ) => {
         /// $prop_name
         #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: FGenericProperty,
@@ -117,6 +136,10 @@ macro_rules! parse_simple_property_prop {
         }
 
         impl FPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &FGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 $(
@@ -124,6 +147,12 @@ macro_rules! parse_simple_property_prop {
                 )*
                 Ok(())
             }
+
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                $(
+                    self.$prop.traverse_package_indices(traverse);
+                )*
+            }
         }
     };
 }
@@ -131,13 +160,20 @@ macro_rules! parse_simple_property_prop {
 /// This must be implemented for all FProperties
 #[enum_dispatch]
 pub trait FPropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
+    /// Get this property's generic property
+    fn get_generic_property(&self) -> &FGenericProperty;
+
     /// Write `FProperty` to an asset
     fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error>;
+
+    /// Traverse the package indices of this property and of the properties nested in it
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F);
 }
 
 /// FProperty
 #[enum_dispatch(FPropertyTrait)]
 #[derive(FNameContainer, Hash, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[container_nobounds]
 pub enum FProperty {
     /// Generic FProperty
@@ -180,7 +216,18 @@ impl Eq for FProperty {}
 
 impl FProperty {
     /// Read an `FProperty` from an asset
+    ///
+    /// Fails if the property is nested deeper than the reader allows,
+    /// see [`ArchiveReader::enter_property`]
     pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
+        asset.enter_property()?;
+        let property = FProperty::read_serialized(asset);
+        asset.leave_property();
+        property
+    }
+
+    /// Read an `FProperty` without checking its nesting depth
+    fn read_serialized<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
         let serialized_type = asset.read_fname()?;
         serialized_type.get_content(|ty| {
             Ok::<FProperty, Error>(match ty {
@@ -208,6 +255,25 @@ impl FProperty {
         })
     }
 
+    /// Get this property's name
+    pub fn get_name(&self) -> &FName {
+        &self.get_generic_property().name
+    }
+
+    /// Get the properties nested in this property
+    ///
+    /// These are the inner property of an array, the element property of a set,
+    /// the key and value properties of a map and the underlying property of an enum
+    pub fn get_inner_properties(&self) -> Vec<&FProperty> {
+        match self {
+            FProperty::FArrayProperty(array) => vec![array.inner.as_ref()],
+            FProperty::FSetProperty(set) => vec![set.element_prop.as_ref()],
+            FProperty::FMapProperty(map) => vec![map.key_prop.as_ref(), map.value_prop.as_ref()],
+            FProperty::FEnumProperty(enum_property) => vec![enum_property.underlying_prop.as_ref()],
+            _ => Vec::new(),
+        }
+    }
+
     /// Write an `FProperty` to an asset
     pub fn write<Writer: ArchiveWriter>(
         property: &FProperty,
@@ -256,6 +322,7 @@ impl ToSerializedName for FProperty {
 
 /// Generic FProperty
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FGenericProperty {
     /// Property name
     pub name: FName,
@@ -283,34 +350,36 @@ pub struct FGenericProperty {
 
 /// Enum FProperty
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FEnumProperty {
     /// Generic property
-    generic_property: FGenericProperty,
+    pub generic_property: FGenericProperty,
     /// Enum value
     #[container_ignore]
-    enum_value: PackageIndex,
+    pub enum_value: PackageIndex,
     /// Underlying property
-    underlying_prop: Box<FProperty>,
+    pub underlying_prop: Box<FProperty>,
 }
 
 /// Boolean FProperty
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FBoolProperty {
     /// Generic property
-    generic_property: FGenericProperty,
+    pub generic_property: FGenericProperty,
 
     /// Field size
-    field_size: u8,
+    pub field_size: u8,
     /// Byte offset
-    byte_offset: u8,
+    pub byte_offset: u8,
     /// Byte mask
-    byte_mask: u8,
+    pub byte_mask: u8,
     /// Field mask
-    field_mask: u8,
+    pub field_mask: u8,
     /// Is native boolean
-    native_bool: bool,
+    pub native_bool: bool,
     /// Value
-    value: bool,
+    pub value: bool,
 }
 
 impl FGenericProperty {
@@ -350,6 +419,10 @@ impl FGenericProperty {
 }
 
 impl FPropertyTrait for FGenericProperty {
+    fn get_generic_property(&self) -> &FGenericProperty {
+        self
+    }
+
     fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         asset.write_fname(&self.name)?;
         asset.write_u32::<LE>(self.flags.bits())?;
@@ -361,6 +434,8 @@ impl FPropertyTrait for FGenericProperty {
         asset.write_u8(self.blueprint_replication_condition.into())?;
         Ok(())
     }
+
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
 }
 
 impl FEnumProperty {
@@ -379,12 +454,21 @@ impl FEnumProperty {
 }
 
 impl FPropertyTrait for FEnumProperty {
+    fn get_generic_property(&self) -> &FGenericProperty {
+        &self.generic_property
+    }
+
     fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         self.generic_property.write(asset)?;
         asset.write_i32::<LE>(self.enum_value.index)?;
         FProperty::write(self.underlying_prop.as_ref(), asset)?;
         Ok(())
     }
+
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        traverse(&mut self.enum_value);
+        self.underlying_prop.traverse_package_indices(traverse);
+    }
 }
 
 impl FBoolProperty {
@@ -411,6 +495,10 @@ impl FBoolProperty {
 }
 
 impl FPropertyTrait for FBoolProperty {
+    fn get_generic_property(&self) -> &FGenericProperty {
+        &self.generic_property
+    }
+
     fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         self.generic_property.write(asset)?;
         asset.write_u8(self.field_size)?;
@@ -421,6 +509,8 @@ impl FPropertyTrait for FBoolProperty {
         asset.write_bool(self.value)?;
         Ok(())
     }
+
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
 }
 
 parse_simple_property_prop!(
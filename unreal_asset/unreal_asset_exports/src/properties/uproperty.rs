@@ -11,7 +11,7 @@ use unreal_asset_base::{
     enums::{EArrayDim, ELifetimeCondition},
     flags::EPropertyFlags,
     reader::{ArchiveReader, ArchiveWriter},
-    types::{FName, PackageIndex},
+    types::{FName, PackageIndex, PackageIndexContainer},
     Error, FNameContainer,
 };
 
@@ -19,6 +19,7 @@ macro_rules! parse_simple_property {
     ($prop_name:ident) => {
         /// $prop_name
         #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: UGenericProperty
@@ -38,6 +39,10 @@ macro_rules! parse_simple_property {
                 self.generic_property.write(asset)?;
                 Ok(())
             }
+
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                self.generic_property.traverse_package_indices(traverse);
+            }
         }
     };
 
@@ -51,6 +56,7 @@ macro_rules! parse_simple_property {
     ) => {
         /// $prop_name
         #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: UGenericProperty,
@@ -81,6 +87,13 @@ macro_rules! parse_simple_property {
                 )*
                 Ok(())
             }
+
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                self.generic_property.traverse_package_indices(traverse);
+                $(
+                    traverse(&mut self.$field_name);
+                )*
+            }
         }
     }
 }
@@ -90,11 +103,15 @@ macro_rules! parse_simple_property {
 pub trait UPropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
     /// Write `UProperty` to an asset
     fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error>;
+
+    /// Traverse the package indices of this property
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F);
 }
 
 /// UProperty
 #[enum_dispatch(UPropertyTrait)]
 #[derive(FNameContainer, Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[container_nobounds]
 pub enum UProperty {
     /// Generic UProperty
@@ -203,6 +220,7 @@ impl UProperty {
 
 /// UField
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UField {
     /// Next field package index
     pub next: Option<PackageIndex>,
@@ -210,6 +228,7 @@ pub struct UField {
 
 /// Generic UProperty
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UGenericProperty {
     /// UField
     #[container_ignore]
@@ -229,6 +248,7 @@ pub struct UGenericProperty {
 
 /// Boolean UProperty
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UBoolProperty {
     /// Generic property
     pub generic_property: UGenericProperty,
@@ -274,6 +294,12 @@ impl UField {
     }
 }
 
+impl PackageIndexContainer for UField {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.next.traverse_package_indices(traverse);
+    }
+}
+
 impl UGenericProperty {
     /// Read a `UGenericProperty` from an asset
     pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
@@ -320,6 +346,10 @@ impl UPropertyTrait for UGenericProperty {
         }
         Ok(())
     }
+
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.u_field.traverse_package_indices(traverse);
+    }
 }
 
 impl UBoolProperty {
@@ -345,6 +375,10 @@ impl UPropertyTrait for UBoolProperty {
         asset.write_bool(self.native_bool)?;
         Ok(())
     }
+
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.generic_property.traverse_package_indices(traverse);
+    }
 }
 
 parse_simple_property!(
@@ -16,6 +16,7 @@ use crate::{BaseExport, NormalExport};
 ///
 /// This is a `UProperty` export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PropertyExport {
     /// Base normal export
     pub normal_export: NormalExport,
@@ -10,10 +10,12 @@ use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// An export that failed to deserialize is stored as `Vec<u8>`
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawExport {
     /// Base export
     pub base_export: BaseExport,
     /// Raw data
+    #[cfg_attr(feature = "serde", serde(with = "unreal_asset_base::serde_base64"))]
     pub data: Vec<u8>,
 }
 
@@ -43,6 +45,15 @@ impl RawExport {
         base: BaseExport,
         asset: &mut Reader,
     ) -> Result<Self, Error> {
+        // check the size against the data left before allocating for it
+        let remaining = asset.data_length()?.saturating_sub(asset.position());
+        if base.serial_size < 0 || base.serial_size as u64 > remaining {
+            return Err(Error::invalid_file(format!(
+                "Export serial size {} doesn't fit in the remaining {remaining} bytes",
+                base.serial_size
+            )));
+        }
+
         let mut data = vec![0u8; base.serial_size as usize];
         asset.read_exact(&mut data)?;
 
@@ -1,5 +1,7 @@
 //! String table export
 
+use std::hash::Hash;
+
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
@@ -14,6 +16,7 @@ use crate::{BaseExport, NormalExport};
 
 /// String table export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringTableExport {
     /// Base normal export
     pub normal_export: NormalExport,
@@ -25,6 +28,18 @@ pub struct StringTableExport {
 
 implement_get!(StringTableExport);
 
+#[allow(clippy::derived_hash_with_manual_eq)]
+impl Hash for StringTableExport {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.normal_export.hash(state);
+        self.namespace.hash(state);
+        for (key, value) in self.table.iter_key_value() {
+            key.hash(state);
+            value.hash(state);
+        }
+    }
+}
+
 impl StringTableExport {
     /// Read a `StringTableExport` from an asset
     pub fn from_base<Reader: ArchiveReader>(
@@ -64,7 +79,7 @@ impl ExportTrait for StringTableExport {
 
         asset.write_fstring(self.namespace.as_deref())?;
         asset.write_i32::<LE>(self.table.len() as i32)?;
-        for (_, key, value) in &self.table {
+        for (key, value) in self.table.iter_key_value() {
             asset.write_fstring(Some(key))?;
             asset.write_fstring(Some(value))?;
         }
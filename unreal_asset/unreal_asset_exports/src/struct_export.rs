@@ -1,13 +1,13 @@
 //! Struct export
 
-use std::io::SeekFrom;
+use std::io::{Cursor, SeekFrom};
 
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
     custom_version::FCoreObjectVersion,
     engine_version::EngineVersion,
-    reader::{ArchiveReader, ArchiveWriter},
+    reader::{ArchiveReader, ArchiveWriter, RawWriter},
     types::PackageIndex,
     Error, FNameContainer,
 };
@@ -20,6 +20,7 @@ use crate::{BaseExport, NormalExport};
 
 /// Struct export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructExport {
     /// Base normal export
     pub normal_export: NormalExport,
@@ -36,9 +37,21 @@ pub struct StructExport {
     pub loaded_properties: Vec<FProperty>,
     /// Script bytecode, exists if bytecode deserialized successfully
     pub script_bytecode: Option<Vec<KismetExpression>>,
+    /// Start offset of each `script_bytecode` expression in deserialized memory, as read
+    ///
+    /// These are the offsets bytecode jumps refer to, they aren't updated
+    /// when `script_bytecode` is modified and aren't used for writing.
+    /// Empty if the sizes of the expressions couldn't be computed
+    #[container_ignore]
+    pub script_bytecode_offsets: Vec<u32>,
     /// Script bytecode size
     pub script_bytecode_size: i32,
     /// Script bytecode raw, exists if bytecode couldn't deserialize successfully
+    /// or if the asset was read with raw bytecode retention enabled
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "unreal_asset_base::serde_base64::option")
+    )]
     pub script_bytecode_raw: Option<Vec<u8>>,
 }
 
@@ -79,15 +92,19 @@ impl StructExport {
         let script_storage_size = asset.read_i32::<LE>()?; // number of bytes in total
         let start_offset = asset.position();
 
-        let mut script_bytecode = None;
-        if asset.get_engine_version() >= EngineVersion::VER_UE4_16 {
-            script_bytecode =
-                StructExport::read_bytecode(asset, start_offset, script_storage_size).ok();
-        }
-
-        let script_bytecode_raw = match &script_bytecode {
-            Some(_) => None,
-            None => {
+        let script_bytecode = match asset.get_engine_version() >= EngineVersion::VER_UE4_16 {
+            true => StructExport::read_bytecode(asset, start_offset, script_storage_size).ok(),
+            false => None,
+        };
+        // offsets are only informational, parsed bytecode is kept even if they can't be computed
+        let script_bytecode_offsets = script_bytecode
+            .as_ref()
+            .and_then(|code| StructExport::bytecode_offsets_of(asset, code).ok())
+            .unwrap_or_default();
+
+        let script_bytecode_raw = match script_bytecode.is_some() && !asset.retain_raw_bytecode() {
+            true => None,
+            false => {
                 asset.seek(SeekFrom::Start(start_offset))?;
                 let mut data = vec![0u8; script_storage_size as usize];
                 asset.read_exact(&mut data)?;
@@ -103,11 +120,33 @@ impl StructExport {
             children,
             loaded_properties,
             script_bytecode,
+            script_bytecode_offsets,
             script_bytecode_size,
             script_bytecode_raw,
         })
     }
 
+    /// Get the raw script bytecode as it was read from the asset
+    ///
+    /// Always present if the bytecode couldn't be parsed, otherwise only present
+    /// if the asset was read with raw bytecode retention enabled
+    pub fn raw_bytecode(&self) -> Option<&[u8]> {
+        self.script_bytecode_raw.as_deref()
+    }
+
+    /// Get the start offset of each bytecode expression in deserialized memory
+    pub fn bytecode_offsets(&self) -> &[u32] {
+        &self.script_bytecode_offsets
+    }
+
+    /// Get the bytecode expression starting at an offset in deserialized memory
+    ///
+    /// Useful for resolving jump targets
+    pub fn expression_at_offset(&self, offset: u32) -> Option<&KismetExpression> {
+        let index = self.script_bytecode_offsets.binary_search(&offset).ok()?;
+        self.script_bytecode.as_ref()?.get(index)
+    }
+
     /// Read kismet bytecode
     fn read_bytecode<Reader: ArchiveReader>(
         asset: &mut Reader,
@@ -120,6 +159,30 @@ impl StructExport {
         }
         Ok(code)
     }
+
+    /// Get the start offset of each bytecode expression in deserialized memory
+    fn bytecode_offsets_of<Reader: ArchiveReader>(
+        asset: &Reader,
+        code: &[KismetExpression],
+    ) -> Result<Vec<u32>, Error> {
+        // deserialized sizes differ from sizes on disk, they are only known when writing
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = RawWriter::new(
+            &mut cursor,
+            asset.get_object_version(),
+            asset.get_object_version_ue5(),
+            asset.use_event_driven_loader(),
+            asset.get_name_map(),
+        );
+        let mut offsets = Vec::with_capacity(code.len());
+        let mut offset = 0;
+        for expression in code {
+            offsets.push(offset);
+            offset += KismetExpression::write(expression, &mut writer)? as u32;
+        }
+
+        Ok(offsets)
+    }
 }
 
 impl ExportTrait for StructExport {
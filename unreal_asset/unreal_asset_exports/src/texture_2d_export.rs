@@ -0,0 +1,344 @@
+//! Texture2D export
+
+use std::io::SeekFrom;
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    engine_version::EngineVersion,
+    object_version::ObjectVersionUE5,
+    reader::{ArchiveReader, ArchiveWriter},
+    types::{bulk_data::FByteBulkData, FName, FStripDataFlags},
+    Error, FNameContainer, Guid,
+};
+
+use crate::implement_get;
+use crate::ExportTrait;
+use crate::{BaseExport, NormalExport};
+
+/// Texture mip map
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FTexture2DMipMap {
+    /// Is cooked
+    pub cooked: bool,
+    /// Mip data
+    pub bulk_data: FByteBulkData,
+    /// Width
+    pub size_x: i32,
+    /// Height
+    pub size_y: i32,
+    /// Depth, only stored since 4.20
+    pub size_z: i32,
+}
+
+impl FTexture2DMipMap {
+    /// Read a `FTexture2DMipMap` from an asset
+    pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
+        let cooked = asset.read_i32::<LE>()? == 1;
+        let bulk_data = FByteBulkData::read(asset)?;
+        let size_x = asset.read_i32::<LE>()?;
+        let size_y = asset.read_i32::<LE>()?;
+        let size_z = match asset.get_engine_version() >= EngineVersion::VER_UE4_20 {
+            true => asset.read_i32::<LE>()?,
+            false => 1,
+        };
+
+        if !cooked {
+            return Err(Error::unimplemented(
+                "Uncooked texture mips are not supported".to_string(),
+            ));
+        }
+
+        Ok(FTexture2DMipMap {
+            cooked,
+            bulk_data,
+            size_x,
+            size_y,
+            size_z,
+        })
+    }
+
+    /// Write a `FTexture2DMipMap` to an asset
+    pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
+        asset.write_i32::<LE>(match self.cooked {
+            true => 1,
+            false => 0,
+        })?;
+        self.bulk_data.write(asset)?;
+        asset.write_i32::<LE>(self.size_x)?;
+        asset.write_i32::<LE>(self.size_y)?;
+        if asset.get_engine_version() >= EngineVersion::VER_UE4_20 {
+            asset.write_i32::<LE>(self.size_z)?;
+        }
+        Ok(())
+    }
+}
+
+/// Cooked texture data for a single pixel format
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FTexturePlatformData {
+    /// Pixel format name
+    pub pixel_format_name: FName,
+    /// Width
+    #[container_ignore]
+    pub size_x: i32,
+    /// Height
+    #[container_ignore]
+    pub size_y: i32,
+    /// Slice count and flags, only the slice count before 4.24
+    #[container_ignore]
+    pub packed_data: u32,
+    /// Pixel format, e.g. `PF_DXT5`
+    pub pixel_format: Option<String>,
+    /// Optional data, exists if `packed_data` has the optional data bit set
+    #[container_ignore]
+    pub opt_data: Option<(u32, u32)>,
+    /// First mip to serialize
+    #[container_ignore]
+    pub first_mip_to_serialize: i32,
+    /// Mips
+    #[container_ignore]
+    pub mips: Vec<FTexture2DMipMap>,
+    /// Is virtual, only stored since 4.23
+    #[container_ignore]
+    pub is_virtual: bool,
+}
+
+impl FTexturePlatformData {
+    /// `packed_data` bit set if optional data is present
+    const HAS_OPT_DATA: u32 = 1 << 30;
+
+    /// Read `FTexturePlatformData` from an asset
+    pub fn new<Reader: ArchiveReader>(
+        asset: &mut Reader,
+        pixel_format_name: FName,
+    ) -> Result<Self, Error> {
+        let size_x = asset.read_i32::<LE>()?;
+        let size_y = asset.read_i32::<LE>()?;
+        let packed_data = asset.read_u32::<LE>()?;
+        let pixel_format = asset.read_fstring()?;
+
+        let opt_data = match asset.get_engine_version() >= EngineVersion::VER_UE4_24
+            && packed_data & Self::HAS_OPT_DATA != 0
+        {
+            true => Some((asset.read_u32::<LE>()?, asset.read_u32::<LE>()?)),
+            false => None,
+        };
+
+        let first_mip_to_serialize = asset.read_i32::<LE>()?;
+        let mips = asset.read_array(FTexture2DMipMap::new)?;
+
+        let is_virtual = match asset.get_engine_version() >= EngineVersion::VER_UE4_23 {
+            true => asset.read_i32::<LE>()? == 1,
+            false => false,
+        };
+        if is_virtual {
+            return Err(Error::unimplemented(
+                "Virtual textures are not supported".to_string(),
+            ));
+        }
+
+        Ok(FTexturePlatformData {
+            pixel_format_name,
+            size_x,
+            size_y,
+            packed_data,
+            pixel_format,
+            opt_data,
+            first_mip_to_serialize,
+            mips,
+            is_virtual,
+        })
+    }
+
+    /// Write `FTexturePlatformData` to an asset
+    pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
+        asset.write_i32::<LE>(self.size_x)?;
+        asset.write_i32::<LE>(self.size_y)?;
+        asset.write_u32::<LE>(self.packed_data)?;
+        asset.write_fstring(self.pixel_format.as_deref())?;
+
+        if let Some((a, b)) = self.opt_data {
+            asset.write_u32::<LE>(a)?;
+            asset.write_u32::<LE>(b)?;
+        }
+
+        asset.write_i32::<LE>(self.first_mip_to_serialize)?;
+        asset.write_i32::<LE>(self.mips.len() as i32)?;
+        for mip in &self.mips {
+            mip.write(asset)?;
+        }
+
+        if asset.get_engine_version() >= EngineVersion::VER_UE4_23 {
+            asset.write_i32::<LE>(match self.is_virtual {
+                true => 1,
+                false => 0,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Mip of a texture
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MipData {
+    /// Width
+    pub width: i32,
+    /// Height
+    pub height: i32,
+    /// Depth
+    pub depth: i32,
+    /// Pixel format, e.g. `PF_DXT5`
+    pub pixel_format: Option<String>,
+    /// Mip data location, the payload can be read with `FByteBulkData::read_payload`
+    pub bulk_data: FByteBulkData,
+}
+
+/// Texture2D export
+///
+/// Only cooked textures are supported
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Texture2DExport {
+    /// Base normal export
+    pub normal_export: NormalExport,
+    /// Object guid, serialized after the properties
+    #[container_ignore]
+    pub object_guid: Option<Guid>,
+    /// Texture strip flags
+    #[container_ignore]
+    pub texture_strip_flags: FStripDataFlags,
+    /// Texture2D strip flags
+    #[container_ignore]
+    pub strip_flags: FStripDataFlags,
+    /// Cooked platform data for every pixel format
+    pub platform_data: Vec<FTexturePlatformData>,
+}
+
+implement_get!(Texture2DExport);
+
+impl Texture2DExport {
+    /// Read a `Texture2DExport` from an asset
+    pub fn from_base<Reader: ArchiveReader>(
+        base: &BaseExport,
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        if asset.get_object_version_ue5() > ObjectVersionUE5::UNKNOWN {
+            return Err(Error::unimplemented(
+                "UE5 textures are not supported".to_string(),
+            ));
+        }
+
+        let normal_export = NormalExport::from_base(base, asset)?;
+        let object_guid = match asset.read_i32::<LE>()? != 0 {
+            true => Some(asset.read_guid()?),
+            false => None,
+        };
+
+        let texture_strip_flags = FStripDataFlags::read(asset)?;
+        if !texture_strip_flags.is_editor_data_stripped() {
+            return Err(Error::unimplemented(
+                "Textures with editor data are not supported".to_string(),
+            ));
+        }
+
+        let strip_flags = FStripDataFlags::read(asset)?;
+        let cooked = asset.read_i32::<LE>()? == 1;
+        if !cooked {
+            return Err(Error::unimplemented(
+                "Uncooked textures are not supported".to_string(),
+            ));
+        }
+
+        let mut platform_data = Vec::new();
+        loop {
+            let pixel_format_name = asset.read_fname()?;
+            if pixel_format_name == "None" {
+                break;
+            }
+
+            // absolute offset of the next pixel format, recomputed when writing
+            match asset.get_engine_version() >= EngineVersion::VER_UE4_20 {
+                true => asset.read_i64::<LE>()?,
+                false => asset.read_i32::<LE>()? as i64,
+            };
+
+            platform_data.push(FTexturePlatformData::new(asset, pixel_format_name)?);
+        }
+
+        Ok(Texture2DExport {
+            normal_export,
+            object_guid,
+            texture_strip_flags,
+            strip_flags,
+            platform_data,
+        })
+    }
+
+    /// Get the mips of the first pixel format
+    pub fn mips(&self) -> Vec<MipData> {
+        self.platform_data
+            .first()
+            .map(|platform_data| {
+                platform_data
+                    .mips
+                    .iter()
+                    .map(|mip| MipData {
+                        width: mip.size_x,
+                        height: mip.size_y,
+                        depth: mip.size_z,
+                        pixel_format: platform_data.pixel_format.clone(),
+                        bulk_data: mip.bulk_data.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl ExportTrait for Texture2DExport {
+    fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
+        self.normal_export.write(asset)?;
+        match self.object_guid {
+            Some(ref guid) => {
+                asset.write_i32::<LE>(1)?;
+                asset.write_guid(guid)?;
+            }
+            None => asset.write_i32::<LE>(0)?,
+        }
+
+        self.texture_strip_flags.write(asset)?;
+        self.strip_flags.write(asset)?;
+        asset.write_i32::<LE>(1)?;
+
+        let large_skip_offset = asset.get_engine_version() >= EngineVersion::VER_UE4_20;
+        for platform_data in &self.platform_data {
+            asset.write_fname(&platform_data.pixel_format_name)?;
+
+            let skip_offset_position = asset.position();
+            match large_skip_offset {
+                true => asset.write_i64::<LE>(0)?,
+                false => asset.write_i32::<LE>(0)?,
+            };
+
+            platform_data.write(asset)?;
+
+            let end = asset.position();
+            let skip_offset = end + asset.get_package_offset();
+            asset.seek(SeekFrom::Start(skip_offset_position))?;
+            match large_skip_offset {
+                true => asset.write_i64::<LE>(skip_offset as i64)?,
+                false => asset.write_i32::<LE>(skip_offset as i32)?,
+            };
+            asset.seek(SeekFrom::Start(end))?;
+        }
+
+        let none = asset.get_name_map().get_mut().add_fname("None");
+        asset.write_fname(&none)?;
+
+        Ok(())
+    }
+}
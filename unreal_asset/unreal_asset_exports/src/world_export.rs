@@ -16,6 +16,7 @@ use crate::{BaseExport, NormalExport};
 ///
 /// This is a `World` export
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorldExport {
     /// Base normal export
     pub normal_export: NormalExport,
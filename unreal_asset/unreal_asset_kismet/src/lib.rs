@@ -15,18 +15,18 @@ use ordered_float::OrderedFloat;
 use unreal_asset_base::FNameContainer;
 
 use unreal_asset_base::{
-    error::KismetError,
     object_version::{ObjectVersion, ObjectVersionUE5},
     reader::{ArchiveReader, ArchiveWriter},
     types::{
         vector::{Transform, Vector, Vector4},
-        {FName, PackageIndex},
+        {FName, PackageIndex, PackageIndexContainer},
     },
     Error,
 };
 
 /// Kismet expression token
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum EExprToken {
     /// A local variable.
@@ -219,8 +219,111 @@ pub enum EExprToken {
     ExMax = 0xff,
 }
 
+/// Tokens that `KismetExpression::new` can read
+const SUPPORTED_OPCODES: [EExprToken; 93] = [
+    EExprToken::ExLocalVariable,
+    EExprToken::ExInstanceVariable,
+    EExprToken::ExDefaultVariable,
+    EExprToken::ExReturn,
+    EExprToken::ExJump,
+    EExprToken::ExJumpIfNot,
+    EExprToken::ExAssert,
+    EExprToken::ExNothing,
+    EExprToken::ExLet,
+    EExprToken::ExClassContext,
+    EExprToken::ExMetaCast,
+    EExprToken::ExLetBool,
+    EExprToken::ExEndParmValue,
+    EExprToken::ExEndFunctionParms,
+    EExprToken::ExSelf,
+    EExprToken::ExSkip,
+    EExprToken::ExContext,
+    EExprToken::ExContextFailSilent,
+    EExprToken::ExVirtualFunction,
+    EExprToken::ExFinalFunction,
+    EExprToken::ExIntConst,
+    EExprToken::ExFloatConst,
+    EExprToken::ExStringConst,
+    EExprToken::ExObjectConst,
+    EExprToken::ExNameConst,
+    EExprToken::ExRotationConst,
+    EExprToken::ExVectorConst,
+    EExprToken::ExByteConst,
+    EExprToken::ExIntZero,
+    EExprToken::ExIntOne,
+    EExprToken::ExTrue,
+    EExprToken::ExFalse,
+    EExprToken::ExTextConst,
+    EExprToken::ExNoObject,
+    EExprToken::ExTransformConst,
+    EExprToken::ExIntConstByte,
+    EExprToken::ExNoInterface,
+    EExprToken::ExDynamicCast,
+    EExprToken::ExStructConst,
+    EExprToken::ExEndStructConst,
+    EExprToken::ExSetArray,
+    EExprToken::ExEndArray,
+    EExprToken::ExPropertyConst,
+    EExprToken::ExUnicodeStringConst,
+    EExprToken::ExInt64Const,
+    EExprToken::ExUInt64Const,
+    EExprToken::ExPrimitiveCast,
+    EExprToken::ExSetSet,
+    EExprToken::ExEndSet,
+    EExprToken::ExSetMap,
+    EExprToken::ExEndMap,
+    EExprToken::ExSetConst,
+    EExprToken::ExEndSetConst,
+    EExprToken::ExMapConst,
+    EExprToken::ExEndMapConst,
+    EExprToken::ExStructMemberContext,
+    EExprToken::ExLetMulticastDelegate,
+    EExprToken::ExLetDelegate,
+    EExprToken::ExLocalVirtualFunction,
+    EExprToken::ExLocalFinalFunction,
+    EExprToken::ExLocalOutVariable,
+    EExprToken::ExDeprecatedOp4A,
+    EExprToken::ExInstanceDelegate,
+    EExprToken::ExPushExecutionFlow,
+    EExprToken::ExPopExecutionFlow,
+    EExprToken::ExComputedJump,
+    EExprToken::ExPopExecutionFlowIfNot,
+    EExprToken::ExBreakpoint,
+    EExprToken::ExInterfaceContext,
+    EExprToken::ExObjToInterfaceCast,
+    EExprToken::ExEndOfScript,
+    EExprToken::ExCrossInterfaceCast,
+    EExprToken::ExInterfaceToObjCast,
+    EExprToken::ExWireTracepoint,
+    EExprToken::ExSkipOffsetConst,
+    EExprToken::ExAddMulticastDelegate,
+    EExprToken::ExClearMulticastDelegate,
+    EExprToken::ExTracepoint,
+    EExprToken::ExLetObj,
+    EExprToken::ExLetWeakObjPtr,
+    EExprToken::ExBindDelegate,
+    EExprToken::ExRemoveMulticastDelegate,
+    EExprToken::ExCallMulticastDelegate,
+    EExprToken::ExLetValueOnPersistentFrame,
+    EExprToken::ExArrayConst,
+    EExprToken::ExEndArrayConst,
+    EExprToken::ExSoftObjectConst,
+    EExprToken::ExCallMath,
+    EExprToken::ExSwitchValue,
+    EExprToken::ExInstrumentationEvent,
+    EExprToken::ExArrayGetByRef,
+    EExprToken::ExClassSparseDataVariable,
+    EExprToken::ExFieldPathConst,
+];
+
+/// Get all kismet tokens that can be read
+pub fn supported_opcodes() -> &'static [EExprToken] {
+    &SUPPORTED_OPCODES
+}
+
 /// Kismet cast token
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ECastToken {
     /// Cast object to interface
@@ -233,8 +336,13 @@ pub enum ECastToken {
     Max = 0xFF,
 }
 
+impl PackageIndexContainer for ECastToken {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
+}
+
 /// Kismet instrumentation type
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum EScriptInstrumentationType {
     /// Class
@@ -273,8 +381,13 @@ pub enum EScriptInstrumentationType {
     Stop,
 }
 
+impl PackageIndexContainer for EScriptInstrumentationType {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
+}
+
 /// Kismet text literal type
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum EBlueprintTextLiteralType {
     /// Text is an empty string. The bytecode contains no strings, and you should use FText::GetEmpty() to initialize the FText instance.
@@ -291,6 +404,7 @@ pub enum EBlueprintTextLiteralType {
 
 /// Kismet field path
 #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldPath {
     /// Path
     pub path: Vec<FName>,
@@ -309,6 +423,12 @@ impl FieldPath {
     }
 }
 
+impl PackageIndexContainer for FieldPath {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        traverse(&mut self.resolved_owner);
+    }
+}
+
 /// Read a UTF-8 kismet string
 fn read_kismet_string<Reader: ArchiveReader>(asset: &mut Reader) -> Result<String, Error> {
     let mut data = Vec::new();
@@ -375,6 +495,7 @@ macro_rules! declare_expression {
     ) => {
         /// $name
         #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name {
             /// Kismet token
             #[container_ignore]
@@ -392,6 +513,15 @@ macro_rules! declare_expression {
         impl KismetExpressionDataTrait for $name {
             fn get_token(&self) -> EExprToken { self.token }
         }
+
+        impl KismetExpressionIndexTrait for $name {
+            #[allow(unused_variables)]
+            fn traverse_expression_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                $(
+                    self.$v.traverse_package_indices(traverse);
+                )*
+            }
+        }
     }
 }
 
@@ -405,6 +535,7 @@ macro_rules! implement_expression {
         $(
             $(#[$inner $($args)*])*
             #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct $name {
                 /// Kismet token
                 #[container_ignore]
@@ -425,6 +556,10 @@ macro_rules! implement_expression {
                 fn get_token(&self) -> EExprToken { self.token }
             }
 
+            impl KismetExpressionIndexTrait for $name {
+                fn traverse_expression_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
+            }
+
             impl $name {
                 /// Read `$name` from an asset
                 pub fn new<Reader: ArchiveReader>(_asset: &mut Reader) -> Result<Self, Error> {
@@ -495,6 +630,7 @@ macro_rules! implement_value_expression {
 
 /// Kismet script text
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FScriptText {
     /// Literal type
     #[container_ignore]
@@ -656,9 +792,24 @@ impl FScriptText {
     }
 }
 
+impl PackageIndexContainer for FScriptText {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.localized_source.traverse_package_indices(traverse);
+        self.localized_key.traverse_package_indices(traverse);
+        self.localized_namespace.traverse_package_indices(traverse);
+        self.invariant_literal_string
+            .traverse_package_indices(traverse);
+        self.literal_string.traverse_package_indices(traverse);
+        self.string_table_asset.traverse_package_indices(traverse);
+        self.string_table_id.traverse_package_indices(traverse);
+        self.string_table_key.traverse_package_indices(traverse);
+    }
+}
+
 // todo: replace with an enum with 2 variants
 /// Represents a Kismet bytecode pointer to an FProperty or FField.
 #[derive(FNameContainer, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KismetPropertyPointer {
     /// Pointer serialized as PackageIndex. Used in versions older than [`KismetPropertyPointer::XFER_PROP_POINTER_SWITCH_TO_SERIALIZING_AS_FIELD_PATH_VERSION`]
     #[container_ignore]
@@ -734,8 +885,16 @@ impl KismetPropertyPointer {
     }
 }
 
+impl PackageIndexContainer for KismetPropertyPointer {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.old.traverse_package_indices(traverse);
+        self.new.traverse_package_indices(traverse);
+    }
+}
+
 /// Kismet switch case
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KismetSwitchCase {
     /// Case value index
     pub case_index_value_term: KismetExpression,
@@ -760,6 +919,14 @@ impl KismetSwitchCase {
     }
 }
 
+impl PackageIndexContainer for KismetSwitchCase {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.case_index_value_term
+            .traverse_package_indices(traverse);
+        self.case_term.traverse_package_indices(traverse);
+    }
+}
+
 /// This must be implemented for all KismetExpressions
 #[enum_dispatch]
 pub trait KismetExpressionTrait: Debug + Clone + PartialEq + Eq + Hash {
@@ -781,13 +948,23 @@ pub trait KismetExpressionEnumEqTrait {
     fn enum_eq(&self, token: &EExprToken) -> bool;
 }
 
+/// Traverses the `PackageIndex` references of a KismetExpression,
+/// used to implement `PackageIndexContainer` for `KismetExpression`
+#[enum_dispatch]
+trait KismetExpressionIndexTrait {
+    /// Traverse the package indices of this expression and of the expressions nested in it
+    fn traverse_expression_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F);
+}
+
 /// Kismet expression
 #[enum_dispatch(
     KismetExpressionTrait,
     KismetExpressionEnumEqTrait,
-    KismetExpressionDataTrait
+    KismetExpressionDataTrait,
+    KismetExpressionIndexTrait
 )]
 #[derive(FNameContainer, Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[container_nobounds]
 pub enum KismetExpression {
     /// A local variable.
@@ -980,10 +1157,18 @@ pub enum KismetExpression {
 
 impl Eq for KismetExpression {}
 
+impl PackageIndexContainer for KismetExpression {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.traverse_expression_indices(traverse);
+    }
+}
+
 impl KismetExpression {
     /// Read a `KismetExpression` from an asset
     pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
-        let token: EExprToken = asset.read_u8()?.try_into()?;
+        let opcode = asset.read_u8()?;
+        let token =
+            EExprToken::try_from(opcode).map_err(|_| Error::unsupported_kismet_opcode(opcode))?;
         let expr: Result<Self, Error> = match token {
             EExprToken::ExLocalVariable => Ok(ExLocalVariable::new(asset)?.into()),
             EExprToken::ExInstanceVariable => Ok(ExInstanceVariable::new(asset)?.into()),
@@ -1086,11 +1271,7 @@ impl KismetExpression {
                 Ok(ExClassSparseDataVariable::new(asset)?.into())
             }
             EExprToken::ExFieldPathConst => Ok(ExFieldPathConst::new(asset)?.into()),
-            _ => Err(KismetError::expression(format!(
-                "Unknown kismet expression {}",
-                token as i32
-            ))
-            .into()),
+            _ => Err(Error::unsupported_kismet_opcode(opcode)),
         };
         expr
     }
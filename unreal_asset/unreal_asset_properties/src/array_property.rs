@@ -1,9 +1,11 @@
 //! Array property
 
+use crate::int_property::BytePropertyValue;
 use crate::property_prelude::*;
 
 /// Array property
 #[derive(FNameContainer, Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArrayProperty {
     /// Name
     pub name: FName,
@@ -13,7 +15,9 @@ pub struct ArrayProperty {
     pub property_guid: Option<Guid>,
     /// Property duplication index
     pub duplication_index: i32,
-    /// Array type
+    /// Array element type
+    ///
+    /// When set, this type is written as is, otherwise it's taken from the first element
     pub array_type: Option<FName>,
     /// Array values
     pub value: Vec<Property>,
@@ -68,6 +72,39 @@ impl ArrayProperty {
         }
     }
 
+    /// Get the enum names of a `ByteProperty` array
+    ///
+    /// Byte values are resolved using `enum_values`, which are the `(name, value)` pairs of the element's enum,
+    /// e.g. `UEnum::names` from an enum export. Values which are already serialized as names are returned as is.
+    pub fn get_enum_names(&self, enum_values: &[(FName, i64)]) -> Result<Vec<FName>, Error> {
+        self.value
+            .iter()
+            .map(|entry| {
+                let byte_property = cast!(Property, ByteProperty, entry).ok_or_else(|| {
+                    PropertyError::invalid_array(format!(
+                        "expected ByteProperty got {}",
+                        entry.to_serialized_name()
+                    ))
+                })?;
+
+                match &byte_property.value {
+                    BytePropertyValue::FName(name) => Ok(name.clone()),
+                    BytePropertyValue::Byte(byte) => enum_values
+                        .iter()
+                        .find(|(_, value)| *value == *byte as i64)
+                        .map(|(name, _)| name.clone())
+                        .ok_or_else(|| {
+                            PropertyError::invalid_array(format!(
+                                "Enum value {} is not in the enum",
+                                byte
+                            ))
+                            .into()
+                        }),
+                }
+            })
+            .collect()
+    }
+
     /// Read an `ArrayProperty` from an asset without reading the property header
     #[allow(clippy::too_many_arguments)]
     pub fn new_no_header<Reader: ArchiveReader>(
@@ -83,7 +120,7 @@ impl ArrayProperty {
     ) -> Result<Self, Error> {
         let num_entries = asset.read_i32::<LE>()?;
         let mut entries = Vec::new();
-        let mut name = name;
+        let mut element_name = name.clone();
 
         let mut struct_length = 1;
         let mut struct_guid = None;
@@ -124,8 +161,8 @@ impl ArrayProperty {
         {
             let mut full_type = FName::from_slice("Generic");
             if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
-                name = asset.read_fname()?;
-                if name == "None" {
+                element_name = asset.read_fname()?;
+                if element_name == "None" {
                     return Ok(ArrayProperty::default());
                 }
 
@@ -160,8 +197,8 @@ impl ArrayProperty {
 
             if num_entries == 0 {
                 dummy_struct = Some(StructProperty::dummy(
-                    name.clone(),
-                    ancestry.with_parent(name.clone()),
+                    element_name.clone(),
+                    new_ancestry.clone(),
                     full_type.clone(),
                     struct_guid,
                 ));
@@ -169,7 +206,7 @@ impl ArrayProperty {
             for _i in 0..num_entries {
                 let data = StructProperty::custom_header(
                     asset,
-                    name.clone(),
+                    element_name.clone(),
                     new_ancestry.clone(),
                     struct_length,
                     0,
@@ -238,13 +275,7 @@ impl ArrayProperty {
         include_header: bool,
         serialize_structs_differently: bool,
     ) -> Result<usize, Error> {
-        let array_type = match !self.value.is_empty() {
-            true => {
-                let value = self.value[0].to_serialized_name();
-                Some(asset.get_name_map().get_mut().add_fname(&value))
-            }
-            false => self.array_type.clone(),
-        };
+        let array_type = serialized_array_type(asset, self.array_type.as_ref(), &self.value)?;
 
         if include_header {
             asset.write_fname(array_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
@@ -273,11 +304,12 @@ impl ArrayProperty {
                 },
             }?;
 
+            // (length position, struct data start)
             let mut length_loc = None;
             if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
                 asset.write_fname(&property.name)?;
                 asset.write_fname(&asset.get_name_map().get_mut().add_fname("StructProperty"))?;
-                length_loc = Some(asset.position());
+                let length_position = asset.position();
                 asset.write_i64::<LE>(0)?;
                 asset.write_fname(
                     property.struct_type.as_ref().ok_or_else(|| {
@@ -286,13 +318,14 @@ impl ArrayProperty {
                 )?;
                 if asset.get_object_version() >= ObjectVersion::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG
                 {
-                    asset.write_guid(&property.property_guid.unwrap_or_default())?;
+                    asset.write_guid(&property.struct_guid.unwrap_or_default())?;
                 }
                 if asset.get_object_version()
                     >= ObjectVersion::VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG
                 {
                     asset.write_u8(0)?;
                 }
+                length_loc = Some((length_position, asset.position()));
             }
 
             for property in &self.value {
@@ -306,19 +339,12 @@ impl ArrayProperty {
                 struct_property.write(asset, false)?;
             }
 
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
-                let length_loc = length_loc.expect("Corrupted memory");
-                let full_len = asset.position() - length_loc;
+            // the inner tag length only covers the struct data, which doesn't depend on
+            // this array's own header or on the nesting level
+            if let Some((length_position, data_start)) = length_loc {
                 let new_loc = asset.position();
-                asset.seek(SeekFrom::Start(length_loc))?;
-                let length = full_len
-                    - 32
-                    - match include_header {
-                        true => 1,
-                        false => 0,
-                    };
-
-                asset.write_i32::<LE>(length as i32)?;
+                asset.seek(SeekFrom::Start(length_position))?;
+                asset.write_i64::<LE>((new_loc - data_start) as i64)?;
                 asset.seek(SeekFrom::Start(new_loc))?;
             }
         } else {
@@ -330,6 +356,45 @@ impl ArrayProperty {
     }
 }
 
+/// Get the element type to write for an array
+///
+/// An explicit `array_type` is used directly and must match every element,
+/// otherwise the type of the first element is used.
+/// The type is added to the name map of `asset`
+pub(crate) fn serialized_array_type<Writer: ArchiveWriter>(
+    asset: &mut Writer,
+    array_type: Option<&FName>,
+    values: &[Property],
+) -> Result<Option<FName>, Error> {
+    match array_type {
+        Some(array_type) => {
+            for value in values {
+                let value_type = value.to_serialized_name();
+                if *array_type != value_type {
+                    return Err(PropertyError::invalid_array(format!(
+                        "expected {} got {}",
+                        array_type.get_owned_content(),
+                        value_type
+                    ))
+                    .into());
+                }
+            }
+            let array_type = array_type.get_owned_content();
+            Ok(Some(asset.get_name_map().get_mut().add_fname(&array_type)))
+        }
+        None => Ok(values.first().map(|value| {
+            let value_type = value.to_serialized_name();
+            asset.get_name_map().get_mut().add_fname(&value_type)
+        })),
+    }
+}
+
+impl PackageIndexContainer for ArrayProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for ArrayProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
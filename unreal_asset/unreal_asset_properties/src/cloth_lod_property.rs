@@ -5,6 +5,7 @@ use crate::vector_property::Vector4Property;
 
 /// Mesh to mesh vertex data
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MeshToMeshVertData {
     /// Position barycentric coords and distance
     pub position_bary_coords_and_dist: Vector4Property,
@@ -100,6 +101,7 @@ impl MeshToMeshVertData {
 
 /// Cloth lod data property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClothLodDataProperty {
     /// Base struct property
     pub struct_property: StructProperty,
@@ -177,6 +179,12 @@ impl PropertyDataTrait for ClothLodDataProperty {
     }
 }
 
+impl PackageIndexContainer for ClothLodDataProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.struct_property.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for ClothLodDataProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 
 /// Color property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorProperty {
     /// Name
     pub name: FName,
@@ -23,6 +24,7 @@ impl_property_data_trait!(ColorProperty);
 
 /// Linear color property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearColorProperty {
     /// Name
     pub name: FName,
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Time span property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeSpanProperty {
     /// Name
     pub name: FName,
@@ -20,6 +21,7 @@ impl_property_data_trait!(TimeSpanProperty);
 
 /// Date time property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTimeProperty {
     /// Name
     pub name: FName,
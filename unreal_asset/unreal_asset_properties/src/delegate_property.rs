@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Delegate
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delegate {
     /// Delegate object
     #[container_ignore]
@@ -19,8 +20,15 @@ impl Delegate {
     }
 }
 
+impl PackageIndexContainer for Delegate {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.object.traverse_package_indices(traverse);
+    }
+}
+
 /// Delegate property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DelegateProperty {
     /// Name
     pub name: FName,
@@ -60,6 +68,12 @@ impl DelegateProperty {
     }
 }
 
+impl PackageIndexContainer for DelegateProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for DelegateProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -80,6 +94,7 @@ macro_rules! impl_multicast {
     ($property_name:ident) => {
         /// $property_name
         #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $property_name {
             /// Name
             pub name: FName,
@@ -106,13 +121,12 @@ macro_rules! impl_multicast {
                 let property_guid = optional_guid!(asset, include_header);
 
                 let length = asset.read_i32::<LE>()?;
-                let mut value = Vec::with_capacity(length as usize);
-                for _ in 0..length {
-                    value.push(Delegate::new(
+                let value = asset.read_array_with_length(length, |asset| {
+                    Ok(Delegate::new(
                         PackageIndex::new(asset.read_i32::<LE>()?),
                         asset.read_fname()?,
-                    ));
-                }
+                    ))
+                })?;
 
                 Ok($property_name {
                     name,
@@ -124,6 +138,12 @@ macro_rules! impl_multicast {
             }
         }
 
+        impl PackageIndexContainer for $property_name {
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                self.value.traverse_package_indices(traverse);
+            }
+        }
+
         impl PropertyTrait for $property_name {
             fn write<Writer: ArchiveWriter>(
                 &self,
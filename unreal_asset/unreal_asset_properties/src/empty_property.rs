@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Empty unversioned property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmptyProperty {
     /// Property type name
     pub type_name: FName,
@@ -2,8 +2,96 @@
 
 use crate::property_prelude::*;
 
+/// Integer type backing an enum, unversioned enum values are serialized as this type
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EnumUnderlyingType {
+    /// `Int8Property`
+    Int8,
+    /// `ByteProperty`
+    Byte,
+    /// `Int16Property`
+    Int16,
+    /// `UInt16Property`
+    UInt16,
+    /// `IntProperty`
+    Int,
+    /// `UInt32Property`
+    UInt32,
+    /// `Int64Property`
+    Int64,
+    /// `UInt64Property`
+    UInt64,
+}
+
+impl EnumUnderlyingType {
+    /// Get the underlying type of an enum from the type of its underlying property
+    pub fn from_property_type(property_type: &str) -> Option<Self> {
+        match property_type {
+            "Int8Property" => Some(EnumUnderlyingType::Int8),
+            "ByteProperty" => Some(EnumUnderlyingType::Byte),
+            "Int16Property" => Some(EnumUnderlyingType::Int16),
+            "UInt16Property" => Some(EnumUnderlyingType::UInt16),
+            "IntProperty" => Some(EnumUnderlyingType::Int),
+            "UInt32Property" => Some(EnumUnderlyingType::UInt32),
+            "Int64Property" => Some(EnumUnderlyingType::Int64),
+            "UInt64Property" => Some(EnumUnderlyingType::UInt64),
+            _ => None,
+        }
+    }
+
+    /// Get the serialized size of this type
+    pub fn size(&self) -> usize {
+        match self {
+            EnumUnderlyingType::Int8 | EnumUnderlyingType::Byte => size_of::<u8>(),
+            EnumUnderlyingType::Int16 | EnumUnderlyingType::UInt16 => size_of::<u16>(),
+            EnumUnderlyingType::Int | EnumUnderlyingType::UInt32 => size_of::<u32>(),
+            EnumUnderlyingType::Int64 | EnumUnderlyingType::UInt64 => size_of::<u64>(),
+        }
+    }
+
+    /// Index with all bits set, used for unset enums
+    fn none_index(&self) -> i64 {
+        match self {
+            EnumUnderlyingType::Byte => u8::MAX as i64,
+            EnumUnderlyingType::UInt16 => u16::MAX as i64,
+            EnumUnderlyingType::UInt32 => u32::MAX as i64,
+            _ => -1,
+        }
+    }
+
+    /// Read an index of this type
+    fn read<Reader: ArchiveReader>(&self, asset: &mut Reader) -> Result<i64, Error> {
+        Ok(match self {
+            EnumUnderlyingType::Int8 => asset.read_i8()? as i64,
+            EnumUnderlyingType::Byte => asset.read_u8()? as i64,
+            EnumUnderlyingType::Int16 => asset.read_i16::<LE>()? as i64,
+            EnumUnderlyingType::UInt16 => asset.read_u16::<LE>()? as i64,
+            EnumUnderlyingType::Int => asset.read_i32::<LE>()? as i64,
+            EnumUnderlyingType::UInt32 => asset.read_u32::<LE>()? as i64,
+            EnumUnderlyingType::Int64 => asset.read_i64::<LE>()?,
+            EnumUnderlyingType::UInt64 => asset.read_u64::<LE>()? as i64,
+        })
+    }
+
+    /// Write an index as this type
+    fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer, index: i64) -> Result<(), Error> {
+        match self {
+            EnumUnderlyingType::Int8 => asset.write_i8(index as i8)?,
+            EnumUnderlyingType::Byte => asset.write_u8(index as u8)?,
+            EnumUnderlyingType::Int16 => asset.write_i16::<LE>(index as i16)?,
+            EnumUnderlyingType::UInt16 => asset.write_u16::<LE>(index as u16)?,
+            EnumUnderlyingType::Int => asset.write_i32::<LE>(index as i32)?,
+            EnumUnderlyingType::UInt32 => asset.write_u32::<LE>(index as u32)?,
+            EnumUnderlyingType::Int64 => asset.write_i64::<LE>(index)?,
+            EnumUnderlyingType::UInt64 => asset.write_u64::<LE>(index as u64)?,
+        }
+        Ok(())
+    }
+}
+
 /// Enum property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumProperty {
     /// Name
     pub name: FName,
@@ -17,8 +105,12 @@ pub struct EnumProperty {
     pub enum_type: Option<FName>,
     /// Inner type, used only with unversioned properties
     pub inner_type: Option<FName>,
-    /// Enum value
+    /// Enum value, `None` if the enum is unset
     pub value: Option<FName>,
+    /// Serialized index of an unversioned enum value that's missing from the mappings
+    ///
+    /// Written back when `value` is `None` so unknown values aren't lost
+    pub unknown_index: Option<i64>,
 }
 impl_property_data_trait!(EnumProperty);
 
@@ -44,8 +136,10 @@ impl EnumProperty {
                 let inner_ty =
                     FName::new_dummy(enum_data.inner_property.get_property_type().to_string(), 0);
 
-                if inner_ty == "ByteProperty" {
-                    let enum_index = asset.read_u8()?;
+                if let Some(underlying_type) =
+                    inner_ty.get_content(EnumUnderlyingType::from_property_type)
+                {
+                    let enum_index = underlying_type.read(asset)?;
                     let info = enum_ty
                         .get_content(|ty| asset.get_mappings().unwrap().enum_map.get_by_key(ty))
                         .ok_or_else(|| {
@@ -53,10 +147,18 @@ impl EnumProperty {
                                 "Missing unversioned info for: ".to_string() + ty
                             }))
                         })?;
-                    let value = match enum_index == u8::MAX {
+                    let value = match enum_index == underlying_type.none_index() {
                         true => None,
-                        false => Some(FName::new_dummy(info[enum_index as usize].clone(), 0)),
+                        false => usize::try_from(enum_index)
+                            .ok()
+                            .and_then(|index| info.get(index))
+                            .map(|e| FName::new_dummy(e.clone(), 0)),
                     };
+                    let unknown_index =
+                        match value.is_none() && enum_index != underlying_type.none_index() {
+                            true => Some(enum_index),
+                            false => None,
+                        };
 
                     return Ok(EnumProperty {
                         name,
@@ -66,6 +168,7 @@ impl EnumProperty {
                         enum_type: Some(enum_ty),
                         inner_type: Some(inner_ty),
                         value,
+                        unknown_index,
                     });
                 }
 
@@ -82,6 +185,11 @@ impl EnumProperty {
             false => None,
         };
         let value = asset.read_fname()?;
+        // unset enums are serialized as "None"
+        let value = match value.get_number() == 0 && value == "None" {
+            true => None,
+            false => Some(value),
+        };
 
         Ok(EnumProperty {
             name,
@@ -90,9 +198,17 @@ impl EnumProperty {
             duplication_index,
             enum_type,
             inner_type,
-            value: Some(value),
+            value,
+            unknown_index: None,
         })
     }
+
+    /// Get the integer type unversioned values of this enum are serialized as
+    pub fn underlying_type(&self) -> Option<EnumUnderlyingType> {
+        self.inner_type
+            .as_ref()?
+            .get_content(EnumUnderlyingType::from_property_type)
+    }
 }
 
 impl PropertyTrait for EnumProperty {
@@ -101,48 +217,44 @@ impl PropertyTrait for EnumProperty {
         asset: &mut Writer,
         include_header: bool,
     ) -> Result<usize, Error> {
-        if asset.has_unversioned_properties()
-            && self
-                .inner_type
-                .as_ref()
-                .map(|e| e == "ByteProperty")
-                .unwrap_or(false)
+        if let Some(underlying_type) = self
+            .underlying_type()
+            .filter(|_| asset.has_unversioned_properties())
         {
-            self.enum_type
-                .as_ref()
-                .ok_or_else(|| {
-                    Error::no_data("enum_type is None on an unversioned property".to_string())
-                })?
-                .get_content(|enum_type| {
-                    let info = asset
-                        .get_mappings()
-                        .ok_or_else(PropertyError::no_mappings)?
-                        .enum_map
-                        .get_by_key(enum_type)
-                        .ok_or_else(|| {
-                            Error::invalid_file(
-                                "Missing unversioned info for: ".to_string() + enum_type,
-                            )
-                        })?;
+            let enum_index = match (self.value.as_ref(), self.unknown_index) {
+                (Some(value), _) => self
+                    .enum_type
+                    .as_ref()
+                    .ok_or_else(|| {
+                        Error::no_data("enum_type is None on an unversioned property".to_string())
+                    })?
+                    .get_content(|enum_type| {
+                        let info = asset
+                            .get_mappings()
+                            .ok_or_else(PropertyError::no_mappings)?
+                            .enum_map
+                            .get_by_key(enum_type)
+                            .ok_or_else(|| {
+                                Error::invalid_file(
+                                    "Missing unversioned info for: ".to_string() + enum_type,
+                                )
+                            })?;
 
-                    let enum_index = match self.value.as_ref() {
-                        Some(value) => info
-                            .iter()
-                            .enumerate()
-                            .find(|(_, e)| value == *e)
-                            .map(|(index, _)| index as u8)
+                        info.iter()
+                            .position(|e| value == e)
+                            .map(|index| index as i64)
                             .ok_or_else(|| {
                                 Error::invalid_file(
                                     "Missing unversioned info for: ".to_string() + enum_type,
                                 )
-                            })?,
-                        None => u8::MAX,
-                    };
+                            })
+                    })?,
+                (None, Some(unknown_index)) => unknown_index,
+                (None, None) => underlying_type.none_index(),
+            };
 
-                    asset.write_u8(enum_index)?;
-                    Ok::<(), Error>(())
-                })?;
-            return Ok(size_of::<u8>());
+            underlying_type.write(asset, enum_index)?;
+            return Ok(underlying_type.size());
         }
 
         if include_header {
@@ -153,7 +265,10 @@ impl PropertyTrait for EnumProperty {
             )?;
             asset.write_property_guid(self.property_guid.as_ref())?;
         }
-        asset.write_fname(self.value.as_ref().unwrap())?;
+        match self.value {
+            Some(ref value) => asset.write_fname(value)?,
+            None => asset.write_fname(&asset.get_name_map().get_mut().add_fname("None"))?,
+        }
 
         Ok(size_of::<i32>() * 2)
     }
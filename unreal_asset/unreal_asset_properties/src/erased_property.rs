@@ -0,0 +1,60 @@
+//! Object-safe property interface
+
+use std::fmt::Debug;
+
+use unreal_asset_base::reader::ArchiveWriter;
+use unreal_asset_base::types::{fname::ToSerializedName, FName};
+use unreal_asset_base::Error;
+
+use crate::{PropertyDataTrait, PropertyTrait};
+
+/// Object-safe subset of [`PropertyTrait`]
+///
+/// `PropertyTrait` can't be used as a trait object because of its `Hash + Clone + Eq` bounds
+/// and generic `write`, this trait fixes the writer type instead so that
+/// properties can be stored as `Box<dyn ErasedProperty<Writer>>`.
+///
+/// Implemented for every property and for [`crate::Property`] itself.
+///
+/// ```no_run
+/// use unreal_asset_base::{reader::ArchiveWriter, Error};
+/// use unreal_asset_properties::erased_property::ErasedProperty;
+///
+/// fn write_all<W: ArchiveWriter>(
+///     properties: &[Box<dyn ErasedProperty<W>>],
+///     asset: &mut W,
+/// ) -> Result<(), Error> {
+///     for property in properties {
+///         property.write(asset, true)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait ErasedProperty<Writer: ArchiveWriter>: Debug {
+    /// Write property to an asset
+    fn write(&self, asset: &mut Writer, include_header: bool) -> Result<usize, Error>;
+
+    /// Get property's name
+    fn get_name(&self) -> FName;
+
+    /// Add property's serialized type name to the writer's name map and get it as an `FName`
+    fn to_fname(&self, asset: &mut Writer) -> FName;
+}
+
+impl<Writer, T> ErasedProperty<Writer> for T
+where
+    Writer: ArchiveWriter,
+    T: PropertyTrait + ToSerializedName,
+{
+    fn write(&self, asset: &mut Writer, include_header: bool) -> Result<usize, Error> {
+        PropertyTrait::write(self, asset, include_header)
+    }
+
+    fn get_name(&self) -> FName {
+        PropertyDataTrait::get_name(self)
+    }
+
+    fn to_fname(&self, asset: &mut Writer) -> FName {
+        asset.add_fname(&self.to_serialized_name())
+    }
+}
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Float range property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FloatRangeProperty {
     /// Name
     pub name: FName,
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Gameplay tag container property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameplayTagContainerProperty {
     /// Name
     pub name: FName,
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Guid property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GuidProperty {
     /// Name
     pub name: FName,
@@ -33,6 +33,7 @@ macro_rules! impl_int_property {
 
 /// Int8 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int8Property {
     /// Name
     pub name: FName,
@@ -49,6 +50,7 @@ impl_property_data_trait!(Int8Property);
 
 /// Byte property value
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BytePropertyValue {
     /// Byte variant
     Byte(u8),
@@ -58,6 +60,7 @@ pub enum BytePropertyValue {
 
 /// Byte property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ByteProperty {
     /// Name
     pub name: FName,
@@ -76,6 +79,7 @@ impl_property_data_trait!(ByteProperty);
 
 /// Bool property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolProperty {
     /// Name
     pub name: FName,
@@ -92,6 +96,7 @@ impl_property_data_trait!(BoolProperty);
 
 /// Int32 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntProperty {
     /// Name
     pub name: FName,
@@ -108,6 +113,7 @@ impl_property_data_trait!(IntProperty);
 
 /// Int16 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int16Property {
     /// Name
     pub name: FName,
@@ -124,6 +130,7 @@ impl_property_data_trait!(Int16Property);
 
 /// Int64 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int64Property {
     /// Name
     pub name: FName,
@@ -140,6 +147,7 @@ impl_property_data_trait!(Int64Property);
 
 /// UInt16 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UInt16Property {
     /// Name
     pub name: FName,
@@ -156,6 +164,7 @@ impl_property_data_trait!(UInt16Property);
 
 /// UInt32 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UInt32Property {
     /// Name
     pub name: FName,
@@ -172,6 +181,7 @@ impl_property_data_trait!(UInt32Property);
 
 /// UInt64 property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UInt64Property {
     /// Name
     pub name: FName,
@@ -188,6 +198,7 @@ impl_property_data_trait!(UInt64Property);
 
 /// Float property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FloatProperty {
     /// Name
     pub name: FName,
@@ -204,6 +215,7 @@ impl_property_data_trait!(FloatProperty);
 
 /// Double property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoubleProperty {
     /// Name
     pub name: FName,
@@ -3,7 +3,7 @@
 
 //! Unreal asset properties
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::io::SeekFrom;
@@ -20,6 +20,7 @@ pub use unreal_asset_base::Guid;
 use unreal_asset_base::error::{Error, PropertyError};
 use unreal_asset_base::reader::{ArchiveReader, ArchiveWriter};
 use unreal_asset_base::types::fname::ToSerializedName;
+use unreal_asset_base::types::{PackageIndex, PackageIndexContainer};
 use unreal_asset_base::unversioned::header::UnversionedHeaderFragment;
 use unreal_asset_base::unversioned::{
     header::UnversionedHeader, properties::UsmapPropertyDataTrait,
@@ -33,6 +34,7 @@ pub mod date_property;
 pub mod delegate_property;
 pub mod empty_property;
 pub mod enum_property;
+pub mod erased_property;
 pub mod float_range_property;
 pub mod font_character_property;
 pub mod game_framework;
@@ -45,6 +47,7 @@ pub mod movies;
 pub mod niagara;
 pub mod object_property;
 pub mod per_platform_property;
+pub mod property_tag;
 pub mod raw_struct_property;
 pub mod rich_curve_key_property;
 pub mod sampler_property;
@@ -150,7 +153,7 @@ mod property_prelude {
     pub use unreal_asset_base::reader::{ArchiveReader, ArchiveWriter};
     pub use unreal_asset_base::types::{
         fname::{FName, ToSerializedName},
-        PackageIndex,
+        PackageIndex, PackageIndexContainer,
     };
     pub use unreal_asset_base::unversioned::{
         header::UnversionedHeader,
@@ -337,6 +340,7 @@ pub trait PropertyTrait: PropertyDataTrait + Debug + Hash + Clone + PartialEq +
 #[allow(clippy::large_enum_variant)]
 #[enum_dispatch(PropertyTrait, PropertyDataTrait)]
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[container_nobounds]
 pub enum Property {
     /// Bool property
@@ -539,10 +543,16 @@ impl Property {
 
             loop {
                 let current_fragment = header.fragments[header.current_fragment_index];
-                if header.unversioned_property_index > current_fragment.get_last_num() as usize {
+                if header.unversioned_property_index
+                    < current_fragment.first_num as usize + current_fragment.value_num as usize
+                {
                     break;
                 }
 
+                if current_fragment.is_last {
+                    return Ok(None);
+                }
+
                 header.current_fragment_index += 1;
                 header.unversioned_property_index =
                     header.fragments[header.current_fragment_index].first_num as usize;
@@ -622,6 +632,9 @@ impl Property {
     }
 
     /// Tries to read a property from an ArchiveReader while specified a type and length
+    ///
+    /// Fails if the property is nested deeper than the reader allows,
+    /// see [`ArchiveReader::enter_property`]
     #[allow(clippy::too_many_arguments)]
     pub fn from_type<Reader: ArchiveReader>(
         asset: &mut Reader,
@@ -633,6 +646,35 @@ impl Property {
         fallback_length: i64,
         duplication_index: i32,
         is_zero: bool,
+    ) -> Result<Self, Error> {
+        asset.enter_property()?;
+        let property = Property::read_type(
+            asset,
+            type_name,
+            name,
+            ancestry,
+            include_header,
+            length,
+            fallback_length,
+            duplication_index,
+            is_zero,
+        );
+        asset.leave_property();
+        property
+    }
+
+    /// Read a property of a type without checking its nesting depth
+    #[allow(clippy::too_many_arguments)]
+    fn read_type<Reader: ArchiveReader>(
+        asset: &mut Reader,
+        type_name: &FName,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        length: i64,
+        fallback_length: i64,
+        duplication_index: i32,
+        is_zero: bool,
     ) -> Result<Self, Error> {
         if is_zero {
             return Ok(EmptyProperty::new(type_name.clone(), name, ancestry).into());
@@ -1332,6 +1374,13 @@ impl Property {
         asset: &mut Writer,
         include_header: bool,
     ) -> Result<usize, Error> {
+        // unversioned properties have no tag, the header generated from the mappings replaces it
+        if asset.has_unversioned_properties() {
+            let begin = asset.position();
+            property.write(asset, include_header)?;
+            return Ok(begin as usize);
+        }
+
         asset.write_fname(&property.get_name())?;
 
         let property_serialized_name = property.to_serialized_name();
@@ -1360,6 +1409,121 @@ impl Property {
     }
 }
 
+impl PackageIndexContainer for Property {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        // every variant is listed so new properties that hold references can't be missed
+        match self {
+            Property::ObjectProperty(property) => property.traverse_package_indices(traverse),
+            Property::SetProperty(property) => property.traverse_package_indices(traverse),
+            Property::ArrayProperty(property) => property.traverse_package_indices(traverse),
+            Property::MapProperty(property) => property.traverse_package_indices(traverse),
+            Property::DelegateProperty(property) => property.traverse_package_indices(traverse),
+            Property::MulticastDelegateProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::MulticastSparseDelegateProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::MulticastInlineDelegateProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::StructProperty(property) => property.traverse_package_indices(traverse),
+            Property::ClothLodDataProperty(property) => property.traverse_package_indices(traverse),
+            Property::NiagaraVariableProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::NiagaraVariableWithOffsetProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::FontDataProperty(property) => property.traverse_package_indices(traverse),
+            Property::MovieSceneEvalTemplatePtrProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::MovieSceneTrackImplementationPtrProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::MovieSceneSequenceInstanceDataPtrProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::SectionEvaluationDataTreeProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::MovieSceneSegmentProperty(property) => {
+                property.traverse_package_indices(traverse)
+            }
+            Property::BoolProperty(_)
+            | Property::UInt16Property(_)
+            | Property::UInt32Property(_)
+            | Property::UInt64Property(_)
+            | Property::FloatProperty(_)
+            | Property::Int16Property(_)
+            | Property::Int64Property(_)
+            | Property::Int8Property(_)
+            | Property::IntProperty(_)
+            | Property::ByteProperty(_)
+            | Property::DoubleProperty(_)
+            | Property::NameProperty(_)
+            | Property::StrProperty(_)
+            | Property::TextProperty(_)
+            | Property::AssetObjectProperty(_)
+            | Property::SoftObjectProperty(_)
+            | Property::IntPointProperty(_)
+            | Property::VectorProperty(_)
+            | Property::Vector4Property(_)
+            | Property::Vector2DProperty(_)
+            | Property::BoxProperty(_)
+            | Property::Box2DProperty(_)
+            | Property::QuatProperty(_)
+            | Property::RotatorProperty(_)
+            | Property::PlaneProperty(_)
+            | Property::LinearColorProperty(_)
+            | Property::ColorProperty(_)
+            | Property::TimeSpanProperty(_)
+            | Property::DateTimeProperty(_)
+            | Property::GuidProperty(_)
+            | Property::PerPlatformBoolProperty(_)
+            | Property::PerPlatformIntProperty(_)
+            | Property::PerPlatformFloatProperty(_)
+            | Property::MaterialAttributesInputProperty(_)
+            | Property::ExpressionInputProperty(_)
+            | Property::ColorMaterialInputProperty(_)
+            | Property::ScalarMaterialInputProperty(_)
+            | Property::ShadingModelMaterialInputProperty(_)
+            | Property::VectorMaterialInputProperty(_)
+            | Property::Vector2MaterialInputProperty(_)
+            | Property::WeightedRandomSamplerProperty(_)
+            | Property::SkeletalMeshSamplingLODBuiltDataProperty(_)
+            | Property::SkeletalMeshAreaWeightedTriangleSampler(_)
+            | Property::SoftAssetPathProperty(_)
+            | Property::SoftObjectPathProperty(_)
+            | Property::SoftClassPathProperty(_)
+            | Property::StringAssetReferenceProperty(_)
+            | Property::RichCurveKeyProperty(_)
+            | Property::ViewTargetBlendParamsProperty(_)
+            | Property::GameplayTagContainerProperty(_)
+            | Property::SmartNameProperty(_)
+            | Property::EnumProperty(_)
+            | Property::FontCharacterProperty(_)
+            | Property::UniqueNetIdProperty(_)
+            | Property::FloatRangeProperty(_)
+            | Property::RawStructProperty(_)
+            | Property::MovieSceneEvaluationFieldEntityTreeProperty(_)
+            | Property::MovieSceneSubSequenceTreeProperty(_)
+            | Property::MovieSceneTrackFieldDataProperty(_)
+            | Property::MovieSceneEventParametersProperty(_)
+            | Property::MovieSceneFloatChannelProperty(_)
+            | Property::MovieSceneFloatValueProperty(_)
+            | Property::MovieSceneFrameRangeProperty(_)
+            | Property::MovieSceneSegmentIdentifierProperty(_)
+            | Property::MovieSceneTrackIdentifierProperty(_)
+            | Property::MovieSceneSequenceIdProperty(_)
+            | Property::MovieSceneEvaluationKeyProperty(_)
+            | Property::EmptyProperty(_)
+            | Property::UnknownProperty(_) => {}
+        }
+    }
+}
+
 /// Implements `ToSerializedName` trait for properties
 macro_rules! property_inner_serialized_name {
     ($($inner:ident : $name:expr),*) => {
@@ -1375,9 +1539,29 @@ macro_rules! property_inner_serialized_name {
                 }
             }
         }
+
+        $(
+            impl ToSerializedName for $inner {
+                fn to_serialized_name(&self) -> String {
+                    String::from($name)
+                }
+            }
+        )*
     };
 }
 
+impl ToSerializedName for UnknownProperty {
+    fn to_serialized_name(&self) -> String {
+        self.serialized_type.get_owned_content()
+    }
+}
+
+impl ToSerializedName for EmptyProperty {
+    fn to_serialized_name(&self) -> String {
+        self.type_name.get_owned_content()
+    }
+}
+
 property_inner_serialized_name! {
     SkeletalMeshSamplingLODBuiltDataProperty: "SkeletalMeshSamplingLODBuiltData",
     SkeletalMeshAreaWeightedTriangleSampler: "SkeletalMeshAreaWeightedTriangleSampler",
@@ -1482,10 +1666,9 @@ pub fn generate_unversioned_header<W: ArchiveWriter>(
         return Ok(None);
     };
 
-    let mut first_global_index = u32::MAX;
     let mut last_global_index = u32::MIN;
 
-    let mut properties_to_process = HashSet::new();
+    let mut properties_to_process = HashMap::new();
     let mut zero_properties: HashSet<u32> = HashSet::new();
 
     for property in properties {
@@ -1501,24 +1684,23 @@ pub fn generate_unversioned_header<W: ArchiveWriter>(
             zero_properties.insert(global_index);
         }
 
-        first_global_index = first_global_index.min(global_index);
         last_global_index = last_global_index.max(global_index);
-        properties_to_process.insert(global_index);
+        properties_to_process.insert(global_index, property);
     }
 
     // Sort properties and generate header fragments
     let mut sorted_properties = Vec::new();
 
     let mut fragments: Vec<UnversionedHeaderFragment> = Vec::new();
-    let mut last_num_before_fragment = 0;
+    // first global index not yet covered by a fragment
+    let mut next_index = 0;
 
     if !properties_to_process.is_empty() {
         loop {
-            let mut has_zeros = false;
-
             // Find next contiguous properties chunk
-            let mut start_index = last_num_before_fragment;
-            while !properties_to_process.contains(&start_index) && start_index <= last_global_index
+            let mut start_index = next_index;
+            while !properties_to_process.contains_key(&start_index)
+                && start_index <= last_global_index
             {
                 start_index += 1;
             }
@@ -1529,20 +1711,14 @@ pub fn generate_unversioned_header<W: ArchiveWriter>(
 
             // Process contiguous properties chunk
             let mut end_index = start_index;
-            while properties_to_process.contains(&end_index) {
-                if zero_properties.contains(&end_index) {
-                    has_zeros = true;
-                }
-
+            while let Some(property) = properties_to_process.get(&end_index) {
                 // todo: clone might not be needed
-                sorted_properties.push(properties[end_index as usize].clone());
+                sorted_properties.push((*property).clone());
                 end_index += 1;
             }
 
-            // Create extra fragments for this chunk
-            let mut skip_num = start_index - last_num_before_fragment - 1;
-            let mut value_num = (end_index - 1) - start_index + 1;
-
+            // Create extra skip fragments for this chunk
+            let mut skip_num = start_index - next_index;
             while skip_num > i8::MAX as u32 {
                 fragments.push(UnversionedHeaderFragment {
                     skip_num: i8::MAX as u8,
@@ -1553,28 +1729,27 @@ pub fn generate_unversioned_header<W: ArchiveWriter>(
                 });
                 skip_num -= i8::MAX as u32;
             }
-            while value_num > i8::MAX as u32 {
+
+            // Create value fragments for this chunk, the first one carries the remaining skip
+            let mut first_num = start_index;
+            while first_num < end_index {
+                let value_num = u32::min(end_index - first_num, i8::MAX as u32);
+                let has_zeros =
+                    (first_num..first_num + value_num).any(|e| zero_properties.contains(&e));
+
                 fragments.push(UnversionedHeaderFragment {
-                    skip_num: 0,
-                    value_num: i8::MAX as u8,
-                    first_num: 0,
+                    skip_num: skip_num as u8,
+                    value_num: value_num as u8,
+                    first_num: first_num as u8,
                     is_last: false,
-                    has_zeros: false,
+                    has_zeros,
                 });
-                value_num -= i8::MAX as u32;
+
+                skip_num = 0;
+                first_num += value_num;
             }
 
-            // Create the main fragment for this chunk
-            let fragment = UnversionedHeaderFragment {
-                skip_num: skip_num as u8,
-                value_num: value_num as u8,
-                first_num: start_index as u8,
-                is_last: false,
-                has_zeros,
-            };
-
-            fragments.push(fragment);
-            last_num_before_fragment = end_index - 1;
+            next_index = end_index;
         }
     } else {
         fragments.push(parent_name.get_content(|name| UnversionedHeaderFragment {
@@ -1595,7 +1770,7 @@ pub fn generate_unversioned_header<W: ArchiveWriter>(
 
     for fragment in fragments.iter().filter(|e| e.has_zeros) {
         for i in 0..fragment.value_num {
-            let is_zero = zero_properties.contains(&((fragment.first_num + i) as u32));
+            let is_zero = zero_properties.contains(&(fragment.first_num as u32 + i as u32));
             if !is_zero {
                 has_non_zero_values = true;
             }
@@ -8,6 +8,7 @@ use crate::property_prelude::*;
 
 /// Map property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MapProperty {
     /// Name
     pub name: FName,
@@ -30,12 +31,16 @@ impl_property_data_trait!(MapProperty);
 
 #[allow(clippy::derived_hash_with_manual_eq)]
 impl Hash for MapProperty {
-    //todo: probably do something with map
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.name.hash(state);
         self.property_guid.hash(state);
         self.key_type.hash(state);
         self.value_type.hash(state);
+        for (key, value) in self.value.iter_key_value() {
+            key.hash(state);
+            value.hash(state);
+        }
+        self.keys_to_remove.hash(state);
     }
 }
 
@@ -156,8 +161,7 @@ impl MapProperty {
         let type_2 = type_2.ok_or_else(|| Error::invalid_file("No type2".to_string()))?;
 
         for _ in 0..num_keys_to_remove as usize {
-            let mut vec = Vec::with_capacity(num_keys_to_remove as usize);
-            vec.push(MapProperty::map_type_to_class(
+            keys_to_remove = Some(vec![MapProperty::map_type_to_class(
                 asset,
                 type_1.clone(),
                 name.clone(),
@@ -165,8 +169,7 @@ impl MapProperty {
                 0,
                 false,
                 true,
-            )?);
-            keys_to_remove = Some(vec);
+            )?]);
         }
 
         let num_entries = asset.read_i32::<LE>()?;
@@ -207,6 +210,21 @@ impl MapProperty {
     }
 }
 
+impl PackageIndexContainer for MapProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        // keys are immutable in the map, so it has to be rebuilt
+        let entries = std::mem::replace(&mut self.value, IndexedMap::new());
+        let mut value = IndexedMap::with_capacity(entries.len());
+        for (_, mut key, mut entry) in entries {
+            key.traverse_package_indices(traverse);
+            entry.traverse_package_indices(traverse);
+            value.insert(key, entry);
+        }
+        self.value = value;
+        self.keys_to_remove.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for MapProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -214,7 +232,7 @@ impl PropertyTrait for MapProperty {
         include_header: bool,
     ) -> Result<usize, Error> {
         if include_header && !asset.has_unversioned_properties() {
-            if let Some((_, key, value)) = self.value.iter().next() {
+            if let Some((key, value)) = self.value.iter_key_value().next() {
                 let key_name = key.to_serialized_name();
                 let value_name = value.to_serialized_name();
 
@@ -248,7 +266,7 @@ impl PropertyTrait for MapProperty {
 
         asset.write_i32::<LE>(self.value.len() as i32)?;
 
-        for (_, key, value) in &self.value {
+        for (key, value) in self.value.iter_key_value() {
             key.write(asset, false)?;
             value.write(asset, false)?;
         }
@@ -193,6 +193,7 @@ pub enum EMovieSceneCompletionMode {
 #[repr(u8)]
 #[allow(non_camel_case_types)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ESectionEvaluationFlags {
     /// None
     None = 0,
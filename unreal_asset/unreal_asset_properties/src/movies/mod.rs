@@ -23,6 +23,7 @@ pub mod section_evaluation_data_tree_property;
 
 /// Movie scene tangent data
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneTangentData {
     /// Arrive tangent
     pub arrive_tangent: OrderedFloat<f32>,
@@ -35,6 +36,7 @@ pub struct MovieSceneTangentData {
     /// Tangent weight mode
     pub tangent_weight_mode: RichCurveTangentWeightMode,
     /// Padding
+    #[cfg_attr(feature = "serde", serde(with = "unreal_asset_base::serde_base64"))]
     pub padding: Vec<u8>,
     /// Is compiled with clang win64
     clang_win64: bool,
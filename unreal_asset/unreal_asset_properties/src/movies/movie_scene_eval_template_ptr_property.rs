@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Movie scene evaluation template pointer property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneEvalTemplatePtrProperty {
     /// Name
     pub name: FName,
@@ -64,6 +65,12 @@ impl MovieSceneEvalTemplatePtrProperty {
     }
 }
 
+impl PackageIndexContainer for MovieSceneEvalTemplatePtrProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for MovieSceneEvalTemplatePtrProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -9,6 +9,7 @@ use crate::property_prelude::*;
 
 /// Movie scene evaluation entry
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FEntry {
     /// Start index
     pub start_index: i32,
@@ -43,6 +44,7 @@ impl FEntry {
 
 /// Evaluation tree entry handle
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EvaluationTreeEntryHandle {
     /// Entry index
     pub entry_index: i32,
@@ -65,6 +67,7 @@ impl EvaluationTreeEntryHandle {
 
 /// Movie scene evaluation tree node handle
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneEvaluationTreeNodeHandle {
     /// Children handle
     pub children_handle: EvaluationTreeEntryHandle,
@@ -95,6 +98,7 @@ impl MovieSceneEvaluationTreeNodeHandle {
 
 /// Generic evaluation tree entry container
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TEvaluationTreeEntryContainer<T>
 where
     T: Debug + Clone + PartialEq + Eq + Hash,
@@ -106,6 +110,15 @@ where
     pub items: Vec<T>,
 }
 
+impl<T> PackageIndexContainer for TEvaluationTreeEntryContainer<T>
+where
+    T: Debug + Clone + PartialEq + Eq + Hash + PackageIndexContainer,
+{
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.items.traverse_package_indices(traverse);
+    }
+}
+
 impl<T> TEvaluationTreeEntryContainer<T>
 where
     T: Debug + Clone + PartialEq + Eq + Hash,
@@ -115,24 +128,32 @@ where
         TEvaluationTreeEntryContainer { entries, items }
     }
 
+    /// Get the items of an entry
+    ///
+    /// Returns an empty slice for invalid handles
+    pub fn get(&self, handle: EvaluationTreeEntryHandle) -> &[T] {
+        let Some(entry) = usize::try_from(handle.entry_index)
+            .ok()
+            .and_then(|e| self.entries.get(e))
+        else {
+            return &[];
+        };
+
+        let start = entry.start_index.max(0) as usize;
+        let end = start.saturating_add(entry.size.max(0) as usize);
+        self.items.get(start..end).unwrap_or_default()
+    }
+
     /// Read a `TEvaluationTreeEntryContainer` from an asset
     pub fn read<Reader: ArchiveReader>(
         asset: &mut Reader,
         item_reader: fn(&mut Reader) -> Result<T, Error>,
     ) -> Result<Self, Error> {
         let entries_amount = asset.read_i32::<LE>()?;
-        let mut entries = Vec::with_capacity(entries_amount as usize);
-
-        for _ in 0..entries_amount {
-            entries.push(FEntry::new(asset)?);
-        }
+        let entries = asset.read_array_with_length(entries_amount, FEntry::new)?;
 
         let items_amount = asset.read_i32::<LE>()?;
-        let mut items = Vec::with_capacity(items_amount as usize);
-
-        for _ in 0..entries_amount {
-            items.push(item_reader(asset)?);
-        }
+        let items = asset.read_array_with_length(items_amount, item_reader)?;
 
         Ok(TEvaluationTreeEntryContainer { entries, items })
     }
@@ -159,6 +180,7 @@ where
 
 /// Generic movie scene evaluation tree
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TMovieSceneEvaluationTree<T>
 where
     T: Debug + Clone + PartialEq + Eq + Hash,
@@ -173,6 +195,15 @@ where
     pub data: TEvaluationTreeEntryContainer<T>,
 }
 
+impl<T> PackageIndexContainer for TMovieSceneEvaluationTree<T>
+where
+    T: Debug + Clone + PartialEq + Eq + Hash + PackageIndexContainer,
+{
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.data.traverse_package_indices(traverse);
+    }
+}
+
 impl<T> TMovieSceneEvaluationTree<T>
 where
     T: Debug + Clone + PartialEq + Eq + Hash,
@@ -190,6 +221,44 @@ where
         }
     }
 
+    /// Get the data of a node
+    pub fn get_node_data(&self, node: &MovieSceneEvaluationTreeNode) -> &[T] {
+        self.data.get(node.data_id)
+    }
+
+    /// Get the children of a node
+    pub fn get_children(
+        &self,
+        node: &MovieSceneEvaluationTreeNode,
+    ) -> &[MovieSceneEvaluationTreeNode] {
+        self.child_nodes.get(node.children_id)
+    }
+
+    /// Get the data of every node that has any, together with the node's range
+    ///
+    /// Nodes are visited depth-first starting at the root node
+    pub fn get_ranges(&self) -> Vec<(&FFrameNumberRange, &[T])> {
+        let mut ranges = Vec::new();
+        let mut stack = vec![&self.root_node];
+        // every child node can only be visited once in a well-formed tree
+        let mut budget = self.child_nodes.items.len() + 1;
+
+        while let Some(node) = stack.pop() {
+            if budget == 0 {
+                break;
+            }
+            budget -= 1;
+
+            let data = self.get_node_data(node);
+            if !data.is_empty() {
+                ranges.push((&node.range, data));
+            }
+            stack.extend(self.get_children(node).iter().rev());
+        }
+
+        ranges
+    }
+
     /// Read a `TMovieSceneEvaluationTree` from an asset
     pub fn read<Reader: ArchiveReader>(
         asset: &mut Reader,
@@ -230,6 +299,7 @@ where
 
 /// Movie scene evaluation tree node
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneEvaluationTreeNode {
     /// Frame number range
     pub range: FFrameNumberRange,
@@ -270,6 +340,7 @@ impl MovieSceneEvaluationTreeNode {
 
 /// Movie entity and metadata index
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FEntityAndMetaDataIndex {
     /// Entity index
     pub entity_index: i32,
@@ -300,6 +371,7 @@ impl FEntityAndMetaDataIndex {
 
 /// Movie scene evaluation field entity tree
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneEvaluationFieldEntityTree {
     /// Serialized data
     pub serialized_data: TMovieSceneEvaluationTree<FEntityAndMetaDataIndex>,
@@ -314,6 +386,11 @@ impl MovieSceneEvaluationFieldEntityTree {
         Ok(MovieSceneEvaluationFieldEntityTree { serialized_data })
     }
 
+    /// Get the entities of every time range in this tree
+    pub fn get_entities(&self) -> Vec<(&FFrameNumberRange, &[FEntityAndMetaDataIndex])> {
+        self.serialized_data.get_ranges()
+    }
+
     /// Write a `MovieSceneEvaluationFieldEntityTree` to an asset
     pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         self.serialized_data.write(asset, |writer, node| {
@@ -4,10 +4,12 @@ use crate::property_prelude::*;
 
 /// Movie scene event parameters
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneEventParameters {
     /// Struct type
     pub struct_type: SoftObjectPath,
     /// Struct raw data
+    #[cfg_attr(feature = "serde", serde(with = "unreal_asset_base::serde_base64"))]
     pub struct_bytes: Vec<u8>,
 }
 
@@ -17,8 +19,7 @@ impl MovieSceneEventParameters {
         let struct_type = SoftObjectPath::new(asset)?;
 
         let struct_bytes_length = asset.read_i32::<LE>()?;
-        let mut struct_bytes = vec![0u8; struct_bytes_length as usize];
-        asset.read_exact(&mut struct_bytes)?;
+        let struct_bytes = asset.read_bytes(struct_bytes_length as u64)?;
 
         Ok(MovieSceneEventParameters {
             struct_type,
@@ -39,6 +40,7 @@ impl MovieSceneEventParameters {
 
 /// Movie scene event parameters property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneEventParametersProperty {
     /// Name
     pub name: FName,
@@ -9,6 +9,7 @@ use super::movie_scene_float_value_property::MovieSceneFloatValue;
 
 /// Movie scene float channel
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneFloatChannel {
     /// Pre infinity extrapolation
     pub pre_infinity_extrap: RichCurveExtrapolation,
@@ -44,19 +45,17 @@ impl MovieSceneFloatChannel {
         let times_struct_length = asset.read_i32::<LE>()?;
         let times_length = asset.read_i32::<LE>()?;
 
-        let mut times = Vec::with_capacity(times_length as usize);
-        for _ in 0..times_length {
-            times.push(FrameNumber::new(asset.read_i32::<LE>()?));
-        }
+        let times = asset.read_array_with_length(times_length, |asset| {
+            Ok(FrameNumber::new(asset.read_i32::<LE>()?))
+        })?;
 
         let values_struct_length = asset.read_i32::<LE>()?;
         let values_length = asset.read_i32::<LE>()?;
 
-        let mut values = Vec::with_capacity(values_length as usize);
-        for _ in 0..values_length {
-            //todo: clangwin64 is always false?
-            values.push(MovieSceneFloatValue::new(asset, false)?);
-        }
+        //todo: clangwin64 is always false?
+        let values = asset.read_array_with_length(values_length, |asset| {
+            MovieSceneFloatValue::new(asset, false)
+        })?;
 
         let default_value = asset.read_f32::<LE>()?;
         let has_default_value = asset.read_i32::<LE>()? == 1;
@@ -110,6 +109,7 @@ impl MovieSceneFloatChannel {
 
 /// Movie scene float channel property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneFloatChannelProperty {
     /// Name
     pub name: FName,
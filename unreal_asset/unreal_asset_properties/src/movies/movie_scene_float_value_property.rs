@@ -7,6 +7,7 @@ use super::MovieSceneTangentData;
 
 /// Movie scene float value
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneFloatValue {
     /// Value
     pub value: OrderedFloat<f32>,
@@ -49,6 +50,7 @@ impl MovieSceneFloatValue {
 
 /// Movie scene float value property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneFloatValueProperty {
     /// Name
     pub name: FName,
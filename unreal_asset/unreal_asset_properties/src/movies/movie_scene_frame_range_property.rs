@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 
 /// Int32 value bound by a range
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int32RangeBound {
     /// Type
     pub ty: ERangeBoundTypes,
@@ -33,6 +34,7 @@ impl Int32RangeBound {
 
 /// Movie scene frame range property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneFrameRangeProperty {
     /// Name
     pub name: FName,
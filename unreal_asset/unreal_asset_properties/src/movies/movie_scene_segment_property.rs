@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 
 /// Movie scene segment identifier
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSegmentIdentifier {
     /// Identifier index
     pub identifier_index: i32,
@@ -28,6 +29,7 @@ impl MovieSceneSegmentIdentifier {
 
 /// Movie scene segment
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSegment {
     /// Name
     pub name: FName,
@@ -55,9 +57,7 @@ impl MovieSceneSegment {
         let allow_empty = asset.read_i32::<LE>()? != 0;
 
         let impls_length = asset.read_i32::<LE>()?;
-        let mut impls = Vec::with_capacity(impls_length as usize);
-
-        for _ in 0..impls_length {
+        let impls = asset.read_array_with_length(impls_length, |asset| {
             let mut properties_list = Vec::new();
             let mut unversioned_header = UnversionedHeader::new(asset)?;
             while let Some(property) =
@@ -65,9 +65,8 @@ impl MovieSceneSegment {
             {
                 properties_list.push(property);
             }
-
-            impls.push(properties_list);
-        }
+            Ok(properties_list)
+        })?;
 
         Ok(MovieSceneSegment {
             name,
@@ -115,8 +114,15 @@ impl MovieSceneSegment {
     }
 }
 
+impl PackageIndexContainer for MovieSceneSegment {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.impls.traverse_package_indices(traverse);
+    }
+}
+
 /// Movie scene segment property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSegmentProperty {
     /// Name
     pub name: FName,
@@ -155,6 +161,12 @@ impl MovieSceneSegmentProperty {
     }
 }
 
+impl PackageIndexContainer for MovieSceneSegmentProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for MovieSceneSegmentProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -173,6 +185,7 @@ impl PropertyTrait for MovieSceneSegmentProperty {
 
 /// Movie scene segment identifier property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSegmentIdentifierProperty {
     /// Name
     pub name: FName,
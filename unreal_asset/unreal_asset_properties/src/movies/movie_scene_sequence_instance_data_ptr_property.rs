@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Movie scene sequence instance data pointer property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSequenceInstanceDataPtrProperty {
     /// Name
     pub name: FName,
@@ -42,6 +43,12 @@ impl MovieSceneSequenceInstanceDataPtrProperty {
     }
 }
 
+impl PackageIndexContainer for MovieSceneSequenceInstanceDataPtrProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for MovieSceneSequenceInstanceDataPtrProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
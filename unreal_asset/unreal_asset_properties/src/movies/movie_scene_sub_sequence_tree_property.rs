@@ -9,6 +9,7 @@ use super::{
 
 /// Movie scene sub sequence tree entry
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSubSequenceTreeEntry {
     /// Sequence id
     pub sequence_id: MovieSceneSequenceId,
@@ -36,6 +37,7 @@ impl MovieSceneSubSequenceTreeEntry {
 
 /// Movie scene sub sequence tree
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSubSequenceTree {
     /// Tree data
     pub data: TMovieSceneEvaluationTree<MovieSceneSubSequenceTreeEntry>,
@@ -64,6 +66,7 @@ impl MovieSceneSubSequenceTree {
 
 /// Movie scene sub sequence tree property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneSubSequenceTreeProperty {
     /// Name
     pub name: FName,
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Movie scene track implementation pointer property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovieSceneTrackImplementationPtrProperty {
     /// Name
     pub name: FName,
@@ -64,6 +65,12 @@ impl MovieSceneTrackImplementationPtrProperty {
     }
 }
 
+impl PackageIndexContainer for MovieSceneTrackImplementationPtrProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for MovieSceneTrackImplementationPtrProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -6,6 +6,7 @@ use super::movie_scene_evaluation::TMovieSceneEvaluationTree;
 
 /// Section evaluation tree
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionEvaluationTree {
     /// Evaluation tree
     pub tree: TMovieSceneEvaluationTree<Vec<Property>>,
@@ -65,8 +66,15 @@ impl SectionEvaluationTree {
     }
 }
 
+impl PackageIndexContainer for SectionEvaluationTree {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.tree.traverse_package_indices(traverse);
+    }
+}
+
 /// Section evaluation data tree property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionEvaluationDataTreeProperty {
     /// Name
     pub name: FName,
@@ -104,6 +112,12 @@ impl SectionEvaluationDataTreeProperty {
     }
 }
 
+impl PackageIndexContainer for SectionEvaluationDataTreeProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for SectionEvaluationDataTreeProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Niagara variable property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NiagaraVariableProperty {
     /// Base struct property
     pub struct_property: StructProperty,
@@ -82,6 +83,12 @@ impl PropertyDataTrait for NiagaraVariableProperty {
     }
 }
 
+impl PackageIndexContainer for NiagaraVariableProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.struct_property.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for NiagaraVariableProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -123,6 +130,7 @@ impl PropertyTrait for NiagaraVariableProperty {
 
 /// Niagara variable with offset property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NiagaraVariableWithOffsetProperty {
     /// Variable
     pub niagara_variable: NiagaraVariableProperty,
@@ -177,6 +185,12 @@ impl PropertyDataTrait for NiagaraVariableWithOffsetProperty {
     }
 }
 
+impl PackageIndexContainer for NiagaraVariableWithOffsetProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.niagara_variable.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for NiagaraVariableWithOffsetProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
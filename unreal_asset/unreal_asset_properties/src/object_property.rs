@@ -1,11 +1,12 @@
 //! Object properties
 
-use unreal_asset_base::object_version::ObjectVersionUE5;
+pub use unreal_asset_base::types::soft_object_path::{SoftObjectPath, TopLevelAssetPath};
 
 use crate::property_prelude::*;
 
 /// Object property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectProperty {
     /// Name
     pub name: FName,
@@ -23,6 +24,7 @@ impl_property_data_trait!(ObjectProperty);
 
 /// Asset object property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssetObjectProperty {
     /// Name
     pub name: FName,
@@ -37,92 +39,9 @@ pub struct AssetObjectProperty {
 }
 impl_property_data_trait!(AssetObjectProperty);
 
-/// Top level asset path
-#[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
-pub struct TopLevelAssetPath {
-    /// Package name that contains the asset e.g. /Some/Path/Package
-    /// Only present in 5.1 and higher
-    pub package_name: Option<FName>,
-    /// If 5.1 and higher contains the name of the asset within the package
-    /// If less than 5.1 contians the full path to the asset
-    pub asset_name: FName,
-}
-
-impl TopLevelAssetPath {
-    /// Create a new `TopLevelAssetPath` instance
-    pub fn new(package_name: Option<FName>, asset_name: FName) -> Self {
-        TopLevelAssetPath {
-            package_name,
-            asset_name,
-        }
-    }
-
-    /// Read a `TopLevelAssetPath` from an asset
-    pub fn read<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
-        let package_name = match asset.get_object_version_ue5()
-            >= ObjectVersionUE5::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES
-        {
-            true => Some(asset.read_fname()?),
-            false => None,
-        };
-        let asset_name = asset.read_fname()?;
-
-        Ok(TopLevelAssetPath {
-            package_name,
-            asset_name,
-        })
-    }
-
-    /// Write a `TopLevelAssetPath` to an asset
-    pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
-        if asset.get_object_version_ue5()
-            >= ObjectVersionUE5::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES
-        {
-            let Some(package_name) = self.package_name.as_ref() else {
-                return Err(Error::no_data("ObjectVersionUE5 is >= FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES, but package_name is None".to_string()));
-            };
-
-            asset.write_fname(package_name)?;
-        }
-
-        asset.write_fname(&self.asset_name)?;
-
-        Ok(())
-    }
-}
-
-/// Soft object path
-#[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
-pub struct SoftObjectPath {
-    /// Asset path
-    pub asset_path: TopLevelAssetPath,
-    /// Sub path string
-    pub sub_path_string: Option<String>,
-}
-
-impl SoftObjectPath {
-    /// Read a `SoftObjectPath` from an asset
-    pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
-        let asset_path = TopLevelAssetPath::read(asset)?;
-        let sub_path_string = asset.read_fstring()?;
-
-        Ok(SoftObjectPath {
-            asset_path,
-            sub_path_string,
-        })
-    }
-
-    /// Write a `SoftObjectPath` to an asset
-    pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
-        self.asset_path.write(asset)?;
-        asset.write_fstring(self.sub_path_string.as_deref())?;
-
-        Ok(())
-    }
-}
-
 /// Soft object property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SoftObjectProperty {
     /// Name
     pub name: FName,
@@ -158,6 +77,12 @@ impl ObjectProperty {
     }
 }
 
+impl PackageIndexContainer for ObjectProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for ObjectProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
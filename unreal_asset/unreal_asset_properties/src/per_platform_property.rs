@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Per platform bool property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerPlatformBoolProperty {
     /// Name
     pub name: FName,
@@ -20,6 +21,7 @@ impl_property_data_trait!(PerPlatformBoolProperty);
 
 /// Per platform int property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerPlatformIntProperty {
     /// Name
     pub name: FName,
@@ -36,6 +38,7 @@ impl_property_data_trait!(PerPlatformIntProperty);
 
 /// Per platform float property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerPlatformFloatProperty {
     /// Name
     pub name: FName,
@@ -63,11 +66,7 @@ impl PerPlatformBoolProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(num_entries as usize);
-
-        for _i in 0..num_entries as usize {
-            value.push(asset.read_bool()?);
-        }
+        let value = asset.read_array_with_length(num_entries, |asset| Ok(asset.read_bool()?))?;
 
         Ok(PerPlatformBoolProperty {
             name,
@@ -107,11 +106,8 @@ impl PerPlatformIntProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(num_entries as usize);
-
-        for _i in 0..num_entries as usize {
-            value.push(asset.read_i32::<LE>()?);
-        }
+        let value =
+            asset.read_array_with_length(num_entries, |asset| Ok(asset.read_i32::<LE>()?))?;
 
         Ok(PerPlatformIntProperty {
             name,
@@ -151,11 +147,9 @@ impl PerPlatformFloatProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(num_entries as usize);
-
-        for _i in 0..num_entries as usize {
-            value.push(OrderedFloat(asset.read_f32::<LE>()?));
-        }
+        let value = asset.read_array_with_length(num_entries, |asset| {
+            Ok(OrderedFloat(asset.read_f32::<LE>()?))
+        })?;
 
         Ok(PerPlatformFloatProperty {
             name,
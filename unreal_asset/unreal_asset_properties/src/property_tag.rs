@@ -0,0 +1,84 @@
+//! Serialized property tag
+
+use crate::property_prelude::*;
+
+/// Serialized property tag
+///
+/// This is the header written before every versioned property
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyTag {
+    /// Property name
+    pub name: FName,
+    /// Property type
+    pub property_type: FName,
+    /// Declared length of the property value
+    pub length: i32,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Struct type if this is a `StructProperty`
+    pub struct_type: Option<FName>,
+    /// Element type if this is an `ArrayProperty` or a `SetProperty`
+    pub inner_type: Option<FName>,
+}
+
+impl PropertyTag {
+    /// Read a `PropertyTag` from an asset
+    ///
+    /// Returns `None` when the terminating `None` tag is read.
+    ///
+    /// After this call the reader is positioned at the start of the property value,
+    /// seeking forward by `length` bytes skips it.
+    pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Option<Self>, Error> {
+        if asset.has_unversioned_properties() {
+            return Err(Error::invalid_file(
+                "Unversioned properties don't have property tags".to_string(),
+            ));
+        }
+
+        let name = asset.read_fname()?;
+        if name == "None" {
+            return Ok(None);
+        }
+
+        let property_type = asset.read_fname()?;
+        let length = asset.read_i32::<LE>()?;
+        let duplication_index = asset.read_i32::<LE>()?;
+
+        // type specific tag data
+        let extra_names = property_type.get_content(|ty| match ty {
+            "StructProperty" | "ByteProperty" | "EnumProperty" | "ArrayProperty"
+            | "SetProperty" => 1,
+            "MapProperty" => 2,
+            _ => 0,
+        });
+        let mut type_names = Vec::with_capacity(extra_names);
+        for _ in 0..extra_names {
+            type_names.push(asset.read_fname()?);
+        }
+
+        let mut struct_type = None;
+        let mut inner_type = None;
+        if property_type == "ArrayProperty" || property_type == "SetProperty" {
+            inner_type = type_names.pop();
+        }
+        if property_type == "StructProperty" {
+            struct_type = type_names.pop();
+            if asset.get_object_version() >= ObjectVersion::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG {
+                asset.read_guid()?;
+            }
+        }
+        if property_type == "BoolProperty" {
+            asset.read_bool()?;
+        }
+        asset.read_property_guid()?;
+
+        Ok(Some(PropertyTag {
+            name,
+            property_type,
+            length,
+            duplication_index,
+            struct_type,
+            inner_type,
+        }))
+    }
+}
@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Raw struct property
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawStructProperty {
     /// Name
     pub name: FName,
@@ -14,6 +15,7 @@ pub struct RawStructProperty {
     /// Property duplication index
     pub duplication_index: i32,
     /// Raw data
+    #[cfg_attr(feature = "serde", serde(with = "unreal_asset_base::serde_base64"))]
     pub value: Vec<u8>,
 }
 impl_property_data_trait!(RawStructProperty);
@@ -30,8 +32,7 @@ impl RawStructProperty {
     ) -> Result<Self, Error> {
         let property_guid = optional_guid!(asset, include_header);
 
-        let mut value = vec![0u8; length as usize];
-        asset.read_exact(&mut value)?;
+        let value = asset.read_bytes(length as u64)?;
 
         Ok(RawStructProperty {
             name,
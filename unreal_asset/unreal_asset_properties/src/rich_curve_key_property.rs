@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Rich curve extrapolation
 #[derive(Debug, IntoPrimitive, TryFromPrimitive, Hash, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum RichCurveExtrapolation {
     /// Cycle
@@ -24,6 +25,7 @@ pub enum RichCurveExtrapolation {
 
 /// Rich curve interpolation mode
 #[derive(Debug, IntoPrimitive, TryFromPrimitive, Hash, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i8)]
 pub enum RichCurveInterpMode {
     /// Linear
@@ -38,6 +40,7 @@ pub enum RichCurveInterpMode {
 
 /// Rich curve tangent mode
 #[derive(Debug, IntoPrimitive, TryFromPrimitive, Hash, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i8)]
 pub enum RichCurveTangentMode {
     /// Auto
@@ -52,6 +55,7 @@ pub enum RichCurveTangentMode {
 
 /// Rich curve tangent weight mode
 #[derive(Debug, IntoPrimitive, TryFromPrimitive, Hash, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i8)]
 pub enum RichCurveTangentWeightMode {
     /// None
@@ -66,6 +70,7 @@ pub enum RichCurveTangentWeightMode {
 
 /// Rich curve key property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RichCurveKeyProperty {
     /// Name
     pub name: FName,
@@ -1,10 +1,11 @@
 //! Set property
 
-use crate::array_property::ArrayProperty;
+use crate::array_property::{serialized_array_type, ArrayProperty};
 use crate::property_prelude::*;
 
 /// Set property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetProperty {
     /// Name
     pub name: FName,
@@ -14,7 +15,9 @@ pub struct SetProperty {
     pub property_guid: Option<Guid>,
     /// Property duplication index
     pub duplication_index: i32,
-    /// Array type
+    /// Set element type
+    ///
+    /// When set, this type is written as is, otherwise it's taken from the first element
     pub array_type: Option<FName>,
     /// Set values
     pub value: ArrayProperty,
@@ -74,19 +77,20 @@ impl SetProperty {
     }
 }
 
+impl PackageIndexContainer for SetProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+        self.removed_items.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for SetProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
         asset: &mut Writer,
         include_header: bool,
     ) -> Result<usize, Error> {
-        let array_type = match !self.value.value.is_empty() {
-            true => {
-                let value = self.value.value[0].to_serialized_name();
-                Some(asset.get_name_map().get_mut().add_fname(&value))
-            }
-            false => self.array_type.clone(),
-        };
+        let array_type = serialized_array_type(asset, self.array_type.as_ref(), &self.value.value)?;
 
         if include_header {
             asset.write_fname(array_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 
 /// Font hinting
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum EFontHinting {
     /// Use the default hinting specified in the font.
@@ -22,6 +23,7 @@ pub enum EFontHinting {
 
 /// Font loading policy
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum EFontLoadingPolicy {
     /// Lazy load the entire font into memory. This will consume more memory than Streaming, however there will be zero file-IO when rendering glyphs within the font, although the initial load may cause a hitch.
@@ -34,6 +36,7 @@ pub enum EFontLoadingPolicy {
 
 /// Font data
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontData {
     /// UObject
     #[container_ignore]
@@ -121,8 +124,16 @@ impl FontData {
     }
 }
 
+impl PackageIndexContainer for FontData {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.local_font_face_asset
+            .traverse_package_indices(traverse);
+    }
+}
+
 /// Font data property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontDataProperty {
     /// Name
     pub name: FName,
@@ -161,6 +172,12 @@ impl FontDataProperty {
     }
 }
 
+impl PackageIndexContainer for FontDataProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for FontDataProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 
 /// Smart name property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SmartNameProperty {
     /// Name
     pub name: FName,
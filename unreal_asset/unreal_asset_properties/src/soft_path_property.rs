@@ -1,9 +1,12 @@
 //! Soft path properties
 
+use unreal_asset_base::types::soft_object_path::split_path;
+
 use crate::property_prelude::*;
 
 /// Soft path property value
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SoftObjectPathPropertyValue {
     /// asset.get_object_version() < ObjectVersion::VER_UE4_ADDED_SOFT_OBJECT_PATH
     Old(Option<String>),
@@ -20,6 +23,28 @@ impl SoftObjectPathPropertyValue {
         }
     }
 
+    /// Get the name of the package containing the asset, see [`SoftObjectPath::package_name`]
+    pub fn package_name(&self) -> Option<FName> {
+        match self {
+            Self::Old(path) => path
+                .as_deref()
+                .and_then(|path| split_path(path).0)
+                .map(FName::from_display),
+            Self::New(path) => path.package_name(),
+        }
+    }
+
+    /// Get the name of the asset within its package, see [`SoftObjectPath::asset_name`]
+    pub fn asset_name(&self) -> Option<FName> {
+        match self {
+            Self::Old(path) => path
+                .as_deref()
+                .and_then(|path| split_path(path).1)
+                .map(FName::from_display),
+            Self::New(path) => path.asset_name(),
+        }
+    }
+
     /// Write `SoftObjectPathPropertyValue` to an asset
     pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         match self {
@@ -37,6 +62,7 @@ impl SoftObjectPathPropertyValue {
 
 /// Soft asset path property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SoftAssetPathProperty {
     /// Name
     pub name: FName,
@@ -53,6 +79,7 @@ impl_property_data_trait!(SoftAssetPathProperty);
 
 /// Soft object path property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SoftObjectPathProperty {
     /// Name
     pub name: FName,
@@ -69,6 +96,7 @@ impl_property_data_trait!(SoftObjectPathProperty);
 
 /// Soft class path property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SoftClassPathProperty {
     /// Name
     pub name: FName,
@@ -85,6 +113,7 @@ impl_property_data_trait!(SoftClassPathProperty);
 
 /// String asset reference property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringAssetReferenceProperty {
     /// Name
     pub name: FName,
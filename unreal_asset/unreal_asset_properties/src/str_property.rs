@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 #[derive(
     FNameContainer, Debug, Hash, Copy, Clone, PartialEq, Eq, IntoPrimitive, TryFromPrimitive,
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i8)]
 pub enum TextHistoryType {
     /// None
@@ -46,8 +47,114 @@ impl Default for TextHistoryType {
     }
 }
 
+/// Text transform type
+#[derive(
+    FNameContainer, Debug, Hash, Copy, Clone, PartialEq, Eq, IntoPrimitive, TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum TransformType {
+    /// To lowercase
+    ToLower = 0,
+    /// To uppercase
+    ToUpper,
+}
+
+/// Format argument type
+#[derive(
+    FNameContainer, Debug, Hash, Copy, Clone, PartialEq, Eq, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(i8)]
+pub enum FormatArgumentType {
+    /// Int
+    Int = 0,
+    /// UInt
+    UInt,
+    /// Float
+    Float,
+    /// Double
+    Double,
+    /// Text
+    Text,
+    /// Gender
+    Gender,
+}
+
+/// Format argument value
+#[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[container_nobounds]
+pub enum FormatArgumentValue {
+    /// Int
+    Int(i64),
+    /// UInt
+    UInt(u64),
+    /// Float
+    Float(OrderedFloat<f32>),
+    /// Double
+    Double(OrderedFloat<f64>),
+    /// Text
+    Text(Box<TextProperty>),
+    /// Gender
+    Gender(u8),
+}
+
+impl FormatArgumentValue {
+    /// Read a `FormatArgumentValue` from an asset
+    pub fn new<Reader: ArchiveReader>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+    ) -> Result<Self, Error> {
+        let argument_type = FormatArgumentType::try_from(asset.read_i8()?)?;
+        Ok(match argument_type {
+            FormatArgumentType::Int => FormatArgumentValue::Int(asset.read_i64::<LE>()?),
+            FormatArgumentType::UInt => FormatArgumentValue::UInt(asset.read_u64::<LE>()?),
+            FormatArgumentType::Float => {
+                FormatArgumentValue::Float(OrderedFloat(asset.read_f32::<LE>()?))
+            }
+            FormatArgumentType::Double => {
+                FormatArgumentValue::Double(OrderedFloat(asset.read_f64::<LE>()?))
+            }
+            FormatArgumentType::Text => {
+                FormatArgumentValue::Text(TextProperty::read_nested(asset, name, ancestry)?)
+            }
+            FormatArgumentType::Gender => FormatArgumentValue::Gender(asset.read_u8()?),
+        })
+    }
+
+    /// Get the type of this `FormatArgumentValue`
+    pub fn get_type(&self) -> FormatArgumentType {
+        match self {
+            FormatArgumentValue::Int(_) => FormatArgumentType::Int,
+            FormatArgumentValue::UInt(_) => FormatArgumentType::UInt,
+            FormatArgumentValue::Float(_) => FormatArgumentType::Float,
+            FormatArgumentValue::Double(_) => FormatArgumentType::Double,
+            FormatArgumentValue::Text(_) => FormatArgumentType::Text,
+            FormatArgumentValue::Gender(_) => FormatArgumentType::Gender,
+        }
+    }
+
+    /// Write a `FormatArgumentValue` to an asset
+    pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
+        asset.write_i8(self.get_type().into())?;
+        match self {
+            FormatArgumentValue::Int(value) => asset.write_i64::<LE>(*value)?,
+            FormatArgumentValue::UInt(value) => asset.write_u64::<LE>(*value)?,
+            FormatArgumentValue::Float(value) => asset.write_f32::<LE>(value.0)?,
+            FormatArgumentValue::Double(value) => asset.write_f64::<LE>(value.0)?,
+            FormatArgumentValue::Text(value) => {
+                value.write(asset, false)?;
+            }
+            FormatArgumentValue::Gender(value) => asset.write_u8(*value)?,
+        }
+        Ok(())
+    }
+}
+
 /// String property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StrProperty {
     /// Name
     pub name: FName,
@@ -64,6 +171,8 @@ impl_property_data_trait!(StrProperty);
 
 /// Text property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[container_nobounds]
 pub struct TextProperty {
     /// Name
     pub name: FName,
@@ -83,6 +192,12 @@ pub struct TextProperty {
     pub flags: u32,
     /// History type
     pub history_type: TextHistoryType,
+    /// Source text for `Transform` history, format text for `OrderedFormat` history
+    pub source_text: Option<Box<TextProperty>>,
+    /// Transform type for `Transform` history
+    pub transform_type: Option<TransformType>,
+    /// Format arguments for `OrderedFormat` history
+    pub arguments: Option<Vec<FormatArgumentValue>>,
     /// FString value
     pub value: Option<String>,
 }
@@ -90,6 +205,7 @@ impl_property_data_trait!(TextProperty);
 
 /// Name property
 #[derive(FNameContainer, Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NameProperty {
     /// Name
     pub name: FName,
@@ -169,6 +285,9 @@ impl TextProperty {
         let flags = asset.read_u32::<LE>()?;
         let mut history_type = TextHistoryType::Base;
         let mut table_id = None;
+        let mut source_text = None;
+        let mut transform_type = None;
+        let mut arguments = None;
         if asset.get_object_version() >= ObjectVersion::VER_UE4_FTEXT_HISTORY {
             history_type = TextHistoryType::try_from(asset.read_i8()?)?;
 
@@ -195,6 +314,31 @@ impl TextProperty {
                     table_id = Some(asset.read_fname()?);
                     value = asset.read_fstring()?;
                 }
+                TextHistoryType::Transform => {
+                    source_text = Some(TextProperty::read_nested(
+                        asset,
+                        name.clone(),
+                        ancestry.clone(),
+                    )?);
+                    transform_type = Some(TransformType::try_from(asset.read_u8()?)?);
+                }
+                TextHistoryType::OrderedFormat => {
+                    source_text = Some(TextProperty::read_nested(
+                        asset,
+                        name.clone(),
+                        ancestry.clone(),
+                    )?);
+
+                    let arguments_count = asset.read_i32::<LE>()?;
+                    if arguments_count < 0 {
+                        return Err(Error::invalid_file(format!(
+                            "Invalid format argument count {arguments_count}"
+                        )));
+                    }
+                    arguments = Some(asset.read_array_with_length(arguments_count, |asset| {
+                        FormatArgumentValue::new(asset, name.clone(), ancestry.clone())
+                    })?);
+                }
                 _ => {
                     return Err(Error::unimplemented(format!(
                         "Unimplemented reader for {history_type:?}"
@@ -213,9 +357,27 @@ impl TextProperty {
             table_id,
             flags,
             history_type,
+            source_text,
+            transform_type,
+            arguments,
             value,
         })
     }
+
+    /// Read a `TextProperty` nested in another one
+    ///
+    /// Fails if the text is nested deeper than the reader allows,
+    /// see [`ArchiveReader::enter_property`]
+    fn read_nested<Reader: ArchiveReader>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+    ) -> Result<Box<Self>, Error> {
+        asset.enter_property()?;
+        let text = TextProperty::new(asset, name, ancestry, false, 0);
+        asset.leave_property();
+        Ok(Box::new(text?))
+    }
 }
 
 impl PropertyTrait for TextProperty {
@@ -276,6 +438,33 @@ impl PropertyTrait for TextProperty {
                     asset.write_fstring(self.value.as_deref())?;
                     Ok(())
                 }
+                TextHistoryType::Transform => {
+                    let source_text = self.source_text.as_ref().ok_or_else(|| {
+                        PropertyError::property_field_none("source_text", "TextProperty")
+                    })?;
+                    source_text.write(asset, false)?;
+
+                    let transform_type = self.transform_type.ok_or_else(|| {
+                        PropertyError::property_field_none("transform_type", "TransformType")
+                    })?;
+                    asset.write_u8(transform_type.into())?;
+                    Ok(())
+                }
+                TextHistoryType::OrderedFormat => {
+                    let source_text = self.source_text.as_ref().ok_or_else(|| {
+                        PropertyError::property_field_none("source_text", "TextProperty")
+                    })?;
+                    source_text.write(asset, false)?;
+
+                    let arguments = self.arguments.as_ref().ok_or_else(|| {
+                        PropertyError::property_field_none("arguments", "Vec<FormatArgumentValue>")
+                    })?;
+                    asset.write_i32::<LE>(arguments.len() as i32)?;
+                    for argument in arguments {
+                        argument.write(asset)?;
+                    }
+                    Ok(())
+                }
                 _ => Err(Error::unimplemented(format!(
                     "Unimplemented writer for {}",
                     history_type as i8
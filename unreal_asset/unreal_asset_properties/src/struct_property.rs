@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// Struct property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructProperty {
     /// Name
     pub name: FName,
@@ -103,6 +104,21 @@ impl StructProperty {
             return name.get_content(|name| Err(PropertyError::no_type(name, &ancestry).into()));
         }
 
+        // zero-length structs have no data to detect the serialization from,
+        // keep the struct type from the header so that writing can reproduce it
+        if length == 0 {
+            return Ok(StructProperty {
+                name,
+                ancestry,
+                struct_type,
+                struct_guid,
+                property_guid,
+                duplication_index,
+                serialize_none: false,
+                value: Vec::new(),
+            });
+        }
+
         let mut custom_serialization = match struct_type {
             Some(ref e) => e.get_content(Property::has_custom_serialization),
             None => false,
@@ -166,18 +182,12 @@ impl StructProperty {
                 Ok::<(), Error>(())
             })?;
 
-        if length == 0 {
-            return Ok(StructProperty {
-                name,
-                ancestry,
-                struct_type,
-                struct_guid,
-                property_guid,
-                duplication_index,
-                serialize_none: false,
-                value: Vec::new(),
-            });
-        }
+        let native_struct_layout = match custom_serialization {
+            true => None,
+            false => struct_type
+                .as_ref()
+                .and_then(|e| e.get_content(|ty| asset.get_native_struct_layout(ty).cloned())),
+        };
 
         if custom_serialization {
             let new_ancestry = ancestry.with_parent(name.clone());
@@ -204,6 +214,33 @@ impl StructProperty {
                 serialize_none: true,
                 value,
             })
+        } else if let Some(native_struct_layout) = native_struct_layout {
+            let new_ancestry = ancestry.with_parent(struct_type.clone().unwrap());
+            let mut values = Vec::with_capacity(native_struct_layout.fields.len());
+            for field in &native_struct_layout.fields {
+                values.push(Property::from_type(
+                    asset,
+                    &FName::new_dummy(field.property_type.clone(), 0),
+                    FName::new_dummy(field.name.clone(), 0),
+                    new_ancestry.clone(),
+                    false,
+                    0,
+                    0,
+                    0,
+                    false,
+                )?);
+            }
+
+            Ok(StructProperty {
+                name,
+                ancestry,
+                struct_type,
+                struct_guid,
+                property_guid,
+                duplication_index,
+                serialize_none: true,
+                value: values,
+            })
         } else {
             let mut values = Vec::new();
             let mut unversioned_header = UnversionedHeader::new(asset)?;
@@ -283,7 +320,9 @@ impl StructProperty {
             }
         }
 
-        if has_custom_serialization {
+        if self.value.is_empty() && !self.serialize_none {
+            Ok(0)
+        } else if has_custom_serialization {
             if self.value.len() != 1 {
                 return Err(PropertyError::invalid_struct(
                     struct_type
@@ -295,8 +334,28 @@ impl StructProperty {
                 .into());
             }
             self.value[0].write(asset, false)
-        } else if self.value.is_empty() && !self.serialize_none {
-            Ok(0)
+        } else if let Some(native_struct_layout) = struct_type
+            .as_ref()
+            .and_then(|e| e.get_content(|ty| asset.get_native_struct_layout(ty).cloned()))
+        {
+            if self.value.len() != native_struct_layout.fields.len() {
+                return Err(
+                    PropertyError::invalid_struct(struct_type.unwrap().get_content(|e| {
+                        format!(
+                            "Structs with native layout {} must have exactly {} entries",
+                            e,
+                            native_struct_layout.fields.len()
+                        )
+                    }))
+                    .into(),
+                );
+            }
+
+            let mut size = 0;
+            for entry in &self.value {
+                size += entry.write(asset, false)?;
+            }
+            Ok(size)
         } else {
             let begin = asset.position();
 
@@ -326,6 +385,12 @@ impl StructProperty {
     }
 }
 
+impl PackageIndexContainer for StructProperty {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.value.traverse_package_indices(traverse);
+    }
+}
+
 impl PropertyTrait for StructProperty {
     fn write<Writer: ArchiveWriter>(
         &self,
@@ -6,6 +6,7 @@ use crate::property_prelude::*;
 ///
 /// This gets created when an unknown property was encountered while deserializing
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnknownProperty {
     /// Name
     pub name: FName,
@@ -16,6 +17,7 @@ pub struct UnknownProperty {
     /// Property duplication index
     pub duplication_index: i32,
     /// Raw data
+    #[cfg_attr(feature = "serde", serde(with = "unreal_asset_base::serde_base64"))]
     pub value: Vec<u8>,
     /// Serialized type
     pub serialized_type: FName,
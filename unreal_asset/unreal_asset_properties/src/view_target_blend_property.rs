@@ -4,6 +4,7 @@ use crate::property_prelude::*;
 
 /// View target blend function
 #[derive(Debug, IntoPrimitive, TryFromPrimitive, Hash, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ViewTargetBlendFunction {
     /// Camera does a simple linear interpolation.
@@ -22,6 +23,7 @@ pub enum ViewTargetBlendFunction {
 
 /// View target blend params property
 #[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewTargetBlendParamsProperty {
     /// Name
     pub name: FName,
@@ -1,5 +1,6 @@
 //! World tile properties
 
+use unreal_asset_base::reader::ArchiveTrait;
 use unreal_asset_base::types::vector::Vector;
 
 use crate::property_prelude::*;
@@ -8,6 +9,7 @@ use crate::vector_property::{BoxProperty, IntPointProperty};
 //todo: what is this file even doing in properties?
 /// World tile layer
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FWorldTileLayer {
     /// Name
     pub name: Option<String>,
@@ -86,6 +88,7 @@ impl FWorldTileLayer {
 
 /// World tile lod info
 #[derive(FNameContainer, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FWorldTileLODInfo {
     /// Relative streaming distance
     pub relative_streaming_distance: i32,
@@ -124,6 +127,7 @@ impl FWorldTileLODInfo {
 
 /// World tile ifno
 #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FWorldTileInfo {
     /// Position
     #[container_ignore]
@@ -144,20 +148,45 @@ pub struct FWorldTileInfo {
 }
 
 impl FWorldTileInfo {
+    /// Check if the tile position is serialized with a z component
+    ///
+    /// Assets that don't register `FFortniteMainBranchObjectVersion` predate
+    /// the 3D offset and always use the 2D layout
+    fn has_3d_offset<Archive: ArchiveTrait>(asset: &Archive) -> bool {
+        asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::WorldCompositionTile3DOffset as i32
+    }
+
+    /// Get the minimum corner of the tile bounds
+    pub fn get_bounds_min(&self) -> &Vector<OrderedFloat<f64>> {
+        &self.bounds.v1.value
+    }
+
+    /// Get the maximum corner of the tile bounds
+    pub fn get_bounds_max(&self) -> &Vector<OrderedFloat<f64>> {
+        &self.bounds.v2.value
+    }
+
+    /// Set the tile bounds and mark them as valid
+    pub fn set_bounds(&mut self, min: Vector<OrderedFloat<f64>>, max: Vector<OrderedFloat<f64>>) {
+        self.bounds.v1.value = min;
+        self.bounds.v2.value = max;
+        self.bounds.is_valid = true;
+    }
+
     /// Read `FWorldTileInfo` from an asset
     pub fn new<Reader: ArchiveReader>(asset: &mut Reader) -> Result<Self, Error> {
-        let version = asset.get_custom_version::<FFortniteMainBranchObjectVersion>();
         let object_version = asset.get_object_version();
 
-        let position = match version.version
-            < FFortniteMainBranchObjectVersion::WorldCompositionTile3DOffset as i32
-        {
-            true => Vector::new(asset.read_i32::<LE>()?, asset.read_i32::<LE>()?, 0),
-            false => Vector::new(
+        let position = match Self::has_3d_offset(asset) {
+            true => Vector::new(
                 asset.read_i32::<LE>()?,
                 asset.read_i32::<LE>()?,
                 asset.read_i32::<LE>()?,
             ),
+            false => Vector::new(asset.read_i32::<LE>()?, asset.read_i32::<LE>()?, 0),
         };
 
         let new_ancestry = Ancestry::new(asset.get_parent_class_export_name().unwrap_or_default());
@@ -201,16 +230,9 @@ impl FWorldTileInfo {
     pub fn write<Writer: ArchiveWriter>(&self, asset: &mut Writer) -> Result<(), Error> {
         let object_version = asset.get_object_version();
 
-        if asset
-            .get_custom_version::<FFortniteMainBranchObjectVersion>()
-            .version
-            < FFortniteMainBranchObjectVersion::WorldCompositionTile3DOffset as i32
-        {
-            asset.write_i32::<LE>(self.position.x)?;
-            asset.write_i32::<LE>(self.position.y)?;
-        } else {
-            asset.write_i32::<LE>(self.position.x)?;
-            asset.write_i32::<LE>(self.position.y)?;
+        asset.write_i32::<LE>(self.position.x)?;
+        asset.write_i32::<LE>(self.position.y)?;
+        if Self::has_3d_offset(asset) {
             asset.write_i32::<LE>(self.position.z)?;
         }
 
@@ -9,7 +9,10 @@ use unreal_asset_base::{
     engine_version::EngineVersion,
     object_version::{ObjectVersion, ObjectVersionUE5},
     reader::{ArchiveReader, ArchiveTrait, ArchiveType, PassthroughArchiveReader},
-    types::{FName, PackageIndex},
+    types::{
+        native_struct_layout::NativeStructLayout, object_data_resource::FObjectDataResource,
+        soft_object_path::SoftObjectPath, FName, PackageIndex,
+    },
     unversioned::Usmap,
     Error, Import,
 };
@@ -78,6 +81,14 @@ impl<'reader, Reader: ArchiveReader> ArchiveTrait for NameTableReader<'reader, R
         self.reader.use_event_driven_loader()
     }
 
+    fn retain_raw_bytecode(&self) -> bool {
+        self.reader.retain_raw_bytecode()
+    }
+
+    fn get_package_offset(&self) -> u64 {
+        self.reader.get_package_offset()
+    }
+
     fn position(&mut self) -> u64 {
         self.reader.position()
     }
@@ -102,6 +113,22 @@ impl<'reader, Reader: ArchiveReader> ArchiveTrait for NameTableReader<'reader, R
         self.reader.get_map_value_override()
     }
 
+    fn get_native_struct_layout(&self, struct_type: &str) -> Option<&NativeStructLayout> {
+        self.reader.get_native_struct_layout(struct_type)
+    }
+
+    fn get_soft_object_path_list(&self) -> &[SoftObjectPath] {
+        self.reader.get_soft_object_path_list()
+    }
+
+    fn get_soft_object_path_index(&self, path: &SoftObjectPath) -> Option<usize> {
+        self.reader.get_soft_object_path_index(path)
+    }
+
+    fn get_data_resources(&self) -> &[FObjectDataResource] {
+        self.reader.get_data_resources()
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.reader.get_engine_version()
     }
@@ -7,7 +7,10 @@ use unreal_asset_base::{
     engine_version::EngineVersion,
     object_version::{ObjectVersion, ObjectVersionUE5},
     reader::{ArchiveTrait, ArchiveType, ArchiveWriter, PassthroughArchiveWriter},
-    types::{FName, PackageIndex},
+    types::{
+        native_struct_layout::NativeStructLayout, object_data_resource::FObjectDataResource,
+        soft_object_path::SoftObjectPath, FName, PackageIndex,
+    },
     unversioned::Usmap,
     Import,
 };
@@ -49,6 +52,10 @@ impl<'writer, Writer: ArchiveWriter> ArchiveTrait for NameTableWriter<'writer, W
         self.writer.use_event_driven_loader()
     }
 
+    fn get_package_offset(&self) -> u64 {
+        self.writer.get_package_offset()
+    }
+
     fn position(&mut self) -> u64 {
         self.writer.position()
     }
@@ -73,6 +80,22 @@ impl<'writer, Writer: ArchiveWriter> ArchiveTrait for NameTableWriter<'writer, W
         self.writer.get_map_value_override()
     }
 
+    fn get_native_struct_layout(&self, struct_type: &str) -> Option<&NativeStructLayout> {
+        self.writer.get_native_struct_layout(struct_type)
+    }
+
+    fn get_soft_object_path_list(&self) -> &[SoftObjectPath] {
+        self.writer.get_soft_object_path_list()
+    }
+
+    fn get_soft_object_path_index(&self, path: &SoftObjectPath) -> Option<usize> {
+        self.writer.get_soft_object_path_index(path)
+    }
+
+    fn get_data_resources(&self) -> &[FObjectDataResource] {
+        self.writer.get_data_resources()
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.writer.get_engine_version()
     }
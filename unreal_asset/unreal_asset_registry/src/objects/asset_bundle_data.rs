@@ -1,8 +1,11 @@
 //! Asset registry bundle d ata
 
+use std::collections::HashMap;
+
 use byteorder::{WriteBytesExt, LE};
 
 use unreal_asset_base::{
+    error::RegistryError,
     reader::{ArchiveReader, ArchiveWriter},
     types::FName,
     unversioned::Ancestry,
@@ -96,3 +99,245 @@ impl AssetBundleData {
         Self { bundles }
     }
 }
+
+/// Asset registry tag that stores `FAssetBundleData` as exported text
+pub const ASSET_BUNDLE_DATA_TAG: &str = "AssetBundleData";
+
+/// Value of exported struct text
+#[derive(Debug)]
+enum TextValue {
+    /// Plain or quoted string
+    String(String),
+    /// Struct or array, struct fields have a name
+    List(Vec<(Option<String>, TextValue)>),
+}
+
+impl TextValue {
+    /// Get a struct field by name
+    fn get_field(&self, name: &str) -> Option<&TextValue> {
+        match self {
+            TextValue::List(entries) => entries
+                .iter()
+                .find(|(key, _)| key.as_deref() == Some(name))
+                .map(|(_, value)| value),
+            TextValue::String(_) => None,
+        }
+    }
+
+    /// Get a string value
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            TextValue::String(value) => Some(value),
+            TextValue::List(_) => None,
+        }
+    }
+}
+
+/// Maximum nesting depth of exported text, bundle data nests a few levels deep
+const MAX_TEXT_DEPTH: usize = 64;
+
+/// Parser for text exported with `UScriptStruct::ExportText`
+struct TextParser<'a> {
+    /// Text
+    text: &'a str,
+    /// Current position
+    position: usize,
+    /// Current nesting depth
+    depth: usize,
+}
+
+impl<'a> TextParser<'a> {
+    /// Create an error for the current position
+    fn error(&self, msg: &str) -> Error {
+        RegistryError::other(format!(
+            "Invalid exported text at {}: {}, text: {}",
+            self.position, msg, self.text
+        ))
+        .into()
+    }
+
+    /// Peek the next character, skipping whitespace
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.text[self.position..];
+        let trimmed = rest.trim_start();
+        self.position += rest.len() - trimmed.len();
+        trimmed.chars().next()
+    }
+
+    /// Consume an expected character
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        match self.peek() {
+            Some(e) if e == expected => {
+                self.position += expected.len_utf8();
+                Ok(())
+            }
+            _ => Err(self.error(&format!("expected {}", expected))),
+        }
+    }
+
+    /// Parse a quoted or a plain string
+    fn parse_string(&mut self) -> Result<String, Error> {
+        if self.peek() != Some('"') {
+            let rest = &self.text[self.position..];
+            let len = rest.find([',', '(', ')', '=']).unwrap_or(rest.len());
+            self.position += len;
+            return Ok(rest[..len].trim_end().to_string());
+        }
+
+        self.position += 1;
+        let mut value = String::new();
+        let mut chars = self.text[self.position..].chars();
+        while let Some(c) = chars.next() {
+            self.position += c.len_utf8();
+            match c {
+                '"' => return Ok(value),
+                '\\' => {
+                    let escaped = chars
+                        .next()
+                        .ok_or_else(|| self.error("unterminated escape"))?;
+                    self.position += escaped.len_utf8();
+                    value.push(escaped);
+                }
+                c => value.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    /// Parse a value
+    fn parse_value(&mut self) -> Result<TextValue, Error> {
+        if self.peek() != Some('(') {
+            return Ok(TextValue::String(self.parse_string()?));
+        }
+
+        if self.depth >= MAX_TEXT_DEPTH {
+            return Err(self.error("max nesting depth exceeded"));
+        }
+        self.depth += 1;
+        let value = self.parse_list();
+        self.depth -= 1;
+        value
+    }
+
+    /// Parse a parenthesized list of values
+    fn parse_list(&mut self) -> Result<TextValue, Error> {
+        self.position += 1;
+        let mut entries = Vec::new();
+        if self.peek() == Some(')') {
+            self.position += 1;
+            return Ok(TextValue::List(entries));
+        }
+        loop {
+            let entry = match self.peek() {
+                Some('(') => (None, self.parse_value()?),
+                _ => {
+                    let value = self.parse_string()?;
+                    match self.peek() {
+                        Some('=') => {
+                            self.position += 1;
+                            (Some(value), self.parse_value()?)
+                        }
+                        _ => (None, TextValue::String(value)),
+                    }
+                }
+            };
+            entries.push(entry);
+
+            match self.peek() {
+                Some(',') => self.position += 1,
+                _ => {
+                    self.expect(')')?;
+                    return Ok(TextValue::List(entries));
+                }
+            }
+        }
+    }
+}
+
+/// Get an object path from an exported `FSoftObjectPath` or `FTopLevelAssetPath`
+fn exported_path(value: &TextValue) -> Option<String> {
+    if let Some(path) = value.as_str() {
+        return Some(path.to_string());
+    }
+
+    // 4.x soft object paths
+    if let Some(path) = value.get_field("AssetPathName").and_then(TextValue::as_str) {
+        let sub_path = value
+            .get_field("SubPathString")
+            .and_then(TextValue::as_str)
+            .unwrap_or_default();
+        return Some(match sub_path.is_empty() {
+            true => path.to_string(),
+            false => format!("{}:{}", path, sub_path),
+        });
+    }
+
+    // 5.x soft object paths wrap a top level asset path
+    if let Some(asset_path) = value.get_field("AssetPath") {
+        let path = exported_path(asset_path)?;
+        let sub_path = value
+            .get_field("SubPathString")
+            .and_then(TextValue::as_str)
+            .unwrap_or_default();
+        return Some(match sub_path.is_empty() {
+            true => path,
+            false => format!("{}:{}", path, sub_path),
+        });
+    }
+
+    let package_name = value.get_field("PackageName")?.as_str()?;
+    let asset_name = value.get_field("AssetName")?.as_str()?;
+    Some(match asset_name.is_empty() || asset_name == "None" {
+        true => package_name.to_string(),
+        false => format!("{}.{}", package_name, asset_name),
+    })
+}
+
+/// Parse the exported text of `FAssetBundleData` stored in the [`ASSET_BUNDLE_DATA_TAG`] asset registry tag
+///
+/// Returns the asset paths of every bundle keyed by bundle name
+pub fn parse_asset_bundle_tag(value: &str) -> Result<HashMap<String, Vec<String>>, Error> {
+    let mut parser = TextParser {
+        text: value,
+        position: 0,
+        depth: 0,
+    };
+    let data = parser.parse_value()?;
+    if parser.peek().is_some() {
+        return Err(parser.error("trailing data"));
+    }
+
+    let mut bundles = HashMap::new();
+    let Some(TextValue::List(entries)) = data.get_field("Bundles") else {
+        return Ok(bundles);
+    };
+
+    for (_, bundle) in entries {
+        let bundle_name = bundle
+            .get_field("BundleName")
+            .and_then(TextValue::as_str)
+            .ok_or_else(|| parser.error("bundle without a name"))?;
+
+        // bundles store soft object paths before 5.1 and top level asset paths after
+        let paths = match bundle
+            .get_field("BundleAssets")
+            .or_else(|| bundle.get_field("AssetPaths"))
+        {
+            Some(TextValue::List(paths)) => paths
+                .iter()
+                .map(|(_, path)| {
+                    exported_path(path).ok_or_else(|| parser.error("invalid bundle asset path"))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(TextValue::String(_)) => return Err(parser.error("invalid bundle assets")),
+            None => Vec::new(),
+        };
+
+        bundles
+            .entry(bundle_name.to_string())
+            .or_insert_with(Vec::new)
+            .extend(paths);
+    }
+
+    Ok(bundles)
+}
@@ -1,5 +1,7 @@
 //! Asset bundle asset data
 
+use std::collections::HashMap;
+
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
@@ -11,7 +13,9 @@ use unreal_asset_base::{
     Error,
 };
 
-use crate::objects::asset_bundle_data::AssetBundleData;
+use crate::objects::asset_bundle_data::{
+    parse_asset_bundle_tag, AssetBundleData, ASSET_BUNDLE_DATA_TAG,
+};
 
 /// Top level asset path
 #[derive(Clone, Debug)]
@@ -92,7 +96,7 @@ impl AssetData {
         tags_and_values: &IndexedMap<FName, Option<String>>,
     ) -> Result<(), Error> {
         asset.write_i32::<LE>(tags_and_values.len() as i32)?;
-        for (_, key, value) in tags_and_values {
+        for (key, value) in tags_and_values.iter_key_value() {
             asset.write_fname(key)?;
             asset.write_fstring(value.as_deref())?;
         }
@@ -167,6 +171,18 @@ impl AssetData {
         }
     }
 
+    /// Get the asset paths of every asset bundle keyed by bundle name
+    ///
+    /// Parsed from the [`ASSET_BUNDLE_DATA_TAG`] tag, returns `None` if the tag isn't present
+    pub fn get_asset_bundles(&self) -> Result<Option<HashMap<String, Vec<String>>>, Error> {
+        self.tags_and_values
+            .iter_key_value()
+            .find(|(key, _)| **key == ASSET_BUNDLE_DATA_TAG)
+            .and_then(|(_, value)| value.as_deref())
+            .map(parse_asset_bundle_tag)
+            .transpose()
+    }
+
     /// Write `AssetData` to an asset
     pub fn write<Writer: ArchiveWriter>(&self, writer: &mut Writer) -> Result<(), Error> {
         writer.write_fname(&self.object_path)?;